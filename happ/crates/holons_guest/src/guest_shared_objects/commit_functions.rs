@@ -1,7 +1,7 @@
 use hdk::prelude::*;
 use std::sync::{Arc, RwLock};
 
-use crate::persistence_layer::{persist_holon, put_smartlink};
+use crate::persistence_layer::{index_under_descriptor_instances, persist_holon, put_smartlink};
 use core_types::{HolonWriteRequest, PreparedSmartLink, PutSmartLinkOutcome};
 
 use holons_core::{
@@ -47,10 +47,13 @@ type RelationshipCollectionSnapshot = Vec<(RelationshipName, Arc<RwLock<HolonCol
 ///
 /// New creates and version-producing updates commit to a new node id. Graph-only
 /// updates commit to the existing persisted source id carried by the staged holon.
+///
+/// `begins_lineage` is true only for new creates, whose committed id is a lineage root.
 struct RelationshipCommitSource {
     source_local_id: LocalId,
     source_key: Option<MapString>,
     source_reference: HolonReference,
+    begins_lineage: bool,
 }
 
 impl RelationshipCommitSource {
@@ -63,6 +66,7 @@ impl RelationshipCommitSource {
                 source_local_id: local_id,
                 source_key: staged_holon.key(),
                 source_reference: staged_reference.into(),
+                begins_lineage: staged_holon.versioned_source_id_ref().is_none(),
             }),
             _ => None,
         }
@@ -681,6 +685,15 @@ fn save_smartlinks_for_collection(
         persist_smartlink(inverse_smartlink)?;
     }
 
+    // A new lineage joins the instance index of each descriptor it is DescribedBy.
+    // Versions inherit their root's membership, mirroring the AllHolonNodes index.
+    if source.begins_lineage && name == CoreRelationshipTypeName::DescribedBy.as_relationship_name()
+    {
+        for resolved_target in &resolved_targets {
+            index_under_descriptor_instances(&resolved_target.target_local_id, source_id)?;
+        }
+    }
+
     Ok(())
 }
 
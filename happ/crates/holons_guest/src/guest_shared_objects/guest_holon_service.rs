@@ -20,10 +20,11 @@ use crate::get_holon_by_path;
 use crate::guest_shared_objects::commit_functions;
 use crate::persistence_layer::{
     delete_holon_node, expand_all_from_source, expand_from_source, get_all_holon_ids, get_holon,
-    index_local_holon_space, persist_holon, saved_holon_from_stored,
+    get_holon_ids_by_descriptor, get_holon_ids_by_key, index_local_holon_space, persist_holon,
+    saved_holon_from_stored,
 };
 use base_types::MapString;
-use core_types::{HolonError, HolonId, HolonWriteRequest, SmartLink};
+use core_types::{HolonError, HolonId, HolonWriteRequest, KeyMatch, SmartLink};
 use holons_core::core_shared_objects::transactions::TransactionContextHandle;
 use holons_core::{
     core_shared_objects::{transactions::TransactionContext, Holon, HolonCollection},
@@ -296,6 +297,37 @@ impl HolonServiceApi for GuestHolonService {
         Ok(collection)
     }
 
+    fn get_holons_by_descriptor_internal(
+        &self,
+        context: &Arc<TransactionContext>,
+        descriptor_id: &HolonId,
+    ) -> Result<HolonCollection, HolonError> {
+        let descriptor_local_id = Self::ensure_id_is_local(descriptor_id)?;
+        let mut collection = HolonCollection::new_existing();
+        let mut holon_references = Vec::new();
+        for id in get_holon_ids_by_descriptor(&descriptor_local_id)? {
+            holon_references.push(self.mint_smart_reference(context, id, None)?);
+        }
+        collection.add_references(holon_references)?;
+
+        Ok(collection)
+    }
+
+    fn get_saved_holons_by_key_internal(
+        &self,
+        context: &Arc<TransactionContext>,
+        key_match: &KeyMatch,
+    ) -> Result<HolonCollection, HolonError> {
+        let mut collection = HolonCollection::new_existing();
+        let mut holon_references = Vec::new();
+        for id in get_holon_ids_by_key(key_match)? {
+            holon_references.push(self.mint_smart_reference(context, id, None)?);
+        }
+        collection.add_references(holon_references)?;
+
+        Ok(collection)
+    }
+
     /// Execute a Holon import from a `HolonLoadSet`.
     /// Delegates to the `HolonLoaderController` and returns a transient `HolonLoadResponse`.
     fn load_holons_internal(
@@ -0,0 +1,79 @@
+use crate::persistence_layer::get_holon;
+use core_types::{HolonError, HolonId};
+use hdk::prelude::*;
+use holons_guest_integrity::type_conversions::*;
+use holons_integrity::*;
+use integrity_core_types::LocalId;
+
+/// Adds a newly created lineage root to the instance index of the descriptor it is
+/// `DescribedBy`.
+///
+/// The index is anchored at the lineage root of the descriptor's node, so enumerating one
+/// type reads one link set rather than the whole-space `AllHolonNodes` index, and instances
+/// described by any version of a descriptor share one index. Like `AllHolonNodes` it holds
+/// one entry per instance lineage root: versions and graph-only updates are not indexed again.
+///
+/// Commit's relationship pass can be retried after a partial failure, so an existing link
+/// for the same instance is treated as already indexed.
+pub fn index_under_descriptor_instances(
+    descriptor_id: &LocalId,
+    instance_id: &LocalId,
+) -> Result<(), HolonError> {
+    let base = try_action_hash_from_local_id(&descriptor_lineage_root(descriptor_id)?)?;
+    let target = try_action_hash_from_local_id(instance_id)?;
+
+    let query = LinkQuery::try_new(base.clone(), LinkTypes::DescriptorInstances)
+        .map_err(holon_error_from_wasm_error)?;
+    let existing = get_links(query, GetStrategy::Local).map_err(holon_error_from_wasm_error)?;
+    let target_address: AnyLinkableHash = target.clone().into();
+    if existing.iter().any(|link| link.target == target_address) {
+        return Ok(());
+    }
+
+    create_link(base, target, LinkTypes::DescriptorInstances, ())
+        .map_err(holon_error_from_wasm_error)?;
+
+    Ok(())
+}
+
+/// Get the `HolonId` of every HolonNode described by `descriptor_id`, one per lineage.
+///
+/// Results are lineage roots, with the same caveats as `get_all_holon_ids`. `descriptor_id`
+/// may name any version of the descriptor: holons whose lineage began `DescribedBy` any
+/// version in the same descriptor lineage are returned.
+///
+/// Only lineages committed since the index was introduced are linked; the index is not
+/// backfilled, so instances persisted before then are reachable through
+/// `get_all_holon_ids` but not here.
+// `GetStrategy::default()` performs a network fetch.
+pub fn get_holon_ids_by_descriptor(descriptor_id: &LocalId) -> Result<Vec<HolonId>, HolonError> {
+    let base = try_action_hash_from_local_id(&descriptor_lineage_root(descriptor_id)?)?;
+    let query = LinkQuery::try_new(base, LinkTypes::DescriptorInstances)
+        .map_err(holon_error_from_wasm_error)?;
+    let links = get_links(query, GetStrategy::default()).map_err(holon_error_from_wasm_error)?;
+    info!("Retrieved {:?} descriptor instance links, converting to HolonIds..", links.len());
+
+    let mut holon_ids = Vec::with_capacity(links.len());
+    for link in links {
+        let holon_id = HolonId::Local(local_id_from_action_hash(
+            link.target.clone().into_action_hash().ok_or(HolonError::HashConversion(
+                "Target".to_string(),
+                "ActionHash".to_string(),
+            ))?,
+        ));
+        holon_ids.push(holon_id);
+    }
+
+    Ok(holon_ids)
+}
+
+/// Resolves a descriptor version to the lineage root its instance index is anchored at.
+///
+/// Lineage comes from the descriptor's record, as it does for `ActionEvent.affected_holon`,
+/// so re-versioning a descriptor does not move its index.
+fn descriptor_lineage_root(descriptor_id: &LocalId) -> Result<LocalId, HolonError> {
+    let stored = get_holon(descriptor_id)?.ok_or_else(|| {
+        HolonError::HolonNotFound(format!("Descriptor {:?} has no persisted record", descriptor_id))
+    })?;
+    Ok(stored.version_metadata.lineage_root().into_local_id())
+}
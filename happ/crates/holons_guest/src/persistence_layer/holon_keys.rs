@@ -0,0 +1,201 @@
+//! Space-wide key index for saved holons.
+//!
+//! Each lineage root with a `Key` property is linked from the canonical `holon_keys` path, tagged
+//! with its key (see `holon_key_tag`). Keys are produced at staging by the descriptor's effective
+//! key rule, so holons whose rule is keyless carry no `Key` and are never indexed.
+//!
+//! Like `AllHolonNodes`, the index records lineage roots only. When a version changes its key,
+//! the root's link for the previous key is deleted and one for the new key is added, so a lineage
+//! is found under the key of its latest version and no longer under the key it began with.
+//! Lookups resolve each hit to that latest version, so the holon returned carries the key it was
+//! found under rather than the root's.
+
+use base_types::BaseValue;
+use core_types::{CanonicalKey, HolonError, HolonId, KeyMatch};
+use hdk::prelude::*;
+use holons_guest_integrity::{
+    holon_key_from_tag, holon_key_tag, type_conversions::*, HOLON_KEYS_PATH,
+};
+use holons_integrity::LinkTypes;
+use integrity_core_types::HolonNodeModel;
+use type_names::CorePropertyTypeName;
+
+/// Returns the key a new lineage root is indexed under, or `None` for a keyless holon.
+///
+/// Called before the root is authored so an unindexable key fails the write instead of leaving
+/// a persisted holon missing from the index.
+pub fn root_index_key(holon_node: &HolonNodeModel) -> Result<Option<CanonicalKey>, HolonError> {
+    match holon_node.property_map.get(&CorePropertyTypeName::Key.as_property_name()) {
+        Some(BaseValue::StringValue(key)) if !key.0.is_empty() => {
+            Ok(Some(CanonicalKey::new(key.0.clone())?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Adds a new lineage root to the space-wide key index.
+pub fn index_under_holon_keys(
+    action_hash: &ActionHash,
+    key: &CanonicalKey,
+) -> Result<(), HolonError> {
+    let base =
+        Path::from(HOLON_KEYS_PATH).path_entry_hash().map_err(holon_error_from_wasm_error)?;
+
+    create_link(base, action_hash.clone(), LinkTypes::HolonKeys, holon_key_tag(key.as_str()))
+        .map_err(holon_error_from_wasm_error)?;
+
+    Ok(())
+}
+
+/// Moves a lineage root's key index entry after a new version is published.
+///
+/// Links for `previous_keys` that target `lineage_root` are deleted unless the key is unchanged,
+/// and a link for `key` is added unless one already exists. A keyless version leaves the lineage
+/// unindexed. Integrity lets only a link's author delete it, so links another agent authored are
+/// left in place; they still resolve to the lineage's current version.
+pub fn reindex_holon_keys(
+    lineage_root: &ActionHash,
+    previous_keys: &[CanonicalKey],
+    key: Option<&CanonicalKey>,
+) -> Result<(), HolonError> {
+    let base =
+        Path::from(HOLON_KEYS_PATH).path_entry_hash().map_err(holon_error_from_wasm_error)?;
+    let target: AnyLinkableHash = lineage_root.clone().into();
+    let agent = agent_info().map_err(holon_error_from_wasm_error)?.agent_initial_pubkey;
+
+    for previous in previous_keys {
+        if Some(previous) == key {
+            continue;
+        }
+        for link in exact_key_links(&base, previous)? {
+            if link.target == target && link.author == agent {
+                delete_link(link.create_link_hash, GetOptions::default())
+                    .map_err(holon_error_from_wasm_error)?;
+            }
+        }
+    }
+
+    if let Some(key) = key {
+        if !exact_key_links(&base, key)?.iter().any(|link| link.target == target) {
+            create_link(
+                base,
+                lineage_root.clone(),
+                LinkTypes::HolonKeys,
+                holon_key_tag(key.as_str()),
+            )
+            .map_err(holon_error_from_wasm_error)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn exact_key_links(base: &EntryHash, key: &CanonicalKey) -> Result<Vec<Link>, HolonError> {
+    let query = LinkQuery::try_new(base.clone(), LinkTypes::HolonKeys)
+        .map_err(holon_error_from_wasm_error)?
+        .tag_prefix(holon_key_tag(key.as_str()));
+    get_links(query, GetStrategy::Local).map_err(holon_error_from_wasm_error)
+}
+
+/// Get the `HolonId` of the current version of every lineage whose indexed key satisfies
+/// `key_match`.
+///
+/// `StartsWith("")` is rejected with `HolonError::InvalidParameter`, as it is for SmartLink key
+/// queries. Tags that do not decode as keys are skipped; integrity validation rejects them, so
+/// they can only come from peers running other validation rules.
+// `GetStrategy::default()` performs a network fetch.
+pub fn get_holon_ids_by_key(key_match: &KeyMatch) -> Result<Vec<HolonId>, HolonError> {
+    let prefix = match key_match {
+        KeyMatch::Exact(key) => holon_key_tag(key.as_str()),
+        KeyMatch::StartsWith(prefix) => {
+            if prefix.as_str().is_empty() {
+                return Err(HolonError::InvalidParameter(
+                    "get_holon_ids_by_key: StartsWith(\"\") is not a valid key prefix".to_string(),
+                ));
+            }
+            LinkTag::new(prefix.as_str().as_bytes().to_vec())
+        }
+    };
+    let base =
+        Path::from(HOLON_KEYS_PATH).path_entry_hash().map_err(holon_error_from_wasm_error)?;
+    let query = LinkQuery::try_new(base, LinkTypes::HolonKeys)
+        .map_err(holon_error_from_wasm_error)?
+        .tag_prefix(prefix);
+    let links = get_links(query, GetStrategy::default()).map_err(holon_error_from_wasm_error)?;
+    info!("Retrieved {:?} links for 'holon_keys' path, converting to HolonIds..", links.len());
+
+    let mut holon_ids = Vec::with_capacity(links.len());
+    for link in links {
+        if holon_key_from_tag(&link.tag).is_none() {
+            warn!("Skipping HolonKeys link with a malformed key tag: {:?}", link.create_link_hash);
+            continue;
+        }
+        let lineage_root = link
+            .target
+            .clone()
+            .into_action_hash()
+            .ok_or(HolonError::HashConversion("Target".to_string(), "ActionHash".to_string()))?;
+        let holon_id = HolonId::Local(local_id_from_action_hash(current_version(lineage_root)?));
+        // A lineage indexed under several matching keys is still one result.
+        if !holon_ids.contains(&holon_id) {
+            holon_ids.push(holon_id);
+        }
+    }
+
+    Ok(holon_ids)
+}
+
+/// Returns the latest version of the lineage rooted at `lineage_root`.
+///
+/// Versions are authored as updates addressed at the root, so the latest is the root's most
+/// recent update by timestamp, as `reindex_holon_keys` last moved the index entry for it; a
+/// lineage with no updates is its own current version.
+fn current_version(lineage_root: ActionHash) -> Result<ActionHash, HolonError> {
+    match get_details(lineage_root.clone(), GetOptions::default())
+        .map_err(holon_error_from_wasm_error)?
+    {
+        Some(Details::Record(details)) => Ok(details
+            .updates
+            .iter()
+            .max_by_key(|update| update.action().timestamp())
+            .map(|update| update.action_address().clone())
+            .unwrap_or(lineage_root)),
+        Some(Details::Entry(_)) => Err(HolonError::RecordConversion(format!(
+            "Lineage root {:?} of a HolonKeys link returned entry details",
+            lineage_root
+        ))),
+        None => Err(HolonError::HolonNotFound(format!(
+            "Lineage root {:?} of a HolonKeys link",
+            lineage_root
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base_types::MapString;
+    use integrity_core_types::PropertyMap;
+
+    fn model_with_key(key: &str) -> HolonNodeModel {
+        HolonNodeModel::new(PropertyMap::from([(
+            CorePropertyTypeName::Key.as_property_name(),
+            BaseValue::StringValue(MapString(key.to_string())),
+        )]))
+    }
+
+    #[test]
+    fn root_index_key_reads_the_key_property_and_skips_keyless_holons() {
+        assert_eq!(
+            root_index_key(&model_with_key("Book.HolonType")).unwrap().unwrap().as_str(),
+            "Book.HolonType"
+        );
+        assert_eq!(root_index_key(&model_with_key("")).unwrap(), None);
+        assert_eq!(root_index_key(&HolonNodeModel::new(PropertyMap::new())).unwrap(), None);
+    }
+
+    #[test]
+    fn root_index_key_rejects_keys_the_tag_codec_cannot_terminate() {
+        assert!(root_index_key(&model_with_key("Bo\0ok")).is_err());
+    }
+}
@@ -13,9 +13,11 @@
 //! exact read ambiguous.
 //!
 //! One deliberate non-decision, recorded so it is not mistaken for an oversight: `PublishRoot`
-//! indexes the new holon under `AllHolonNodes`; `PublishVersion` does not. The lineage root is
+//! indexes the new holon under `AllHolonNodes` and, when it has a key, under `HolonKeys`;
+//! `PublishVersion` adds no new index entry. The lineage root is
 //! already indexed, and one index entry per version would make a get-all return every version of
-//! every holon as a separate top-level result.
+//! every holon as a separate top-level result. It does move the root's `HolonKeys` entry when the
+//! version changes its key, so key lookups follow the lineage's current key.
 //!
 //! Versions are authored as native root-addressed Holochain updates and nothing else: Holochain's
 //! own update graph already records that an update happened, so there is no parallel link index
@@ -32,6 +34,10 @@ use holons_integrity::{EntryTypes, LinkTypes};
 use integrity_core_types::{short_hex, HolonNodeModel, LocalId};
 use shared_validation::{validate_holon_node_decoded, validate_holon_node_size};
 
+use crate::persistence_layer::holon_keys::{
+    index_under_holon_keys, reindex_holon_keys, root_index_key,
+};
+
 // ---------------------------------------------------------------------------
 // Read: exact-version retrieval
 // ---------------------------------------------------------------------------
@@ -123,12 +129,16 @@ pub fn persist_holon(request: HolonWriteRequest) -> Result<StoredHolonNode, Holo
     match request {
         HolonWriteRequest::PublishRoot { holon_node } => {
             preflight_holon_node(&holon_node)?;
+            let index_key = root_index_key(&holon_node)?;
 
             let action_hash =
                 create_entry(&EntryTypes::HolonNode(HolonNode::from(holon_node.clone())))
                     .map_err(holon_error_from_wasm_error)?;
 
             index_under_all_holon_nodes(&action_hash)?;
+            if let Some(key) = &index_key {
+                index_under_holon_keys(&action_hash, key)?;
+            }
 
             let version_metadata = VersionMetadata::root(local_id_from_action_hash(action_hash));
             debug!(
@@ -141,16 +151,25 @@ pub fn persist_holon(request: HolonWriteRequest) -> Result<StoredHolonNode, Holo
 
         HolonWriteRequest::PublishVersion { holon_node, predecessor_ids } => {
             preflight_holon_node(&holon_node)?;
+            let index_key = root_index_key(&holon_node)?;
 
-            let lineage_id = resolve_lineage_root_for_predecessors(&predecessor_ids)?;
+            let (lineage_id, predecessors) =
+                resolve_lineage_root_for_predecessors(&predecessor_ids)?;
             let root_hash = try_action_hash_from_local_id(lineage_id.as_local_id())?;
 
             let action_hash = update_entry(
-                root_hash,
+                root_hash.clone(),
                 &EntryTypes::HolonNode(HolonNode::from(holon_node.clone())),
             )
             .map_err(holon_error_from_wasm_error)?;
 
+            // The lineage stays indexed under its root, but under the key this version carries.
+            let previous_keys = predecessors
+                .iter()
+                .filter_map(|predecessor| root_index_key(&predecessor.holon_node).ok().flatten())
+                .collect::<Vec<_>>();
+            reindex_holon_keys(&root_hash, &previous_keys, index_key.as_ref())?;
+
             let version_metadata = VersionMetadata::derived(
                 local_id_from_action_hash(action_hash),
                 lineage_id.clone(),
@@ -290,32 +309,39 @@ fn classify_record(record: &Record) -> Result<RecordClassification, HolonError>
     })
 }
 
-/// Loads each predecessor and returns the single lineage root they share.
+/// Loads each predecessor and returns the single lineage root they share, with the loaded
+/// predecessors.
 ///
 /// Predecessors are loaded through `get_holons` so they pass the same decoding rule as any
 /// other read: a predecessor that is not a holon node fails here, with a storage-layer message,
-/// rather than being caught later and more opaquely by integrity validation.
+/// rather than being caught later and more opaquely by integrity validation. They are returned
+/// so the key index can retire the keys they were indexed under.
 fn resolve_lineage_root_for_predecessors(
     predecessor_ids: &[LocalId],
-) -> Result<LineageId, HolonError> {
+) -> Result<(LineageId, Vec<StoredHolonNode>), HolonError> {
     let slots = get_holons(predecessor_ids)?;
 
     let predecessors = predecessor_ids
         .iter()
         .zip(slots)
         .map(|(local_id, slot)| {
-            slot.map(|stored| stored.version_metadata).ok_or_else(|| {
+            slot.ok_or_else(|| {
                 HolonError::HolonNotFound(format!(
                     "Predecessor {:?} is not persisted, so no lineage can be resolved for it",
                     local_id
                 ))
             })
         })
-        .collect::<Result<Vec<VersionMetadata>, HolonError>>()?;
+        .collect::<Result<Vec<StoredHolonNode>, HolonError>>()?;
 
-    resolve_shared_lineage_root(&predecessors).inspect_err(|error| {
+    let version_metadata = predecessors
+        .iter()
+        .map(|stored| stored.version_metadata.clone())
+        .collect::<Vec<VersionMetadata>>();
+    let lineage_id = resolve_shared_lineage_root(&version_metadata).inspect_err(|error| {
         warn!("persist_holon: PublishVersion rejected — {}", error);
-    })
+    })?;
+    Ok((lineage_id, predecessors))
 }
 
 /// Adds the new holon to the space-wide holon index.
@@ -10,8 +10,9 @@
 //! Deliberately-invalid authoring seams live in the separately built `holons_test_probes` zome so
 //! they cannot become part of the packaged production coordinator surface.
 
+use crate::persistence_layer::holon_keys::get_holon_ids_by_key;
 use crate::persistence_layer::holon_storage::{get_holon, get_holons, persist_holon};
-use core_types::{HolonError, HolonWriteRequest, StoredHolonNode};
+use core_types::{HolonError, HolonId, HolonWriteRequest, KeyMatch, StoredHolonNode};
 use hdk::prelude::*;
 use integrity_core_types::LocalId;
 
@@ -35,3 +36,8 @@ pub fn holon_storage_get_many(
 pub fn holon_storage_persist(request: HolonWriteRequest) -> ExternResult<StoredHolonNode> {
     persist_holon(request).map_err(to_wasm)
 }
+
+#[hdk_extern]
+pub fn holon_storage_get_ids_by_key(key_match: KeyMatch) -> ExternResult<Vec<HolonId>> {
+    get_holon_ids_by_key(&key_match).map_err(to_wasm)
+}
@@ -1,6 +1,8 @@
 //! This module re-exports all public functions from `holon_node.rs`,
 //! which is auto-generated by the Holochain scaffolding tool.
 pub mod all_holon_nodes;
+pub mod descriptor_instances;
+pub mod holon_keys;
 pub mod holon_node;
 pub mod holon_storage;
 pub mod holon_storage_externs;
@@ -10,6 +12,8 @@ pub mod smartlink;
 pub mod smartlink_externs;
 
 pub use all_holon_nodes::*;
+pub use descriptor_instances::*;
+pub use holon_keys::*;
 pub use holon_node::*;
 pub use holon_storage::*;
 pub use holon_storage_externs::*;
@@ -127,7 +127,10 @@ fn run_holon_node_envelope(raw: &[u8]) -> ExternResult<Result<HolonNodeModel, Pv
 mod tests {
     use std::collections::BTreeMap;
 
-    use base_types::{BaseValue, MapBoolean, MapBytes, MapEnumValue, MapInteger, MapString};
+    use base_types::{
+        BaseValue, MapBoolean, MapBytes, MapDate, MapDecimal, MapEnumValue, MapInteger, MapRecord,
+        MapString, MapTimestamp, MapValueArray,
+    };
     use holochain_serialized_bytes::UnsafeBytes;
     use integrity_core_types::{PropertyMap, PropertyName};
     use serde::ser::{SerializeMap, SerializeStruct};
@@ -346,6 +349,23 @@ mod tests {
             (property_name("a-string"), BaseValue::StringValue(MapString("value".into()))),
             (property_name("m-bool"), BaseValue::BooleanValue(MapBoolean(true))),
             (property_name("c-int"), BaseValue::IntegerValue(MapInteger(-42))),
+            (property_name("d-decimal"), BaseValue::DecimalValue(MapDecimal::new(-1999, 2))),
+            (property_name("t-timestamp"), BaseValue::TimestampValue(MapTimestamp::MAX)),
+            (property_name("f-date"), BaseValue::DateValue(MapDate::MIN)),
+            (
+                property_name("g-tags"),
+                BaseValue::ValueArray(MapValueArray(vec![
+                    BaseValue::StringValue(MapString("alpha".into())),
+                    BaseValue::StringValue(MapString("beta".into())),
+                ])),
+            ),
+            (
+                property_name("h-price"),
+                BaseValue::Record(MapRecord::from_iter([
+                    (MapString("Amount".into()), BaseValue::DecimalValue(MapDecimal::new(950, 2))),
+                    (MapString("Currency".into()), BaseValue::StringValue(MapString("EUR".into()))),
+                ])),
+            ),
             (
                 property_name("e-enum"),
                 BaseValue::EnumValue(MapEnumValue(MapString("Active".into()))),
@@ -11,7 +11,7 @@ use hdi::prelude::*;
 use shared_validation::{TargetActionKind, TargetEntryKind};
 
 use crate::action_target::classify_target;
-use crate::holon_node::{ALL_HOLON_NODES_PATH, LOCAL_HOLON_SPACE_PATH};
+use crate::holon_node::{ALL_HOLON_NODES_PATH, HOLON_KEYS_PATH, LOCAL_HOLON_SPACE_PATH};
 use crate::holon_node_envelope::HOLON_NODE_ENTRY_DEF_INDEX;
 
 /// A completed fixed-policy verdict for an infrastructure link.
@@ -21,10 +21,14 @@ use crate::holon_node_envelope::HOLON_NODE_ENTRY_DEF_INDEX;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InfrastructureLinkRejection {
     NonCanonicalBase { link_name: &'static str, expected_path: &'static str },
+    NonActionBase { link_name: &'static str },
     NonEmptyTag { link_name: &'static str },
+    MalformedKeyTag { link_name: &'static str },
     NonActionTarget { link_name: &'static str },
     NonRootHolonNodeTarget { link_name: &'static str },
     AllHolonNodesDelete,
+    DescriptorInstancesDelete,
+    HolonKeysDeleteByOtherAuthor,
 }
 
 impl fmt::Display for InfrastructureLinkRejection {
@@ -34,9 +38,16 @@ impl fmt::Display for InfrastructureLinkRejection {
                 formatter,
                 "{link_name} links must use the canonical `{expected_path}` path base"
             ),
+            Self::NonActionBase { link_name } => {
+                write!(formatter, "{link_name} links must use a descriptor ActionHash base")
+            }
             Self::NonEmptyTag { link_name } => {
                 write!(formatter, "{link_name} links must use an empty tag")
             }
+            Self::MalformedKeyTag { link_name } => write!(
+                formatter,
+                "{link_name} links must tag a non-empty UTF-8 key followed by one NUL terminator"
+            ),
             Self::NonActionTarget { link_name } => {
                 write!(formatter, "{link_name} links must target an ActionHash")
             }
@@ -47,6 +58,12 @@ impl fmt::Display for InfrastructureLinkRejection {
             Self::AllHolonNodesDelete => {
                 formatter.write_str("AllHolonNodes links cannot be deleted")
             }
+            Self::DescriptorInstancesDelete => {
+                formatter.write_str("DescriptorInstances links cannot be deleted")
+            }
+            Self::HolonKeysDeleteByOtherAuthor => {
+                formatter.write_str("HolonKeys links can only be deleted by their author")
+            }
         }
     }
 }
@@ -79,6 +96,96 @@ pub fn validate_all_holon_nodes_delete(
     Ok(Err(InfrastructureLinkRejection::AllHolonNodesDelete))
 }
 
+/// Validates a per-descriptor index link from a descriptor lineage root to one instance root.
+///
+/// The descriptor itself is the anchor, so the base only has to be an action address; resolving
+/// it would add a second dependency without making the index more authoritative than the
+/// `DescribedBy` SmartLink it mirrors. Targets follow the whole-space index: lineage roots only.
+pub fn validate_descriptor_instances_create(
+    base_address: &AnyLinkableHash,
+    target_address: &AnyLinkableHash,
+    tag: &LinkTag,
+) -> ExternResult<Result<(), InfrastructureLinkRejection>> {
+    let link_name = "DescriptorInstances";
+    if base_address.clone().into_action_hash().is_none() {
+        return Ok(Err(InfrastructureLinkRejection::NonActionBase { link_name }));
+    }
+    if !tag.0.is_empty() {
+        return Ok(Err(InfrastructureLinkRejection::NonEmptyTag { link_name }));
+    }
+    validate_root_holon_node_target(link_name, target_address)
+}
+
+pub fn validate_descriptor_instances_delete(
+    _original_action: &CreateLink,
+) -> ExternResult<Result<(), InfrastructureLinkRejection>> {
+    // Like the whole-space index, per-descriptor membership is append-only until deleted
+    // lineages are retired from storage indexes as a whole.
+    Ok(Err(InfrastructureLinkRejection::DescriptorInstancesDelete))
+}
+
+/// Encodes `key` as a `HolonKeys` link tag: the key's UTF-8 bytes followed by one NUL.
+///
+/// The terminator makes an exact-key lookup a tag-prefix query that cannot also match longer
+/// keys sharing the same leading characters, while an unterminated prefix still matches every
+/// key that starts with it. Keys never contain NUL (see `CanonicalKey`).
+pub fn holon_key_tag(key: &str) -> LinkTag {
+    let mut bytes = Vec::with_capacity(key.len() + 1);
+    bytes.extend_from_slice(key.as_bytes());
+    bytes.push(0);
+    LinkTag::new(bytes)
+}
+
+/// Decodes a `HolonKeys` link tag, returning `None` unless it is exactly one non-empty,
+/// NUL-free UTF-8 key followed by the NUL terminator.
+pub fn holon_key_from_tag(tag: &LinkTag) -> Option<&str> {
+    let (terminator, key_bytes) = tag.0.split_last()?;
+    if *terminator != 0 || key_bytes.is_empty() || key_bytes.contains(&0) {
+        return None;
+    }
+    std::str::from_utf8(key_bytes).ok()
+}
+
+/// Validates a space-wide key index link from the canonical `holon_keys` path to one lineage root.
+///
+/// Only the tag's shape is checked. Whether the key matches the target's `Key` property would
+/// require decoding entry content, which infrastructure validation deliberately never does; the
+/// index is therefore a lookup accelerator whose results readers resolve and may re-check.
+pub fn validate_holon_keys_create(
+    base_address: &AnyLinkableHash,
+    target_address: &AnyLinkableHash,
+    tag: &LinkTag,
+) -> ExternResult<Result<(), InfrastructureLinkRejection>> {
+    let link_name = "HolonKeys";
+    let canonical_base: AnyLinkableHash = Path::from(HOLON_KEYS_PATH).path_entry_hash()?.into();
+    if base_address != &canonical_base {
+        return Ok(Err(InfrastructureLinkRejection::NonCanonicalBase {
+            link_name,
+            expected_path: HOLON_KEYS_PATH,
+        }));
+    }
+    if holon_key_from_tag(tag).is_none() {
+        return Ok(Err(InfrastructureLinkRejection::MalformedKeyTag { link_name }));
+    }
+    validate_root_holon_node_target(link_name, target_address)
+}
+
+/// Allows a key index link to be deleted only by the agent that created it.
+///
+/// Unlike the whole-space index, a lineage's key entry moves: a version that changes its key
+/// retires the link for the old key and adds one for the new key. Only that move deletes these
+/// links, and it is authored by the agent that indexed the key, so another agent's delete could
+/// only hide a holon from key lookups.
+pub fn validate_holon_keys_delete(
+    original_action: &CreateLink,
+    delete_action: &DeleteLink,
+) -> ExternResult<Result<(), InfrastructureLinkRejection>> {
+    if delete_action.author != original_action.author {
+        return Ok(Err(InfrastructureLinkRejection::HolonKeysDeleteByOtherAuthor));
+    }
+    Ok(Ok(()))
+}
+
 /// Validates the bootstrap path link to the designated local-space lineage root.
 ///
 /// This path selects a lineage, not a movable exact-version pointer. Version selection within the
@@ -124,6 +231,13 @@ fn validate_root_index_create(
     if !tag.0.is_empty() {
         return Ok(Err(InfrastructureLinkRejection::NonEmptyTag { link_name }));
     }
+    validate_root_holon_node_target(link_name, target_address)
+}
+
+fn validate_root_holon_node_target(
+    link_name: &'static str,
+    target_address: &AnyLinkableHash,
+) -> ExternResult<Result<(), InfrastructureLinkRejection>> {
     let Some(target_action_hash) = target_address.clone().into_action_hash() else {
         return Ok(Err(InfrastructureLinkRejection::NonActionTarget { link_name }));
     };
@@ -277,6 +391,17 @@ mod tests {
         }
     }
 
+    fn delete_link(author_seed: u8) -> DeleteLink {
+        DeleteLink {
+            author: AgentPubKey::from_raw_36(vec![author_seed; 36]),
+            timestamp: Timestamp::from_micros(2),
+            action_seq: 2,
+            prev_action: action_hash(3),
+            base_address: canonical_base(HOLON_KEYS_PATH),
+            link_add_address: action_hash(7),
+        }
+    }
+
     type CreateValidator = fn(
         &AnyLinkableHash,
         &AnyLinkableHash,
@@ -422,5 +547,123 @@ mod tests {
             validate_all_holon_nodes_delete(&original),
             Ok(Err(InfrastructureLinkRejection::AllHolonNodesDelete))
         );
+        set_hdi(no_dependency_mock());
+        assert_eq!(
+            validate_descriptor_instances_delete(&original),
+            Ok(Err(InfrastructureLinkRejection::DescriptorInstancesDelete))
+        );
+    }
+
+    #[test]
+    fn descriptor_instances_accept_root_targets_under_a_descriptor_action_base() {
+        let descriptor_base: AnyLinkableHash = action_hash(8).into();
+
+        install_target(create_action(EntryType::App(holon_entry_def())));
+        assert_eq!(
+            validate_descriptor_instances_create(
+                &descriptor_base,
+                &action_hash(9).into(),
+                &LinkTag::new(Vec::new()),
+            ),
+            Ok(Ok(()))
+        );
+
+        install_target(update_action(EntryType::App(holon_entry_def())));
+        assert_eq!(
+            validate_descriptor_instances_create(
+                &descriptor_base,
+                &action_hash(9).into(),
+                &LinkTag::new(Vec::new()),
+            ),
+            Ok(Err(InfrastructureLinkRejection::NonRootHolonNodeTarget {
+                link_name: "DescriptorInstances"
+            }))
+        );
+    }
+
+    #[test]
+    fn holon_key_tags_round_trip_and_reject_malformed_bytes() {
+        assert_eq!(holon_key_from_tag(&holon_key_tag("Book.HolonType")), Some("Book.HolonType"));
+        assert_eq!(holon_key_from_tag(&LinkTag::new(b"Book".to_vec())), None);
+        assert_eq!(holon_key_from_tag(&LinkTag::new(vec![0])), None);
+        assert_eq!(holon_key_from_tag(&LinkTag::new(b"Bo\0ok\0".to_vec())), None);
+        assert_eq!(holon_key_from_tag(&LinkTag::new(vec![0xff, 0])), None);
+        assert!(holon_key_tag("Book").0.starts_with(b"Bo"));
+        assert!(!holon_key_tag("Bookshelf").0.starts_with(&holon_key_tag("Book").0));
+    }
+
+    #[test]
+    fn holon_keys_validate_base_and_tag_before_resolving_root_targets() {
+        let link_name = "HolonKeys";
+
+        install_target(create_action(EntryType::App(holon_entry_def())));
+        assert_eq!(
+            validate_holon_keys_create(
+                &canonical_base(HOLON_KEYS_PATH),
+                &action_hash(9).into(),
+                &holon_key_tag("Book"),
+            ),
+            Ok(Ok(()))
+        );
+
+        set_hdi(no_dependency_mock());
+        assert_eq!(
+            validate_holon_keys_create(
+                &canonical_base(ALL_HOLON_NODES_PATH),
+                &action_hash(9).into(),
+                &holon_key_tag("Book"),
+            ),
+            Ok(Err(InfrastructureLinkRejection::NonCanonicalBase {
+                link_name,
+                expected_path: HOLON_KEYS_PATH,
+            }))
+        );
+
+        set_hdi(no_dependency_mock());
+        assert_eq!(
+            validate_holon_keys_create(
+                &canonical_base(HOLON_KEYS_PATH),
+                &action_hash(9).into(),
+                &LinkTag::new(Vec::new()),
+            ),
+            Ok(Err(InfrastructureLinkRejection::MalformedKeyTag { link_name }))
+        );
+
+        set_hdi(no_dependency_mock());
+        assert_eq!(
+            validate_holon_keys_delete(&original_create_link(), &delete_link(0)),
+            Ok(Ok(()))
+        );
+
+        set_hdi(no_dependency_mock());
+        assert_eq!(
+            validate_holon_keys_delete(&original_create_link(), &delete_link(1)),
+            Ok(Err(InfrastructureLinkRejection::HolonKeysDeleteByOtherAuthor))
+        );
+    }
+
+    #[test]
+    fn descriptor_instances_reject_malformed_shapes_before_dependency_resolution() {
+        let link_name = "DescriptorInstances";
+
+        set_hdi(no_dependency_mock());
+        assert_eq!(
+            validate_descriptor_instances_create(
+                &canonical_base(ALL_HOLON_NODES_PATH),
+                &action_hash(9).into(),
+                &LinkTag::new(Vec::new()),
+            ),
+            Ok(Err(InfrastructureLinkRejection::NonActionBase { link_name }))
+        );
+
+        set_hdi(no_dependency_mock());
+        assert_eq!(
+            validate_descriptor_instances_create(
+                &action_hash(8).into(),
+                &action_hash(9).into(),
+                &LinkTag::new(vec![1]),
+            ),
+            Ok(Err(InfrastructureLinkRejection::NonEmptyTag { link_name }))
+        );
     }
 }
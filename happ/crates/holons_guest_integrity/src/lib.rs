@@ -21,15 +21,17 @@ pub mod type_conversions;
 
 pub use agent_activity::{validate_create_agent, AgentActivityRejection};
 pub use holon_node::{
-    HolonNode, ALL_HOLON_NODES_PATH, LOCAL_HOLON_SPACE_DESCRIPTION, LOCAL_HOLON_SPACE_NAME,
-    LOCAL_HOLON_SPACE_PATH,
+    HolonNode, ALL_HOLON_NODES_PATH, HOLON_KEYS_PATH, LOCAL_HOLON_SPACE_DESCRIPTION,
+    LOCAL_HOLON_SPACE_NAME, LOCAL_HOLON_SPACE_PATH,
 };
 pub use holon_node_envelope::{prepare_holon_node_envelope, HolonNodeEnvelope};
 pub use holon_node_lifecycle::{
     validate_holon_node_delete_target, validate_holon_node_update_target,
 };
 pub use infrastructure_links::{
-    validate_all_holon_nodes_create, validate_all_holon_nodes_delete,
+    holon_key_from_tag, holon_key_tag, validate_all_holon_nodes_create,
+    validate_all_holon_nodes_delete, validate_descriptor_instances_create,
+    validate_descriptor_instances_delete, validate_holon_keys_create, validate_holon_keys_delete,
     validate_local_holon_space_create, validate_local_holon_space_delete,
     InfrastructureLinkRejection,
 };
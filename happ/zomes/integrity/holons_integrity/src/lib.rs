@@ -27,6 +27,8 @@ pub enum LinkTypes {
     AllHolonNodes,
     LocalHolonSpace,
     SmartLink,
+    DescriptorInstances,
+    HolonKeys,
 }
 
 #[hdk_extern]
@@ -131,9 +133,17 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 LinkTypes::LocalHolonSpace => Ok(fixed_callback_result(
                     validate_local_holon_space_create(&base_address, &target_address, &tag)?,
                 )),
+                LinkTypes::DescriptorInstances => Ok(fixed_callback_result(
+                    validate_descriptor_instances_create(&base_address, &target_address, &tag)?,
+                )),
+                LinkTypes::HolonKeys => Ok(fixed_callback_result(validate_holon_keys_create(
+                    &base_address,
+                    &target_address,
+                    &tag,
+                )?)),
             }
         }
-        FlatOp::RegisterDeleteLink { link_type, original_action, .. } => match link_type {
+        FlatOp::RegisterDeleteLink { link_type, original_action, action, .. } => match link_type {
             LinkTypes::SmartLink => {
                 Ok(pvl_callback_result(validate_smartlink_delete(&original_action)?))
             }
@@ -143,6 +153,12 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             LinkTypes::LocalHolonSpace => {
                 Ok(fixed_callback_result(validate_local_holon_space_delete(&original_action)?))
             }
+            LinkTypes::DescriptorInstances => {
+                Ok(fixed_callback_result(validate_descriptor_instances_delete(&original_action)?))
+            }
+            LinkTypes::HolonKeys => {
+                Ok(fixed_callback_result(validate_holon_keys_delete(&original_action, &action)?))
+            }
         },
         FlatOp::StoreRecord(store_record) => match store_record {
             // HolonNode envelope validation already succeeded in the raw-op guard above.
@@ -175,9 +191,17 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     LinkTypes::LocalHolonSpace => Ok(fixed_callback_result(
                         validate_local_holon_space_create(&base_address, &target_address, &tag)?,
                     )),
+                    LinkTypes::DescriptorInstances => Ok(fixed_callback_result(
+                        validate_descriptor_instances_create(&base_address, &target_address, &tag)?,
+                    )),
+                    LinkTypes::HolonKeys => Ok(fixed_callback_result(validate_holon_keys_create(
+                        &base_address,
+                        &target_address,
+                        &tag,
+                    )?)),
                 }
             }
-            OpRecord::DeleteLink { original_action_hash, .. } => {
+            OpRecord::DeleteLink { original_action_hash, action, .. } => {
                 let create_link = match resolve_link_delete_target(original_action_hash)? {
                     Ok(create_link) => create_link,
                     Err(violation) => {
@@ -199,6 +223,13 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     LinkTypes::LocalHolonSpace => {
                         Ok(fixed_callback_result(validate_local_holon_space_delete(&create_link)?))
                     }
+                    LinkTypes::DescriptorInstances => Ok(fixed_callback_result(
+                        validate_descriptor_instances_delete(&create_link)?,
+                    )),
+                    LinkTypes::HolonKeys => Ok(fixed_callback_result(validate_holon_keys_delete(
+                        &create_link,
+                        &action,
+                    )?)),
                 }
             }
             OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
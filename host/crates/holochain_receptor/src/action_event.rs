@@ -1,45 +1,5 @@
 use crate::host_signal::HolonsZomeSignal;
-use integrity_core_types::{LocalId, PersistenceTimestamp};
-
-/// Classification of the mutation that triggered the event.
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-pub enum MutationKind {
-    HolonCreated,
-    HolonUpdated,
-    HolonDeleted,
-    LinkCreated,
-    LinkDeleted,
-}
-
-/// MAP-facing adapter event: notifies that a change occurred at the identified holon.
-///
-/// Carries NO holon state — this is the only signal type that crosses the adapter
-/// boundary into the runtime-consumer layer. Consumers who need the current holon
-/// state resolve it through the normal `HolonReference` / cache path using
-/// `affected_holon`.
-///
-/// `HostSignal` and `HolonsZomeSignal` are adapter-internal; this type is the
-/// public subscription surface.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct ActionEvent {
-    /// What kind of mutation occurred.
-    pub mutation_kind: MutationKind,
-    /// The Holochain action hash for this specific commit (39 bytes).
-    pub action_id: LocalId,
-    /// The permanent identity of the affected holon (the original CREATE hash).
-    /// For created holons this equals `action_id`. For updates/deletes it is the
-    /// holon's lineage root. For link events it equals `action_id`.
-    pub affected_holon: LocalId,
-    /// The predecessor record being superseded — `update.original_action_address`
-    /// for updates, `delete.deletes_address` for deletes. `None` for creates and
-    /// link events.
-    pub previous_holon: Option<LocalId>,
-    /// The committing action's timestamp.
-    pub timestamp: PersistenceTimestamp,
-    /// For link events: the link type string (`format!("{:?}", LinkTypes)` from the guest).
-    /// `None` for holon mutation events.
-    pub link_type: Option<String>,
-}
+pub use core_types::{ActionEvent, MutationKind};
 
 /// Convert an adapter-internal `HolonsZomeSignal` into the MAP-facing `ActionEvent`.
 pub(crate) fn to_action_event(zs: &HolonsZomeSignal) -> ActionEvent {
@@ -94,6 +54,7 @@ pub(crate) fn to_action_event(zs: &HolonsZomeSignal) -> ActionEvent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use integrity_core_types::{LocalId, PersistenceTimestamp};
 
     fn id(seed: u8) -> LocalId {
         LocalId(vec![seed; 39])
@@ -34,7 +34,7 @@
 
 #![allow(unused_variables)]
 
-use core_types::{HolonError, HolonId};
+use core_types::{HolonError, HolonId, KeyMatch};
 use futures_executor::block_on;
 use holons_core::core_shared_objects::transactions::{
     TransactionContext, TransactionContextHandle,
@@ -285,6 +285,64 @@ impl HolonServiceApi for ClientHolonService {
         }
     }
 
+    fn get_holons_by_descriptor_internal(
+        &self,
+        context: &Arc<TransactionContext>,
+        descriptor_id: &HolonId,
+    ) -> Result<HolonCollection, HolonError> {
+        // `LookupFacade::get_holons_by_descriptor` expands subtypes before calling in, so
+        // each call asks the guest for one descriptor only.
+        let request = holon_dance_builders::build_get_holons_by_descriptor_dance_request(
+            descriptor_id.clone(),
+            false,
+        )?;
+
+        let response =
+            run_future_synchronously(async move { context.initiate_dance(request).await })?;
+
+        if response.status_code != ResponseStatusCode::OK {
+            return Err(HolonError::Misc(format!(
+                "GetHolonsByDescriptor dance failed: {:?} — {}",
+                response.status_code, response.description.0
+            )));
+        }
+
+        match response.body {
+            ResponseBody::HolonCollection(collection) => Ok(collection),
+            other => Err(HolonError::InvalidParameter(format!(
+                "GetHolonsByDescriptor: expected ResponseBody::HolonCollection, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    fn get_saved_holons_by_key_internal(
+        &self,
+        context: &Arc<TransactionContext>,
+        key_match: &KeyMatch,
+    ) -> Result<HolonCollection, HolonError> {
+        let request =
+            holon_dance_builders::build_get_saved_holons_by_key_dance_request(key_match.clone())?;
+
+        let response =
+            run_future_synchronously(async move { context.initiate_dance(request).await })?;
+
+        if response.status_code != ResponseStatusCode::OK {
+            return Err(HolonError::Misc(format!(
+                "GetSavedHolonsByKey dance failed: {:?} — {}",
+                response.status_code, response.description.0
+            )));
+        }
+
+        match response.body {
+            ResponseBody::HolonCollection(collection) => Ok(collection),
+            other => Err(HolonError::InvalidParameter(format!(
+                "GetSavedHolonsByKey: expected ResponseBody::HolonCollection, got {:?}",
+                other
+            ))),
+        }
+    }
+
     fn load_holons_internal(
         &self,
         context: &Arc<TransactionContext>,
@@ -39,7 +39,14 @@ impl DeprecatedHolochainReceptor {
     }
 
     fn is_read_only_request(request_name: &str) -> bool {
-        matches!(request_name, "get_all_holons" | "get_holon_by_id" | "query_relationships")
+        matches!(
+            request_name,
+            "get_all_holons"
+                | "get_holon_by_id"
+                | "get_holons_by_descriptor"
+                | "get_saved_holons_by_key"
+                | "query_relationships"
+        )
     }
 
     pub fn new(base: DeprecatedBaseReceptor) -> Self {
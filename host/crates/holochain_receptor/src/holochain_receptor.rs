@@ -10,10 +10,13 @@ use holochain_types::signal::Signal;
 use crate::action_event::{to_action_event, ActionEvent};
 use crate::holochain_conductor_client::HolochainConductorClient;
 use crate::host_signal::{decode_signal, HostSignal};
+use crate::live_query_subscription::LiveQuerySubscription;
 use client_shared_types::holon_space::SpaceInfo;
 use client_shared_types::storage_receptor::StorageReceptor;
 use client_shared_types::ReceptorType;
 use core_types::HolonError;
+use holons_core::core_shared_objects::transactions::TransactionContext;
+use holons_core::query_layer::{LiveQuery, LiveQueryWatch};
 
 pub const SIGNAL_CHANNEL_CAPACITY: usize = 64;
 
@@ -121,6 +124,23 @@ impl HolochainReceptor {
         self.action_event_tx.subscribe()
     }
 
+    /// Register a live query over a `Query` or a (source holon, relationship) watch.
+    ///
+    /// The watch is evaluated immediately in `context`; afterwards every relevant
+    /// [`ActionEvent`] — including those caused by other agents' commits — triggers a
+    /// re-evaluation, and the added, removed and changed members are delivered
+    /// through the returned [`LiveQuerySubscription`].
+    pub fn subscribe_live_query(
+        &self,
+        context: &Arc<TransactionContext>,
+        watch: LiveQueryWatch,
+    ) -> Result<LiveQuerySubscription, HolonError> {
+        // Subscribe first so no event between evaluation and spawn is lost.
+        let events = self.subscribe_action_events();
+        let live_query = LiveQuery::register(context, watch)?;
+        Ok(LiveQuerySubscription::spawn(live_query, events))
+    }
+
     /// Query live space info from the conductor (delegates to `HolochainConductorClient`).
     pub async fn get_space_info(&self) -> Result<SpaceInfo, HolonError> {
         self.client.get_all_spaces().await
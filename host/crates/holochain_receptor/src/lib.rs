@@ -7,6 +7,7 @@ pub mod deprecated_holochain_receptor;
 pub mod holochain_conductor_client;
 pub mod holochain_receptor;
 mod host_signal;
+mod live_query_subscription;
 
 // Re-export key types and traits for external use
 pub use deprecated_holochain_receptor::DeprecatedHolochainReceptor;
@@ -14,4 +15,5 @@ pub use holochain_conductor_client::HolochainConductorClient;
 pub use holochain_receptor::HolochainReceptor;
 // MAP-facing public API: identification-only action events (no holon state)
 pub use action_event::{ActionEvent, MutationKind};
+pub use live_query_subscription::LiveQuerySubscription;
 // HostSignal, HolonsZomeSignal, decode_signal are adapter-internal — not re-exported
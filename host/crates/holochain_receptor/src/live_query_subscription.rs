@@ -0,0 +1,153 @@
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use core_types::{ActionEvent, HolonError};
+use holons_core::query_layer::{LiveQuery, ResultDelta};
+use holons_core::HolonReference;
+
+/// Handle to a live query kept current from a receptor's [`ActionEvent`] stream.
+///
+/// Created by [`HolochainReceptor::subscribe_live_query`](crate::HolochainReceptor::subscribe_live_query).
+/// A background task re-evaluates the query whenever a relevant event arrives and
+/// queues the non-empty [`ResultDelta`]s for [`next_delta`](Self::next_delta).
+/// Dropping the handle stops the task.
+pub struct LiveQuerySubscription {
+    initial_members: Vec<HolonReference>,
+    deltas: mpsc::UnboundedReceiver<Result<ResultDelta, HolonError>>,
+    task: JoinHandle<()>,
+}
+
+impl LiveQuerySubscription {
+    /// Starts driving `live_query` from `events`.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub(crate) fn spawn(
+        mut live_query: LiveQuery,
+        mut events: broadcast::Receiver<ActionEvent>,
+    ) -> Self {
+        let initial_members = live_query.members().to_vec();
+        let (delta_tx, deltas) = mpsc::unbounded_channel();
+
+        let task = tokio::spawn(async move {
+            loop {
+                let outcome = match events.recv().await {
+                    Ok(event) => live_query.apply(&event).transpose(),
+                    // Events were dropped, so relevance can't be judged — re-evaluate.
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            "[LiveQuerySubscription] missed {} action events; refreshing.",
+                            skipped
+                        );
+                        match live_query.refresh() {
+                            Ok(delta) if delta.is_empty() => None,
+                            outcome => Some(outcome),
+                        }
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                if let Some(outcome) = outcome {
+                    if delta_tx.send(outcome).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Self { initial_members, deltas, task }
+    }
+
+    /// Returns the result set evaluated at subscription time.
+    pub fn initial_members(&self) -> &[HolonReference] {
+        &self.initial_members
+    }
+
+    /// Waits for the next result delta, or a re-evaluation error.
+    ///
+    /// Returns `None` once the receptor's event stream has closed.
+    pub async fn next_delta(&mut self) -> Option<Result<ResultDelta, HolonError>> {
+        self.deltas.recv().await
+    }
+}
+
+impl Drop for LiveQuerySubscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client_context::init_client_context;
+    use base_types::{BaseValue, MapString};
+    use core_types::{
+        HolonId, LocalId, MutationKind, PersistenceTimestamp, PropertyMap, PropertyName,
+        RelationshipName,
+    };
+    use holons_core::query_layer::LiveQueryWatch;
+    use holons_core::reference_layer::{ReadableHolon, SmartReference, WritableHolon};
+
+    fn saved_id(seed: u8) -> HolonId {
+        LocalId(vec![seed; 39]).into()
+    }
+
+    fn link_created() -> ActionEvent {
+        ActionEvent {
+            mutation_kind: MutationKind::LinkCreated,
+            action_id: LocalId(vec![0xAA; 39]),
+            affected_holon: LocalId(vec![0xAA; 39]),
+            previous_holon: None,
+            timestamp: PersistenceTimestamp(0),
+            link_type: Some("SmartLink".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn action_events_reach_the_subscriber_until_it_is_dropped() -> Result<(), HolonError> {
+        let context = init_client_context(None);
+        // Smart references carrying their key, so no member is fetched from the conductor.
+        let saved = |seed| -> HolonReference {
+            SmartReference::new_with_properties(
+                context.context_handle(),
+                saved_id(seed),
+                PropertyMap::from([(
+                    PropertyName(MapString("Key".to_string())),
+                    BaseValue::StringValue(MapString(format!("saved-{seed}"))),
+                )]),
+            )
+            .into()
+        };
+        let mut source = context.mutation().new_holon(Some(MapString("source".to_string())))?;
+        source.add_related_holons("Members", vec![saved(1)])?;
+
+        let (events_tx, events) = broadcast::channel(16);
+        let live_query = LiveQuery::register(
+            &context,
+            LiveQueryWatch::Relationship {
+                source: source.clone().into(),
+                relationship: RelationshipName(MapString("Members".to_string())),
+            },
+        )?;
+        let mut subscription = LiveQuerySubscription::spawn(live_query, events);
+        assert_eq!(subscription.initial_members().len(), 1);
+
+        source.add_related_holons("Members", vec![saved(2)])?;
+        events_tx.send(link_created()).expect("the subscription is listening");
+        let delta = subscription.next_delta().await.expect("the event stream is open")?;
+        assert_eq!(delta.added.len(), 1);
+        assert_eq!(delta.added[0].holon_id()?, saved_id(2));
+        assert!(delta.removed.is_empty() && delta.changed.is_empty());
+
+        // Dropping the handle aborts the task, which releases its event receiver.
+        drop(subscription);
+        for _ in 0..100 {
+            if events_tx.receiver_count() == 0 {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(events_tx.receiver_count(), 0);
+        Ok(())
+    }
+}
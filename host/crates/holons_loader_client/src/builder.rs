@@ -19,7 +19,9 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
-use base_types::{BaseValue, MapBoolean, MapInteger, MapString};
+use base_types::{
+    BaseValue, MapBoolean, MapDecimal, MapInteger, MapRecord, MapString, MapValueArray,
+};
 use holons_core::core_shared_objects::transactions::TransactionContext;
 
 /// Raw JSON representation of the `"meta"` block from a loader import file.
@@ -335,20 +337,86 @@ fn json_value_to_base_value(property_name: &str, value: &Value) -> Result<BaseVa
                     )))
                 }
             } else {
-                Err(HolonError::InvalidParameter(format!(
-                    "Property '{}' numeric value '{}' must be an integer",
-                    property_name, value
-                )))
+                // Non-integral numbers load as exact decimals, from the shortest
+                // text that round-trips the parsed number.
+                num.to_string().parse::<MapDecimal>().map(BaseValue::DecimalValue).map_err(|err| {
+                    HolonError::InvalidParameter(format!(
+                        "Property '{}' numeric value '{}' is not a valid decimal: {}",
+                        property_name, value, err
+                    ))
+                })
             }
         }
-        // For loader meta fields we occasionally receive arrays/objects; serialize them
+        Value::Object(object) if object.len() == 1 => match object.iter().next() {
+            Some((tag, Value::String(text))) if tag == "$timestamp" => {
+                parse_temporal(property_name, text, BaseValue::TimestampValue)
+            }
+            Some((tag, Value::String(text))) if tag == "$date" => {
+                parse_temporal(property_name, text, BaseValue::DateValue)
+            }
+            Some((tag, Value::Object(fields))) if tag == "$record" => {
+                parse_record(property_name, fields)
+            }
+            _ => Ok(BaseValue::StringValue(MapString(value.to_string()))),
+        },
+        // Arrays load as value arrays of scalars; nested arrays are not representable.
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::Array(_) => Err(HolonError::InvalidParameter(format!(
+                    "Property '{}' value array cannot contain a nested array",
+                    property_name
+                ))),
+                _ => json_value_to_base_value(property_name, item),
+            })
+            .collect::<Result<MapValueArray, _>>()
+            .map(BaseValue::ValueArray),
+        // For loader meta fields we occasionally receive objects; serialize them
         // to a JSON string to preserve the content without rejecting the file.
-        Value::Array(_) | Value::Object(_) | Value::Null => {
-            Ok(BaseValue::StringValue(MapString(value.to_string())))
-        }
+        Value::Object(_) | Value::Null => Ok(BaseValue::StringValue(MapString(value.to_string()))),
     }
 }
 
+/// Build a record from the fields of a typed `{"$record": {...}}` value.
+///
+/// Record fields hold scalars only; arrays and nested records are rejected here
+/// rather than left for property validation to report as a nesting violation.
+fn parse_record(
+    property_name: &str,
+    fields: &serde_json::Map<String, Value>,
+) -> Result<BaseValue, HolonError> {
+    fields
+        .iter()
+        .map(|(field, value)| match json_value_to_base_value(property_name, value)? {
+            BaseValue::ValueArray(_) | BaseValue::Record(_) => {
+                Err(HolonError::InvalidParameter(format!(
+                    "Property '{}' record field '{}' must hold a scalar value",
+                    property_name, field
+                )))
+            }
+            scalar => Ok((MapString(field.clone()), scalar)),
+        })
+        .collect::<Result<MapRecord, _>>()
+        .map(BaseValue::Record)
+}
+
+/// Parse the ISO 8601 text of a typed `{"$timestamp": ...}` or `{"$date": ...}` value.
+fn parse_temporal<T>(
+    property_name: &str,
+    text: &str,
+    into_base_value: fn(T) -> BaseValue,
+) -> Result<BaseValue, HolonError>
+where
+    T: std::str::FromStr<Err = base_types::ParseTemporalError>,
+{
+    text.parse::<T>().map(into_base_value).map_err(|err| {
+        HolonError::InvalidParameter(format!(
+            "Property '{}' value is invalid: {}",
+            property_name, err
+        ))
+    })
+}
+
 /// Normalize a supported local reference string into a canonical holon key.
 /// Currently `#key` and `key` are supported local-key forms; `id:` remains unsupported.
 pub fn normalize_ref_key(raw: &str) -> Result<String, HolonError> {
@@ -479,6 +547,7 @@ fn create_relationship_reference(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use base_types::{MapDate, MapTimestamp};
     use serde::Deserialize;
     use serde_json::json;
     use std::collections::BTreeSet;
@@ -669,6 +738,75 @@ mod tests {
         assert_eq!(normalized, vec!["SomeType", "OtherType", "ThirdType"]);
     }
 
+    #[test]
+    fn json_numbers_load_as_integers_or_exact_decimals() {
+        assert_eq!(
+            json_value_to_base_value("PageCount", &json!(412)).unwrap(),
+            BaseValue::IntegerValue(MapInteger(412))
+        );
+        assert_eq!(
+            json_value_to_base_value("Price", &json!(19.99)).unwrap(),
+            BaseValue::DecimalValue(MapDecimal::new(1999, 2))
+        );
+    }
+
+    #[test]
+    fn typed_json_objects_load_as_timestamps_and_dates() {
+        assert_eq!(
+            json_value_to_base_value(
+                "PublishedAt",
+                &json!({ "$timestamp": "2024-03-01T10:30:00+01:00" })
+            )
+            .unwrap(),
+            BaseValue::TimestampValue("2024-03-01T09:30:00Z".parse::<MapTimestamp>().unwrap())
+        );
+        assert_eq!(
+            json_value_to_base_value("PublishedOn", &json!({ "$date": "2024-03-01" })).unwrap(),
+            BaseValue::DateValue(MapDate::from_ymd(2024, 3, 1).unwrap())
+        );
+        let error =
+            json_value_to_base_value("PublishedOn", &json!({ "$date": "2024-02-30" })).unwrap_err();
+        assert!(
+            matches!(error, HolonError::InvalidParameter(message) if message.contains("2024-02-30"))
+        );
+    }
+
+    #[test]
+    fn json_arrays_load_as_value_arrays() {
+        assert_eq!(
+            json_value_to_base_value("Tags", &json!(["alpha", "beta"])).unwrap(),
+            BaseValue::ValueArray(MapValueArray(vec![
+                BaseValue::StringValue(MapString("alpha".into())),
+                BaseValue::StringValue(MapString("beta".into())),
+            ]))
+        );
+        let error = json_value_to_base_value("Tags", &json!([["alpha"]])).unwrap_err();
+        assert!(
+            matches!(error, HolonError::InvalidParameter(message) if message.contains("nested array"))
+        );
+    }
+
+    #[test]
+    fn typed_record_objects_load_as_records_of_scalars() {
+        assert_eq!(
+            json_value_to_base_value(
+                "Address",
+                &json!({ "$record": { "Street": "Main St", "Number": 12 } })
+            )
+            .unwrap(),
+            BaseValue::Record(MapRecord::from_iter([
+                (MapString("Number".into()), BaseValue::IntegerValue(MapInteger(12))),
+                (MapString("Street".into()), BaseValue::StringValue(MapString("Main St".into()))),
+            ]))
+        );
+        let error =
+            json_value_to_base_value("Address", &json!({ "$record": { "Lines": ["a", "b"] } }))
+                .unwrap_err();
+        assert!(
+            matches!(error, HolonError::InvalidParameter(message) if message.contains("'Lines'"))
+        );
+    }
+
     #[test]
     fn deserialize_targets_rejects_id_ref_objects() {
         let error = parse_targets(json!({ "$ref": "id:abc" })).unwrap_err();
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use base_types::BaseValue;
 use core_types::{PropertyName, RelationshipName};
 use holons_core::core_shared_objects::transactions::TransactionContext;
+use holons_core::query_layer::{PageRequest, SortKey};
 use holons_core::reference_layer::HolonReference;
 
 use super::CommandLifecyclePolicy;
@@ -48,6 +49,9 @@ impl HolonAction {
             HolonAction::Read(ReadableHolonAction::GetVersionedKey) => "get_versioned_key",
             HolonAction::Read(ReadableHolonAction::GetPropertyValue { .. }) => "get_property_value",
             HolonAction::Read(ReadableHolonAction::GetRelatedHolons { .. }) => "get_related_holons",
+            HolonAction::Read(ReadableHolonAction::GetRelatedHolonsPage { .. }) => {
+                "get_related_holons_page"
+            }
             HolonAction::Write(_) => "holon_write",
         }
     }
@@ -84,6 +88,9 @@ pub enum ReadableHolonAction {
 
     /// `ReadableHolon::related_holons(name)` → `HolonCollection`
     GetRelatedHolons { name: RelationshipName },
+
+    /// `ReadableHolon::related_holons(name)`, sorted and windowed → `CollectionPage`
+    GetRelatedHolonsPage { name: RelationshipName, sort: Option<SortKey>, page: PageRequest },
 }
 
 /// Mutating holon actions.
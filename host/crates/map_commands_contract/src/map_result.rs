@@ -3,6 +3,7 @@ use core_types::HolonId;
 use holons_core::core_shared_objects::transactions::TxId;
 use holons_core::core_shared_objects::HolonCollection;
 use holons_core::dances::DanceResponse;
+use holons_core::query_layer::CollectionPage;
 use holons_core::reference_layer::HolonReference;
 
 /// Domain-level result variants from command execution.
@@ -40,6 +41,9 @@ pub enum MapResult {
     /// Canonical plural command result carrier.
     Collection(HolonCollection),
 
+    /// One sorted window of a collection plus its continuation cursor.
+    Page(CollectionPage),
+
     /// Universal scalar return — covers MapString, MapInteger, MapBoolean, PropertyValue.
     Value(BaseValue),
 
@@ -1,5 +1,7 @@
 use base_types::{BaseValue, MapString};
-use core_types::{LocalId, PropertyName};
+use core_types::{LocalId, PropertyName, RelationshipName};
+use holons_core::core_shared_objects::transactions::RelationshipEnforcement;
+use holons_core::query_layer::{PageRequest, SortKey};
 
 use crate::{
     CommandLifecyclePolicy, HolonAction, MutationClassification, ReadableHolonAction, SpaceCommand,
@@ -17,6 +19,17 @@ fn space_begin_transaction_policy() {
 #[test]
 fn transaction_action_policies() {
     assert_eq!(TransactionAction::Commit.policy(), CommandLifecyclePolicy::mutating_with_guard());
+    assert_eq!(
+        TransactionAction::ValidateTransaction.policy(),
+        CommandLifecyclePolicy::transaction_read_only()
+    );
+    assert_eq!(
+        TransactionAction::SetRelationshipEnforcement {
+            enforcement: RelationshipEnforcement::Strict
+        }
+        .policy(),
+        CommandLifecyclePolicy::transaction_read_only()
+    );
     assert_eq!(
         TransactionAction::GetStagedCount.policy(),
         CommandLifecyclePolicy::transaction_read_only()
@@ -29,6 +42,23 @@ fn transaction_action_policies() {
         TransactionAction::GetAllHolons.policy(),
         CommandLifecyclePolicy::transaction_read_only()
     );
+    assert_eq!(
+        TransactionAction::GetAllHolonsPage { sort: None, page: PageRequest::first(25) }.policy(),
+        CommandLifecyclePolicy::transaction_read_only()
+    );
+    assert_eq!(
+        TransactionAction::GetSavedHolonByKey { key: MapString::from("Book.HolonType") }.policy(),
+        CommandLifecyclePolicy::transaction_read_only()
+    );
+    assert_eq!(
+        TransactionAction::GetSavedHolonByVersionedKey { key: MapString::from("Book.HolonType1") }
+            .policy(),
+        CommandLifecyclePolicy::transaction_read_only()
+    );
+    assert_eq!(
+        TransactionAction::GetSavedHolonsByKeyPrefix { prefix: MapString::from("Book") }.policy(),
+        CommandLifecyclePolicy::transaction_read_only()
+    );
     assert_eq!(
         TransactionAction::NewHolon { key: None }.policy(),
         CommandLifecyclePolicy::mutating()
@@ -45,6 +75,15 @@ fn holon_action_policies() {
         HolonAction::Read(ReadableHolonAction::GetKey).policy(),
         CommandLifecyclePolicy::holon_read_only()
     );
+    assert_eq!(
+        HolonAction::Read(ReadableHolonAction::GetRelatedHolonsPage {
+            name: RelationshipName(MapString::from("Authors")),
+            sort: Some(SortKey::ascending(PropertyName(MapString::from("Title")))),
+            page: PageRequest::first(25),
+        })
+        .policy(),
+        CommandLifecyclePolicy::holon_read_only()
+    );
     assert_eq!(
         HolonAction::Read(ReadableHolonAction::CloneHolon).policy(),
         CommandLifecyclePolicy::mutating(),
@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use base_types::MapString;
 use core_types::{ContentSet, HolonId, LocalId};
-use holons_core::core_shared_objects::transactions::TransactionContext;
+use holons_core::core_shared_objects::transactions::{RelationshipEnforcement, TransactionContext};
 use holons_core::dances::{DanceInvocation, DanceRequest};
+use holons_core::query_layer::{PageRequest, SortKey};
 use holons_core::reference_layer::{HolonReference, SmartReference, TransientReference};
 
 use super::{CommandLifecyclePolicy, MutationClassification};
@@ -27,6 +28,12 @@ pub enum TransactionAction {
     /// Commits the transaction.
     Commit,
 
+    /// Validates the staged holons without committing; returns a `ValidationReport` holon.
+    ValidateTransaction,
+
+    /// Sets how relationship contracts are enforced for later staged mutations.
+    SetRelationshipEnforcement { enforcement: RelationshipEnforcement },
+
     /// Undoes the last mutation in this transaction.
     UndoLast,
 
@@ -56,6 +63,21 @@ pub enum TransactionAction {
     /// `get_all_holons()` → `HolonCollection`
     GetAllHolons,
 
+    /// `get_all_holons_page(sort, page)` → `CollectionPage`
+    GetAllHolonsPage { sort: Option<SortKey>, page: PageRequest },
+
+    /// `get_holons_by_descriptor(descriptor, include_subtypes)` → `HolonCollection`
+    GetHolonsByDescriptor { descriptor: HolonReference, include_subtypes: bool },
+
+    /// `get_saved_holon_by_key(key)` → `HolonReference`
+    GetSavedHolonByKey { key: MapString },
+
+    /// `get_saved_holon_by_versioned_key(key)` → `HolonReference`
+    GetSavedHolonByVersionedKey { key: MapString },
+
+    /// `get_saved_holons_by_key_prefix(prefix)` → `HolonCollection`
+    GetSavedHolonsByKeyPrefix { prefix: MapString },
+
     /// `get_staged_holon_by_base_key(key)` → `StagedReference`
     GetStagedHolonByBaseKey { key: MapString },
 
@@ -103,6 +125,14 @@ impl TransactionAction {
     pub fn policy(&self) -> CommandLifecyclePolicy {
         match self {
             TransactionAction::Commit => CommandLifecyclePolicy::mutating_with_guard(),
+            // Report holons are transient; staged state is left untouched.
+            TransactionAction::ValidateTransaction => {
+                CommandLifecyclePolicy::transaction_read_only()
+            }
+            // Changes how later mutations are checked; no holon state changes.
+            TransactionAction::SetRelationshipEnforcement { .. } => {
+                CommandLifecyclePolicy::transaction_read_only()
+            }
             TransactionAction::UndoLast | TransactionAction::RedoLast => {
                 CommandLifecyclePolicy::transaction_read_only()
             }
@@ -119,6 +149,11 @@ impl TransactionAction {
             }
             // Lookups
             TransactionAction::GetAllHolons
+            | TransactionAction::GetAllHolonsPage { .. }
+            | TransactionAction::GetHolonsByDescriptor { .. }
+            | TransactionAction::GetSavedHolonByKey { .. }
+            | TransactionAction::GetSavedHolonByVersionedKey { .. }
+            | TransactionAction::GetSavedHolonsByKeyPrefix { .. }
             | TransactionAction::GetStagedHolonByBaseKey { .. }
             | TransactionAction::GetStagedHolonsByBaseKey { .. }
             | TransactionAction::GetStagedHolonByVersionedKey { .. }
@@ -142,6 +177,8 @@ impl TransactionAction {
     pub fn label(&self) -> &'static str {
         match self {
             TransactionAction::Commit => "commit",
+            TransactionAction::ValidateTransaction => "validate_transaction",
+            TransactionAction::SetRelationshipEnforcement { .. } => "set_relationship_enforcement",
             TransactionAction::UndoLast => "undo_last",
             TransactionAction::RedoLast => "redo_last",
             TransactionAction::UndoToMarker { .. } => "undo_to_marker",
@@ -150,6 +187,13 @@ impl TransactionAction {
             TransactionAction::Dance(_) => "dance",
             TransactionAction::DanceV2 { .. } => "dance_v2",
             TransactionAction::GetAllHolons => "get_all_holons",
+            TransactionAction::GetAllHolonsPage { .. } => "get_all_holons_page",
+            TransactionAction::GetHolonsByDescriptor { .. } => "get_holons_by_descriptor",
+            TransactionAction::GetSavedHolonByKey { .. } => "get_saved_holon_by_key",
+            TransactionAction::GetSavedHolonByVersionedKey { .. } => {
+                "get_saved_holon_by_versioned_key"
+            }
+            TransactionAction::GetSavedHolonsByKeyPrefix { .. } => "get_saved_holons_by_key_prefix",
             TransactionAction::GetStagedHolonByBaseKey { .. } => "get_staged_holon_by_base_key",
             TransactionAction::GetStagedHolonsByBaseKey { .. } => "get_staged_holons_by_base_key",
             TransactionAction::GetStagedHolonByVersionedKey { .. } => {
@@ -1,5 +1,6 @@
 use base_types::{BaseValue, MapString};
 use core_types::HolonError;
+use holons_core::query_layer::page_collection;
 use holons_core::reference_layer::{HolonReference, ReadableHolon, WritableHolon};
 
 use map_commands_contract::{
@@ -60,6 +61,17 @@ fn handle_read(
                 .clone();
             Ok(MapResult::Collection(collection))
         }
+        ReadableHolonAction::GetRelatedHolonsPage { name, sort, page } => {
+            let collection_arc = target.related_holons(name)?;
+            let collection = collection_arc.read().map_err(|e| {
+                HolonError::FailedToAcquireLock(format!(
+                    "Failed to read-lock HolonCollection: {}",
+                    e
+                ))
+            })?;
+            let page = page_collection(&collection, sort.as_ref(), &page)?;
+            Ok(MapResult::Page(page))
+        }
     }
 }
 
@@ -6,10 +6,13 @@ use std::sync::Arc;
 use base_types::{BaseValue, MapInteger, MapString};
 use core_types::{HolonError, HolonId, LocalId};
 use holons_core::core_shared_objects::space_manager::HolonSpaceManager;
-use holons_core::core_shared_objects::transactions::{TransactionContext, TxId};
+use holons_core::core_shared_objects::transactions::{
+    RelationshipEnforcement, TransactionContext, TxId,
+};
 use holons_core::core_shared_objects::ServiceRoutingPolicy;
 use holons_core::reference_layer::{
-    HolonReference, HolonServiceApi, StagedReference, TransientReference, WritableHolon,
+    HolonReference, HolonServiceApi, ReadableHolon, StagedReference, TransientReference,
+    WritableHolon,
 };
 
 use client_shared_types::base_receptor::{BaseReceptor, ReceptorType};
@@ -322,6 +325,79 @@ async fn new_holon_stage_then_staged_count() {
     }
 }
 
+#[tokio::test]
+async fn validate_transaction_reports_without_committing() {
+    let runtime = build_test_runtime();
+    let tx_id = begin_tx(&runtime).await;
+
+    let cmd = tx_cmd(
+        &runtime,
+        &tx_id,
+        TransactionAction::NewHolon { key: Some(MapString::from("validate-test")) },
+    );
+    let transient_ref = match runtime
+        .execute_command(cmd, ExecutionPolicy::default())
+        .await
+        .expect("NewHolon should succeed")
+    {
+        MapResult::Reference(HolonReference::Transient(t)) => t,
+        other => panic!("expected Transient reference, got {:?}", other),
+    };
+    let cmd = tx_cmd(&runtime, &tx_id, TransactionAction::StageNewHolon { source: transient_ref });
+    runtime
+        .execute_command(cmd, ExecutionPolicy::default())
+        .await
+        .expect("StageNewHolon should succeed");
+
+    let result = runtime
+        .execute_command(
+            tx_cmd(&runtime, &tx_id, TransactionAction::ValidateTransaction),
+            ExecutionPolicy::default(),
+        )
+        .await
+        .expect("ValidateTransaction should succeed");
+    let report = match result {
+        MapResult::Reference(HolonReference::Transient(report)) => report,
+        other => panic!("expected Transient report reference, got {:?}", other),
+    };
+
+    // The staged holon has no descriptor, so it is counted but has no bound rules.
+    assert_eq!(
+        report.property_value("ValidatedHolonCount").expect("report property should read"),
+        Some(BaseValue::IntegerValue(MapInteger(1)))
+    );
+    assert_eq!(
+        report.property_value("BlockingResultCount").expect("report property should read"),
+        Some(BaseValue::IntegerValue(MapInteger(0)))
+    );
+    assert_eq!(staged_count(&runtime, &tx_id).await, 1);
+}
+
+#[tokio::test]
+async fn set_relationship_enforcement_switches_the_transaction_mode() {
+    let runtime = build_test_runtime();
+    let tx_id = begin_tx(&runtime).await;
+    let context = runtime.session().get_transaction(&tx_id).expect("tx should exist");
+    assert_eq!(context.relationship_enforcement(), RelationshipEnforcement::Advisory);
+
+    let result = runtime
+        .execute_command(
+            tx_cmd(
+                &runtime,
+                &tx_id,
+                TransactionAction::SetRelationshipEnforcement {
+                    enforcement: RelationshipEnforcement::Strict,
+                },
+            ),
+            ExecutionPolicy::default(),
+        )
+        .await
+        .expect("SetRelationshipEnforcement should succeed");
+
+    assert!(matches!(result, MapResult::None), "expected None, got {:?}", result);
+    assert_eq!(context.relationship_enforcement(), RelationshipEnforcement::Strict);
+}
+
 // ── Recovery-backed runtime helpers ────────────────────────────────
 
 fn build_test_session_receptor() -> Arc<SessionReceptor> {
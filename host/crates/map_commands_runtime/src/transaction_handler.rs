@@ -18,6 +18,14 @@ pub async fn handle_transaction(
             let response = context.commit()?;
             Ok(MapResult::Reference(HolonReference::Transient(response)))
         }
+        TransactionAction::ValidateTransaction => {
+            let report = context.validate()?;
+            Ok(MapResult::Reference(HolonReference::Transient(report)))
+        }
+        TransactionAction::SetRelationshipEnforcement { enforcement } => {
+            context.set_relationship_enforcement(enforcement);
+            Ok(MapResult::None)
+        }
         TransactionAction::UndoLast => {
             session.undo_last(&command.context.tx_id()).await?;
             Ok(MapResult::UndoComplete)
@@ -54,6 +62,27 @@ pub async fn handle_transaction(
             let collection = context.lookup().get_all_holons()?;
             Ok(MapResult::Collection(collection))
         }
+        TransactionAction::GetAllHolonsPage { sort, page } => {
+            let page = context.lookup().get_all_holons_page(sort.as_ref(), &page)?;
+            Ok(MapResult::Page(page))
+        }
+        TransactionAction::GetHolonsByDescriptor { descriptor, include_subtypes } => {
+            let collection =
+                context.lookup().get_holons_by_descriptor(&descriptor, include_subtypes)?;
+            Ok(MapResult::Collection(collection))
+        }
+        TransactionAction::GetSavedHolonByKey { key } => {
+            let reference = context.lookup().get_saved_holon_by_key(&key)?;
+            Ok(MapResult::Reference(reference))
+        }
+        TransactionAction::GetSavedHolonByVersionedKey { key } => {
+            let reference = context.lookup().get_saved_holon_by_versioned_key(&key)?;
+            Ok(MapResult::Reference(reference))
+        }
+        TransactionAction::GetSavedHolonsByKeyPrefix { prefix } => {
+            let collection = context.lookup().get_saved_holons_by_key_prefix(&prefix)?;
+            Ok(MapResult::Collection(collection))
+        }
         TransactionAction::GetStagedHolonByBaseKey { key } => {
             let staged = context.lookup().get_staged_holon_by_base_key(&key)?;
             Ok(MapResult::Reference(HolonReference::Staged(staged)))
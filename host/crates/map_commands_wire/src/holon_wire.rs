@@ -4,6 +4,7 @@ use base_types::BaseValue;
 use core_types::{HolonError, PropertyName, RelationshipName};
 use holons_boundary::HolonReferenceWire;
 use holons_core::core_shared_objects::transactions::{TransactionContext, TxId};
+use holons_core::query_layer::{PageRequest, SortKey};
 use serde::{Deserialize, Serialize};
 
 use map_commands_contract::{HolonAction, HolonCommand, ReadableHolonAction, WritableHolonAction};
@@ -53,6 +54,9 @@ pub enum ReadableHolonActionWire {
 
     /// `related_holons(name)` → `HolonCollection`
     GetRelatedHolons { name: RelationshipName },
+
+    /// `related_holons(name)`, sorted and windowed → `CollectionPage`
+    GetRelatedHolonsPage { name: RelationshipName, sort: Option<SortKey>, page: PageRequest },
 }
 
 /// Wire-level write (mutating) holon actions.
@@ -114,6 +118,9 @@ impl ReadableHolonActionWire {
             ReadableHolonActionWire::GetRelatedHolons { name } => {
                 ReadableHolonAction::GetRelatedHolons { name }
             }
+            ReadableHolonActionWire::GetRelatedHolonsPage { name, sort, page } => {
+                ReadableHolonAction::GetRelatedHolonsPage { name, sort, page }
+            }
         }
     }
 }
@@ -2,6 +2,7 @@ use base_types::BaseValue;
 use core_types::HolonId;
 use holons_boundary::{DanceResponseWire, HolonCollectionWire, HolonReferenceWire};
 use holons_core::core_shared_objects::transactions::TxId;
+use holons_core::query_layer::PageCursor;
 use serde::{Deserialize, Serialize};
 
 use map_commands_contract::MapResult;
@@ -41,6 +42,9 @@ pub enum MapResultWire {
     /// Canonical plural command result carrier at the IPC boundary.
     Collection(HolonCollectionWire),
 
+    /// One sorted window of a collection plus its continuation cursor.
+    Page { collection: HolonCollectionWire, next_cursor: Option<PageCursor>, total_count: usize },
+
     /// Universal scalar return.
     Value(BaseValue),
 
@@ -65,6 +69,11 @@ impl From<MapResult> for MapResultWire {
                 MapResultWire::References(refs.iter().map(HolonReferenceWire::from).collect())
             }
             MapResult::Collection(c) => MapResultWire::Collection(HolonCollectionWire::from(&c)),
+            MapResult::Page(page) => MapResultWire::Page {
+                collection: HolonCollectionWire::from(&page.collection),
+                next_cursor: page.next_cursor,
+                total_count: page.total_count,
+            },
             MapResult::Value(v) => MapResultWire::Value(v),
             MapResult::HolonId(id) => MapResultWire::HolonId(id),
             MapResult::DanceResponse(r) => {
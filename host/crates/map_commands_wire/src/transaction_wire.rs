@@ -6,7 +6,10 @@ use holons_boundary::{
     DanceRequestWire, DanceV2InvocationWire, HolonReferenceWire, SmartReferenceWire,
     TransientReferenceWire,
 };
-use holons_core::core_shared_objects::transactions::{TransactionContext, TxId};
+use holons_core::core_shared_objects::transactions::{
+    RelationshipEnforcement, TransactionContext, TxId,
+};
+use holons_core::query_layer::{PageRequest, SortKey};
 use serde::{Deserialize, Serialize};
 
 use map_commands_contract::{TransactionAction, TransactionCommand};
@@ -29,6 +32,12 @@ pub enum TransactionActionWire {
     /// Commits the transaction.
     Commit,
 
+    /// Validates the staged holons without committing.
+    ValidateTransaction,
+
+    /// Sets how relationship contracts are enforced for later staged mutations.
+    SetRelationshipEnforcement { enforcement: RelationshipEnforcement },
+
     /// Undoes the last mutation in this transaction.
     UndoLast,
 
@@ -58,6 +67,21 @@ pub enum TransactionActionWire {
     /// `get_all_holons()` → `HolonCollection`
     GetAllHolons,
 
+    /// `get_all_holons_page(sort, page)` → `CollectionPage`
+    GetAllHolonsPage { sort: Option<SortKey>, page: PageRequest },
+
+    /// `get_holons_by_descriptor(descriptor, include_subtypes)` → `HolonCollection`
+    GetHolonsByDescriptor { descriptor: HolonReferenceWire, include_subtypes: bool },
+
+    /// `get_saved_holon_by_key(key)` → `HolonReference`
+    GetSavedHolonByKey { key: MapString },
+
+    /// `get_saved_holon_by_versioned_key(key)` → `HolonReference`
+    GetSavedHolonByVersionedKey { key: MapString },
+
+    /// `get_saved_holons_by_key_prefix(prefix)` → `HolonCollection`
+    GetSavedHolonsByKeyPrefix { prefix: MapString },
+
     /// `get_staged_holon_by_base_key(key)` → `StagedReference`
     GetStagedHolonByBaseKey { key: MapString },
 
@@ -118,6 +142,12 @@ impl TransactionActionWire {
     fn bind(self, context: &Arc<TransactionContext>) -> Result<TransactionAction, HolonError> {
         match self {
             TransactionActionWire::Commit => Ok(TransactionAction::Commit),
+            TransactionActionWire::ValidateTransaction => {
+                Ok(TransactionAction::ValidateTransaction)
+            }
+            TransactionActionWire::SetRelationshipEnforcement { enforcement } => {
+                Ok(TransactionAction::SetRelationshipEnforcement { enforcement })
+            }
             TransactionActionWire::UndoLast => Ok(TransactionAction::UndoLast),
             TransactionActionWire::RedoLast => Ok(TransactionAction::RedoLast),
             TransactionActionWire::UndoToMarker { marker_id } => {
@@ -135,8 +165,26 @@ impl TransactionActionWire {
             TransactionActionWire::DanceV2 { invocation } => {
                 Ok(TransactionAction::DanceV2 { invocation: invocation.bind(context)? })
             }
-            // Lookup actions — no context binding needed
+            // Lookup actions — only descriptor lookups carry a reference to bind
             TransactionActionWire::GetAllHolons => Ok(TransactionAction::GetAllHolons),
+            TransactionActionWire::GetAllHolonsPage { sort, page } => {
+                Ok(TransactionAction::GetAllHolonsPage { sort, page })
+            }
+            TransactionActionWire::GetHolonsByDescriptor { descriptor, include_subtypes } => {
+                Ok(TransactionAction::GetHolonsByDescriptor {
+                    descriptor: descriptor.bind(context)?,
+                    include_subtypes,
+                })
+            }
+            TransactionActionWire::GetSavedHolonByKey { key } => {
+                Ok(TransactionAction::GetSavedHolonByKey { key })
+            }
+            TransactionActionWire::GetSavedHolonByVersionedKey { key } => {
+                Ok(TransactionAction::GetSavedHolonByVersionedKey { key })
+            }
+            TransactionActionWire::GetSavedHolonsByKeyPrefix { prefix } => {
+                Ok(TransactionAction::GetSavedHolonsByKeyPrefix { prefix })
+            }
             TransactionActionWire::GetStagedHolonByBaseKey { key } => {
                 Ok(TransactionAction::GetStagedHolonByBaseKey { key })
             }
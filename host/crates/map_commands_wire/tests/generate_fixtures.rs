@@ -2,7 +2,10 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
-use base_types::{BaseValue, MapBoolean, MapBytes, MapEnumValue, MapInteger, MapString};
+use base_types::{
+    BaseValue, MapBoolean, MapBytes, MapDate, MapDecimal, MapEnumValue, MapInteger, MapRecord,
+    MapString, MapTimestamp, MapValueArray,
+};
 use core_types::{
     ContentSet, ExternalId, FileData, HolonError, HolonId, LocalId, OutboundProxyId, PropertyMap,
     PropertyName, RelationshipName, TemporaryId, ValidationError,
@@ -428,6 +431,51 @@ fn generate_fixtures() {
             })),
         ),
     );
+    write_fixture(
+        &fixtures_dir,
+        "response-ok-value-decimal.json",
+        &response(119, Ok(MapResultWire::Value(BaseValue::DecimalValue(MapDecimal::new(1999, 2))))),
+    );
+    write_fixture(
+        &fixtures_dir,
+        "response-ok-value-timestamp.json",
+        &response(
+            120,
+            Ok(MapResultWire::Value(BaseValue::TimestampValue(
+                "2024-03-01T09:30:00.25Z".parse::<MapTimestamp>().unwrap(),
+            ))),
+        ),
+    );
+    write_fixture(
+        &fixtures_dir,
+        "response-ok-value-date.json",
+        &response(
+            121,
+            Ok(MapResultWire::Value(BaseValue::DateValue(MapDate::from_ymd(2024, 3, 1).unwrap()))),
+        ),
+    );
+    write_fixture(
+        &fixtures_dir,
+        "response-ok-value-array.json",
+        &response(
+            122,
+            Ok(MapResultWire::Value(BaseValue::ValueArray(MapValueArray(vec![
+                BaseValue::StringValue(map_string("alpha")),
+                BaseValue::StringValue(map_string("beta")),
+            ])))),
+        ),
+    );
+    write_fixture(
+        &fixtures_dir,
+        "response-ok-record.json",
+        &response(
+            123,
+            Ok(MapResultWire::Value(BaseValue::Record(MapRecord::from_iter([
+                (map_string("Amount"), BaseValue::DecimalValue(MapDecimal::new(950, 2))),
+                (map_string("Currency"), BaseValue::StringValue(map_string("EUR"))),
+            ])))),
+        ),
+    );
 }
 
 fn fixtures_dir() -> PathBuf {
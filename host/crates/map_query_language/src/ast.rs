@@ -0,0 +1,69 @@
+use base_types::{BaseValue, MapString};
+use core_types::PropertyName;
+use holons_core::query_layer::{RelationshipPath, VariableName};
+use type_names::{CoreOperatorTypeName, OperatorName};
+
+/// A parsed `MATCH ... RETURN ...` query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapQuery {
+    pub nodes: Vec<NodePattern>,
+    /// `hops[i]` connects `nodes[i]` to `nodes[i + 1]`.
+    pub hops: Vec<RelationshipPath>,
+    pub conditions: Vec<Condition>,
+    pub output: VariableName,
+}
+
+impl MapQuery {
+    /// Returns the index of the node pattern bound to `variable`.
+    pub fn node_index(&self, variable: &VariableName) -> Option<usize> {
+        self.nodes.iter().position(|node| node.variable == *variable)
+    }
+}
+
+/// A `(variable:TypeName)` node pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodePattern {
+    pub variable: VariableName,
+    pub type_name: Option<MapString>,
+}
+
+/// A `variable.property <operator> literal` condition from the `WHERE` clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    pub variable: VariableName,
+    pub property: PropertyName,
+    pub operator: ComparisonOperator,
+    pub operand: BaseValue,
+}
+
+/// Comparison operators accepted in `WHERE` conditions.
+///
+/// Each maps to an `OperatorType` name; whether the property's value type
+/// affords it is checked when the query runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    Equals,
+    NotEquals,
+    LessThan,
+    LessOrEqual,
+    GreaterThan,
+    GreaterOrEqual,
+    StartsWith,
+    Contains,
+}
+
+impl ComparisonOperator {
+    /// Returns the `OperatorType` name a `PropertyFilter` applies for this operator.
+    pub fn operator_name(&self) -> OperatorName {
+        match self {
+            Self::Equals => CoreOperatorTypeName::EqualsOperator.as_operator_name(),
+            Self::LessThan => CoreOperatorTypeName::LessThanOperator.as_operator_name(),
+            Self::NotEquals => CoreOperatorTypeName::NotEqualsOperator.as_operator_name(),
+            Self::LessOrEqual => CoreOperatorTypeName::LessOrEqualOperator.as_operator_name(),
+            Self::GreaterThan => CoreOperatorTypeName::GreaterThanOperator.as_operator_name(),
+            Self::GreaterOrEqual => CoreOperatorTypeName::GreaterOrEqualOperator.as_operator_name(),
+            Self::StartsWith => CoreOperatorTypeName::StartsWithOperator.as_operator_name(),
+            Self::Contains => CoreOperatorTypeName::ContainsOperator.as_operator_name(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use core_types::HolonError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token {
+    Match,
+    Where,
+    And,
+    Return,
+    Starts,
+    With,
+    Contains,
+    True,
+    False,
+    Identifier(String),
+    StringLiteral(String),
+    IntegerLiteral(i64),
+    /// Raw text between `[` and `]`, parsed later as a `RelationshipPath`.
+    RelationshipPath(String),
+    OpenParen,
+    CloseParen,
+    Colon,
+    Dot,
+    Dash,
+    Arrow,
+    Equals,
+    NotEquals,
+    LessThan,
+    LessOrEqual,
+    GreaterThan,
+    GreaterOrEqual,
+}
+
+/// A token and the byte offset where it starts in the query text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Spanned {
+    pub token: Token,
+    pub offset: usize,
+}
+
+pub(crate) fn syntax_error(offset: usize, reason: impl std::fmt::Display) -> HolonError {
+    HolonError::InvalidParameter(format!("Invalid MAP query at offset {offset}: {reason}"))
+}
+
+pub(crate) fn tokenize(text: &str) -> Result<Vec<Spanned>, HolonError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some(&(offset, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let token = match c {
+            '(' => single(&mut chars, Token::OpenParen),
+            ')' => single(&mut chars, Token::CloseParen),
+            ':' => single(&mut chars, Token::Colon),
+            '.' => single(&mut chars, Token::Dot),
+            '=' => single(&mut chars, Token::Equals),
+            '-' => {
+                chars.next();
+                match chars.peek() {
+                    Some(&(_, '>')) => single(&mut chars, Token::Arrow),
+                    Some(&(_, digit)) if digit.is_ascii_digit() => {
+                        let digits = take_while(&mut chars, |c| c.is_ascii_digit());
+                        integer(offset, &format!("-{digits}"))?
+                    }
+                    _ => Token::Dash,
+                }
+            }
+            '<' => {
+                chars.next();
+                match chars.peek() {
+                    Some(&(_, '=')) => single(&mut chars, Token::LessOrEqual),
+                    Some(&(_, '>')) => single(&mut chars, Token::NotEquals),
+                    _ => Token::LessThan,
+                }
+            }
+            '>' => {
+                chars.next();
+                match chars.peek() {
+                    Some(&(_, '=')) => single(&mut chars, Token::GreaterOrEqual),
+                    _ => Token::GreaterThan,
+                }
+            }
+            '[' => {
+                chars.next();
+                let path = take_while(&mut chars, |c| c != ']');
+                if chars.next().is_none() {
+                    return Err(syntax_error(offset, "unterminated relationship `[`"));
+                }
+                Token::RelationshipPath(path.trim().to_string())
+            }
+            '"' | '\'' => string_literal(&mut chars, offset)?,
+            c if c.is_ascii_digit() => {
+                let digits = take_while(&mut chars, |c| c.is_ascii_digit());
+                integer(offset, &digits)?
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let word = take_while(&mut chars, |c| c.is_alphanumeric() || c == '_');
+                keyword(&word).unwrap_or(Token::Identifier(word))
+            }
+            other => return Err(syntax_error(offset, format!("unexpected character `{other}`"))),
+        };
+        tokens.push(Spanned { token, offset });
+    }
+
+    Ok(tokens)
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::CharIndices<'a>>;
+
+fn single(chars: &mut Chars<'_>, token: Token) -> Token {
+    chars.next();
+    token
+}
+
+fn take_while(chars: &mut Chars<'_>, predicate: impl Fn(char) -> bool) -> String {
+    let mut taken = String::new();
+    while let Some(&(_, c)) = chars.peek() {
+        if !predicate(c) {
+            break;
+        }
+        taken.push(c);
+        chars.next();
+    }
+    taken
+}
+
+fn integer(offset: usize, digits: &str) -> Result<Token, HolonError> {
+    digits
+        .parse()
+        .map(Token::IntegerLiteral)
+        .map_err(|_| syntax_error(offset, format!("integer `{digits}` is out of range")))
+}
+
+fn string_literal(chars: &mut Chars<'_>, offset: usize) -> Result<Token, HolonError> {
+    let (_, quote) = chars.next().expect("caller peeked the opening quote");
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some((_, '\\')) => match chars.next() {
+                Some((_, escaped)) => value.push(escaped),
+                None => break,
+            },
+            Some((_, c)) if c == quote => return Ok(Token::StringLiteral(value)),
+            Some((_, c)) => value.push(c),
+            None => break,
+        }
+    }
+    Err(syntax_error(offset, "unterminated string literal"))
+}
+
+fn keyword(word: &str) -> Option<Token> {
+    let token = match word.to_ascii_uppercase().as_str() {
+        "MATCH" => Token::Match,
+        "WHERE" => Token::Where,
+        "AND" => Token::And,
+        "RETURN" => Token::Return,
+        "STARTS" => Token::Starts,
+        "WITH" => Token::With,
+        "CONTAINS" => Token::Contains,
+        "TRUE" => Token::True,
+        "FALSE" => Token::False,
+        _ => return None,
+    };
+    Some(token)
+}
@@ -0,0 +1,39 @@
+//! Textual MAP query language.
+//!
+//! A query names a linear relationship pattern, optional property conditions
+//! and the pattern variable whose holons are returned:
+//!
+//! ```text
+//! MATCH (b:Book)-[AuthoredBy]->(p:Person) WHERE p.name STARTS WITH "A" RETURN b
+//! ```
+//!
+//! Text is parsed into a [`MapQuery`], planned into a linear
+//! [`ExpressionPlan`] chain, and lowered into transient `Query` and
+//! `QueryExpression` holons that `execute_query` runs against an input
+//! collection. The first node pattern filters that input; it does not look
+//! holons up by type.
+//!
+//! Grammar (keywords are case-insensitive):
+//!
+//! ```text
+//! query     := MATCH node (hop node)* [WHERE condition (AND condition)*] RETURN variable
+//! node      := "(" variable [":" TypeName] ")"
+//! hop       := "-[" relationship-path "]->"
+//! condition := variable "." property operator literal
+//! operator  := "=" | "<>" | "<" | "<=" | ">" | ">=" | STARTS WITH | CONTAINS
+//! literal   := string | integer | TRUE | FALSE
+//! ```
+//!
+//! A relationship path uses the `RelationshipPath` syntax, so hops such as
+//! `[Authors/MentoredBy+]` are accepted.
+
+mod ast;
+mod lexer;
+mod lowering;
+mod parser;
+mod plan;
+
+pub use ast::*;
+pub use lowering::*;
+pub use parser::*;
+pub use plan::*;
@@ -0,0 +1,348 @@
+use std::sync::Arc;
+
+use crate::parser::parse_query;
+use crate::plan::{is_single_hop, plan_query, ExpressionPlan};
+use base_types::MapString;
+use core_types::HolonError;
+use holons_core::core_shared_objects::transactions::TransactionContext;
+use holons_core::{HolonReference, TransientReference, WritableHolon};
+use type_names::{CoreHolonTypeName, CorePropertyTypeName, CoreRelationshipTypeName};
+
+/// Supplies the Query schema descriptors that lowered holons are described by.
+///
+/// Implemented for closures so callers can resolve descriptors from whatever
+/// holon space or test fixture they have at hand.
+pub trait QueryTypeResolver {
+    fn resolve(&self, holon_type: CoreHolonTypeName) -> Result<HolonReference, HolonError>;
+}
+
+impl<F> QueryTypeResolver for F
+where
+    F: Fn(CoreHolonTypeName) -> Result<HolonReference, HolonError>,
+{
+    fn resolve(&self, holon_type: CoreHolonTypeName) -> Result<HolonReference, HolonError> {
+        self(holon_type)
+    }
+}
+
+/// Parses `text` and lowers it into a transient `Query` holon.
+///
+/// The query is named by its text and rooted at the planned expression chain;
+/// see [`plan_query`]. A pattern that neither filters nor traverses plans no
+/// expressions and is rejected, since a `Query` needs a `RootExpression`.
+pub fn compile_query(
+    context: &Arc<TransactionContext>,
+    text: &str,
+    types: &impl QueryTypeResolver,
+) -> Result<TransientReference, HolonError> {
+    let plan = plan_query(&parse_query(text)?)?;
+    if plan.is_empty() {
+        return Err(HolonError::InvalidParameter(format!(
+            "MAP query `{text}` has nothing to evaluate: add a type, condition or hop"
+        )));
+    }
+
+    let root = lower_chain(context, &plan, types)?;
+    let mut query = context.mutation().new_holon(Some(MapString("map-query".to_string())))?;
+    query.with_descriptor(types.resolve(CoreHolonTypeName::Query)?)?;
+    query
+        .with_property_value(CorePropertyTypeName::QueryName, text)?
+        .add_related_holons(CoreRelationshipTypeName::RootExpression, vec![root.into()])?;
+    Ok(query)
+}
+
+/// Lowers a non-empty plan into expression holons linked by `Next` and
+/// returns the first.
+fn lower_chain(
+    context: &Arc<TransactionContext>,
+    plan: &[ExpressionPlan],
+    types: &impl QueryTypeResolver,
+) -> Result<TransientReference, HolonError> {
+    let mut expressions = plan
+        .iter()
+        .map(|expression| lower_expression(context, expression, types))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for index in (1..expressions.len()).rev() {
+        let next = expressions[index].clone();
+        expressions[index - 1]
+            .add_related_holons(CoreRelationshipTypeName::Next, vec![next.into()])?;
+    }
+    Ok(expressions.swap_remove(0))
+}
+
+fn lower_expression(
+    context: &Arc<TransactionContext>,
+    plan: &ExpressionPlan,
+    types: &impl QueryTypeResolver,
+) -> Result<TransientReference, HolonError> {
+    let mut expression =
+        context.mutation().new_holon(Some(MapString("map-query-expression".to_string())))?;
+    expression.with_descriptor(types.resolve(plan.holon_type())?)?;
+
+    match plan {
+        ExpressionPlan::Traverse(path) if is_single_hop(path) => {
+            let relationship_name = path.steps()[0].relationship_name();
+            expression.with_property_value(
+                CorePropertyTypeName::RelationshipName,
+                &relationship_name.0,
+            )?;
+        }
+        ExpressionPlan::Traverse(path) => {
+            expression
+                .with_property_value(CorePropertyTypeName::PathExpression, path.to_string())?;
+        }
+        ExpressionPlan::TypeFilter(type_name) => {
+            expression.with_property_value(CorePropertyTypeName::FilterTypeName, type_name)?;
+        }
+        ExpressionPlan::PropertyFilter { property, operator, operand } => {
+            expression
+                .with_property_value(CorePropertyTypeName::FilterProperty, &property.0)?
+                .with_property_value(CorePropertyTypeName::FilterOperator, &operator.0)?
+                .with_property_value(CorePropertyTypeName::FilterOperand, operand.clone())?;
+        }
+        ExpressionPlan::Exists(condition) => {
+            let condition = lower_chain(context, condition, types)?;
+            expression
+                .add_related_holons(CoreRelationshipTypeName::Condition, vec![condition.into()])?;
+        }
+    }
+    Ok(expression)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base_types::{BaseValue, MapInteger};
+    use core_types::{HolonId, LocalId, RelationshipName};
+    use holons_core::core_shared_objects::space_manager::HolonSpaceManager;
+    use holons_core::core_shared_objects::{
+        Holon, HolonCollection, RelationshipMap, ServiceRoutingPolicy,
+    };
+    use holons_core::reference_layer::{HolonServiceApi, ReadableHolon, StagedReference};
+    use std::any::Any;
+
+    /// Fail-fast test double: lowering only creates transient holons, so any
+    /// call into the holon service is a test bug.
+    #[derive(Debug)]
+    struct TestHolonService;
+
+    fn unreachable_in_lowering_tests<T>() -> Result<T, HolonError> {
+        Err(HolonError::NotImplemented("TestHolonService".to_string()))
+    }
+
+    impl HolonServiceApi for TestHolonService {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn commit_internal(
+            &self,
+            _context: &Arc<TransactionContext>,
+            _staged_references: &[StagedReference],
+        ) -> Result<TransientReference, HolonError> {
+            unreachable_in_lowering_tests()
+        }
+
+        fn delete_holon_internal(
+            &self,
+            _context: &Arc<TransactionContext>,
+            _local_id: &LocalId,
+        ) -> Result<(), HolonError> {
+            unreachable_in_lowering_tests()
+        }
+
+        fn fetch_all_related_holons_internal(
+            &self,
+            _context: &Arc<TransactionContext>,
+            _source_id: &HolonId,
+        ) -> Result<RelationshipMap, HolonError> {
+            unreachable_in_lowering_tests()
+        }
+
+        fn fetch_holon_internal(
+            &self,
+            _context: &Arc<TransactionContext>,
+            _id: &HolonId,
+        ) -> Result<Holon, HolonError> {
+            unreachable_in_lowering_tests()
+        }
+
+        fn fetch_related_holons_internal(
+            &self,
+            _context: &Arc<TransactionContext>,
+            _source_id: &HolonId,
+            _relationship_name: &RelationshipName,
+        ) -> Result<HolonCollection, HolonError> {
+            unreachable_in_lowering_tests()
+        }
+
+        fn get_all_holons_internal(
+            &self,
+            _context: &Arc<TransactionContext>,
+        ) -> Result<HolonCollection, HolonError> {
+            unreachable_in_lowering_tests()
+        }
+
+        fn load_holons_internal(
+            &self,
+            _context: &Arc<TransactionContext>,
+            _bundle: TransientReference,
+        ) -> Result<TransientReference, HolonError> {
+            unreachable_in_lowering_tests()
+        }
+    }
+
+    fn build_context() -> Arc<TransactionContext> {
+        let holon_service: Arc<dyn HolonServiceApi> = Arc::new(TestHolonService);
+        let space_manager = Arc::new(HolonSpaceManager::new_with_managers(
+            None,
+            holon_service,
+            None,
+            ServiceRoutingPolicy::BlockExternal,
+        ));
+        space_manager
+            .get_transaction_manager()
+            .open_new_transaction(Arc::clone(&space_manager))
+            .expect("default transaction should open")
+    }
+
+    /// Resolves each Query schema type to a fresh transient descriptor named after it.
+    fn compile(
+        context: &Arc<TransactionContext>,
+        text: &str,
+    ) -> Result<TransientReference, HolonError> {
+        let types = |holon_type: CoreHolonTypeName| -> Result<HolonReference, HolonError> {
+            let type_name = holon_type.as_holon_name().to_string();
+            let mut descriptor =
+                context.mutation().new_holon(Some(MapString(format!("{type_name}-descriptor"))))?;
+            descriptor.with_property_value(CorePropertyTypeName::TypeName, type_name)?;
+            Ok(descriptor.into())
+        };
+        compile_query(context, text, &types)
+    }
+
+    fn related(
+        holon: &impl ReadableHolon,
+        relationship_name: CoreRelationshipTypeName,
+    ) -> Result<Vec<HolonReference>, HolonError> {
+        let collection = holon.related_holons(relationship_name)?;
+        let members = collection.read().expect("collection lock").get_members().clone();
+        Ok(members)
+    }
+
+    /// Follows `Next` from `first` and returns the whole chain.
+    fn chain(first: HolonReference) -> Result<Vec<HolonReference>, HolonError> {
+        let mut expressions = vec![first];
+        loop {
+            let next = related(expressions.last().unwrap(), CoreRelationshipTypeName::Next)?;
+            match next.as_slice() {
+                [] => return Ok(expressions),
+                [next] => expressions.push(next.clone()),
+                many => panic!("expected at most one Next, found {}", many.len()),
+            }
+        }
+    }
+
+    fn type_name(holon: &impl ReadableHolon) -> Result<String, HolonError> {
+        Ok(holon.holon_descriptor()?.header().type_name()?.0)
+    }
+
+    fn property(
+        holon: &impl ReadableHolon,
+        property: CorePropertyTypeName,
+    ) -> Result<Option<BaseValue>, HolonError> {
+        holon.property_value(property)
+    }
+
+    fn string(value: &str) -> Option<BaseValue> {
+        Some(BaseValue::StringValue(MapString(value.to_string())))
+    }
+
+    #[test]
+    fn compile_query_roots_a_query_holon_at_the_planned_chain() -> Result<(), HolonError> {
+        let context = build_context();
+        let text =
+            "match (b)-[Authors/MentoredBy+]->(m)-[Wrote]->(w:Book) where b.pages < 300 return w";
+
+        let query = compile(&context, text)?;
+
+        assert_eq!(type_name(&query)?, "Query");
+        assert_eq!(property(&query, CorePropertyTypeName::QueryName)?, string(text));
+        let roots = related(&query, CoreRelationshipTypeName::RootExpression)?;
+        assert_eq!(roots.len(), 1);
+        let expressions = chain(roots[0].clone())?;
+        let kinds = expressions.iter().map(type_name).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            kinds,
+            vec!["PropertyFilter", "PathTraversal", "RelationshipTraversal", "TypeFilter"]
+        );
+
+        let filter = &expressions[0];
+        assert_eq!(property(filter, CorePropertyTypeName::FilterProperty)?, string("Pages"));
+        assert_eq!(
+            property(filter, CorePropertyTypeName::FilterOperator)?,
+            string("LessThanOperator")
+        );
+        assert_eq!(
+            property(filter, CorePropertyTypeName::FilterOperand)?,
+            Some(BaseValue::IntegerValue(MapInteger(300)))
+        );
+        assert_eq!(
+            property(&expressions[1], CorePropertyTypeName::PathExpression)?,
+            string("Authors/MentoredBy+")
+        );
+        assert_eq!(
+            property(&expressions[2], CorePropertyTypeName::RelationshipName)?,
+            string("Wrote")
+        );
+        assert_eq!(
+            property(&expressions[3], CorePropertyTypeName::FilterTypeName)?,
+            string("Book")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn compile_query_lowers_the_unreturned_pattern_into_an_exists_condition(
+    ) -> Result<(), HolonError> {
+        let context = build_context();
+
+        let query = compile(
+            &context,
+            r#"MATCH (b:Book)-[AuthoredBy]->(p:Person) WHERE p.name STARTS WITH "A" RETURN b"#,
+        )?;
+
+        let roots = related(&query, CoreRelationshipTypeName::RootExpression)?;
+        let expressions = chain(roots[0].clone())?;
+        let kinds = expressions.iter().map(type_name).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(kinds, vec!["TypeFilter", "ExistsFilter"]);
+
+        let conditions = related(&expressions[1], CoreRelationshipTypeName::Condition)?;
+        assert_eq!(conditions.len(), 1);
+        let condition = chain(conditions[0].clone())?;
+        let kinds = condition.iter().map(type_name).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(kinds, vec!["RelationshipTraversal", "TypeFilter", "PropertyFilter"]);
+        assert_eq!(
+            property(&condition[0], CorePropertyTypeName::RelationshipName)?,
+            string("AuthoredBy")
+        );
+        assert_eq!(
+            property(&condition[2], CorePropertyTypeName::FilterOperator)?,
+            string("StartsWithOperator")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn compile_query_rejects_patterns_with_nothing_to_evaluate() {
+        let context = build_context();
+
+        for text in ["MATCH (b) RETURN b", "match (n) return n"] {
+            assert!(
+                matches!(compile(&context, text), Err(HolonError::InvalidParameter(_))),
+                "expected `{text}` to be rejected"
+            );
+        }
+    }
+}
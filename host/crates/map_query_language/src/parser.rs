@@ -0,0 +1,193 @@
+use crate::ast::{ComparisonOperator, Condition, MapQuery, NodePattern};
+use crate::lexer::{syntax_error, tokenize, Spanned, Token};
+use base_types::{BaseValue, MapBoolean, MapInteger, MapString};
+use core_types::HolonError;
+use holons_core::query_layer::{RelationshipPath, VariableName};
+use type_names::ToPropertyName;
+
+/// Parses query text into a [`MapQuery`].
+///
+/// Besides syntax, this checks that pattern variables are distinct and that
+/// every `WHERE` and `RETURN` variable is bound by the pattern. Errors are
+/// reported as `InvalidParameter` with the byte offset of the offending token.
+pub fn parse_query(text: &str) -> Result<MapQuery, HolonError> {
+    let mut parser = Parser { tokens: tokenize(text)?, position: 0, end: text.len() };
+    let query = parser.query()?;
+    parser.finish()?;
+    Ok(query)
+}
+
+struct Parser {
+    tokens: Vec<Spanned>,
+    position: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn query(&mut self) -> Result<MapQuery, HolonError> {
+        self.expect(Token::Match, "`MATCH`")?;
+
+        let mut nodes = vec![self.node()?];
+        let mut hops = Vec::new();
+        while self.peek() == Some(&Token::Dash) {
+            hops.push(self.hop()?);
+            let offset = self.offset();
+            let node = self.node()?;
+            if nodes.iter().any(|bound: &NodePattern| bound.variable == node.variable) {
+                return Err(syntax_error(
+                    offset,
+                    format!("variable `{}` is bound more than once", node.variable),
+                ));
+            }
+            nodes.push(node);
+        }
+
+        let mut conditions = Vec::new();
+        if self.accept(&Token::Where) {
+            loop {
+                conditions.push(self.condition(&nodes)?);
+                if !self.accept(&Token::And) {
+                    break;
+                }
+            }
+        }
+
+        self.expect(Token::Return, "`RETURN`")?;
+        let offset = self.offset();
+        let output = self.variable()?;
+        require_bound(&nodes, &output, offset)?;
+
+        Ok(MapQuery { nodes, hops, conditions, output })
+    }
+
+    fn node(&mut self) -> Result<NodePattern, HolonError> {
+        self.expect(Token::OpenParen, "`(`")?;
+        let variable = self.variable()?;
+        let type_name =
+            if self.accept(&Token::Colon) { Some(MapString(self.identifier()?)) } else { None };
+        self.expect(Token::CloseParen, "`)`")?;
+        Ok(NodePattern { variable, type_name })
+    }
+
+    fn hop(&mut self) -> Result<RelationshipPath, HolonError> {
+        self.expect(Token::Dash, "`-`")?;
+        let offset = self.offset();
+        let path = match self.next() {
+            Some(Token::RelationshipPath(path)) => {
+                RelationshipPath::parse(&path).map_err(|error| syntax_error(offset, error))?
+            }
+            _ => return Err(self.unexpected(offset, "`[relationship]`")),
+        };
+        self.expect(Token::Arrow, "`->`")?;
+        Ok(path)
+    }
+
+    fn condition(&mut self, nodes: &[NodePattern]) -> Result<Condition, HolonError> {
+        let offset = self.offset();
+        let variable = self.variable()?;
+        require_bound(nodes, &variable, offset)?;
+        self.expect(Token::Dot, "`.`")?;
+        let property = self.identifier()?.as_str().to_property_name();
+
+        let offset = self.offset();
+        let operator = match self.next() {
+            Some(Token::Equals) => ComparisonOperator::Equals,
+            Some(Token::NotEquals) => ComparisonOperator::NotEquals,
+            Some(Token::LessThan) => ComparisonOperator::LessThan,
+            Some(Token::LessOrEqual) => ComparisonOperator::LessOrEqual,
+            Some(Token::GreaterThan) => ComparisonOperator::GreaterThan,
+            Some(Token::GreaterOrEqual) => ComparisonOperator::GreaterOrEqual,
+            Some(Token::Contains) => ComparisonOperator::Contains,
+            Some(Token::Starts) => {
+                self.expect(Token::With, "`WITH`")?;
+                ComparisonOperator::StartsWith
+            }
+            _ => return Err(self.unexpected(offset, "a comparison operator")),
+        };
+
+        let offset = self.offset();
+        let operand = match self.next() {
+            Some(Token::StringLiteral(value)) => BaseValue::StringValue(MapString(value)),
+            Some(Token::IntegerLiteral(value)) => BaseValue::IntegerValue(MapInteger(value)),
+            Some(Token::True) => BaseValue::BooleanValue(MapBoolean(true)),
+            Some(Token::False) => BaseValue::BooleanValue(MapBoolean(false)),
+            _ => return Err(self.unexpected(offset, "a string, integer or boolean literal")),
+        };
+
+        Ok(Condition { variable, property, operator, operand })
+    }
+
+    fn variable(&mut self) -> Result<VariableName, HolonError> {
+        Ok(VariableName(MapString(self.identifier()?)))
+    }
+
+    fn identifier(&mut self) -> Result<String, HolonError> {
+        let offset = self.offset();
+        match self.next() {
+            Some(Token::Identifier(name)) => Ok(name),
+            _ => Err(self.unexpected(offset, "an identifier")),
+        }
+    }
+
+    fn finish(&self) -> Result<(), HolonError> {
+        match self.tokens.get(self.position) {
+            Some(spanned) => Err(syntax_error(spanned.offset, "unexpected text after RETURN")),
+            None => Ok(()),
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: &str) -> Result<(), HolonError> {
+        let offset = self.offset();
+        if self.accept(&token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(offset, expected))
+        }
+    }
+
+    fn accept(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|spanned| &spanned.token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.position += 1;
+        token
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens.get(self.position).map_or(self.end, |spanned| spanned.offset)
+    }
+
+    fn unexpected(&self, offset: usize, expected: &str) -> HolonError {
+        if offset == self.end {
+            syntax_error(offset, format!("expected {expected}, found end of query"))
+        } else {
+            syntax_error(offset, format!("expected {expected}"))
+        }
+    }
+}
+
+fn require_bound(
+    nodes: &[NodePattern],
+    variable: &VariableName,
+    offset: usize,
+) -> Result<(), HolonError> {
+    if nodes.iter().any(|node| node.variable == *variable) {
+        Ok(())
+    } else {
+        Err(syntax_error(
+            offset,
+            format!("variable `{variable}` is not bound by the MATCH pattern"),
+        ))
+    }
+}
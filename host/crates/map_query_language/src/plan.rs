@@ -0,0 +1,215 @@
+use crate::ast::MapQuery;
+use base_types::{BaseValue, MapString};
+use core_types::{HolonError, PropertyName};
+use holons_core::query_layer::RelationshipPath;
+use type_names::{CoreHolonTypeName, OperatorName};
+
+/// One `QueryExpression` in a planned query, before it is materialized as a holon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpressionPlan {
+    /// A `RelationshipTraversal` for one-hop paths, otherwise a `PathTraversal`.
+    Traverse(RelationshipPath),
+    TypeFilter(MapString),
+    PropertyFilter {
+        property: PropertyName,
+        operator: OperatorName,
+        operand: BaseValue,
+    },
+    /// An `ExistsFilter` whose `Condition` is the nested chain.
+    Exists(Vec<ExpressionPlan>),
+}
+
+impl ExpressionPlan {
+    /// Returns the Query schema type that describes this expression.
+    pub fn holon_type(&self) -> CoreHolonTypeName {
+        match self {
+            Self::Traverse(path) if is_single_hop(path) => CoreHolonTypeName::RelationshipTraversal,
+            Self::Traverse(_) => CoreHolonTypeName::PathTraversal,
+            Self::TypeFilter(_) => CoreHolonTypeName::TypeFilter,
+            Self::PropertyFilter { .. } => CoreHolonTypeName::PropertyFilter,
+            Self::Exists(_) => CoreHolonTypeName::ExistsFilter,
+        }
+    }
+}
+
+pub(crate) fn is_single_hop(path: &RelationshipPath) -> bool {
+    matches!(path.steps(), [step] if step.min_hops() == 1 && step.max_hops() == Some(1))
+}
+
+/// Plans `query` as a linear `Next` chain.
+///
+/// Each node contributes its type filter and then its conditions, and each hop
+/// a traversal. Nodes up to the `RETURN` variable run in sequence; the rest of
+/// the pattern becomes one trailing `Exists` so that the chain still yields
+/// the returned node's holons.
+///
+/// `parse_query` only produces well-formed queries, but a hand-built
+/// [`MapQuery`] may not bind its `RETURN` or condition variables, or may not
+/// have one hop between each pair of nodes; such queries are rejected.
+pub fn plan_query(query: &MapQuery) -> Result<Vec<ExpressionPlan>, HolonError> {
+    if query.hops.len() + 1 != query.nodes.len() {
+        return Err(HolonError::InvalidParameter(format!(
+            "MAP query has {} node patterns but {} hops; expected one hop between each pair",
+            query.nodes.len(),
+            query.hops.len()
+        )));
+    }
+    if let Some(condition) =
+        query.conditions.iter().find(|condition| query.node_index(&condition.variable).is_none())
+    {
+        return Err(HolonError::InvalidParameter(format!(
+            "MAP query condition uses unbound variable `{}`",
+            condition.variable
+        )));
+    }
+    let output = query.node_index(&query.output).ok_or_else(|| {
+        HolonError::InvalidParameter(format!(
+            "MAP query returns unbound variable `{}`",
+            query.output
+        ))
+    })?;
+
+    let mut chain = node_filters(query, 0);
+    for index in 1..=output {
+        chain.push(ExpressionPlan::Traverse(query.hops[index - 1].clone()));
+        chain.extend(node_filters(query, index));
+    }
+
+    let mut condition = Vec::new();
+    for index in output + 1..query.nodes.len() {
+        condition.push(ExpressionPlan::Traverse(query.hops[index - 1].clone()));
+        condition.extend(node_filters(query, index));
+    }
+    if !condition.is_empty() {
+        chain.push(ExpressionPlan::Exists(condition));
+    }
+
+    Ok(chain)
+}
+
+fn node_filters(query: &MapQuery, index: usize) -> Vec<ExpressionPlan> {
+    let node = &query.nodes[index];
+    let type_filter = node.type_name.clone().map(ExpressionPlan::TypeFilter);
+    let property_filters =
+        query.conditions.iter().filter(|condition| condition.variable == node.variable).map(
+            |condition| ExpressionPlan::PropertyFilter {
+                property: condition.property.clone(),
+                operator: condition.operator.operator_name(),
+                operand: condition.operand.clone(),
+            },
+        );
+    type_filter.into_iter().chain(property_filters).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{ComparisonOperator, Condition};
+    use crate::parse_query;
+    use base_types::MapInteger;
+    use holons_core::query_layer::VariableName;
+
+    fn traverse(path: &str) -> ExpressionPlan {
+        ExpressionPlan::Traverse(RelationshipPath::parse(path).unwrap())
+    }
+
+    fn type_filter(type_name: &str) -> ExpressionPlan {
+        ExpressionPlan::TypeFilter(MapString(type_name.to_string()))
+    }
+
+    #[test]
+    fn returning_an_earlier_node_plans_the_rest_as_an_exists_condition() -> Result<(), HolonError> {
+        let query = parse_query(
+            r#"MATCH (b:Book)-[AuthoredBy]->(p:Person) WHERE p.name STARTS WITH "A" RETURN b"#,
+        )?;
+
+        let plan = plan_query(&query)?;
+
+        assert_eq!(
+            plan,
+            vec![
+                type_filter("Book"),
+                ExpressionPlan::Exists(vec![
+                    traverse("AuthoredBy"),
+                    type_filter("Person"),
+                    ExpressionPlan::PropertyFilter {
+                        property: PropertyName(MapString("Name".to_string())),
+                        operator: OperatorName(MapString("StartsWithOperator".to_string())),
+                        operand: BaseValue::StringValue(MapString("A".to_string())),
+                    },
+                ]),
+            ]
+        );
+        assert!(matches!(plan[1].holon_type(), CoreHolonTypeName::ExistsFilter));
+        Ok(())
+    }
+
+    #[test]
+    fn returning_the_last_node_plans_a_linear_chain() -> Result<(), HolonError> {
+        let query = parse_query(
+            "match (b)-[Authors/MentoredBy+]->(m)-[Wrote]->(w:Book) where b.pages < 300 return w",
+        )?;
+
+        let plan = plan_query(&query)?;
+
+        assert_eq!(
+            plan,
+            vec![
+                ExpressionPlan::PropertyFilter {
+                    property: PropertyName(MapString("Pages".to_string())),
+                    operator: OperatorName(MapString("LessThanOperator".to_string())),
+                    operand: BaseValue::IntegerValue(MapInteger(300)),
+                },
+                traverse("Authors/MentoredBy+"),
+                traverse("Wrote"),
+                type_filter("Book"),
+            ]
+        );
+        assert!(matches!(plan[1].holon_type(), CoreHolonTypeName::PathTraversal));
+        assert!(matches!(plan[2].holon_type(), CoreHolonTypeName::RelationshipTraversal));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_query_rejects_malformed_and_unbound_queries() {
+        for text in [
+            "",
+            "MATCH (b:Book RETURN b",
+            "MATCH (b)-[AuthoredBy]-(p) RETURN b",
+            "MATCH (b)-[]->(p) RETURN b",
+            "MATCH (b)-[AuthoredBy]->(b) RETURN b",
+            "MATCH (b) WHERE p.name = \"A\" RETURN b",
+            "MATCH (b) WHERE b.name STARTS \"A\" RETURN b",
+            "MATCH (b) WHERE b.name = \"A RETURN b",
+            "MATCH (b) RETURN p",
+            "MATCH (b) RETURN b b",
+        ] {
+            assert!(
+                matches!(parse_query(text), Err(HolonError::InvalidParameter(_))),
+                "expected `{text}` to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn plan_query_rejects_hand_built_queries_with_unbound_variables() -> Result<(), HolonError> {
+        let parsed = parse_query("MATCH (b)-[AuthoredBy]->(p) RETURN b")?;
+
+        let unbound_output = MapQuery { output: VariableName::from("x"), ..parsed.clone() };
+        let missing_hop = MapQuery { hops: Vec::new(), ..parsed.clone() };
+        let unbound_condition = MapQuery {
+            conditions: vec![Condition {
+                variable: VariableName::from("x"),
+                property: PropertyName(MapString("Name".to_string())),
+                operator: ComparisonOperator::Equals,
+                operand: BaseValue::StringValue(MapString("A".to_string())),
+            }],
+            ..parsed
+        };
+
+        for query in [unbound_output, missing_hop, unbound_condition] {
+            assert!(matches!(plan_query(&query), Err(HolonError::InvalidParameter(_))));
+        }
+        Ok(())
+    }
+}
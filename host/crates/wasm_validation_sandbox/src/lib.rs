@@ -0,0 +1,509 @@
+//! Host sandbox for `WasmModule` validation implementations.
+//!
+//! [`WasmerValidationSandbox`] compiles registered modules with wasmer and
+//! runs every validation in a fresh instance, so no state carries over from
+//! one subject to the next. Execution is bounded by [`SandboxLimits`]: each
+//! call gets a budget of metered operators (fuel), and a module must export a
+//! memory whose declared maximum fits the page limit. Modules may not import
+//! anything, so they have no access to the host.
+//!
+//! A module exchanges UTF-8 JSON with the host through its exported `memory`:
+//!
+//! ```text
+//! (func (export "alloc") (param $len i32) (result i32))
+//! (func (export "<entrypoint>") (param $ptr i32) (param $len i32) (result i64))
+//! ```
+//!
+//! The host copies the subject into a buffer obtained from `alloc` and calls
+//! the entrypoint, which returns `ptr << 32 | len` locating a JSON array of
+//! violation messages, or `0` when the subject conforms. The subject is
+//! encoded as
+//!
+//! ```json
+//! {"level": "Value", "key": "alice", "path": "Name",
+//!  "properties": {"Name": {"StringValue": "Al"}}, "value": {"StringValue": "Al"}}
+//! ```
+//!
+//! where `key`, `path` and `value` are `null` when the subject has none.
+//!
+//! Install the sandbox in the registry a space validates against:
+//!
+//! ```ignore
+//! let sandbox = WasmerValidationSandbox::new(SandboxLimits::default());
+//! sandbox.register_module(MapString("isbn".into()), &wasm_bytes)?;
+//! let mut registry = ValidationRuleRegistry::with_core_rules();
+//! registry.set_wasm_sandbox(sandbox);
+//! space_manager.set_validation_registry(registry)?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use base_types::MapString;
+use core_types::{HolonError, ValidationError};
+use holons_core::reference_layer::ReadableHolon;
+use holons_core::validation::{ValidationSubject, WasmValidationSandbox};
+use serde_json::{json, Value};
+use wasmer::sys::{CompilerConfig, Cranelift};
+use wasmer::wasmparser::Operator;
+use wasmer::{
+    imports, Engine, ExternType, Instance, Memory, Module, RuntimeError, Store, TypedFunction,
+};
+use wasmer_middlewares::metering::{get_remaining_points, set_remaining_points, MeteringPoints};
+use wasmer_middlewares::Metering;
+
+/// Name of the memory export modules exchange data through.
+pub const MEMORY_EXPORT: &str = "memory";
+/// Name of the export the host calls to obtain a buffer for the subject.
+pub const ALLOC_EXPORT: &str = "alloc";
+
+/// Resource bounds applied to every module call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SandboxLimits {
+    /// Metered operators one entrypoint call may execute, `alloc` included.
+    pub fuel: u64,
+    /// Largest declared maximum, in 64 KiB pages, a module's memory may have.
+    pub max_memory_pages: u32,
+}
+
+impl Default for SandboxLimits {
+    /// Ten million operators and 16 MiB of memory.
+    fn default() -> Self {
+        Self { fuel: 10_000_000, max_memory_pages: 256 }
+    }
+}
+
+/// Runs registered WASM validation modules under [`SandboxLimits`].
+pub struct WasmerValidationSandbox {
+    limits: SandboxLimits,
+    modules: RwLock<HashMap<MapString, MeteredModule>>,
+}
+
+/// A compiled module with the engine it was compiled by. A `Metering`
+/// middleware tracks the globals of exactly one module, so each module gets
+/// an engine of its own.
+#[derive(Clone)]
+struct MeteredModule {
+    engine: Engine,
+    module: Module,
+}
+
+impl WasmerValidationSandbox {
+    /// Creates a sandbox with no modules whose calls are bounded by `limits`.
+    pub fn new(limits: SandboxLimits) -> Self {
+        Self { limits, modules: RwLock::new(HashMap::new()) }
+    }
+
+    /// Returns the limits applied to every call.
+    pub fn limits(&self) -> SandboxLimits {
+        self.limits
+    }
+
+    /// Compiles `wasm` (binary or text format) and registers it under the
+    /// `ImplementationRef` rules use to name it, replacing any module
+    /// previously registered under that reference.
+    ///
+    /// Modules that import anything, do not export a bounded `memory`, or
+    /// declare a maximum above the page limit are rejected here rather than
+    /// when a rule first runs them.
+    pub fn register_module(&self, module_ref: MapString, wasm: &[u8]) -> Result<(), HolonError> {
+        let mut compiler = Cranelift::default();
+        compiler.push_middleware(Arc::new(Metering::new(self.limits.fuel, |_: &Operator| 1)));
+        let engine = Engine::from(compiler);
+        let module = Module::new(&engine, wasm).map_err(|e| {
+            HolonError::WasmError(format!("Failed to compile validation module {module_ref}: {e}"))
+        })?;
+        self.check_module(&module_ref, &module)?;
+
+        let mut modules = self.modules.write().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire write lock on validation modules: {}",
+                e
+            ))
+        })?;
+        modules.insert(module_ref, MeteredModule { engine, module });
+        Ok(())
+    }
+
+    fn check_module(&self, module_ref: &MapString, module: &Module) -> Result<(), HolonError> {
+        if let Some(import) = module.imports().next() {
+            return Err(HolonError::WasmError(format!(
+                "Validation module {module_ref} may not import {}::{}",
+                import.module(),
+                import.name()
+            )));
+        }
+
+        let mut has_memory = false;
+        for export in module.exports() {
+            let ExternType::Memory(memory) = export.ty() else {
+                continue;
+            };
+            match memory.maximum {
+                Some(maximum) if maximum.0 <= self.limits.max_memory_pages => {}
+                maximum => {
+                    return Err(HolonError::WasmError(format!(
+                        "Validation module {module_ref} declares memory `{}` with maximum \
+                         {maximum:?}; at most {} pages are allowed",
+                        export.name(),
+                        self.limits.max_memory_pages
+                    )))
+                }
+            }
+            has_memory |= export.name() == MEMORY_EXPORT;
+        }
+        if !has_memory {
+            return Err(HolonError::WasmError(format!(
+                "Validation module {module_ref} does not export `{MEMORY_EXPORT}`"
+            )));
+        }
+        Ok(())
+    }
+
+    fn module(&self, module_ref: &MapString) -> Result<MeteredModule, HolonError> {
+        let modules = self.modules.read().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire read lock on validation modules: {}",
+                e
+            ))
+        })?;
+        modules.get(module_ref).cloned().ok_or_else(|| {
+            HolonError::WasmError(format!("Validation module {module_ref} is not registered"))
+        })
+    }
+
+    /// Instantiates the module and runs one entrypoint call, returning the
+    /// violation messages it reported.
+    fn run(
+        &self,
+        module_ref: &MapString,
+        entrypoint: &MapString,
+        input: &[u8],
+    ) -> Result<Vec<String>, HolonError> {
+        let MeteredModule { engine, module } = self.module(module_ref)?;
+        let wasm_error = |detail: String| {
+            HolonError::WasmError(format!("Validation module {module_ref}: {detail}"))
+        };
+
+        let mut store = Store::new(engine);
+        let instance = Instance::new(&mut store, &module, &imports! {})
+            .map_err(|e| wasm_error(format!("instantiation failed: {e}")))?;
+        set_remaining_points(&mut store, &instance, self.limits.fuel);
+
+        let memory = instance
+            .exports
+            .get_memory(MEMORY_EXPORT)
+            .map_err(|e| wasm_error(e.to_string()))?
+            .clone();
+        let alloc: TypedFunction<i32, i32> = instance
+            .exports
+            .get_typed_function(&store, ALLOC_EXPORT)
+            .map_err(|e| wasm_error(e.to_string()))?;
+        let validate: TypedFunction<(i32, i32), i64> = instance
+            .exports
+            .get_typed_function(&store, entrypoint.0.as_str())
+            .map_err(|e| wasm_error(format!("entrypoint `{entrypoint}`: {e}")))?;
+
+        let len = i32::try_from(input.len())
+            .map_err(|_| wasm_error(format!("subject of {} bytes is too large", input.len())))?;
+        let call_error = |store: &mut Store, call: &str, e: RuntimeError| {
+            let detail = match get_remaining_points(store, &instance) {
+                MeteringPoints::Exhausted => {
+                    format!("`{call}` exhausted its fuel of {} operators", self.limits.fuel)
+                }
+                MeteringPoints::Remaining(_) => format!("`{call}` trapped: {e}"),
+            };
+            wasm_error(detail)
+        };
+
+        let ptr =
+            alloc.call(&mut store, len).map_err(|e| call_error(&mut store, ALLOC_EXPORT, e))?;
+        write_bytes(&store, &memory, ptr, input).map_err(wasm_error)?;
+        let packed = validate
+            .call(&mut store, ptr, len)
+            .map_err(|e| call_error(&mut store, &entrypoint.0, e))?;
+        if packed == 0 {
+            return Ok(Vec::new());
+        }
+
+        let output = read_bytes(&store, &memory, (packed >> 32) as u32, packed as u32)
+            .map_err(wasm_error)?;
+        serde_json::from_slice(&output)
+            .map_err(|e| wasm_error(format!("result is not a JSON array of messages: {e}")))
+    }
+}
+
+impl WasmValidationSandbox for WasmerValidationSandbox {
+    fn validate(
+        &self,
+        module_ref: &MapString,
+        entrypoint: &MapString,
+        subject: &ValidationSubject,
+    ) -> Result<Vec<HolonError>, HolonError> {
+        let input = serde_json::to_vec(&subject_json(subject)?).map_err(|e| {
+            HolonError::WasmError(format!("Failed to encode validation subject: {e}"))
+        })?;
+        Ok(self
+            .run(module_ref, entrypoint, &input)?
+            .into_iter()
+            .map(|message| HolonError::ValidationError(ValidationError::WasmError(message)))
+            .collect())
+    }
+}
+
+/// Projects `subject` into the JSON document modules receive.
+fn subject_json(subject: &ValidationSubject) -> Result<Value, HolonError> {
+    let holon = subject.holon();
+    let value = match subject {
+        ValidationSubject::Value { value, .. } => Some(value),
+        _ => None,
+    };
+    Ok(json!({
+        "level": subject.level().as_str(),
+        "key": holon.key()?.map(|key| key.0),
+        "path": subject.path(),
+        "properties": holon.into_model()?.property_map,
+        "value": value,
+    }))
+}
+
+fn write_bytes(store: &Store, memory: &Memory, ptr: i32, bytes: &[u8]) -> Result<(), String> {
+    memory
+        .view(store)
+        .write(ptr as u32 as u64, bytes)
+        .map_err(|e| format!("cannot write subject at {ptr}: {e}"))
+}
+
+fn read_bytes(store: &Store, memory: &Memory, ptr: u32, len: u32) -> Result<Vec<u8>, String> {
+    let view = memory.view(store);
+    if ptr as u64 + len as u64 > view.data_size() {
+        return Err(format!("result of {len} bytes at {ptr} lies outside memory"));
+    }
+    let mut bytes = vec![0; len as usize];
+    view.read(ptr as u64, &mut bytes)
+        .map_err(|e| format!("cannot read {len} result bytes at {ptr}: {e}"))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use holons_client::init_client_context;
+    use holons_core::core_shared_objects::transactions::TransactionContext;
+    use holons_core::descriptors::HolonDescriptor;
+    use holons_core::reference_layer::{HolonReference, TransientReference, WritableHolon};
+    use holons_core::validation::{ValidationEngine, ValidationResult, ValidationRuleRegistry};
+    use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+    const DRAFT_VIOLATION: &str = "draft holons may not be committed";
+
+    /// Reports [`DRAFT_VIOLATION`] for any subject whose key is `draft`.
+    fn reject_drafts_module() -> String {
+        let needle = r#""key":"draft""#;
+        let result = format!(r#"["{DRAFT_VIOLATION}"]"#);
+        format!(
+            r#"(module
+              (memory (export "memory") 1 1)
+              (global $next (mut i32) (i32.const 1024))
+              (data (i32.const 0) "{needle_data}")
+              (data (i32.const 64) "{result_data}")
+              (func (export "alloc") (param $len i32) (result i32)
+                (local $ptr i32)
+                (local.set $ptr (global.get $next))
+                (global.set $next (i32.add (global.get $next) (local.get $len)))
+                (local.get $ptr))
+              (func $matches_at (param $at i32) (result i32)
+                (local $i i32)
+                (loop $compare
+                  (if (i32.ne (i32.load8_u (i32.add (local.get $at) (local.get $i)))
+                              (i32.load8_u (local.get $i)))
+                    (then (return (i32.const 0))))
+                  (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                  (br_if $compare (i32.lt_u (local.get $i) (i32.const {needle_len}))))
+                (i32.const 1))
+              (func (export "validate") (param $ptr i32) (param $len i32) (result i64)
+                (local $at i32)
+                (local $last i32)
+                (local.set $at (local.get $ptr))
+                (local.set $last
+                  (i32.sub (i32.add (local.get $ptr) (local.get $len)) (i32.const {needle_len})))
+                (block $done
+                  (loop $scan
+                    (br_if $done (i32.gt_s (local.get $at) (local.get $last)))
+                    (if (call $matches_at (local.get $at))
+                      (then (return (i64.or (i64.shl (i64.const 64) (i64.const 32))
+                                            (i64.const {result_len})))))
+                    (local.set $at (i32.add (local.get $at) (i32.const 1)))
+                    (br $scan)))
+                (i64.const 0)))"#,
+            needle_data = needle.replace('"', "\\\""),
+            needle_len = needle.len(),
+            result_data = result.replace('"', "\\\""),
+            result_len = result.len(),
+        )
+    }
+
+    const SPIN_MODULE: &str = r#"(module
+      (memory (export "memory") 1 1)
+      (func (export "alloc") (param i32) (result i32) (i32.const 0))
+      (func (export "validate") (param i32 i32) (result i64)
+        (loop $spin (br $spin))
+        (i64.const 0)))"#;
+
+    fn rule_ref(name: &str) -> MapString {
+        MapString(name.to_string())
+    }
+
+    /// BookType binds a holon-level rule implemented by the `reject-drafts` module.
+    fn book_type(context: &Arc<TransactionContext>) -> Result<TransientReference, HolonError> {
+        let new_holon = |key: &str| context.mutation().new_holon(Some(MapString(key.to_string())));
+
+        let mut implementation = new_holon("reject-drafts-implementation")?;
+        implementation
+            .with_property_value(CorePropertyTypeName::ImplementationEngine, "WasmModule")?
+            .with_property_value(CorePropertyTypeName::ImplementationRef, "reject-drafts")?;
+        let mut rule = new_holon("RejectDrafts-rule")?;
+        rule.with_property_value(CorePropertyTypeName::TypeName, "RejectDrafts")?
+            .with_property_value(CorePropertyTypeName::ValidationLevel, "Holon")?
+            .with_property_value(CorePropertyTypeName::DefaultSeverity, "Error")?
+            .with_property_value(CorePropertyTypeName::MinimumBlockingBehavior, "CommitBlocking")?;
+        rule.add_related_holons(
+            CoreRelationshipTypeName::ImplementedBy,
+            vec![implementation.into()],
+        )?;
+
+        let mut book_type = new_holon("book")?;
+        book_type
+            .with_property_value(CorePropertyTypeName::TypeName, "BookType")?
+            .with_property_value(CorePropertyTypeName::IsAbstractType, false)?;
+        let mut binding = new_holon("book-reject-drafts-binding")?;
+        binding.add_related_holons(
+            CoreRelationshipTypeName::AppliesTo,
+            vec![book_type.clone().into()],
+        )?;
+        binding.add_related_holons(CoreRelationshipTypeName::UsesRule, vec![rule.into()])?;
+        book_type.add_related_holons(
+            CoreRelationshipTypeName::HasValidationBinding,
+            vec![binding.into()],
+        )?;
+        Ok(book_type)
+    }
+
+    fn book(
+        context: &Arc<TransactionContext>,
+        book_type: &TransientReference,
+        key: &str,
+    ) -> Result<HolonReference, HolonError> {
+        let mut holon = context.mutation().new_holon(Some(MapString(key.to_string())))?;
+        holon.add_related_holons(
+            CoreRelationshipTypeName::DescribedBy,
+            vec![book_type.clone().into()],
+        )?;
+        Ok(holon.into())
+    }
+
+    fn holon_subject(holon: &HolonReference, descriptor: &TransientReference) -> ValidationSubject {
+        ValidationSubject::Holon {
+            holon: holon.clone(),
+            descriptor: HolonDescriptor::from_holon(descriptor.clone().into()),
+        }
+    }
+
+    #[test]
+    fn wasm_rules_run_in_the_sandbox_through_the_validation_engine() -> Result<(), HolonError> {
+        let context = init_client_context(None);
+        let book_type = book_type(&context)?;
+        let sandbox = WasmerValidationSandbox::new(SandboxLimits::default());
+        sandbox.register_module(rule_ref("reject-drafts"), reject_drafts_module().as_bytes())?;
+        let mut registry = ValidationRuleRegistry::new();
+        registry.set_wasm_sandbox(sandbox);
+
+        let holons =
+            vec![book(&context, &book_type, "draft")?, book(&context, &book_type, "final")?];
+        let results = ValidationEngine::new(registry).validate_holons(&holons)?;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].holon, holons[0]);
+        assert_eq!(results[0].rule_name, rule_ref("RejectDrafts"));
+        assert!(results[0].is_blocking());
+        assert!(matches!(
+            &results[0].violation,
+            HolonError::ValidationError(ValidationError::WasmError(message))
+                if message == DRAFT_VIOLATION
+        ));
+        assert!(results.iter().all(ValidationResult::is_blocking));
+        Ok(())
+    }
+
+    #[test]
+    fn calls_that_exhaust_their_fuel_fail() -> Result<(), HolonError> {
+        let context = init_client_context(None);
+        let book_type = book_type(&context)?;
+        let holon = book(&context, &book_type, "draft")?;
+        let sandbox =
+            WasmerValidationSandbox::new(SandboxLimits { fuel: 10_000, ..Default::default() });
+        sandbox.register_module(rule_ref("spin"), SPIN_MODULE.as_bytes())?;
+
+        let error = sandbox
+            .validate(&rule_ref("spin"), &rule_ref("validate"), &holon_subject(&holon, &book_type))
+            .expect_err("an endless loop cannot finish");
+
+        assert!(matches!(
+            &error,
+            HolonError::WasmError(message) if message.contains("exhausted its fuel of 10000")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn modules_must_bound_their_memory_and_import_nothing() {
+        let sandbox = WasmerValidationSandbox::new(SandboxLimits::default());
+        let rejected = |wat: &str, expected: &str| {
+            let error = sandbox
+                .register_module(rule_ref("rejected"), wat.as_bytes())
+                .expect_err("module should be rejected");
+            assert!(
+                matches!(&error, HolonError::WasmError(message) if message.contains(expected)),
+                "unexpected error {error:?}"
+            );
+        };
+
+        rejected(r#"(module (memory (export "memory") 1))"#, "with maximum None");
+        rejected(r#"(module (memory (export "memory") 1 1024))"#, "at most 256 pages");
+        rejected(r#"(module (memory 1 1))"#, "does not export `memory`");
+        rejected(
+            r#"(module (import "env" "clock" (func)) (memory (export "memory") 1 1))"#,
+            "may not import env::clock",
+        );
+    }
+
+    #[test]
+    fn results_outside_memory_are_errors() -> Result<(), HolonError> {
+        let context = init_client_context(None);
+        let book_type = book_type(&context)?;
+        let holon = book(&context, &book_type, "draft")?;
+        let sandbox = WasmerValidationSandbox::new(SandboxLimits::default());
+        sandbox.register_module(
+            rule_ref("overflow"),
+            br#"(module
+              (memory (export "memory") 1 1)
+              (func (export "alloc") (param i32) (result i32) (i32.const 0))
+              (func (export "validate") (param i32 i32) (result i64)
+                (i64.const 0x0000_ff00_ffff_ffff)))"#,
+        )?;
+
+        let error = sandbox
+            .validate(
+                &rule_ref("overflow"),
+                &rule_ref("validate"),
+                &holon_subject(&holon, &book_type),
+            )
+            .expect_err("the result lies outside memory");
+
+        assert!(matches!(
+            &error,
+            HolonError::WasmError(message) if message.contains("lies outside memory")
+        ));
+        Ok(())
+    }
+}
@@ -6,18 +6,22 @@ import {
   expectNone,
   expectOptionalReference,
   expectOptionalValue,
+  expectPage,
   expectReference,
   expectValue,
 } from '../result-decoders';
 import { invokeMapCommand, unwrapMapResponse } from '../transport';
 import type {
   BaseValue,
+  CollectionPageWire,
   HolonId,
   HolonReferenceWire,
   MapResultWire,
+  PageRequest,
   PropertyName,
   RelationshipName,
   ReadableHolonActionWire,
+  SortKey,
   TxId,
   WritableHolonActionWire,
   HolonCollectionWire,
@@ -214,6 +218,34 @@ export function readRelatedHolons(
   );
 }
 
+/**
+ * Return one sorted window of the related holon collection.
+ */
+export function readRelatedHolonsPage(
+  txId: TxId,
+  target: HolonReferenceWire,
+  name: RelationshipName,
+  sort: SortKey | null,
+  page: PageRequest,
+  options?: RequestOptionsOverrides,
+): Promise<CollectionPageWire> {
+  return runHolonCommand(
+    txId,
+    target,
+    {
+      Read: {
+        GetRelatedHolonsPage: {
+          name,
+          sort,
+          page,
+        },
+      },
+    },
+    expectPage,
+    options,
+  );
+}
+
 /**
  * Set or replace a property value on the target holon.
  */
@@ -2,14 +2,15 @@ import type { RequestOptionsOverrides } from '../request-context';
 import { buildRequest } from '../request-context';
 import {
   expectCollection, expectDanceResponse, expectNone, expectRedoComplete,
-  expectRedoToMarkerComplete, expectReference, expectReferences, expectUndoComplete,
+  expectPage, expectRedoToMarkerComplete, expectReference, expectReferences, expectUndoComplete,
   expectUndoToMarkerComplete, expectValue,
 } from '../result-decoders';
 import { invokeMapCommand, unwrapMapResponse } from '../transport';
 import type {
-  BaseValue, ContentSet, DanceRequestWire, DanceV2InvocationWire, DanceResponseWire,
-  HolonCollectionWire, HolonId, HolonReferenceWire, LocalId, MapResultWire,
-  SmartReferenceWire, TransactionActionWire, TransientReferenceWire, TxId,
+  BaseValue, CollectionPageWire, ContentSet, DanceRequestWire, DanceV2InvocationWire,
+  DanceResponseWire, HolonCollectionWire, HolonId, HolonReferenceWire, LocalId, MapResultWire,
+  PageRequest, RelationshipEnforcement, SmartReferenceWire, SortKey, TransactionActionWire,
+  TransientReferenceWire, TxId,
 } from '../wire-types';
 
 // ===========================================
@@ -57,6 +58,34 @@ export function commit(
   return runTransactionCommand(txId, 'Commit', expectReference);
 }
 
+/**
+ * Validate the transaction's staged holons without committing.
+ *
+ * Returns a reference to a transient `ValidationReport` holon.
+ */
+export function validateTransaction(
+  txId: TxId,
+  options?: RequestOptionsOverrides,
+): Promise<HolonReferenceWire> {
+  return runTransactionCommand(txId, 'ValidateTransaction', expectReference, options);
+}
+
+/**
+ * Set how relationship contracts are enforced for later staged mutations.
+ */
+export function setRelationshipEnforcement(
+  txId: TxId,
+  enforcement: RelationshipEnforcement,
+  options?: RequestOptionsOverrides,
+): Promise<void> {
+  return runTransactionCommand(
+    txId,
+    { SetRelationshipEnforcement: { enforcement } },
+    expectNone,
+    options,
+  );
+}
+
 /**
  * Experiential unit functions for undo/redo operations.
  */
@@ -242,6 +271,114 @@ export function getAllHolons(
   return runTransactionCommand(txId, 'GetAllHolons', expectCollection, options);
 }
 
+/**
+ * Return one sorted window of the holons visible to the transaction.
+ */
+export function getAllHolonsPage(
+  txId: TxId,
+  sort: SortKey | null,
+  page: PageRequest,
+  options?: RequestOptionsOverrides,
+): Promise<CollectionPageWire> {
+  return runTransactionCommand(
+    txId,
+    {
+      GetAllHolonsPage: {
+        sort,
+        page,
+      },
+    },
+    expectPage,
+    options,
+  );
+}
+
+/**
+ * Return the saved holons described by a descriptor, read from its instance index.
+ *
+ * With `includeSubtypes`, instances of descriptors that extend it are included.
+ */
+export function getHolonsByDescriptor(
+  txId: TxId,
+  descriptor: HolonReferenceWire,
+  includeSubtypes: boolean,
+  options?: RequestOptionsOverrides,
+): Promise<HolonCollectionWire> {
+  return runTransactionCommand(
+    txId,
+    {
+      GetHolonsByDescriptor: {
+        descriptor,
+        include_subtypes: includeSubtypes,
+      },
+    },
+    expectCollection,
+    options,
+  );
+}
+
+/**
+ * Return the single saved holon whose key is exactly `key`.
+ */
+export function getSavedHolonByKey(
+  txId: TxId,
+  key: string,
+  options?: RequestOptionsOverrides,
+): Promise<HolonReferenceWire> {
+  return runTransactionCommand(
+    txId,
+    {
+      GetSavedHolonByKey: {
+        key,
+      },
+    },
+    expectReference,
+    options,
+  );
+}
+
+/**
+ * Return the saved holon bound to a versioned key.
+ */
+export function getSavedHolonByVersionedKey(
+  txId: TxId,
+  key: string,
+  options?: RequestOptionsOverrides,
+): Promise<HolonReferenceWire> {
+  return runTransactionCommand(
+    txId,
+    {
+      GetSavedHolonByVersionedKey: {
+        key,
+      },
+    },
+    expectReference,
+    options,
+  );
+}
+
+/**
+ * Return every saved holon whose key starts with `prefix`.
+ *
+ * An empty prefix is rejected by the host.
+ */
+export function getSavedHolonsByKeyPrefix(
+  txId: TxId,
+  prefix: string,
+  options?: RequestOptionsOverrides,
+): Promise<HolonCollectionWire> {
+  return runTransactionCommand(
+    txId,
+    {
+      GetSavedHolonsByKeyPrefix: {
+        prefix,
+      },
+    },
+    expectCollection,
+    options,
+  );
+}
+
 /**
  * Return the staged holon bound to a base key.
  */
@@ -1,6 +1,7 @@
 import { MalformedResponseError } from './errors';
 import type {
   BaseValue,
+  CollectionPageWire,
   DanceResponseWire,
   HolonCollectionWire,
   HolonId,
@@ -148,6 +149,17 @@ export function expectCollection(result: MapResultWire): HolonCollectionWire {
   throw unexpectedResultVariant('Collection', result);
 }
 
+/**
+ * Decode a `MapResultWire::Page` payload.
+ */
+export function expectPage(result: MapResultWire): CollectionPageWire {
+  if (typeof result === 'object' && result !== null && 'Page' in result) {
+    return result.Page;
+  }
+
+  throw unexpectedResultVariant('Page', result);
+}
+
 /**
  * Decode a `MapResultWire::Value` payload.
  */
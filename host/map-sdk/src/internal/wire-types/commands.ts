@@ -8,6 +8,8 @@ import {
   type TxId,
   type LocalId,
   type HolonId,
+  type PageRequest,
+  type SortKey,
   type SmartReferenceWire,
   type StagedReferenceWire,
   type TransientReferenceWire,
@@ -18,9 +20,12 @@ import {
   isHolonId,
   isHolonReferenceWire,
   isLocalId,
+  isNullable,
   isNumber,
+  isPageRequest,
   isRecord,
   isSmartReferenceWire,
+  isSortKey,
   isStagedReferenceWire,
   isString,
   isTransientReferenceWire,
@@ -50,6 +55,12 @@ export interface ContentSet {
   files_to_load: FileData[];
 }
 
+/**
+ * How staged mutations apply relationship contracts, mirroring Rust
+ * `RelationshipEnforcement`. `Advisory` is the transaction default.
+ */
+export type RelationshipEnforcement = 'Strict' | 'Advisory';
+
 /**
  * Flat transaction action enum mirroring Rust `TransactionActionWire`.
  *
@@ -65,6 +76,8 @@ export interface ContentSet {
  */
 export type TransactionActionWire =
   | 'Commit'
+  | 'ValidateTransaction'
+  | { SetRelationshipEnforcement: { enforcement: RelationshipEnforcement } }
   | 'UndoLast'
   | 'RedoLast'
   | { UndoToMarker: { marker_id: string } }
@@ -75,6 +88,16 @@ export type TransactionActionWire =
   | { Dance: DanceRequestWire }
   | { DanceV2: DanceV2InvocationWire }
   | 'GetAllHolons'
+  | { GetAllHolonsPage: { sort: SortKey | null; page: PageRequest } }
+  | {
+      GetHolonsByDescriptor: {
+        descriptor: HolonReferenceWire;
+        include_subtypes: boolean;
+      };
+    }
+  | { GetSavedHolonByKey: { key: string } }
+  | { GetSavedHolonByVersionedKey: { key: string } }
+  | { GetSavedHolonsByKeyPrefix: { prefix: string } }
   | { GetStagedHolonByBaseKey: { key: string } }
   // Deliberate exception: duplicate-base-key staging lookup stays
   // reference-shaped rather than using HolonCollection.
@@ -110,7 +133,14 @@ export type ReadableHolonActionWire =
   | 'GetKey'
   | 'GetVersionedKey'
   | { GetPropertyValue: { name: PropertyName } }
-  | { GetRelatedHolons: { name: RelationshipName } };
+  | { GetRelatedHolons: { name: RelationshipName } }
+  | {
+      GetRelatedHolonsPage: {
+        name: RelationshipName;
+        sort: SortKey | null;
+        page: PageRequest;
+      };
+    };
 
 // Mutating holon actions.
 export type WritableHolonActionWire =
@@ -152,6 +182,7 @@ const READABLE_HOLON_UNIT_ACTIONS = new Set<ReadableHolonActionWire>([
 
 const TRANSACTION_UNIT_ACTIONS = new Set([
   'Commit',
+  'ValidateTransaction',
   'UndoLast',
   'RedoLast',
   'GetAllHolons',
@@ -202,7 +233,12 @@ export function isReadableHolonActionWire(
     (hasSingleKey(value, 'GetPropertyValue') &&
       isStringFieldObject(value.GetPropertyValue, 'name')) ||
     (hasSingleKey(value, 'GetRelatedHolons') &&
-      isStringFieldObject(value.GetRelatedHolons, 'name'))
+      isStringFieldObject(value.GetRelatedHolons, 'name')) ||
+    (hasSingleKey(value, 'GetRelatedHolonsPage') &&
+      isRecord(value.GetRelatedHolonsPage) &&
+      isString(value.GetRelatedHolonsPage['name']) &&
+      isNullable(value.GetRelatedHolonsPage['sort'], isSortKey) &&
+      isPageRequest(value.GetRelatedHolonsPage['page']))
   );
 }
 
@@ -251,6 +287,20 @@ export function isTransactionActionWire(
     (hasSingleKey(value, 'Dance') && isDanceRequestWire(value.Dance)) ||
     (hasSingleKey(value, 'DanceV2') &&
       isDanceV2InvocationWire(value.DanceV2)) ||
+    (hasSingleKey(value, 'GetAllHolonsPage') &&
+      isRecord(value.GetAllHolonsPage) &&
+      isNullable(value.GetAllHolonsPage['sort'], isSortKey) &&
+      isPageRequest(value.GetAllHolonsPage['page'])) ||
+    (hasSingleKey(value, 'GetHolonsByDescriptor') &&
+      isRecord(value.GetHolonsByDescriptor) &&
+      isHolonReferenceWire(value.GetHolonsByDescriptor['descriptor']) &&
+      typeof value.GetHolonsByDescriptor['include_subtypes'] === 'boolean') ||
+    (hasSingleKey(value, 'GetSavedHolonByKey') &&
+      isStringFieldObject(value.GetSavedHolonByKey, 'key')) ||
+    (hasSingleKey(value, 'GetSavedHolonByVersionedKey') &&
+      isStringFieldObject(value.GetSavedHolonByVersionedKey, 'key')) ||
+    (hasSingleKey(value, 'GetSavedHolonsByKeyPrefix') &&
+      isStringFieldObject(value.GetSavedHolonsByKeyPrefix, 'prefix')) ||
     (hasSingleKey(value, 'GetStagedHolonByBaseKey') &&
       isStringFieldObject(value.GetStagedHolonByBaseKey, 'key')) ||
     (hasSingleKey(value, 'GetStagedHolonsByBaseKey') &&
@@ -36,8 +36,18 @@ export type BaseValue =
   | { StringValue: string }
   | { BooleanValue: boolean }
   | { IntegerValue: number }
+  // Canonical decimal text (e.g. "-12.5"), so no digits are lost to floats.
+  | { DecimalValue: string }
+  // RFC 3339 UTC text with microsecond precision (e.g. "2024-03-01T09:30:00.25Z").
+  | { TimestampValue: string }
+  // ISO 8601 calendar date (e.g. "2024-03-01").
+  | { DateValue: string }
   | { EnumValue: string }
-  | { BytesValue: MapBytes };
+  | { BytesValue: MapBytes }
+  // Flat array of scalars that share one variant; arrays never nest.
+  | { ValueArray: BaseValue[] }
+  // Named scalar fields keyed by field name; records never nest.
+  | { Record: Record<string, BaseValue> };
 
 // BTreeMap<PropertyName, BaseValue> serialized with string keys.
 export type PropertyMap = Record<string, BaseValue>;
@@ -84,6 +94,39 @@ export interface HolonCollectionWire {
   keyed_index: Record<string, number>;
 }
 
+export type SortDirection = 'Ascending' | 'Descending';
+
+/**
+ * Orders a paged collection by one property, mirroring Rust `SortKey`.
+ */
+export interface SortKey {
+  property: PropertyName;
+  direction: SortDirection;
+}
+
+// Opaque resume token; a transparent `MapString` newtype on the Rust side.
+export type PageCursor = MapString;
+
+/**
+ * Window over a sorted collection, mirroring Rust `PageRequest`.
+ *
+ * Either `offset` or `cursor` positions the window, never both.
+ */
+export interface PageRequest {
+  limit: number | null;
+  offset: number;
+  cursor: PageCursor | null;
+}
+
+/**
+ * One window of a sorted collection, carried by `MapResultWire::Page`.
+ */
+export interface CollectionPageWire {
+  collection: HolonCollectionWire;
+  next_cursor: PageCursor | null;
+  total_count: number;
+}
+
 /**
  * Deprecated compatibility payload retained only for legacy dance/query flows.
  *
@@ -339,6 +382,15 @@ export type PvlViolationWire =
       };
     }
   | { CanonicalKeyTooLarge: { actual_bytes: number; max_bytes: number } }
+  | {
+      DecimalValueOutOfRange: {
+        property_name: PropertyName;
+        actual_precision: number;
+        actual_scale: number;
+        max_precision: number;
+        max_scale: number;
+      };
+    }
   | {
       CollectionTooLarge: {
         property_name: PropertyName;
@@ -642,8 +694,20 @@ export function isBaseValue(value: unknown): value is BaseValue {
       typeof candidate === 'boolean',
     ) ||
     isTaggedValue(value, 'IntegerValue', isNumber) ||
+    isTaggedValue(value, 'DecimalValue', isString) ||
+    isTaggedValue(value, 'TimestampValue', isString) ||
+    isTaggedValue(value, 'DateValue', isString) ||
     isTaggedValue(value, 'EnumValue', isString) ||
-    isTaggedValue(value, 'BytesValue', isMapBytes)
+    isTaggedValue(value, 'BytesValue', isMapBytes) ||
+    isTaggedValue(value, 'ValueArray', (candidate): candidate is BaseValue[] =>
+      Array.isArray(candidate) && candidate.every(isBaseValue),
+    ) ||
+    isTaggedValue(
+      value,
+      'Record',
+      (candidate): candidate is Record<string, BaseValue> =>
+        isStringRecord(candidate, isBaseValue),
+    )
   );
 }
 
@@ -700,6 +764,32 @@ export function isHolonCollectionWire(value: unknown): value is HolonCollectionW
   );
 }
 
+export function isSortKey(value: unknown): value is SortKey {
+  return (
+    isRecord(value) &&
+    isString(value['property']) &&
+    (value['direction'] === 'Ascending' || value['direction'] === 'Descending')
+  );
+}
+
+export function isPageRequest(value: unknown): value is PageRequest {
+  return (
+    isRecord(value) &&
+    isNullable(value['limit'], isNumber) &&
+    isNumber(value['offset']) &&
+    isNullable(value['cursor'], isString)
+  );
+}
+
+export function isCollectionPageWire(value: unknown): value is CollectionPageWire {
+  return (
+    isRecord(value) &&
+    isHolonCollectionWire(value['collection']) &&
+    isNullable(value['next_cursor'], isString) &&
+    isNumber(value['total_count'])
+  );
+}
+
 export function isQueryExpression(value: unknown): value is QueryExpression {
   return isRecord(value) && isString(value['relationship_name']);
 }
@@ -891,6 +981,25 @@ export function isPvlViolationWire(value: unknown): value is PvlViolationWire {
     isTaggedValue(value, 'EnumValueTooLarge', isPropertyByteLimitPayload) ||
     isTaggedValue(value, 'BytesValueTooLarge', isPropertyByteLimitPayload) ||
     isTaggedValue(value, 'CanonicalKeyTooLarge', isByteLimitPayload) ||
+    isTaggedValue(
+      value,
+      'DecimalValueOutOfRange',
+      (
+        candidate,
+      ): candidate is {
+        property_name: PropertyName;
+        actual_precision: number;
+        actual_scale: number;
+        max_precision: number;
+        max_scale: number;
+      } =>
+        isRecord(candidate) &&
+        isString(candidate['property_name']) &&
+        isNumber(candidate['actual_precision']) &&
+        isNumber(candidate['actual_scale']) &&
+        isNumber(candidate['max_precision']) &&
+        isNumber(candidate['max_scale']),
+    ) ||
     isTaggedValue(
       value,
       'CollectionTooLarge',
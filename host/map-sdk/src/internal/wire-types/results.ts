@@ -1,11 +1,13 @@
 import {
   type BaseValue,
+  type CollectionPageWire,
   type DanceResponseWire,
   type HolonCollectionWire,
   type HolonId,
   type HolonReferenceWire,
   hasSingleKey,
   isBaseValue,
+  isCollectionPageWire,
   isDanceResponseWire,
   isHolonCollectionWire,
   isHolonId,
@@ -35,6 +37,7 @@ export type MapResultWire =
   | { Reference: HolonReferenceWire }
   | { References: HolonReferenceWire[] }
   | { Collection: HolonCollectionWire }
+  | { Page: CollectionPageWire }
   | { Value: BaseValue }
   | { HolonId: HolonId }
   | { DanceResponse: DanceResponseWire };
@@ -59,6 +62,7 @@ export function isMapResultWire(value: unknown): value is MapResultWire {
       value.References.every(isHolonReferenceWire)) ||
     (hasSingleKey(value, 'Collection') &&
       isHolonCollectionWire(value.Collection)) ||
+    (hasSingleKey(value, 'Page') && isCollectionPageWire(value.Page)) ||
     (hasSingleKey(value, 'Value') && isBaseValue(value.Value)) ||
     (hasSingleKey(value, 'HolonId') && isHolonId(value.HolonId)) ||
     (hasSingleKey(value, 'DanceResponse') &&
@@ -1,4 +1,5 @@
 import type {
+  CollectionPageWire,
   HolonCollectionWire,
   TxId,
 } from '../internal/wire-types/references';
@@ -46,3 +47,25 @@ export class HolonCollection implements Iterable<HolonReference> {
     return this.members[Symbol.iterator]();
   }
 }
+
+/**
+ * One sorted window of a holon collection.
+ *
+ * Pass `nextCursor` back as the page request's `cursor` to read the next
+ * window; it is `null` on the last page.
+ */
+export class HolonCollectionPage {
+  readonly holons: HolonCollection;
+  readonly nextCursor: string | null;
+  readonly totalCount: number;
+
+  constructor(
+    txId: TxId,
+    page: CollectionPageWire,
+    wrapReference: HolonReferenceFactory = wrapHolonReference,
+  ) {
+    this.holons = new HolonCollection(txId, page.collection, wrapReference);
+    this.nextCursor = page.next_cursor;
+    this.totalCount = page.total_count;
+  }
+}
@@ -1,4 +1,4 @@
-export { HolonCollection } from './collection';
+export { HolonCollection, HolonCollectionPage } from './collection';
 export { MapClient } from './client';
 export {
   HolonReference,
@@ -11,8 +11,13 @@ export {
   MapError,
   TransportError,
   extractBytes,
+  extractDate,
+  extractDecimal,
   extractNumber,
+  extractRecord,
   extractString,
+  extractTimestamp,
+  extractValueArray,
 } from './types';
 export type {
   BaseValue,
@@ -23,9 +28,13 @@ export type {
   LocalId,
   MapBytes,
   MapErrorCode,
+  PageRequest,
   PropertyName,
   ReadableHolon,
+  RelationshipEnforcement,
   RelationshipName,
   SmartReference,
+  SortDirection,
+  SortKey,
   WritableHolon,
 } from './types';
@@ -6,11 +6,13 @@ import type {
   TransientReferenceWire,
   TxId,
 } from '../internal/wire-types/references';
-import { HolonCollection } from './collection';
+import { HolonCollection, HolonCollectionPage } from './collection';
 import {
   type BaseValue,
   extractString,
   type HolonId,
+  type PageRequest,
+  type SortKey,
   type WritableHolon,
 } from './types';
 
@@ -87,6 +89,22 @@ export class HolonReference implements WritableHolon {
     return new HolonCollection(txId, collection);
   }
 
+  async relatedHolonsPage(
+    name: RelationshipName,
+    sort: SortKey | null,
+    page: PageRequest,
+  ): Promise<HolonCollectionPage> {
+    const txId = txIdFor(this);
+    const collectionPage = await internalHolon.readRelatedHolonsPage(
+      txId,
+      wireRefFor(this),
+      name,
+      sort,
+      page,
+    );
+    return new HolonCollectionPage(txId, collectionPage);
+  }
+
   withPropertyValue(name: PropertyName, value: BaseValue): Promise<void> {
     return internalHolon.withPropertyValue(
       txIdFor(this),
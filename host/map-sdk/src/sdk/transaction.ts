@@ -6,7 +6,7 @@ import type {
   SmartReferenceWire,
   TxId,
 } from '../internal';
-import { HolonCollection } from './collection';
+import { HolonCollection, HolonCollectionPage } from './collection';
 import {
   createHolonReference,
   createTransientHolonReference,
@@ -18,7 +18,10 @@ import {
 import {
   type ContentSet,
   extractNumber,
+  type PageRequest,
+  type RelationshipEnforcement,
   type SmartReference,
+  type SortKey,
 } from './types';
 
 // ===========================================
@@ -47,6 +50,16 @@ export class MapTransaction {
     await internalTransaction.commit(txIdFor(this));
   }
 
+  async validate(): Promise<TransientHolonReference> {
+    const txId = txIdFor(this);
+    const wireRef = await internalTransaction.validateTransaction(txId);
+    return createTransientHolonReference(txId, wireRef);
+  }
+
+  async setRelationshipEnforcement(enforcement: RelationshipEnforcement): Promise<void> {
+    await internalTransaction.setRelationshipEnforcement(txIdFor(this), enforcement);
+  }
+
   async newHolon(key?: string): Promise<TransientHolonReference> {
     const txId = txIdFor(this);
     const wireRef = await internalTransaction.newHolon(txId, key);
@@ -120,6 +133,62 @@ export class MapTransaction {
     return new HolonCollection(txId, collection);
   }
 
+  async getAllHolonsPage(
+    sort: SortKey | null,
+    page: PageRequest,
+  ): Promise<HolonCollectionPage> {
+    const txId = txIdFor(this);
+    const collectionPage = await internalTransaction.getAllHolonsPage(
+      txId,
+      sort,
+      page,
+    );
+    return new HolonCollectionPage(txId, collectionPage);
+  }
+
+  async getHolonsByDescriptor(
+    descriptor: HolonReference,
+    includeSubtypes = false,
+  ): Promise<HolonCollection> {
+    const txId = txIdFor(this);
+    const collection = await internalTransaction.getHolonsByDescriptor(
+      txId,
+      unwrapHolonReference(descriptor),
+      includeSubtypes,
+    );
+    return new HolonCollection(txId, collection);
+  }
+
+  async getSavedHolonByKey(key: string): Promise<HolonReference | null> {
+    const txId = txIdFor(this);
+    return withHolonNotFoundAsNull(async () => {
+      const wireRef = await internalTransaction.getSavedHolonByKey(txId, key);
+      return createHolonReference(txId, wireRef);
+    });
+  }
+
+  async getSavedHolonByVersionedKey(
+    key: string,
+  ): Promise<HolonReference | null> {
+    const txId = txIdFor(this);
+    return withHolonNotFoundAsNull(async () => {
+      const wireRef = await internalTransaction.getSavedHolonByVersionedKey(
+        txId,
+        key,
+      );
+      return createHolonReference(txId, wireRef);
+    });
+  }
+
+  async getSavedHolonsByKeyPrefix(prefix: string): Promise<HolonCollection> {
+    const txId = txIdFor(this);
+    const collection = await internalTransaction.getSavedHolonsByKeyPrefix(
+      txId,
+      prefix,
+    );
+    return new HolonCollection(txId, collection);
+  }
+
   async getStagedHolonByBaseKey(key: string): Promise<HolonReference | null> {
     const txId = txIdFor(this);
     return withHolonNotFoundAsNull(async () => {
@@ -4,14 +4,18 @@ import type {
   HolonId,
   LocalId,
   MapBytes,
+  PageRequest as InternalPageRequest,
   PropertyName,
   RelationshipName,
+  SortDirection as InternalSortDirection,
+  SortKey as InternalSortKey,
 } from '../internal/wire-types/references';
 import type {
   ContentSet as InternalContentSet,
   FileData as InternalFileData,
+  RelationshipEnforcement as InternalRelationshipEnforcement,
 } from '../internal/wire-types/commands';
-import type { HolonCollection } from './collection';
+import type { HolonCollection, HolonCollectionPage } from './collection';
 import type { HolonReference, TransientHolonReference } from './references';
 
 export type {
@@ -49,6 +53,14 @@ export type FileData = InternalFileData;
 
 export type ContentSet = InternalContentSet;
 
+export type RelationshipEnforcement = InternalRelationshipEnforcement;
+
+export type SortDirection = InternalSortDirection;
+
+export type SortKey = InternalSortKey;
+
+export type PageRequest = InternalPageRequest;
+
 /**
  * Public alias for domain errors returned by MAP operations.
  *
@@ -73,6 +85,11 @@ export interface ReadableHolon {
   versionedKey(): Promise<string>;
   propertyValue(name: PropertyName): Promise<BaseValue | null>;
   relatedHolons(name: RelationshipName): Promise<HolonCollection>;
+  relatedHolonsPage(
+    name: RelationshipName,
+    sort: SortKey | null,
+    page: PageRequest,
+  ): Promise<HolonCollectionPage>;
 }
 
 /**
@@ -122,6 +139,50 @@ export function extractNumber(value: BaseValue): number {
   );
 }
 
+/**
+ * Extract the canonical decimal text from a `BaseValue.DecimalValue`.
+ *
+ * The text is returned as-is so callers can choose a decimal library rather
+ * than lose digits to a JavaScript number.
+ */
+export function extractDecimal(value: BaseValue): string {
+  if ('DecimalValue' in value) {
+    return value.DecimalValue;
+  }
+
+  throw new TypeError(
+    `Expected BaseValue.DecimalValue, received ${baseValueVariant(value)}`,
+  );
+}
+
+/**
+ * Extract the RFC 3339 UTC text from a `BaseValue.TimestampValue`.
+ *
+ * `new Date(text)` accepts it, at the cost of truncating to milliseconds.
+ */
+export function extractTimestamp(value: BaseValue): string {
+  if ('TimestampValue' in value) {
+    return value.TimestampValue;
+  }
+
+  throw new TypeError(
+    `Expected BaseValue.TimestampValue, received ${baseValueVariant(value)}`,
+  );
+}
+
+/**
+ * Extract the ISO 8601 `YYYY-MM-DD` text from a `BaseValue.DateValue`.
+ */
+export function extractDate(value: BaseValue): string {
+  if ('DateValue' in value) {
+    return value.DateValue;
+  }
+
+  throw new TypeError(
+    `Expected BaseValue.DateValue, received ${baseValueVariant(value)}`,
+  );
+}
+
 /**
  * Extract the bytes payload from a `BaseValue.BytesValue`.
  */
@@ -135,6 +196,38 @@ export function extractBytes(value: BaseValue): MapBytes {
   );
 }
 
+/**
+ * Extract the elements of a `BaseValue.ValueArray`.
+ *
+ * Elements are themselves `BaseValue`s of one shared variant, so the scalar
+ * extractors above apply to each of them.
+ */
+export function extractValueArray(value: BaseValue): BaseValue[] {
+  if ('ValueArray' in value) {
+    return value.ValueArray;
+  }
+
+  throw new TypeError(
+    `Expected BaseValue.ValueArray, received ${baseValueVariant(value)}`,
+  );
+}
+
+/**
+ * Extract the fields of a `BaseValue.Record`, keyed by field name.
+ *
+ * Field values are scalar `BaseValue`s, so the scalar extractors above apply
+ * to each of them.
+ */
+export function extractRecord(value: BaseValue): Record<string, BaseValue> {
+  if ('Record' in value) {
+    return value.Record;
+  }
+
+  throw new TypeError(
+    `Expected BaseValue.Record, received ${baseValueVariant(value)}`,
+  );
+}
+
 function baseValueVariant(value: BaseValue): string {
   if ('StringValue' in value) {
     return 'StringValue';
@@ -148,9 +241,29 @@ function baseValueVariant(value: BaseValue): string {
     return 'IntegerValue';
   }
 
+  if ('DecimalValue' in value) {
+    return 'DecimalValue';
+  }
+
+  if ('TimestampValue' in value) {
+    return 'TimestampValue';
+  }
+
+  if ('DateValue' in value) {
+    return 'DateValue';
+  }
+
   if ('BytesValue' in value) {
     return 'BytesValue';
   }
 
+  if ('ValueArray' in value) {
+    return 'ValueArray';
+  }
+
+  if ('Record' in value) {
+    return 'Record';
+  }
+
   return 'EnumValue';
 }
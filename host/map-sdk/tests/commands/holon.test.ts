@@ -8,6 +8,7 @@ import {
   readKey,
   readPropertyValue,
   readRelatedHolons,
+  readRelatedHolonsPage,
   readVersionedKey,
   removePropertyValue,
   removeRelatedHolons,
@@ -19,12 +20,15 @@ import { MalformedResponseError } from '../../src/internal/errors';
 import { resetRequestIdCounter } from '../../src/internal/request-context';
 import type {
   BaseValue,
+  CollectionPageWire,
   HolonCollectionWire,
   HolonId,
   HolonReferenceWire,
   HolonActionWire,
   MapResultWire,
+  PageRequest,
   RequestOptions,
+  SortKey,
 } from '../../src/internal/wire-types';
 
 const { invokeMapCommandMock } = vi.hoisted(() => ({
@@ -100,6 +104,23 @@ const collection: HolonCollectionWire = {
   },
 };
 
+const sortKey: SortKey = {
+  property: 'title',
+  direction: 'Descending',
+};
+
+const pageRequest: PageRequest = {
+  limit: 2,
+  offset: 0,
+  cursor: null,
+};
+
+const collectionPage: CollectionPageWire = {
+  collection,
+  next_cursor: '2@title:desc',
+  total_count: 5,
+};
+
 function expectHolonRequest(
   action: HolonActionWire,
   options: RequestOptions = defaultOptions,
@@ -191,6 +212,18 @@ const holonCases: HolonCase<unknown>[] = [
     expected: collection,
     wrongResult: { References: [transientReference] },
   },
+  {
+    name: 'readRelatedHolonsPage',
+    run: () => readRelatedHolonsPage(txId, target, 'related_to', sortKey, pageRequest),
+    action: {
+      Read: {
+        GetRelatedHolonsPage: { name: 'related_to', sort: sortKey, page: pageRequest },
+      },
+    },
+    okResult: { Page: collectionPage },
+    expected: collectionPage,
+    wrongResult: { Collection: collection },
+  },
   {
     name: 'withPropertyValue',
     run: () => withPropertyValue(txId, target, 'title', stringValue),
@@ -5,6 +5,11 @@ import {
   dance,
   deleteHolon,
   getAllHolons,
+  getAllHolonsPage,
+  getHolonsByDescriptor,
+  getSavedHolonByKey,
+  getSavedHolonByVersionedKey,
+  getSavedHolonsByKeyPrefix,
   getStagedHolonByBaseKey,
   getStagedHolonByVersionedKey,
   getStagedHolonsByBaseKey,
@@ -19,12 +24,15 @@ import {
   stageNewVersionFromId,
   stagedCount,
   transientCount,
+  setRelationshipEnforcement,
   undoLast,
+  validateTransaction,
 } from '../../src/internal/commands/transaction';
 import { MalformedResponseError } from '../../src/internal/errors';
 import { resetRequestIdCounter } from '../../src/internal/request-context';
 import type {
   BaseValue,
+  CollectionPageWire,
   ContentSet,
   DanceRequestWire,
   DanceResponseWire,
@@ -33,6 +41,7 @@ import type {
   HolonReferenceWire,
   LocalId,
   MapResultWire,
+  PageRequest,
   RequestOptions,
   SmartReferenceWire,
   TransactionActionWire,
@@ -106,6 +115,18 @@ const holonCollection: HolonCollectionWire = {
   },
 };
 
+const pageRequest: PageRequest = {
+  limit: 1,
+  offset: 0,
+  cursor: '1@title:asc',
+};
+
+const collectionPage: CollectionPageWire = {
+  collection: holonCollection,
+  next_cursor: null,
+  total_count: 2,
+};
+
 const integerValue: BaseValue = {
   IntegerValue: 7,
 };
@@ -168,6 +189,22 @@ const transactionCases: TransactionCase<unknown>[] = [
     expected: transientReference,
     wrongResult: 'None',
   },
+  {
+    name: 'validateTransaction',
+    run: () => validateTransaction(txId),
+    action: 'ValidateTransaction',
+    okResult: { Reference: transientReference },
+    expected: transientReference,
+    wrongResult: 'None',
+  },
+  {
+    name: 'setRelationshipEnforcement',
+    run: () => setRelationshipEnforcement(txId, 'Strict'),
+    action: { SetRelationshipEnforcement: { enforcement: 'Strict' } },
+    okResult: 'None',
+    expected: undefined,
+    wrongResult: { Reference: transientReference },
+  },
   {
     name: 'newHolon',
     run: () => newHolon(txId, 'alpha'),
@@ -232,6 +269,46 @@ const transactionCases: TransactionCase<unknown>[] = [
     expected: holonCollection,
     wrongResult: { References: [stagedReference] },
   },
+  {
+    name: 'getAllHolonsPage',
+    run: () => getAllHolonsPage(txId, null, pageRequest),
+    action: { GetAllHolonsPage: { sort: null, page: pageRequest } },
+    okResult: { Page: collectionPage },
+    expected: collectionPage,
+    wrongResult: { Collection: holonCollection },
+  },
+  {
+    name: 'getHolonsByDescriptor',
+    run: () => getHolonsByDescriptor(txId, stagedReference, true),
+    action: { GetHolonsByDescriptor: { descriptor: stagedReference, include_subtypes: true } },
+    okResult: { Collection: holonCollection },
+    expected: holonCollection,
+    wrongResult: { Reference: stagedReference },
+  },
+  {
+    name: 'getSavedHolonByKey',
+    run: () => getSavedHolonByKey(txId, 'alpha'),
+    action: { GetSavedHolonByKey: { key: 'alpha' } },
+    okResult: { Reference: { Smart: smartWire } },
+    expected: { Smart: smartWire },
+    wrongResult: { Collection: holonCollection },
+  },
+  {
+    name: 'getSavedHolonByVersionedKey',
+    run: () => getSavedHolonByVersionedKey(txId, 'alpha1'),
+    action: { GetSavedHolonByVersionedKey: { key: 'alpha1' } },
+    okResult: { Reference: { Smart: smartWire } },
+    expected: { Smart: smartWire },
+    wrongResult: 'None',
+  },
+  {
+    name: 'getSavedHolonsByKeyPrefix',
+    run: () => getSavedHolonsByKeyPrefix(txId, 'al'),
+    action: { GetSavedHolonsByKeyPrefix: { prefix: 'al' } },
+    okResult: { Collection: holonCollection },
+    expected: holonCollection,
+    wrongResult: { Reference: stagedReference },
+  },
   {
     name: 'getStagedHolonByBaseKey',
     run: () => getStagedHolonByBaseKey(txId, 'alpha'),
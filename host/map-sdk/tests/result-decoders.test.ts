@@ -8,6 +8,7 @@ import {
   expectNone,
   expectOptionalReference,
   expectOptionalValue,
+  expectPage,
   expectReference,
   expectReferences,
   expectTransactionCreated,
@@ -15,6 +16,7 @@ import {
 } from '../src/internal/result-decoders';
 import type {
   BaseValue,
+  CollectionPageWire,
   DanceResponseWire,
   HolonCollectionWire,
   HolonId,
@@ -57,6 +59,12 @@ const holonCollection: HolonCollectionWire = {
   },
 };
 
+const collectionPage: CollectionPageWire = {
+  collection: holonCollection,
+  next_cursor: '2@title:asc',
+  total_count: 4,
+};
+
 const holonId: HolonId = {
   Local: [1, 2, 3, 4],
 };
@@ -152,6 +160,20 @@ describe('result decoders', () => {
     );
   });
 
+  it('decodes Page results', () => {
+    expect(
+      expectPage({
+        Page: collectionPage,
+      }),
+    ).toEqual(collectionPage);
+  });
+
+  it('throws on the wrong result variant when expecting Page', () => {
+    expect(() => expectPage({ Collection: holonCollection })).toThrow(
+      MalformedResponseError,
+    );
+  });
+
   it('decodes Value results', () => {
     expect(
       expectValue({
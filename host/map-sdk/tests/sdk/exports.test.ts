@@ -27,6 +27,7 @@ describe('public SDK exports', () => {
     expect(sdk.HolonReference).toBeDefined();
     expect(sdk.TransientHolonReference).toBeDefined();
     expect(sdk.HolonCollection).toBeDefined();
+    expect(sdk.HolonCollectionPage).toBeDefined();
     expect(sdk.MapError).toBeDefined();
     expect(sdk.TransportError).toBeDefined();
     expect(sdk.MalformedResponseError).toBeDefined();
@@ -34,6 +35,11 @@ describe('public SDK exports', () => {
     expect(sdk.extractString).toBeDefined();
     expect(sdk.extractNumber).toBeDefined();
     expect(sdk.extractBytes).toBeDefined();
+    expect(sdk.extractDecimal).toBeDefined();
+    expect(sdk.extractTimestamp).toBeDefined();
+    expect(sdk.extractDate).toBeDefined();
+    expect(sdk.extractValueArray).toBeDefined();
+    expect(sdk.extractRecord).toBeDefined();
   });
 
   it('does not expose internal wire or transport-layer exports', () => {
@@ -2,6 +2,7 @@ import { beforeEach, describe, expect, it, vi } from 'vitest';
 
 import type {
   BaseValue,
+  CollectionPageWire,
   HolonCollectionWire,
   HolonId,
   HolonReferenceWire,
@@ -15,6 +16,7 @@ const {
   readKeyMock,
   readPropertyValueMock,
   readRelatedHolonsMock,
+  readRelatedHolonsPageMock,
   readVersionedKeyMock,
   removePropertyValueMock,
   removeRelatedHolonsMock,
@@ -29,6 +31,7 @@ const {
   readKeyMock: vi.fn(),
   readPropertyValueMock: vi.fn(),
   readRelatedHolonsMock: vi.fn(),
+  readRelatedHolonsPageMock: vi.fn(),
   readVersionedKeyMock: vi.fn(),
   removePropertyValueMock: vi.fn(),
   removeRelatedHolonsMock: vi.fn(),
@@ -45,6 +48,7 @@ vi.mock('../../src/internal/commands/holon', () => ({
   readKey: readKeyMock,
   readPropertyValue: readPropertyValueMock,
   readRelatedHolons: readRelatedHolonsMock,
+  readRelatedHolonsPage: readRelatedHolonsPageMock,
   readVersionedKey: readVersionedKeyMock,
   removePropertyValue: removePropertyValueMock,
   removeRelatedHolons: removeRelatedHolonsMock,
@@ -53,7 +57,7 @@ vi.mock('../../src/internal/commands/holon', () => ({
   withPropertyValue: withPropertyValueMock,
 }));
 
-import { HolonCollection } from '../../src/sdk/collection';
+import { HolonCollection, HolonCollectionPage } from '../../src/sdk/collection';
 import {
   createHolonReference,
   createTransientHolonReference,
@@ -112,6 +116,12 @@ const relatedCollection: HolonCollectionWire = {
   },
 };
 
+const relatedPage: CollectionPageWire = {
+  collection: relatedCollection,
+  next_cursor: null,
+  total_count: 2,
+};
+
 function stagedHandle(): HolonReference {
   return createHolonReference(txId, stagedReference);
 }
@@ -133,6 +143,7 @@ describe('HolonReference', () => {
     readKeyMock.mockReset();
     readPropertyValueMock.mockReset();
     readRelatedHolonsMock.mockReset();
+    readRelatedHolonsPageMock.mockReset();
     readVersionedKeyMock.mockReset();
     removePropertyValueMock.mockReset();
     removeRelatedHolonsMock.mockReset();
@@ -229,6 +240,25 @@ describe('HolonReference', () => {
     expect(collection.members[1]).toBeInstanceOf(HolonReference);
   });
 
+  it('wraps relatedHolonsPage as a public HolonCollectionPage', async () => {
+    readRelatedHolonsPageMock.mockResolvedValue(relatedPage);
+    const page = { limit: null, offset: 0, cursor: null };
+
+    const result = await stagedHandle().relatedHolonsPage('related_to', null, page);
+
+    expect(readRelatedHolonsPageMock).toHaveBeenCalledWith(
+      txId,
+      stagedReference,
+      'related_to',
+      null,
+      page,
+    );
+    expect(result).toBeInstanceOf(HolonCollectionPage);
+    expect(result.holons.members[1]).toBeInstanceOf(HolonReference);
+    expect(result.nextCursor).toBeNull();
+    expect(result.totalCount).toBe(2);
+  });
+
   it('delegates property mutation methods with the expected arguments', async () => {
     withPropertyValueMock.mockResolvedValue(undefined);
     removePropertyValueMock.mockResolvedValue(undefined);
@@ -3,6 +3,7 @@ import { beforeEach, describe, expect, it, vi } from 'vitest';
 import { DomainError } from '../../src';
 import type {
   BaseValue,
+  CollectionPageWire,
   ContentSet,
   HolonCollectionWire,
   HolonId,
@@ -14,6 +15,11 @@ const {
   commitMock,
   deleteHolonMock,
   getAllHolonsMock,
+  getAllHolonsPageMock,
+  getHolonsByDescriptorMock,
+  getSavedHolonByKeyMock,
+  getSavedHolonByVersionedKeyMock,
+  getSavedHolonsByKeyPrefixMock,
   getStagedHolonByBaseKeyMock,
   getStagedHolonsByBaseKeyMock,
   getStagedHolonByVersionedKeyMock,
@@ -21,16 +27,23 @@ const {
   getTransientHolonByVersionedKeyMock,
   loadHolonsMock,
   newHolonMock,
+  setRelationshipEnforcementMock,
   stagedCountMock,
   stageNewFromCloneMock,
   stageNewHolonMock,
   stageNewVersionFromIdMock,
   stageNewVersionMock,
   transientCountMock,
+  validateTransactionMock,
 } = vi.hoisted(() => ({
   commitMock: vi.fn(),
   deleteHolonMock: vi.fn(),
   getAllHolonsMock: vi.fn(),
+  getAllHolonsPageMock: vi.fn(),
+  getHolonsByDescriptorMock: vi.fn(),
+  getSavedHolonByKeyMock: vi.fn(),
+  getSavedHolonByVersionedKeyMock: vi.fn(),
+  getSavedHolonsByKeyPrefixMock: vi.fn(),
   getStagedHolonByBaseKeyMock: vi.fn(),
   getStagedHolonsByBaseKeyMock: vi.fn(),
   getStagedHolonByVersionedKeyMock: vi.fn(),
@@ -38,18 +51,25 @@ const {
   getTransientHolonByVersionedKeyMock: vi.fn(),
   loadHolonsMock: vi.fn(),
   newHolonMock: vi.fn(),
+  setRelationshipEnforcementMock: vi.fn(),
   stagedCountMock: vi.fn(),
   stageNewFromCloneMock: vi.fn(),
   stageNewHolonMock: vi.fn(),
   stageNewVersionFromIdMock: vi.fn(),
   stageNewVersionMock: vi.fn(),
   transientCountMock: vi.fn(),
+  validateTransactionMock: vi.fn(),
 }));
 
 vi.mock('../../src/internal/commands/transaction', () => ({
   commit: commitMock,
   deleteHolon: deleteHolonMock,
   getAllHolons: getAllHolonsMock,
+  getAllHolonsPage: getAllHolonsPageMock,
+  getHolonsByDescriptor: getHolonsByDescriptorMock,
+  getSavedHolonByKey: getSavedHolonByKeyMock,
+  getSavedHolonByVersionedKey: getSavedHolonByVersionedKeyMock,
+  getSavedHolonsByKeyPrefix: getSavedHolonsByKeyPrefixMock,
   getStagedHolonByBaseKey: getStagedHolonByBaseKeyMock,
   getStagedHolonsByBaseKey: getStagedHolonsByBaseKeyMock,
   getStagedHolonByVersionedKey: getStagedHolonByVersionedKeyMock,
@@ -57,15 +77,17 @@ vi.mock('../../src/internal/commands/transaction', () => ({
   getTransientHolonByVersionedKey: getTransientHolonByVersionedKeyMock,
   loadHolons: loadHolonsMock,
   newHolon: newHolonMock,
+  setRelationshipEnforcement: setRelationshipEnforcementMock,
   stagedCount: stagedCountMock,
   stageNewFromClone: stageNewFromCloneMock,
   stageNewHolon: stageNewHolonMock,
   stageNewVersion: stageNewVersionMock,
   stageNewVersionFromId: stageNewVersionFromIdMock,
   transientCount: transientCountMock,
+  validateTransaction: validateTransactionMock,
 }));
 
-import { HolonCollection } from '../../src';
+import { HolonCollection, HolonCollectionPage } from '../../src';
 import {
   createHolonReference,
   createTransientHolonReference,
@@ -112,6 +134,12 @@ const holonCollection: HolonCollectionWire = {
   },
 };
 
+const collectionPage: CollectionPageWire = {
+  collection: holonCollection,
+  next_cursor: '2@title:asc',
+  total_count: 3,
+};
+
 const integerValue: BaseValue = {
   IntegerValue: 7,
 };
@@ -142,6 +170,11 @@ describe('MapTransaction', () => {
     commitMock.mockReset();
     deleteHolonMock.mockReset();
     getAllHolonsMock.mockReset();
+    getAllHolonsPageMock.mockReset();
+    getHolonsByDescriptorMock.mockReset();
+    getSavedHolonByKeyMock.mockReset();
+    getSavedHolonByVersionedKeyMock.mockReset();
+    getSavedHolonsByKeyPrefixMock.mockReset();
     getStagedHolonByBaseKeyMock.mockReset();
     getStagedHolonsByBaseKeyMock.mockReset();
     getStagedHolonByVersionedKeyMock.mockReset();
@@ -149,12 +182,14 @@ describe('MapTransaction', () => {
     getTransientHolonByVersionedKeyMock.mockReset();
     loadHolonsMock.mockReset();
     newHolonMock.mockReset();
+    setRelationshipEnforcementMock.mockReset();
     stagedCountMock.mockReset();
     stageNewFromCloneMock.mockReset();
     stageNewHolonMock.mockReset();
     stageNewVersionFromIdMock.mockReset();
     stageNewVersionMock.mockReset();
     transientCountMock.mockReset();
+    validateTransactionMock.mockReset();
   });
 
   it('delegates commit and discards the internal payload', async () => {
@@ -165,6 +200,15 @@ describe('MapTransaction', () => {
     expect(commitMock).toHaveBeenCalledWith(txId);
   });
 
+  it('wraps validate results as transient references', async () => {
+    validateTransactionMock.mockResolvedValue(transientReference);
+
+    const report = await transaction().validate();
+
+    expect(validateTransactionMock).toHaveBeenCalledWith(txId);
+    expect(report).toBeInstanceOf(TransientHolonReference);
+  });
+
   it('wraps newHolon results as transient references', async () => {
     newHolonMock.mockResolvedValue(transientReference);
 
@@ -244,6 +288,13 @@ describe('MapTransaction', () => {
     expect(holon).toBeInstanceOf(HolonReference);
   });
 
+  it('delegates setRelationshipEnforcement directly', async () => {
+    setRelationshipEnforcementMock.mockResolvedValue(undefined);
+
+    await expect(transaction().setRelationshipEnforcement('Strict')).resolves.toBeUndefined();
+    expect(setRelationshipEnforcementMock).toHaveBeenCalledWith(txId, 'Strict');
+  });
+
   it('delegates deleteHolon directly', async () => {
     deleteHolonMock.mockResolvedValue(undefined);
 
@@ -279,6 +330,43 @@ describe('MapTransaction', () => {
     expect(collection.members[0]).toBeInstanceOf(TransientHolonReference);
   });
 
+  it('wraps getAllHolonsPage results as a HolonCollectionPage', async () => {
+    getAllHolonsPageMock.mockResolvedValue(collectionPage);
+    const sort = { property: 'title', direction: 'Ascending' } as const;
+    const page = { limit: 2, offset: 0, cursor: null };
+
+    const result = await transaction().getAllHolonsPage(sort, page);
+
+    expect(getAllHolonsPageMock).toHaveBeenCalledWith(txId, sort, page);
+    expect(result).toBeInstanceOf(HolonCollectionPage);
+    expect(result.holons).toBeInstanceOf(HolonCollection);
+    expect(result.holons.members[0]).toBeInstanceOf(TransientHolonReference);
+    expect(result.nextCursor).toBe('2@title:asc');
+    expect(result.totalCount).toBe(3);
+  });
+
+  it('unwraps the descriptor and wraps getHolonsByDescriptor results', async () => {
+    getHolonsByDescriptorMock.mockResolvedValue(holonCollection);
+
+    const direct = await transaction().getHolonsByDescriptor(stagedHandle());
+    await transaction().getHolonsByDescriptor(stagedHandle(), true);
+
+    expect(getHolonsByDescriptorMock).toHaveBeenNthCalledWith(1, txId, stagedReference, false);
+    expect(getHolonsByDescriptorMock).toHaveBeenNthCalledWith(2, txId, stagedReference, true);
+    expect(direct).toBeInstanceOf(HolonCollection);
+    expect(direct.members[1]).toBeInstanceOf(HolonReference);
+  });
+
+  it('wraps getSavedHolonsByKeyPrefix results as a HolonCollection', async () => {
+    getSavedHolonsByKeyPrefixMock.mockResolvedValue(holonCollection);
+
+    const result = await transaction().getSavedHolonsByKeyPrefix('Book');
+
+    expect(getSavedHolonsByKeyPrefixMock).toHaveBeenCalledWith(txId, 'Book');
+    expect(result).toBeInstanceOf(HolonCollection);
+    expect(result.members[1]).toBeInstanceOf(HolonReference);
+  });
+
   it('wraps getStagedHolonsByBaseKey results as public references', async () => {
     getStagedHolonsByBaseKeyMock.mockResolvedValue([
       stagedReference,
@@ -304,6 +392,18 @@ describe('MapTransaction', () => {
   });
 
   it.each([
+    {
+      name: 'getSavedHolonByKey',
+      run: () => transaction().getSavedHolonByKey('alpha'),
+      mock: getSavedHolonByKeyMock,
+      expectedClass: HolonReference,
+    },
+    {
+      name: 'getSavedHolonByVersionedKey',
+      run: () => transaction().getSavedHolonByVersionedKey('alpha1'),
+      mock: getSavedHolonByVersionedKeyMock,
+      expectedClass: HolonReference,
+    },
     {
       name: 'getStagedHolonByBaseKey',
       run: () => transaction().getStagedHolonByBaseKey('alpha'),
@@ -338,6 +438,16 @@ describe('MapTransaction', () => {
   });
 
   it.each([
+    {
+      name: 'getSavedHolonByKey',
+      run: () => transaction().getSavedHolonByKey('alpha'),
+      mock: getSavedHolonByKeyMock,
+    },
+    {
+      name: 'getSavedHolonByVersionedKey',
+      run: () => transaction().getSavedHolonByVersionedKey('alpha1'),
+      mock: getSavedHolonByVersionedKeyMock,
+    },
     {
       name: 'getStagedHolonByBaseKey',
       run: () => transaction().getStagedHolonByBaseKey('alpha'),
@@ -365,6 +475,16 @@ describe('MapTransaction', () => {
   });
 
   it.each([
+    {
+      name: 'getSavedHolonByKey',
+      run: () => transaction().getSavedHolonByKey('alpha'),
+      mock: getSavedHolonByKeyMock,
+    },
+    {
+      name: 'getSavedHolonByVersionedKey',
+      run: () => transaction().getSavedHolonByVersionedKey('alpha1'),
+      mock: getSavedHolonByVersionedKeyMock,
+    },
     {
       name: 'getStagedHolonByBaseKey',
       run: () => transaction().getStagedHolonByBaseKey('alpha'),
@@ -1,6 +1,15 @@
 import { describe, expect, it } from 'vitest';
 
-import { extractBytes, extractNumber, extractString } from '../../src/sdk/types';
+import {
+  extractBytes,
+  extractDate,
+  extractDecimal,
+  extractNumber,
+  extractRecord,
+  extractString,
+  extractTimestamp,
+  extractValueArray,
+} from '../../src/sdk/types';
 
 // ===========================================
 // Public Value Utility Tests
@@ -15,6 +24,32 @@ describe('public value extractors', () => {
     expect(extractNumber({ IntegerValue: 7 })).toBe(7);
   });
 
+  it('extracts decimal text from BaseValue.DecimalValue', () => {
+    expect(extractDecimal({ DecimalValue: '19.99' })).toBe('19.99');
+  });
+
+  it('extracts ISO 8601 text from temporal values', () => {
+    expect(
+      extractTimestamp({ TimestampValue: '2024-03-01T09:30:00.25Z' }),
+    ).toBe('2024-03-01T09:30:00.25Z');
+    expect(extractDate({ DateValue: '2024-03-01' })).toBe('2024-03-01');
+  });
+
+  it('extracts the elements of a value array', () => {
+    const tags = extractValueArray({
+      ValueArray: [{ StringValue: 'alpha' }, { StringValue: 'beta' }],
+    });
+    expect(tags.map(extractString)).toEqual(['alpha', 'beta']);
+  });
+
+  it('extracts the fields of a record', () => {
+    const price = extractRecord({
+      Record: { Amount: { DecimalValue: '9.5' }, Currency: { StringValue: 'EUR' } },
+    });
+    expect(extractDecimal(price['Amount'])).toBe('9.5');
+    expect(extractString(price['Currency'])).toBe('EUR');
+  });
+
   it('extracts bytes values from BaseValue.BytesValue', () => {
     expect(extractBytes({ BytesValue: [1, 2, 3] })).toEqual([1, 2, 3]);
   });
@@ -31,6 +66,24 @@ describe('public value extractors', () => {
     );
   });
 
+  it('throws when extractDecimal receives a non-decimal variant', () => {
+    expect(() => extractDecimal({ IntegerValue: 19 })).toThrow(
+      'Expected BaseValue.DecimalValue, received IntegerValue',
+    );
+  });
+
+  it('throws when extractDate receives a timestamp', () => {
+    expect(() =>
+      extractDate({ TimestampValue: '2024-03-01T09:30:00Z' }),
+    ).toThrow('Expected BaseValue.DateValue, received TimestampValue');
+  });
+
+  it('throws when extractString receives a value array', () => {
+    expect(() => extractString({ ValueArray: [] })).toThrow(
+      'Expected BaseValue.StringValue, received ValueArray',
+    );
+  });
+
   it('throws when extractBytes receives a non-bytes variant', () => {
     expect(() => extractBytes({ StringValue: 'not-bytes' })).toThrow(
       'Expected BaseValue.BytesValue, received StringValue',
@@ -7,8 +7,10 @@ import { describe, expect, it } from 'vitest';
 import {
   isContentSet,
   isFileData,
+  isHolonActionWire,
   isMapIpcRequest,
   isMapIpcResponse,
+  isMapResultWire,
   isPvlFieldWire,
   isPvlMalformedReasonWire,
   isPvlViolationWire,
@@ -21,7 +23,7 @@ const fixtureFiles = readdirSync(fixturesDir).sort();
 
 describe('wire type fixtures', () => {
   it('discovers the generated fixture set', () => {
-    expect(fixtureFiles.length).toBe(39);
+    expect(fixtureFiles.length).toBe(44);
   });
 
   for (const fixtureFile of fixtureFiles) {
@@ -73,6 +75,15 @@ describe('PVL wire type guards', () => {
           max_bytes: 256,
         },
       },
+      {
+        DecimalValueOutOfRange: {
+          property_name: 'price',
+          actual_precision: 40,
+          actual_scale: 2,
+          max_precision: 38,
+          max_scale: 28,
+        },
+      },
       {
         ValueNestingTooDeep: {
           property_name: 'items',
@@ -210,3 +221,80 @@ describe('LoadHolons wire type guard', () => {
     ).toBe(false);
   });
 });
+
+describe('collection paging wire type guards', () => {
+  const page = { limit: 2, offset: 0, cursor: null };
+  const sort = { property: 'title', direction: 'Descending' };
+
+  it('accepts paged lookup actions with and without a sort key', () => {
+    expect(isTransactionActionWire({ GetAllHolonsPage: { sort: null, page } })).toBe(true);
+    expect(isTransactionActionWire({ GetAllHolonsPage: { sort, page } })).toBe(true);
+    expect(
+      isHolonActionWire({
+        Read: { GetRelatedHolonsPage: { name: 'related_to', sort, page } },
+      }),
+    ).toBe(true);
+  });
+
+  it('rejects malformed sort keys and page requests', () => {
+    expect(
+      isTransactionActionWire({
+        GetAllHolonsPage: { sort: { property: 'title', direction: 'Up' }, page },
+      }),
+    ).toBe(false);
+    expect(
+      isTransactionActionWire({ GetAllHolonsPage: { sort: null, page: { limit: 2 } } }),
+    ).toBe(false);
+  });
+
+  it('accepts Page results with an optional continuation cursor', () => {
+    const collection = { state: 'Saved', members: [], keyed_index: {} };
+
+    expect(
+      isMapResultWire({ Page: { collection, next_cursor: '2@title:desc', total_count: 5 } }),
+    ).toBe(true);
+    expect(
+      isMapResultWire({ Page: { collection, next_cursor: null, total_count: 0 } }),
+    ).toBe(true);
+    expect(isMapResultWire({ Page: { collection, total_count: 0 } })).toBe(false);
+  });
+});
+
+describe('GetHolonsByDescriptor wire type guard', () => {
+  const descriptor = {
+    Smart: {
+      tx_id: 41,
+      holon_id: { Local: [1, 2, 3] },
+      smart_property_values: null,
+    },
+  };
+
+  it('accepts a descriptor reference with an include_subtypes flag', () => {
+    expect(
+      isTransactionActionWire({
+        GetHolonsByDescriptor: { descriptor, include_subtypes: false },
+      }),
+    ).toBe(true);
+  });
+
+  it('rejects a request without include_subtypes', () => {
+    expect(isTransactionActionWire({ GetHolonsByDescriptor: { descriptor } })).toBe(false);
+  });
+});
+
+describe('saved key lookup wire type guards', () => {
+  it('accepts key and prefix payloads', () => {
+    expect(isTransactionActionWire({ GetSavedHolonByKey: { key: 'Book' } })).toBe(true);
+    expect(
+      isTransactionActionWire({ GetSavedHolonByVersionedKey: { key: 'Book1' } }),
+    ).toBe(true);
+    expect(
+      isTransactionActionWire({ GetSavedHolonsByKeyPrefix: { prefix: 'Bo' } }),
+    ).toBe(true);
+  });
+
+  it('rejects payloads with the wrong field', () => {
+    expect(isTransactionActionWire({ GetSavedHolonByKey: { prefix: 'Book' } })).toBe(false);
+    expect(isTransactionActionWire({ GetSavedHolonsByKeyPrefix: { key: 'Bo' } })).toBe(false);
+  });
+});
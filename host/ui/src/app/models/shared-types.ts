@@ -10,14 +10,28 @@
 // Newtype wrappers serialize as their inner value (transparent serialization)
 export type MapString = string;
 export type MapInteger = number;
+// Decimals serialize as their canonical text so no digits are lost.
+export type MapDecimal = string;
+// Timestamps serialize as RFC 3339 UTC text, dates as ISO 8601 YYYY-MM-DD.
+export type MapTimestamp = string;
+export type MapDate = string;
 export type MapBoolean = boolean;
 export type MapEnumValue = MapString;
+// Value arrays hold scalars of one shared variant and never nest.
+export type MapValueArray = BaseValue[];
+// Records hold named scalar fields and never nest.
+export type MapRecord = { [field: string]: BaseValue };
 
 export type BaseValue = 
   | { StringValue: MapString }
   | { BooleanValue: MapBoolean }
   | { IntegerValue: MapInteger }
-  | { EnumValue: MapEnumValue };
+  | { DecimalValue: MapDecimal }
+  | { TimestampValue: MapTimestamp }
+  | { DateValue: MapDate }
+  | { EnumValue: MapEnumValue }
+  | { ValueArray: MapValueArray }
+  | { Record: MapRecord };
 
 // ===========================================
 // Core Type System Types
@@ -193,10 +207,30 @@ export function isBaseValueInteger(value: BaseValue): value is { IntegerValue: M
   return typeof value === "object" && value !== null && "IntegerValue" in value;
 }
 
+export function isBaseValueDecimal(value: BaseValue): value is { DecimalValue: MapDecimal } {
+  return typeof value === "object" && value !== null && "DecimalValue" in value;
+}
+
+export function isBaseValueTimestamp(value: BaseValue): value is { TimestampValue: MapTimestamp } {
+  return typeof value === "object" && value !== null && "TimestampValue" in value;
+}
+
+export function isBaseValueDate(value: BaseValue): value is { DateValue: MapDate } {
+  return typeof value === "object" && value !== null && "DateValue" in value;
+}
+
 export function isBaseValueEnum(value: BaseValue): value is { EnumValue: MapEnumValue } {
   return typeof value === "object" && value !== null && "EnumValue" in value;
 }
 
+export function isBaseValueValueArray(value: BaseValue): value is { ValueArray: MapValueArray } {
+  return typeof value === "object" && value !== null && "ValueArray" in value;
+}
+
+export function isBaseValueRecord(value: BaseValue): value is { Record: MapRecord } {
+  return typeof value === "object" && value !== null && "Record" in value;
+}
+
 // ===========================================
 // FACTORY FUNCTIONS FOR COMMON TYPES
 // ===========================================
@@ -232,9 +266,29 @@ export class BaseValueFactory {
     return { IntegerValue: value };
   }
 
+  static decimal(value: string): BaseValue {
+    return { DecimalValue: value };
+  }
+
+  static timestamp(value: string): BaseValue {
+    return { TimestampValue: value };
+  }
+
+  static date(value: string): BaseValue {
+    return { DateValue: value };
+  }
+
   static enum(value: string): BaseValue {
     return { EnumValue: value };
   }
+
+  static valueArray(values: BaseValue[]): BaseValue {
+    return { ValueArray: values };
+  }
+
+  static record(fields: MapRecord): BaseValue {
+    return { Record: fields };
+  }
 }
 
 export class HolonReferenceFactory {
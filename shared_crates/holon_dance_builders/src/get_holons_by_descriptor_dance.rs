@@ -0,0 +1,17 @@
+use base_types::MapString;
+use core_types::{HolonError, HolonId};
+use holons_core::dances::{DanceRequest, DanceType, RequestBody};
+
+/// Builds a DanceRequest for retrieving the saved holons described by a descriptor, and
+/// optionally by its subtypes
+pub fn build_get_holons_by_descriptor_dance_request(
+    descriptor_id: HolonId,
+    include_subtypes: bool,
+) -> Result<DanceRequest, HolonError> {
+    let body = RequestBody::new_descriptor_instances(descriptor_id, include_subtypes);
+    Ok(DanceRequest::new(
+        MapString("get_holons_by_descriptor".to_string()),
+        DanceType::Standalone,
+        body,
+    ))
+}
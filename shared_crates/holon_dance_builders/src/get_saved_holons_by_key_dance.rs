@@ -0,0 +1,15 @@
+use base_types::MapString;
+use core_types::{HolonError, KeyMatch};
+use holons_core::dances::{DanceRequest, DanceType, RequestBody};
+
+/// Builds a DanceRequest for retrieving saved holons by exact key or key prefix
+pub fn build_get_saved_holons_by_key_dance_request(
+    key_match: KeyMatch,
+) -> Result<DanceRequest, HolonError> {
+    let body = RequestBody::KeyMatch(key_match);
+    Ok(DanceRequest::new(
+        MapString("get_saved_holons_by_key".to_string()),
+        DanceType::Standalone,
+        body,
+    ))
+}
@@ -3,6 +3,8 @@ pub mod delete_holon_dance;
 pub mod fetch_all_related_holons_dance;
 pub mod get_all_holons_dance;
 pub mod get_holon_by_id_dance;
+pub mod get_holons_by_descriptor_dance;
+pub mod get_saved_holons_by_key_dance;
 pub mod load_holons_dance;
 pub mod query_relationships_dance;
 
@@ -12,5 +14,7 @@ pub use delete_holon_dance::build_delete_holon_dance_request;
 pub use fetch_all_related_holons_dance::build_fetch_all_related_holons_dance_request;
 pub use get_all_holons_dance::build_get_all_holons_dance_request;
 pub use get_holon_by_id_dance::build_get_holon_by_id_dance_request;
+pub use get_holons_by_descriptor_dance::build_get_holons_by_descriptor_dance_request;
+pub use get_saved_holons_by_key_dance::build_get_saved_holons_by_key_dance_request;
 pub use load_holons_dance::build_load_holons_dance_request;
 pub use query_relationships_dance::build_query_relationships_dance_request;
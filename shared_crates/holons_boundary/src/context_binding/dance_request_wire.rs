@@ -2,7 +2,7 @@ use crate::context_binding::query_wire::NodeCollectionWire;
 use crate::context_binding::HolonWire;
 use crate::{HolonReferenceWire, StagedReferenceWire, TransientReferenceWire};
 use base_types::MapString;
-use core_types::{HolonError, HolonId, LocalId, PropertyMap, RelationshipName};
+use core_types::{HolonError, HolonId, KeyMatch, LocalId, PropertyMap, RelationshipName};
 use holons_core::core_shared_objects::transactions::TransactionContext;
 use holons_core::dances::{DanceRequest, DanceType, RequestBody};
 use holons_core::query_layer::QueryExpression;
@@ -42,6 +42,8 @@ pub enum RequestBodyWire {
     ParameterValues(PropertyMap),
     StagedRef(StagedReferenceWire),
     QueryExpression(QueryExpression),
+    KeyMatch(KeyMatch),
+    DescriptorInstances(HolonId, bool),
 }
 
 impl DanceRequestWire {
@@ -161,6 +163,10 @@ impl RequestBodyWire {
             RequestBodyWire::ParameterValues(p) => Ok(RequestBody::ParameterValues(p)),
             RequestBodyWire::StagedRef(w) => Ok(RequestBody::StagedRef(w.bind(context)?)),
             RequestBodyWire::QueryExpression(q) => Ok(RequestBody::QueryExpression(q)),
+            RequestBodyWire::KeyMatch(key_match) => Ok(RequestBody::KeyMatch(key_match)),
+            RequestBodyWire::DescriptorInstances(id, include_subtypes) => {
+                Ok(RequestBody::DescriptorInstances(id, include_subtypes))
+            }
         }
     }
 
@@ -199,6 +205,13 @@ impl RequestBodyWire {
             RequestBodyWire::QueryExpression(query_expression) => {
                 format!("  QueryExpression: {:#?}", query_expression)
             }
+
+            RequestBodyWire::KeyMatch(key_match) => format!("  KeyMatch: {:?}", key_match),
+
+            RequestBodyWire::DescriptorInstances(holon_id, include_subtypes) => format!(
+                "  DescriptorInstances: {:?}, include_subtypes: {}",
+                holon_id, include_subtypes
+            ),
         }
     }
 }
@@ -258,6 +271,10 @@ impl From<&RequestBody> for RequestBodyWire {
             RequestBody::QueryExpression(query_expression) => {
                 RequestBodyWire::QueryExpression(query_expression.clone())
             }
+            RequestBody::KeyMatch(key_match) => RequestBodyWire::KeyMatch(key_match.clone()),
+            RequestBody::DescriptorInstances(holon_id, include_subtypes) => {
+                RequestBodyWire::DescriptorInstances(holon_id.clone(), *include_subtypes)
+            }
         }
     }
 }
@@ -3,7 +3,7 @@ use std::sync::{Arc, RwLock};
 
 use crate::core_shared_objects::transactions::TransactionContext;
 use crate::core_shared_objects::Holon;
-use crate::{HolonCollection, RelationshipMap};
+use crate::{HolonCollection, RelationshipCacheStats, RelationshipMap};
 use core_types::{HolonError, HolonId, RelationshipName};
 
 pub trait HolonCacheAccess: Debug + Send + Sync {
@@ -35,4 +35,22 @@ pub trait HolonCacheAccess: Debug + Send + Sync {
         context: &Arc<TransactionContext>,
         source_holon_id: &HolonId,
     ) -> Result<RelationshipMap, HolonError>;
+
+    /// Returns whether `get_related_holons` for this source and relationship would be
+    /// served from the relationship cache rather than fetched. Never fetches.
+    fn is_related_holons_cached(
+        &self,
+        source_holon_id: &HolonId,
+        relationship_name: &RelationshipName,
+    ) -> Result<bool, HolonError>;
+
+    /// Returns cumulative relationship cache hits and misses.
+    fn relationship_cache_stats(&self) -> Result<RelationshipCacheStats, HolonError>;
+
+    /// Drops cached relationships for `source_holon_id`, or for every source when
+    /// `None`, so they are fetched again on next access.
+    fn invalidate_related_holons(
+        &self,
+        source_holon_id: Option<&HolonId>,
+    ) -> Result<(), HolonError>;
 }
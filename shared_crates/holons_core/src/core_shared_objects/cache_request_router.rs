@@ -3,7 +3,9 @@ use std::sync::{Arc, RwLock};
 
 use super::Holon;
 use crate::core_shared_objects::transactions::TransactionContext;
-use crate::{HolonCacheAccess, HolonCacheManager, HolonCollection, RelationshipMap};
+use crate::{
+    HolonCacheAccess, HolonCacheManager, HolonCollection, RelationshipCacheStats, RelationshipMap,
+};
 use core_types::{HolonError, HolonId, RelationshipName};
 
 #[derive(Debug)]
@@ -158,6 +160,50 @@ impl HolonCacheAccess for CacheRequestRouter {
             }
         }
     }
+
+    fn is_related_holons_cached(
+        &self,
+        source_holon_id: &HolonId,
+        relationship_name: &RelationshipName,
+    ) -> Result<bool, HolonError> {
+        match CacheRequestRouter::get_request_route(source_holon_id, &self.cache_routing_policy)? {
+            ServiceRoute::Local => self
+                .local_cache_manager
+                .read()
+                .map_err(|e| {
+                    HolonError::FailedToAcquireLock(format!(
+                        "Cache manager read lock poisoned: {}",
+                        e
+                    ))
+                })?
+                .is_related_holons_cached(source_holon_id, relationship_name),
+        }
+    }
+
+    fn relationship_cache_stats(&self) -> Result<RelationshipCacheStats, HolonError> {
+        self.local_cache_manager
+            .read()
+            .map_err(|e| {
+                HolonError::FailedToAcquireLock(format!("Cache manager read lock poisoned: {}", e))
+            })?
+            .relationship_cache_stats()
+    }
+
+    fn invalidate_related_holons(
+        &self,
+        source_holon_id: Option<&HolonId>,
+    ) -> Result<(), HolonError> {
+        if let Some(source_holon_id) = source_holon_id {
+            // Only the local route exists today; resolving it rejects unsupported ids.
+            CacheRequestRouter::get_request_route(source_holon_id, &self.cache_routing_policy)?;
+        }
+        self.local_cache_manager
+            .read()
+            .map_err(|e| {
+                HolonError::FailedToAcquireLock(format!("Cache manager read lock poisoned: {}", e))
+            })?
+            .invalidate_related_holons(source_holon_id)
+    }
 }
 
 /// Specifies the routing policy for handling holon service requests in the `HolonSpaceManager`.
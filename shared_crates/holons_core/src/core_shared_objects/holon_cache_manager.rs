@@ -4,7 +4,9 @@ use tracing::debug;
 use super::{holon_cache::HolonCache, Holon};
 use crate::core_shared_objects::transactions::TransactionContext;
 use crate::reference_layer::HolonServiceApi;
-use crate::{HolonCacheAccess, HolonCollection, RelationshipCache, RelationshipMap};
+use crate::{
+    HolonCacheAccess, HolonCollection, RelationshipCache, RelationshipCacheStats, RelationshipMap,
+};
 use core_types::{HolonError, HolonId, RelationshipName};
 
 #[derive(Debug)]
@@ -84,6 +86,42 @@ impl HolonCacheAccess for HolonCacheManager {
             })?
             .get_all_related_holons(context, self.holon_service.as_ref(), source_holon_id)
     }
+
+    fn is_related_holons_cached(
+        &self,
+        source_holon_id: &HolonId,
+        relationship_name: &RelationshipName,
+    ) -> Result<bool, HolonError> {
+        self.relationship_cache
+            .read()
+            .map_err(|e| {
+                HolonError::FailedToAcquireLock(format!("Cache manager read lock poisoned: {}", e))
+            })?
+            .contains(source_holon_id, relationship_name)
+    }
+
+    fn relationship_cache_stats(&self) -> Result<RelationshipCacheStats, HolonError> {
+        Ok(self
+            .relationship_cache
+            .read()
+            .map_err(|e| {
+                HolonError::FailedToAcquireLock(format!("Cache manager read lock poisoned: {}", e))
+            })?
+            .stats())
+    }
+
+    fn invalidate_related_holons(
+        &self,
+        source_holon_id: Option<&HolonId>,
+    ) -> Result<(), HolonError> {
+        let relationship_cache = self.relationship_cache.read().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!("Cache manager read lock poisoned: {}", e))
+        })?;
+        match source_holon_id {
+            Some(source_holon_id) => relationship_cache.invalidate(source_holon_id),
+            None => relationship_cache.clear(),
+        }
+    }
 }
 
 #[cfg(test)]
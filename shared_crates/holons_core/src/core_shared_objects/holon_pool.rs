@@ -252,6 +252,13 @@ impl HolonPool {
         Ok(id)
     }
 
+    /// Removes the Holon with `id` and its key mapping, if present.
+    pub fn remove_holon(&mut self, id: &TemporaryId) {
+        if self.holons.remove(id).is_some() {
+            self.keyed_index.retain(|_, indexed_id| indexed_id != id);
+        }
+    }
+
     /// Returns the number of Holons in the pool.
     pub fn len(&self) -> usize {
         self.holons.len()
@@ -35,7 +35,7 @@ pub use nursery::Nursery;
 pub use nursery_access::NurseryAccess;
 pub use relationship::RelationshipMap;
 pub use relationship_behavior::{ReadableRelationship, WritableRelationship};
-pub use relationship_cache::RelationshipCache;
+pub use relationship_cache::{RelationshipCache, RelationshipCacheStats};
 pub use staged_relationship::StagedRelationshipMap;
 pub use transient_collection::TransientCollection;
 pub use transient_holon_manager::TransientHolonManager;
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use tracing::debug;
 
@@ -8,11 +9,40 @@ use crate::reference_layer::HolonServiceApi;
 use core_types::{HolonError, HolonId, RelationshipName};
 /// In-memory cache mapping a source `HolonId` to its relationship map.
 ///
-/// This cache does **not** enforce eviction or invalidation; correctness
-/// relies on transaction scoping and the immutability of saved holons.
+/// This cache does not evict on its own; correctness relies on transaction scoping
+/// and the immutability of saved holons. Live queries, which must observe links
+/// committed by other agents, drop stale entries explicitly through `invalidate`
+/// and `clear`.
 #[derive(Clone, Debug)]
 pub struct RelationshipCache {
     cache: Arc<RwLock<HashMap<HolonId, RelationshipMap>>>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+}
+
+/// Cumulative `RelationshipCache::related_holons` outcomes.
+///
+/// A miss is one call to `HolonServiceApi::fetch_related_holons_internal`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RelationshipCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl RelationshipCacheStats {
+    /// Returns the counts accumulated since `earlier`.
+    pub fn since(&self, earlier: &RelationshipCacheStats) -> RelationshipCacheStats {
+        RelationshipCacheStats {
+            hits: self.hits.saturating_sub(earlier.hits),
+            misses: self.misses.saturating_sub(earlier.misses),
+        }
+    }
+
+    /// Returns hits over all lookups, or `None` when there were no lookups.
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
 }
 
 // TODO: Consider replacing `HashMap<HolonId, RelationshipMap>` with a fine-grained
@@ -22,7 +52,62 @@ pub struct RelationshipCache {
 impl RelationshipCache {
     /// Creates a new RelationshipCache with an empty cache.
     pub fn new() -> Self {
-        Self { cache: Arc::new(RwLock::new(HashMap::new())) }
+        Self {
+            cache: Arc::new(RwLock::new(HashMap::new())),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Returns hit and miss counts for `related_holons` since this cache was created.
+    pub fn stats(&self) -> RelationshipCacheStats {
+        RelationshipCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns whether `related_holons` would be served from memory for this source and
+    /// relationship. Never fetches.
+    pub fn contains(
+        &self,
+        source_holon_id: &HolonId,
+        relationship_name: &RelationshipName,
+    ) -> Result<bool, HolonError> {
+        let cache = self.cache.read().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire read lock on relationship_cache: {}",
+                e
+            ))
+        })?;
+        Ok(cache.get(source_holon_id).is_some_and(|relationship_map| {
+            relationship_map.get_collection_for_relationship(relationship_name).is_some()
+        }))
+    }
+
+    /// Drops every cached relationship for `source_holon_id`, so the next
+    /// `related_holons` call for that source fetches again.
+    pub fn invalidate(&self, source_holon_id: &HolonId) -> Result<(), HolonError> {
+        self.write_cache()?.remove(source_holon_id);
+        Ok(())
+    }
+
+    /// Drops every cached relationship for every source.
+    pub fn clear(&self) -> Result<(), HolonError> {
+        self.write_cache()?.clear();
+        Ok(())
+    }
+
+    fn write_cache(
+        &self,
+    ) -> Result<std::sync::RwLockWriteGuard<'_, HashMap<HolonId, RelationshipMap>>, HolonError>
+    {
+        self.cache.write().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire write lock on relationship_cache: {}",
+                e
+            ))
+        })
     }
 
     /// Retrieves a RelationshipMap for the source HolonReference by calling the HolonService to fetch all related Holons.
@@ -73,6 +158,7 @@ impl RelationshipCache {
                     relationship_map.get_collection_for_relationship(relationship_name)
                 {
                     // Cache hit: return the cached HolonCollection
+                    self.hits.fetch_add(1, Ordering::Relaxed);
                     debug!(
                         "Cache hit for source_holon_id: {:?}, relationship_name: {:?}",
                         source_holon_id, relationship_name
@@ -87,6 +173,7 @@ impl RelationshipCache {
         "Cache miss for source_holon_id: {:?}, relationship_name: {:?}. Fetching from HolonServiceApi.",
         source_holon_id, relationship_name
     );
+        self.misses.fetch_add(1, Ordering::Relaxed);
         let fetched_holons = holon_service.fetch_related_holons_internal(
             context,
             &source_holon_id,
@@ -97,12 +184,7 @@ impl RelationshipCache {
 
         // Update the cache
         {
-            let mut cache = self.cache.write().map_err(|e| {
-                HolonError::FailedToAcquireLock(format!(
-                    "Failed to acquire write lock on relationship_cache: {}",
-                    e
-                ))
-            })?;
+            let mut cache = self.write_cache()?;
             let relationship_map =
                 cache.entry(source_holon_id.clone()).or_insert_with(RelationshipMap::new_empty);
             relationship_map.insert(relationship_name.clone(), Arc::clone(&fetched_arc));
@@ -115,6 +197,8 @@ impl RelationshipCache {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use base_types::MapString;
+    use core_types::LocalId;
 
     fn assert_thread_safe<T: Send + Sync>() {}
 
@@ -122,4 +206,27 @@ mod tests {
     fn relationship_cache_is_thread_safe() {
         assert_thread_safe::<RelationshipCache>();
     }
+
+    #[test]
+    fn invalidate_drops_only_the_named_source() {
+        let cache = RelationshipCache::new();
+        let relationship = RelationshipName(MapString("Members".to_string()));
+        let source = HolonId::Local(LocalId(vec![1]));
+        let other = HolonId::Local(LocalId(vec![2]));
+        for id in [&source, &other] {
+            let mut relationship_map = RelationshipMap::new_empty();
+            relationship_map.insert(
+                relationship.clone(),
+                Arc::new(RwLock::new(HolonCollection::new_existing())),
+            );
+            cache.write_cache().unwrap().insert(id.clone(), relationship_map);
+        }
+
+        cache.invalidate(&source).unwrap();
+        assert!(!cache.contains(&source, &relationship).unwrap());
+        assert!(cache.contains(&other, &relationship).unwrap());
+
+        cache.clear().unwrap();
+        assert!(!cache.contains(&other, &relationship).unwrap());
+    }
 }
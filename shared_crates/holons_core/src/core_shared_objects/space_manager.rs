@@ -4,6 +4,7 @@ use crate::core_shared_objects::cache_request_router::CacheRequestRouter;
 use crate::core_shared_objects::transactions::TransactionManager;
 use crate::core_shared_objects::{HolonCacheAccess, HolonCacheManager, ServiceRoutingPolicy};
 use crate::reference_layer::{HolonServiceApi, HolonSpaceBehavior};
+use crate::validation::ValidationRuleRegistry;
 
 use std::sync::{Arc, RwLock};
 
@@ -25,6 +26,9 @@ pub struct HolonSpaceManager {
 
     /// Per-space transaction manager for opening and tracking transactions.
     transaction_manager: Arc<TransactionManager>,
+
+    /// Validation rule implementations used when transactions in this space validate.
+    validation_registry: RwLock<Arc<ValidationRuleRegistry>>,
 }
 
 impl HolonSpaceManager {
@@ -65,6 +69,7 @@ impl HolonSpaceManager {
             holon_service,
             local_holon_space: RwLock::new(local_holon_space),
             transaction_manager,
+            validation_registry: RwLock::new(Arc::new(ValidationRuleRegistry::with_core_rules())),
         }
     }
 
@@ -73,6 +78,37 @@ impl HolonSpaceManager {
         // Step 1: Clone the Arc for the caller.
         Arc::clone(&self.transaction_manager)
     }
+
+    /// Returns the registry transactions in this space validate against.
+    ///
+    /// Starts out holding the Core rules; hosts extend it through
+    /// [`Self::set_validation_registry`].
+    pub fn get_validation_registry(&self) -> Result<Arc<ValidationRuleRegistry>, HolonError> {
+        let guard = self.validation_registry.read().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire read lock on validation_registry: {}",
+                e
+            ))
+        })?;
+
+        Ok(Arc::clone(&guard))
+    }
+
+    /// Replaces the registry used by subsequent validations in this space.
+    pub fn set_validation_registry(
+        &self,
+        registry: ValidationRuleRegistry,
+    ) -> Result<(), HolonError> {
+        let mut guard = self.validation_registry.write().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire write lock on validation_registry: {}",
+                e
+            ))
+        })?;
+
+        *guard = Arc::new(registry);
+        Ok(())
+    }
 }
 
 impl HolonSpaceBehavior for HolonSpaceManager {
@@ -134,6 +170,7 @@ impl Debug for HolonSpaceManager {
             .field("local_holon_space", &self.local_holon_space)
             .field("cache_request_router", &"<CacheRequestRouter>")
             .field("transaction_manager", &"<TransactionManager>")
+            .field("validation_registry", &"<ValidationRuleRegistry>")
             .finish()
     }
 }
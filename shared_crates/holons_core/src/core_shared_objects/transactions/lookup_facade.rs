@@ -1,8 +1,13 @@
 use super::transaction_context::TransactionOperation;
 use super::{HolonServiceApi, HolonStagingBehavior, TransactionContext, TransientHolonBehavior};
-use crate::{HolonCollection, StagedReference, TransientReference};
+use crate::descriptors::descendants;
+use crate::query_layer::{page_collection, CollectionPage, PageRequest, SortKey};
+use crate::reference_layer::ReadableHolon;
+use crate::{
+    HolonCollection, HolonCollectionApi, HolonReference, StagedReference, TransientReference,
+};
 use base_types::MapString;
-use core_types::HolonError;
+use core_types::{CanonicalKey, CanonicalKeyPrefix, HolonError, KeyMatch};
 use std::sync::Arc;
 
 /// Semantic facade for transaction-scoped lookup operations.
@@ -20,6 +25,122 @@ impl LookupFacade {
         self.holon_service.get_all_holons_internal(&self.context)
     }
 
+    /// Returns one sorted window of `get_all_holons()`.
+    ///
+    /// Paging happens after the fetch: every saved holon is still read from the
+    /// guest, since sorting needs all of them. Paging bounds the returned
+    /// collection, not the fetch.
+    pub fn get_all_holons_page(
+        &self,
+        sort: Option<&SortKey>,
+        request: &PageRequest,
+    ) -> Result<CollectionPage, HolonError> {
+        page_collection(&self.get_all_holons()?, sort, request)
+    }
+
+    /// Returns the saved holons described by `descriptor`, read from its instance index.
+    ///
+    /// With `include_subtypes`, instances of every descriptor that extends `descriptor`
+    /// through `ExtendedBy` are included as well. Staged and transient holons are not
+    /// included, and `descriptor` itself must be saved. Holons saved before the instance
+    /// index existed are not indexed and are not returned.
+    pub fn get_holons_by_descriptor(
+        &self,
+        descriptor: &HolonReference,
+        include_subtypes: bool,
+    ) -> Result<HolonCollection, HolonError> {
+        self.context.assert_allowed(TransactionOperation::ReadState)?;
+        let descriptors =
+            if include_subtypes { descendants(descriptor)? } else { vec![descriptor.clone()] };
+
+        let mut instances = HolonCollection::new_existing();
+        for descriptor in descriptors {
+            let collection = self
+                .holon_service
+                .get_holons_by_descriptor_internal(&self.context, &descriptor.holon_id()?)?;
+            instances.add_references(collection.get_members().clone())?;
+        }
+        Ok(instances)
+    }
+
+    /// Returns the single saved holon whose key is exactly `key`.
+    ///
+    /// Like `get_staged_holon_by_base_key`, this is for callers that expect one match: it
+    /// returns `HolonNotFound` when no saved holon has the key and a duplicate error when
+    /// several lineages share it. Staged and transient holons are not searched.
+    pub fn get_saved_holon_by_key(&self, key: &MapString) -> Result<HolonReference, HolonError> {
+        let key_match = KeyMatch::Exact(CanonicalKey::new(key.0.clone())?);
+        let collection = self.get_saved_holons_by_key_match(&key_match)?;
+
+        match collection.get_members().as_slice() {
+            [] => Err(HolonError::HolonNotFound(format!("for key: {}", key))),
+            [holon] => Ok(holon.clone()),
+            _ => Err(HolonError::DuplicateError("Holons".to_string(), format!("key: {}", key))),
+        }
+    }
+
+    /// Does a lookup by full (unique) key on saved holons.
+    ///
+    /// A saved holon's versioned key is its key followed by its version number, so each
+    /// split of `versioned_key` ahead of its trailing digits is looked up as an exact key and
+    /// only holons whose own versioned key matches are kept. Returns `HolonNotFound` when
+    /// none match and a duplicate error when several do.
+    pub fn get_saved_holon_by_versioned_key(
+        &self,
+        versioned_key: &MapString,
+    ) -> Result<HolonReference, HolonError> {
+        let text = versioned_key.0.as_str();
+        let digits = text.bytes().rev().take_while(u8::is_ascii_digit).count();
+        let first_split = (text.len() - digits).max(1);
+
+        let mut matches = Vec::new();
+        for split in first_split..text.len() {
+            let key_match = KeyMatch::Exact(CanonicalKey::new(text[..split].to_string())?);
+            for holon in self.get_saved_holons_by_key_match(&key_match)?.get_members() {
+                if &holon.versioned_key()? == versioned_key {
+                    matches.push(holon.clone());
+                }
+            }
+        }
+
+        match matches.as_slice() {
+            [] => Err(HolonError::HolonNotFound(format!("for versioned key: {}", versioned_key))),
+            [holon] => Ok(holon.clone()),
+            _ => Err(HolonError::DuplicateError(
+                "Holons".to_string(),
+                format!("versioned key: {}", versioned_key),
+            )),
+        }
+    }
+
+    /// Returns every saved holon whose key starts with `prefix`.
+    ///
+    /// An empty prefix is rejected rather than treated as "every keyed holon"; use
+    /// `get_all_holons` for whole-space enumeration.
+    pub fn get_saved_holons_by_key_prefix(
+        &self,
+        prefix: &MapString,
+    ) -> Result<HolonCollection, HolonError> {
+        let key_match = KeyMatch::StartsWith(CanonicalKeyPrefix::new(prefix.0.clone())?);
+        self.get_saved_holons_by_key_match(&key_match)
+    }
+
+    /// Returns the saved holons, one per lineage, whose key satisfies `key_match`.
+    pub fn get_saved_holons_by_key_match(
+        &self,
+        key_match: &KeyMatch,
+    ) -> Result<HolonCollection, HolonError> {
+        self.context.assert_allowed(TransactionOperation::ReadState)?;
+        if let KeyMatch::StartsWith(prefix) = key_match {
+            if prefix.as_str().is_empty() {
+                return Err(HolonError::InvalidParameter(
+                    "get_saved_holons_by_key: key prefix must not be empty".to_string(),
+                ));
+            }
+        }
+        self.holon_service.get_saved_holons_by_key_internal(&self.context, key_match)
+    }
+
     /// Convenience method for retrieving a single StagedReference for a base key, when the caller expects there to only be one.
     /// Returns a duplicate error if multiple found.
     pub fn get_staged_holon_by_base_key(
@@ -9,6 +9,7 @@
 mod host_commit_execution_guard;
 mod lookup_facade;
 mod mutation_facade;
+mod relationship_enforcement;
 mod transaction_behavior;
 mod transaction_context;
 mod transaction_context_handle;
@@ -36,6 +37,7 @@ pub(crate) use crate::{SmartReference, TransientReference};
 pub use host_commit_execution_guard::HostCommitExecutionGuard;
 pub use lookup_facade::LookupFacade;
 pub use mutation_facade::MutationFacade;
+pub use relationship_enforcement::RelationshipEnforcement;
 pub use transaction_behavior::TransactionBehavior;
 pub use transaction_context::TransactionContext;
 pub use transaction_context_handle::TransactionContextHandle;
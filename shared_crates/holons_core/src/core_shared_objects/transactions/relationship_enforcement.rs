@@ -0,0 +1,47 @@
+//! Per-transaction enforcement mode for declared relationship contracts.
+//!
+//! Like lifecycle state, the mode is stored atomically in `TransactionContext`
+//! so it can be switched through a shared `Arc` without exclusive access.
+
+use serde::{Deserialize, Serialize};
+
+/// How staging-time relationship contract checks are applied.
+///
+/// The contract covers max cardinality and Uniform Endpoint Compatibility of
+/// relationship targets, as declared by the governing relationship descriptor.
+///
+/// - `Advisory` (default): a violating add is logged and applied; commit-time
+///   validation remains responsible for rejecting the resulting graph.
+/// - `Strict`: a violating add fails before the staged holon is mutated.
+///
+/// Advisory is the default so that a graph can pass through intermediate
+/// states that only satisfy their contracts once editing is finished.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RelationshipEnforcement {
+    Strict = 0,
+    #[default]
+    Advisory = 1,
+}
+
+impl RelationshipEnforcement {
+    pub(crate) fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    pub(crate) fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Strict,
+            1 => Self::Advisory,
+            _ => {
+                debug_assert!(
+                    false,
+                    "Invalid relationship enforcement value {} in TransactionContext",
+                    value
+                );
+                // Restrictive fallback for impossible/corrupt values.
+                Self::Strict
+            }
+        }
+    }
+}
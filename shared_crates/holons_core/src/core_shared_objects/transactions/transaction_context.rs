@@ -8,19 +8,24 @@ use std::{
     },
 };
 
+use crate::core_shared_objects::holon::StagedState;
 use crate::core_shared_objects::transient_manager_access_internal::TransientManagerAccessInternal;
+use crate::core_shared_objects::RelationshipCacheStats;
 use crate::reference_layer::{ReadableHolon, StagedReference};
-use base_types::BaseValue;
-use core_types::{HolonError, HolonId};
-use type_names::CorePropertyTypeName;
+use crate::validation::{
+    ValidationEngine, ValidationReport, ValidationRule, ValidationRuleRegistry,
+};
+use base_types::{BaseValue, MapString};
+use core_types::{HolonError, HolonId, RelationshipName, TemporaryId};
+use type_names::{CorePropertyTypeName, CoreValidationRuleName};
 
 use super::{
     DanceInitiator, DanceRequest, DanceResponse, Holon, HolonCacheAccess, HolonCloneModel,
     HolonPool, HolonReference, HolonServiceApi, HolonSpaceBehavior, HolonSpaceManager,
     HolonStagingBehavior, HostCommitExecutionGuard, LookupFacade, MutationFacade, Nursery,
-    NurseryAccess, NurseryAccessInternal, SmartReference, TransactionContextHandle,
-    TransactionLifecycleState, TransientHolonBehavior, TransientHolonManager,
-    TransientManagerAccess, TransientReference, TxId,
+    NurseryAccess, NurseryAccessInternal, RelationshipEnforcement, SmartReference,
+    TransactionContextHandle, TransactionLifecycleState, TransientHolonBehavior,
+    TransientHolonManager, TransientManagerAccess, TransientReference, TxId,
 };
 
 /// Transaction-scoped operations used for lifecycle/access policy checks.
@@ -47,6 +52,9 @@ pub struct TransactionContext {
     /// prevents external request mutations from racing in-flight commit ingress.
     host_commit_in_progress: AtomicBool,
 
+    /// Staging-time relationship contract enforcement mode.
+    relationship_enforcement: AtomicU8,
+
     space_manager: Arc<HolonSpaceManager>,
     nursery: Arc<Nursery>,
     transient_manager: Arc<TransientHolonManager>,
@@ -68,6 +76,7 @@ impl TransactionContext {
             tx_id,
             lifecycle_state: AtomicU8::new(TransactionLifecycleState::Open.as_u8()),
             host_commit_in_progress: AtomicBool::new(false),
+            relationship_enforcement: AtomicU8::new(RelationshipEnforcement::default().as_u8()),
             space_manager,
             nursery: Arc::new(Nursery::new(tx_id, weak_ctx.clone())),
             transient_manager: Arc::new(TransientHolonManager::new_empty(tx_id, weak_ctx.clone())),
@@ -98,6 +107,16 @@ impl TransactionContext {
         self.lifecycle_state() == TransactionLifecycleState::Open
     }
 
+    /// Returns how relationship contracts are enforced when holons are staged.
+    pub fn relationship_enforcement(&self) -> RelationshipEnforcement {
+        RelationshipEnforcement::from_u8(self.relationship_enforcement.load(Ordering::Acquire))
+    }
+
+    /// Sets how relationship contracts are enforced for subsequent staged mutations.
+    pub fn set_relationship_enforcement(&self, enforcement: RelationshipEnforcement) {
+        self.relationship_enforcement.store(enforcement.as_u8(), Ordering::Release);
+    }
+
     /// Transitions the transaction lifecycle from `Open` to `Committed`.
     ///
     /// Returns `true` only when the state transition is applied by this caller.
@@ -260,6 +279,9 @@ impl TransactionContext {
     /// # Errors
     /// - Returns a `HolonError` if the commit operation encounters a system-level issue.
     ///
+    /// Commit does not run validation. Callers that want commit-blocking
+    /// violations to stop a commit check [`Self::validation_report`] first.
+    ///
     pub fn commit(self: &Arc<Self>) -> Result<TransientReference, HolonError> {
         self.assert_allowed(TransactionOperation::CommitExecution)?;
         let staged_references = self.nursery.get_staged_references()?;
@@ -271,6 +293,68 @@ impl TransactionContext {
         Ok(commit_response)
     }
 
+    /// Validates every staged holon a commit would attempt and returns a transient
+    /// `ValidationReport` holon listing every violation found.
+    ///
+    /// Abandoned and already-committed staged holons are skipped. Nothing is
+    /// committed and staged holons are not modified, so the report can be
+    /// requested repeatedly while editing. Rules are dispatched through the
+    /// space's validation registry (see `HolonSpaceManager::set_validation_registry`).
+    ///
+    /// The report is advisory: [`Self::commit`] does not consult it.
+    pub fn validate(self: &Arc<Self>) -> Result<TransientReference, HolonError> {
+        self.validation_report()?.to_holon(self)
+    }
+
+    /// Runs the validation behind [`Self::validate`] without materializing the report.
+    pub fn validation_report(self: &Arc<Self>) -> Result<ValidationReport, HolonError> {
+        self.assert_allowed(TransactionOperation::ReadState)?;
+        let mut holons = Vec::new();
+        for staged in self.nursery.get_staged_references()? {
+            if staged.is_committed()? || staged.is_in_state(self, StagedState::Abandoned)? {
+                continue;
+            }
+            holons.push(HolonReference::Staged(staged));
+        }
+
+        let registry = self.space_manager().get_validation_registry()?;
+        let mut engine = ValidationEngine::new(ValidationRuleRegistry::clone(&registry));
+        if holons.iter().any(|holon| {
+            matches!(holon.holon_descriptor(), Err(HolonError::MissingDescribedBy { .. }))
+        }) {
+            if let Some(rule) =
+                self.core_validation_rule(CoreValidationRuleName::ExactlyOneDescribedBy)?
+            {
+                engine = engine.with_described_by_rule(rule);
+            }
+        }
+
+        Ok(ValidationReport {
+            validated_holon_count: holons.len(),
+            results: engine.validate_holons(&holons)?,
+        })
+    }
+
+    /// Resolves a core `ValidationRule` holon by its schema key, preferring a
+    /// staged copy (the core schema being loaded in this transaction) over the
+    /// saved one. Returns `None` when the core schema has not been loaded.
+    fn core_validation_rule(
+        self: &Arc<Self>,
+        rule_name: CoreValidationRuleName,
+    ) -> Result<Option<ValidationRule>, HolonError> {
+        let key = MapString(format!("{}.ValidationRule", rule_name.as_rule_name()));
+        let rule = match self.nursery.get_staged_holon_by_base_key(&key) {
+            Ok(staged) => HolonReference::Staged(staged),
+            Err(HolonError::HolonNotFound(_)) => match self.lookup().get_saved_holon_by_key(&key) {
+                Ok(saved) => saved,
+                Err(HolonError::HolonNotFound(_)) => return Ok(None),
+                Err(error) => return Err(error),
+            },
+            Err(error) => return Err(error),
+        };
+        Ok(Some(ValidationRule::from_holon(rule)))
+    }
+
     /// Loads holons from a loader set and applies terminal lifecycle semantics.
     ///
     /// This operation is commit-like by design: when the returned load response indicates
@@ -504,6 +588,33 @@ impl TransactionContext {
         self.space_manager().get_cache_access()
     }
 
+    /// Reports whether a saved holon's related holons are already in the relationship
+    /// cache, without fetching them. Used by query explain.
+    pub(crate) fn is_related_holons_cached(
+        &self,
+        source_holon_id: &HolonId,
+        relationship_name: &RelationshipName,
+    ) -> Result<bool, HolonError> {
+        self.space_manager()
+            .get_cache_access()
+            .is_related_holons_cached(source_holon_id, relationship_name)
+    }
+
+    /// Returns cumulative relationship cache hits and misses for this space. Used by
+    /// query profiling.
+    pub(crate) fn relationship_cache_stats(&self) -> Result<RelationshipCacheStats, HolonError> {
+        self.space_manager().get_cache_access().relationship_cache_stats()
+    }
+
+    /// Drops cached relationships for one saved source, or for all sources when `None`.
+    /// Used by live queries after another agent's commit.
+    pub(crate) fn invalidate_related_holons(
+        &self,
+        source_holon_id: Option<&HolonId>,
+    ) -> Result<(), HolonError> {
+        self.space_manager().get_cache_access().invalidate_related_holons(source_holon_id)
+    }
+
     // Internal privileged accessors for reference resolution.
     fn transition_to_committed_if_needed(&self) -> Result<(), HolonError> {
         match self.transition_to_committed() {
@@ -585,6 +696,12 @@ impl TransactionContext {
     pub fn import_transient_holons(&self, transient_holons: HolonPool) -> Result<(), HolonError> {
         self.transient_manager.import_transient_holons(transient_holons)
     }
+
+    /// Removes transient holons that nothing refers to any more. Used to discard query
+    /// execution artifacts.
+    pub(crate) fn release_transient_holons(&self, ids: &[TemporaryId]) -> Result<(), HolonError> {
+        self.transient_manager.release_holons(ids)
+    }
 }
 
 #[cfg(test)]
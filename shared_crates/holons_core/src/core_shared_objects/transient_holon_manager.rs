@@ -309,6 +309,19 @@ impl TransientManagerAccessInternal for TransientHolonManager {
         guard.import_pool(pool); // Mutates the existing pool
         Ok(())
     }
+
+    fn release_holons(&self, ids: &[TemporaryId]) -> Result<(), HolonError> {
+        let mut guard = self.transient_holons.write().map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire write lock for release_holons: {}",
+                e
+            ))
+        })?;
+        for id in ids {
+            guard.remove_holon(id);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -84,6 +84,16 @@ pub trait TransientManagerAccessInternal:
     /// Returns `HolonError::FailedToAcquireLock` if the internal write lock cannot be acquired.
     fn import_transient_holons(&self, pool: HolonPool) -> Result<(), HolonError>;
 
+    /// Removes the transient holons with the given ids from the pool.
+    ///
+    /// Ids that are not in the pool are ignored. References to a removed holon no longer
+    /// resolve, so this is only for holons nothing else refers to, such as the execution
+    /// artifacts of a finished query run.
+    ///
+    /// # Errors
+    /// Returns `HolonError::FailedToAcquireLock` if the internal write lock cannot be acquired.
+    fn release_holons(&self, ids: &[TemporaryId]) -> Result<(), HolonError>;
+
     /// Provides direct access to the underlying transient holon instances.
     ///
     /// This returns the actual `Arc<RwLock<Holon>>` objects stored inside the manager.
@@ -2,6 +2,7 @@ use crate::core_shared_objects::Holon;
 use crate::descriptors::{
     accessor_helpers, DanceDescriptor, DanceResponseDescriptor, Descriptor, HolonDescriptor,
 };
+use crate::query_layer::BoundHolonCollection;
 use crate::reference_layer::{HolonReference, ReadableHolon};
 use base_types::{BaseValue, MapString};
 use core_types::{HolonError, HolonId};
@@ -388,6 +389,54 @@ impl DeleteHolonParameters {
     }
 }
 
+/// Typed wrapper over the `QueryDanceRequest` holon used by the `QueryDance` dance.
+///
+/// The request selects a `Query` through `RequestedQuery`, supplies the
+/// collection it runs over through `InitialInput`, and carries zero or more
+/// `QueryParameterBinding`s through `RequestParameters`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryDanceParameters {
+    request: HolonReference,
+}
+
+impl QueryDanceParameters {
+    /// Wraps the request holon after verifying that it names exactly one query
+    /// and one initial input collection.
+    pub fn new(request: HolonReference) -> Result<Self, HolonError> {
+        let parameters = Self { request };
+        parameters.requested_query()?;
+        parameters.initial_input()?;
+        Ok(parameters)
+    }
+
+    pub fn as_holon_reference(&self) -> &HolonReference {
+        &self.request
+    }
+
+    /// Returns the `Query` holon to execute.
+    pub fn requested_query(&self) -> Result<HolonReference, HolonError> {
+        accessor_helpers::require_single_related(
+            &self.request,
+            CoreRelationshipTypeName::RequestedQuery,
+        )
+    }
+
+    /// Returns the collection the query's root expression consumes.
+    pub fn initial_input(&self) -> Result<BoundHolonCollection, HolonError> {
+        Ok(BoundHolonCollection::from_holon(accessor_helpers::require_single_related(
+            &self.request,
+            CoreRelationshipTypeName::InitialInput,
+        )?))
+    }
+
+    /// Returns the supplied `QueryParameterBinding` holons, in request order.
+    pub fn parameter_bindings(&self) -> Result<Vec<HolonReference>, HolonError> {
+        let bindings = self.request.related_holons(CoreRelationshipTypeName::RequestParameters)?;
+        let members = bindings.read().map_err(accessor_helpers::lock_error)?.get_members().clone();
+        Ok(members)
+    }
+}
+
 /// Typed reference to a response holon described by `DanceResponseType`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DanceResponseReference {
@@ -547,6 +596,7 @@ mod tests {
     use super::{
         DanceContext, DanceDiagnostic, DanceDiagnosticSeverity, DanceEvent, DanceIdentity,
         DanceOutcome, DanceRequestState, DanceResult, DeleteHolonParameters, InvocationSource,
+        QueryDanceParameters,
     };
     use crate::descriptors::test_support::{build_context, new_test_holon};
     use crate::reference_layer::{ReadableHolon, WritableHolon};
@@ -554,7 +604,7 @@ mod tests {
     use base_types::{BaseValue, MapBytes, MapString};
     use core_types::{ExternalId, HolonError, HolonId, LocalId, OutboundProxyId};
     use serde_json::{json, to_value};
-    use type_names::CorePropertyTypeName;
+    use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
 
     #[test]
     fn parameter_holon_accepts_transient_reference() {
@@ -706,4 +756,55 @@ mod tests {
 
         assert_eq!(parameters.holon_id().expect("holon id"), expected_id);
     }
+
+    #[test]
+    fn query_dance_parameters_expose_query_input_and_bindings() {
+        let context = build_context();
+        let query = new_test_holon(&context, "query").expect("query holon");
+        let input = new_test_holon(&context, "input").expect("input holon");
+        let first = new_test_holon(&context, "first-binding").expect("binding holon");
+        let second = new_test_holon(&context, "second-binding").expect("binding holon");
+        let mut request = new_test_holon(&context, "query-dance-request").expect("request holon");
+        request
+            .add_related_holons(
+                CoreRelationshipTypeName::RequestedQuery,
+                vec![query.clone().into()],
+            )
+            .expect("RequestedQuery")
+            .add_related_holons(CoreRelationshipTypeName::InitialInput, vec![input.clone().into()])
+            .expect("InitialInput")
+            .add_related_holons(
+                CoreRelationshipTypeName::RequestParameters,
+                vec![first.clone().into(), second.clone().into()],
+            )
+            .expect("RequestParameters");
+
+        let parameters = QueryDanceParameters::new(HolonReference::from(request))
+            .expect("typed query dance parameters");
+
+        assert_eq!(parameters.requested_query().expect("query"), HolonReference::from(query));
+        assert_eq!(
+            parameters.initial_input().expect("input").as_holon_reference(),
+            &HolonReference::from(input)
+        );
+        assert_eq!(
+            parameters.parameter_bindings().expect("bindings"),
+            vec![HolonReference::from(first), HolonReference::from(second)]
+        );
+    }
+
+    #[test]
+    fn query_dance_parameters_require_a_requested_query() {
+        let context = build_context();
+        let input = new_test_holon(&context, "input").expect("input holon");
+        let mut request = new_test_holon(&context, "query-dance-request").expect("request holon");
+        request
+            .add_related_holons(CoreRelationshipTypeName::InitialInput, vec![input.into()])
+            .expect("InitialInput");
+
+        let error = QueryDanceParameters::new(HolonReference::from(request))
+            .expect_err("missing RequestedQuery should be rejected");
+
+        assert!(matches!(error, HolonError::MissingRequiredRelationship { .. }));
+    }
 }
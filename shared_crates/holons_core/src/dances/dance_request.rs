@@ -6,7 +6,7 @@ use crate::query_layer::{NodeCollection, QueryExpression};
 use crate::reference_layer::TransientReference;
 use crate::{HolonReference, StagedReference};
 use base_types::MapString;
-use core_types::{HolonId, LocalId, PropertyMap, RelationshipName};
+use core_types::{HolonId, KeyMatch, LocalId, PropertyMap, RelationshipName};
 
 /// Runtime dance request (tx-bound, execution-capable).
 ///
@@ -42,6 +42,9 @@ pub enum RequestBody {
     ParameterValues(PropertyMap),
     StagedRef(StagedReference),
     QueryExpression(QueryExpression),
+    KeyMatch(KeyMatch),
+    /// A descriptor's id and whether instances of its subtypes are included.
+    DescriptorInstances(HolonId, bool),
 }
 
 impl RequestBody {
@@ -72,6 +75,14 @@ impl RequestBody {
         Self::QueryExpression(query_expression)
     }
 
+    pub fn new_key_match(key_match: KeyMatch) -> Self {
+        Self::KeyMatch(key_match)
+    }
+
+    pub fn new_descriptor_instances(descriptor_id: HolonId, include_subtypes: bool) -> Self {
+        Self::DescriptorInstances(descriptor_id, include_subtypes)
+    }
+
     pub fn summarize(&self) -> String {
         match &self {
             RequestBody::Holon(holon) => format!("  Holon summary: {}", holon.summarize()),
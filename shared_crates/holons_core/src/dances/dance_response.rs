@@ -204,6 +204,8 @@ impl From<HolonError> for ResponseStatusCode {
             HolonError::InvalidRelationship(_, _) => ResponseStatusCode::BadRequest,
             HolonError::InvalidWireFormat { .. } => ResponseStatusCode::BadRequest,
             HolonError::MissingStagedCollection(_) => ResponseStatusCode::BadRequest,
+            HolonError::MissingQueryParameter { .. } => ResponseStatusCode::BadRequest,
+            HolonError::UnexpectedQueryParameter { .. } => ResponseStatusCode::BadRequest,
 
             // 422-ish (semantic validation / parse errors)
             HolonError::AmbiguousRelationshipTraversal { .. } => {
@@ -211,8 +213,10 @@ impl From<HolonError> for ResponseStatusCode {
             }
             HolonError::DescriptorSchemaInvalid { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::IntegerOutOfRange { .. } => ResponseStatusCode::UnprocessableEntity,
+            HolonError::ItemCountOutOfRange { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::LoaderParsingError(_) => ResponseStatusCode::UnprocessableEntity,
             HolonError::MissingDescribedBy { .. } => ResponseStatusCode::UnprocessableEntity,
+            HolonError::MissingRecordField { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::MultipleDescribedBy { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::MultipleExtends { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::CyclicExtends { .. } => ResponseStatusCode::UnprocessableEntity,
@@ -227,9 +231,24 @@ impl From<HolonError> for ResponseStatusCode {
             HolonError::MultipleRelatedHolons { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::NoEffectiveKeyRule { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::PvlViolation(_) => ResponseStatusCode::UnprocessableEntity,
+            HolonError::QueryParameterTypeMismatch { .. } => {
+                ResponseStatusCode::UnprocessableEntity
+            }
             HolonError::ReferenceBindingFailed { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::ReferenceResolutionFailed { .. } => ResponseStatusCode::UnprocessableEntity,
+            HolonError::ReferenceTargetTypeMismatch { .. } => {
+                ResponseStatusCode::UnprocessableEntity
+            }
+            HolonError::RelationshipCardinalityExceeded { .. } => {
+                ResponseStatusCode::UnprocessableEntity
+            }
+            HolonError::RelationshipEndpointMismatch { .. } => {
+                ResponseStatusCode::UnprocessableEntity
+            }
             HolonError::StringLengthOutOfRange { .. } => ResponseStatusCode::UnprocessableEntity,
+            HolonError::StringFormatMismatch { .. } => ResponseStatusCode::UnprocessableEntity,
+            HolonError::StringPatternMismatch { .. } => ResponseStatusCode::UnprocessableEntity,
+            HolonError::TemporalOutOfRange { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::UnknownOperatorCategory { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::UnsupportedOperator { .. } => ResponseStatusCode::UnprocessableEntity,
             HolonError::UnsupportedStagedTraversal { .. } => {
@@ -20,7 +20,7 @@ use tracing::{debug, info};
 
 use crate::core_shared_objects::transactions::TransactionContext;
 use crate::query_layer::{Node, NodeCollection, QueryPathMap};
-use crate::reference_layer::TransientReference;
+use crate::reference_layer::{SmartReference, TransientReference};
 use crate::{
     dances::{
         dance_request::{DanceType, RequestBody},
@@ -100,13 +100,70 @@ pub fn get_all_holons_dance(
     context: &Arc<TransactionContext>,
     _request: DanceRequest,
 ) -> Result<ResponseBody, HolonError> {
-    // TODO: add support for descriptor parameter
-    //
-    //
+    // Descriptor-scoped enumeration is served by `get_holons_by_descriptor_dance`.
     info!("----- Entered get_all_holons dance ----");
     Ok(ResponseBody::HolonCollection(context.lookup().get_all_holons()?))
 }
 
+/// Get the saved holons described by a descriptor, from the descriptor's instance index
+///
+/// *DanceRequest:*
+/// - dance_name: "get_holons_by_descriptor"
+/// - dance_type: Standalone
+/// - request_body:
+///     - DescriptorInstances(HolonId, bool) -- the descriptor's id, and whether instances of
+///       its `ExtendedBy` subtypes are included
+///
+/// *ResponseBody:*
+/// - HolonCollection
+///
+pub fn get_holons_by_descriptor_dance(
+    context: &Arc<TransactionContext>,
+    request: DanceRequest,
+) -> Result<ResponseBody, HolonError> {
+    info!("----- Entered get_holons_by_descriptor dance ----");
+    let (descriptor_id, include_subtypes) = match request.body {
+        RequestBody::DescriptorInstances(id, include_subtypes) => (id, include_subtypes),
+        _ => {
+            return Err(HolonError::InvalidParameter(
+                "RequestBody variant must be DescriptorInstances".to_string(),
+            ))
+        }
+    };
+    let descriptor =
+        HolonReference::Smart(SmartReference::new_from_id(context.context_handle(), descriptor_id));
+    Ok(ResponseBody::HolonCollection(
+        context.lookup().get_holons_by_descriptor(&descriptor, include_subtypes)?,
+    ))
+}
+
+/// Get the saved holons whose key matches exactly or by prefix, from the space's key index
+///
+/// *DanceRequest:*
+/// - dance_name: "get_saved_holons_by_key"
+/// - dance_type: Standalone
+/// - request_body:
+///     - KeyMatch(KeyMatch)
+///
+/// *ResponseBody:*
+/// - HolonCollection
+///
+pub fn get_saved_holons_by_key_dance(
+    context: &Arc<TransactionContext>,
+    request: DanceRequest,
+) -> Result<ResponseBody, HolonError> {
+    info!("----- Entered get_saved_holons_by_key dance ----");
+    let key_match = match request.body {
+        RequestBody::KeyMatch(key_match) => key_match,
+        _ => {
+            return Err(HolonError::InvalidParameter(
+                "RequestBody variant must be KeyMatch".to_string(),
+            ))
+        }
+    };
+    Ok(ResponseBody::HolonCollection(context.lookup().get_saved_holons_by_key_match(&key_match)?))
+}
+
 /// Gets Holon from persistent store, located by HolonId
 ///
 /// *DanceRequest:*
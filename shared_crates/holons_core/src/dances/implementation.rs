@@ -46,6 +46,10 @@ impl DanceImplementation {
             return implementations::delete_holon::invoke(context, bound_invocation);
         }
 
+        if implementation_name == CoreDanceImplementationName::QueryDance.as_command_name().0 {
+            return implementations::query_dance::invoke(context, bound_invocation);
+        }
+
         Err(HolonError::NotImplemented(format!(
             "Descriptor-driven host invocation is not implemented for DanceImplementation `{}` yet. Guest-side persistence primitives should be wrapped explicitly rather than treated as canonical host dances.",
             implementation_name
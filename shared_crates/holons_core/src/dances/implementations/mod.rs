@@ -1,2 +1,3 @@
 pub mod commit;
 pub mod delete_holon;
+pub mod query_dance;
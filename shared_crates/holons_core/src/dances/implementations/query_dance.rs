@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use core_types::HolonError;
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::dances::{BoundDanceInvocation, QueryDanceParameters};
+use crate::descriptors::Descriptor;
+use crate::query_layer::execute_query_with_parameters;
+use crate::reference_layer::{HolonReference, ReadableHolon, WritableHolon};
+
+pub fn invoke(
+    context: &Arc<TransactionContext>,
+    bound_invocation: &BoundDanceInvocation,
+) -> Result<Option<HolonReference>, HolonError> {
+    let request = bound_invocation.request().cloned().ok_or_else(|| {
+        HolonError::MissingRequiredRelationship {
+            relationship: "Request".to_string(),
+            descriptor: bound_invocation
+                .invocation()
+                .as_holon_reference()
+                .summarize()
+                .unwrap_or_else(|_| "DanceInvocation".to_string()),
+        }
+    })?;
+    let parameters = QueryDanceParameters::new(request)?;
+
+    let execution = execute_query_with_parameters(
+        context,
+        &parameters.requested_query()?,
+        &parameters.initial_input()?,
+        &parameters.parameter_bindings()?,
+    )?;
+
+    // The result collection is minted by the executor without a descriptor;
+    // describe it with the response's declared body type so the response
+    // conforms to `QueryDanceResponse`.
+    let mut body = execution.into_result().into_inner();
+    if body.get_descriptor()?.is_none() {
+        if let Some(body_type) = bound_invocation.response_type()?.response_body()? {
+            body.with_descriptor(body_type.holon().clone())?;
+        }
+    }
+
+    Ok(Some(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dances::DanceInvocation;
+    use crate::descriptors::test_support::{
+        build_context, core_holon_type_name, new_descriptor_holon, new_test_holon,
+    };
+    use crate::query_layer::BoundHolonCollection;
+    use crate::reference_layer::TransientReference;
+    use core_types::TypeKind;
+    use type_names::{CoreHolonTypeName, CorePropertyTypeName, CoreRelationshipTypeName};
+
+    /// Builds a `QueryDance` request whose query traverses `Members` from one
+    /// source holon related to `member`.
+    fn query_request(
+        context: &Arc<TransactionContext>,
+        member: &TransientReference,
+    ) -> Result<TransientReference, HolonError> {
+        let traversal_type = new_descriptor_holon(
+            context,
+            "members-traversal-type",
+            &core_holon_type_name(CoreHolonTypeName::RelationshipTraversal),
+            TypeKind::Holon,
+        )?;
+        let mut traversal = new_test_holon(context, "members-traversal")?;
+        traversal.with_descriptor(traversal_type.into())?;
+        traversal.with_property_value(CorePropertyTypeName::RelationshipName, "Members")?;
+        let mut query = new_test_holon(context, "members-query")?;
+        query
+            .add_related_holons(CoreRelationshipTypeName::RootExpression, vec![traversal.into()])?;
+
+        let mut source = new_test_holon(context, "source")?;
+        source.add_related_holons("Members", vec![member.clone().into()])?;
+        let input = BoundHolonCollection::new_transient(context, vec![source.into()])?;
+
+        let mut request = new_test_holon(context, "query-dance-request")?;
+        request
+            .add_related_holons(CoreRelationshipTypeName::RequestedQuery, vec![query.into()])?
+            .add_related_holons(CoreRelationshipTypeName::InitialInput, vec![input.into()])?;
+        Ok(request)
+    }
+
+    /// Builds and binds a `QueryDance` invocation carrying `request`, afforded
+    /// by a holon whose type declares the dance with `body_type` as its
+    /// response body.
+    fn bind_invocation(
+        context: &Arc<TransactionContext>,
+        request: Option<TransientReference>,
+        body_type: &TransientReference,
+    ) -> Result<BoundDanceInvocation, HolonError> {
+        let mut response_type =
+            new_descriptor_holon(context, "query-dance-response", "QueryDanceResponse", "Holon")?;
+        response_type.add_related_holons(
+            CoreRelationshipTypeName::ResponseBody,
+            vec![body_type.clone().into()],
+        )?;
+        let mut dance = new_descriptor_holon(context, "query-dance", "QueryDance", "Holon")?;
+        dance.add_related_holons(CoreRelationshipTypeName::Response, vec![response_type.into()])?;
+        let mut affording_type =
+            new_descriptor_holon(context, "affording-type", "Transaction", "Holon")?;
+        affording_type
+            .add_related_holons(CoreRelationshipTypeName::AffordsDance, vec![dance.into()])?;
+        let mut affording = new_test_holon(context, "affording")?;
+        affording.with_descriptor(affording_type.into())?;
+
+        let invocation_type =
+            new_descriptor_holon(context, "dance-invocation-type", "DanceInvocation", "Holon")?;
+        let mut invocation = new_test_holon(context, "query-dance-invocation")?;
+        invocation.with_descriptor(invocation_type.into())?;
+        invocation
+            .with_property_value(CorePropertyTypeName::DanceName, "QueryDance")?
+            .add_related_holons(CoreRelationshipTypeName::AffordingHolon, vec![affording.into()])?;
+        if let Some(request) = request {
+            invocation
+                .add_related_holons(CoreRelationshipTypeName::Request, vec![request.into()])?;
+        }
+
+        DanceInvocation::new(invocation.into())?.bind()
+    }
+
+    #[test]
+    fn invoke_returns_the_result_collection_as_a_described_response_body() -> Result<(), HolonError>
+    {
+        let context = build_context();
+        let member = new_test_holon(&context, "member")?;
+        let body_type =
+            new_descriptor_holon(&context, "query-result-type", "HolonCollection", "Holon")?;
+        let request = query_request(&context, &member)?;
+        let bound = bind_invocation(&context, Some(request), &body_type)?;
+
+        let body = invoke(&context, &bound)?.expect("QueryDance returns a response body");
+
+        assert_eq!(body.get_descriptor()?, Some(HolonReference::from(body_type)));
+        let members = BoundHolonCollection::from_holon(body).members()?;
+        let members = members.read().expect("result members lock").get_members().clone();
+        assert_eq!(members, vec![HolonReference::from(member)]);
+        Ok(())
+    }
+
+    #[test]
+    fn invoke_rejects_requests_without_a_usable_query() -> Result<(), HolonError> {
+        let context = build_context();
+        let body_type =
+            new_descriptor_holon(&context, "query-result-type", "HolonCollection", "Holon")?;
+
+        let bound = bind_invocation(&context, None, &body_type)?;
+        assert!(matches!(
+            invoke(&context, &bound),
+            Err(HolonError::MissingRequiredRelationship { relationship, .. })
+                if relationship == "Request"
+        ));
+
+        let mut request = new_test_holon(&context, "query-dance-request")?;
+        request.add_related_holons(
+            CoreRelationshipTypeName::InitialInput,
+            vec![BoundHolonCollection::new_transient(&context, Vec::new())?.into()],
+        )?;
+        let bound = bind_invocation(&context, Some(request), &body_type)?;
+        assert!(matches!(
+            invoke(&context, &bound),
+            Err(HolonError::MissingRequiredRelationship { relationship, .. })
+                if relationship == "RequestedQuery"
+        ));
+
+        let not_a_query = new_test_holon(&context, "not-a-query")?;
+        let mut request = query_request(&context, &new_test_holon(&context, "member")?)?;
+        request.add_related_holons(
+            CoreRelationshipTypeName::RequestedQuery,
+            vec![not_a_query.into()],
+        )?;
+        let bound = bind_invocation(&context, Some(request), &body_type)?;
+        assert!(matches!(
+            invoke(&context, &bound),
+            Err(HolonError::MultipleRelatedHolons { relationship, count, .. })
+                if relationship == "RequestedQuery" && count == 2
+        ));
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ pub use self::contract::{
     DanceDiagnostic, DanceDiagnosticSeverity, DanceEvent, DanceExecutionResult, DanceIdentity,
     DanceInvocation, DanceInvocationSource, DanceOutcome, DanceParameters, DanceRequestState,
     DanceResponseReference, DanceResult, DanceTarget, DeleteHolonParameters, InvocationSource,
+    QueryDanceParameters,
 };
 pub use self::dance_initiator::DanceInitiator;
 pub use self::dance_request::{DanceRequest, DanceType, RequestBody};
@@ -91,6 +91,21 @@ pub(crate) fn require_integer<T: ToPropertyName>(
     }
 }
 
+/// Returns an optional integer property from a descriptor holon.
+pub(crate) fn optional_integer<T: ToPropertyName>(
+    holon: &HolonReference,
+    property_name: T,
+) -> Result<Option<i64>, HolonError> {
+    let name = property_name.to_property_name();
+    match holon.property_value(&name)? {
+        Some(BaseValue::IntegerValue(value)) => Ok(Some(value.0)),
+        Some(other) => {
+            Err(HolonError::UnexpectedValueType(format!("{:?}", other), "Integer".to_string()))
+        }
+        None => Ok(None),
+    }
+}
+
 /// Returns the single target of a required singular descriptor relationship.
 pub(crate) fn require_single_related(
     holon: &HolonReference,
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use crate::descriptors::accessor_helpers::{descriptor_label, lock_error, search_extends_chain};
+use crate::descriptors::HolonDescriptor;
 use crate::reference_layer::{HolonReference, ReadableHolon};
 use core_types::HolonError;
 use type_names::{CoreHolonTypeName, CoreRelationshipTypeName};
@@ -42,8 +43,10 @@ pub(crate) fn inheritance_rule(relationship: &CoreRelationshipTypeName) -> Inher
         | CoreRelationshipTypeName::AffordsDance
         | CoreRelationshipTypeName::AffordsOperator
         | CoreRelationshipTypeName::Validations
-        | CoreRelationshipTypeName::Constraints => InheritanceRule::Additive,
-        CoreRelationshipTypeName::InstanceKeyRule => InheritanceRule::Override,
+        | CoreRelationshipTypeName::Constraints
+        | CoreRelationshipTypeName::RecordFields => InheritanceRule::Additive,
+        CoreRelationshipTypeName::InstanceKeyRule
+        | CoreRelationshipTypeName::ReferenceTargetType => InheritanceRule::Override,
         _ => InheritanceRule::Local,
     }
 }
@@ -84,6 +87,30 @@ pub fn ancestors(start: &HolonReference) -> Result<Vec<HolonReference>, HolonErr
     walk_extends_chain(start).collect()
 }
 
+/// Collects `start` and every descriptor that extends it, directly or transitively.
+///
+/// Follows the inverse `ExtendedBy` relationship breadth-first, so `start` comes
+/// first and each subtype appears once, before any of its own subtypes.
+pub fn descendants(start: &HolonReference) -> Result<Vec<HolonReference>, HolonError> {
+    let mut visited: HashSet<String> = HashSet::from([start.reference_id_string()]);
+    let mut descendants = vec![start.clone()];
+    let mut next = 0;
+
+    while next < descendants.len() {
+        let collection_arc =
+            descendants[next].related_holons(CoreRelationshipTypeName::ExtendedBy)?;
+        let subtypes = collection_arc.read().map_err(lock_error)?.get_members().clone();
+        for subtype in subtypes {
+            if visited.insert(subtype.reference_id_string()) {
+                descendants.push(subtype);
+            }
+        }
+        next += 1;
+    }
+
+    Ok(descendants)
+}
+
 /// Returns true when `candidate` is `anchor` or inherits from it through `Extends`.
 ///
 /// Compatibility is based on reference identity, not descriptor names.
@@ -102,6 +129,35 @@ pub(crate) fn equals_or_extends(
     Ok(false)
 }
 
+/// Applies Uniform Endpoint Compatibility to `endpoint`.
+///
+/// `required_type` must occur in the lineage of the endpoint's describing type
+/// or in the endpoint's own `Extends` lineage: `requiredType ∈ L(D(H)) or
+/// requiredType ∈ L(H)`. The same predicate covers ordinary and descriptor
+/// holons.
+pub(crate) fn is_endpoint_compatible(
+    endpoint: &HolonReference,
+    required_type: &HolonReference,
+) -> Result<bool, HolonError> {
+    if let Some(descriptor) = described_by_descriptor(endpoint)? {
+        if equals_or_extends(&descriptor, required_type)? {
+            return Ok(true);
+        }
+    }
+    equals_or_extends(endpoint, required_type)
+}
+
+/// Names the describing type of `endpoint` for endpoint-compatibility errors,
+/// or `untyped` when it has no `DescribedBy` descriptor.
+pub(crate) fn endpoint_type_name(endpoint: &HolonReference) -> Result<String, HolonError> {
+    Ok(match described_by_descriptor(endpoint)? {
+        Some(descriptor) => {
+            HolonDescriptor::from_holon(descriptor).header().type_name()?.to_string()
+        }
+        None => "untyped".to_string(),
+    })
+}
+
 pub(crate) fn described_by_descriptor(
     holon: &HolonReference,
 ) -> Result<Option<HolonReference>, HolonError> {
@@ -154,9 +210,9 @@ pub fn classify_relationship_direction(
 /// chain using the MAP kernel's canonical [`InheritanceRule`].
 ///
 /// Additive contributions are ancestor-before-local and intentionally retain
-/// duplicate targets and their provenance. Override currently applies only to
-/// the singular `InstanceKeyRule` relationship: the nearest non-empty local
-/// target set wins.
+/// duplicate targets and their provenance. Override applies to the singular
+/// `InstanceKeyRule` and `ReferenceTargetType` relationships: the nearest
+/// non-empty local target set wins.
 pub(crate) fn effective_relationship_members(
     start: &HolonReference,
     relationship_name: CoreRelationshipTypeName,
@@ -290,6 +346,35 @@ mod tests {
         format!("{} or {}", declared_relationship_type_name(), inverse_relationship_type_name())
     }
 
+    #[test]
+    fn descendants_walks_extended_by_breadth_first_once_per_subtype() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut root = new_test_holon(&context, "root")?;
+        let mut left = new_test_holon(&context, "left")?;
+        let right = new_test_holon(&context, "right")?;
+        let leaf = new_test_holon(&context, "leaf")?;
+        root.add_related_holons(
+            CoreRelationshipTypeName::ExtendedBy,
+            vec![left.clone().into(), right.clone().into()],
+        )?;
+        // A repeated edge back to the root must not revisit it.
+        left.add_related_holons(
+            CoreRelationshipTypeName::ExtendedBy,
+            vec![leaf.clone().into(), root.clone().into()],
+        )?;
+
+        assert_eq!(
+            descendants(&HolonReference::from(&root))?,
+            vec![
+                HolonReference::from(&root),
+                HolonReference::from(&left),
+                HolonReference::from(&right),
+                HolonReference::from(&leaf),
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn ancestors_returns_self_for_root_descriptor() -> Result<(), HolonError> {
         let context = build_context();
@@ -347,6 +432,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn is_endpoint_compatible_accepts_describing_or_own_lineage() -> Result<(), HolonError> {
+        let context = build_context();
+        let required = new_test_holon(&context, "agent-type")?;
+        let mut person_type = new_test_holon(&context, "person-type")?;
+        person_type.add_related_holons(
+            CoreRelationshipTypeName::Extends,
+            vec![HolonReference::from(&required)],
+        )?;
+        let mut instance = new_test_holon(&context, "alice")?;
+        instance.add_related_holons(
+            CoreRelationshipTypeName::DescribedBy,
+            vec![HolonReference::from(&person_type)],
+        )?;
+        let undescribed = new_test_holon(&context, "bob")?;
+
+        let required_ref = HolonReference::from(&required);
+        // L(D(H)) for an ordinary instance, L(H) for a descriptor endpoint.
+        assert!(is_endpoint_compatible(&HolonReference::from(&instance), &required_ref)?);
+        assert!(is_endpoint_compatible(&HolonReference::from(&person_type), &required_ref)?);
+        assert!(!is_endpoint_compatible(&HolonReference::from(&undescribed), &required_ref)?);
+
+        Ok(())
+    }
+
     #[test]
     fn classify_relationship_direction_returns_declared_for_declared_relationship_type(
     ) -> Result<(), HolonError> {
@@ -662,13 +772,16 @@ mod tests {
             CoreRelationshipTypeName::AffordsOperator,
             CoreRelationshipTypeName::Validations,
             CoreRelationshipTypeName::Constraints,
+            CoreRelationshipTypeName::RecordFields,
         ] {
             assert_eq!(inheritance_rule(&relationship), InheritanceRule::Additive);
         }
-        assert_eq!(
-            inheritance_rule(&CoreRelationshipTypeName::InstanceKeyRule),
-            InheritanceRule::Override
-        );
+        for relationship in [
+            CoreRelationshipTypeName::InstanceKeyRule,
+            CoreRelationshipTypeName::ReferenceTargetType,
+        ] {
+            assert_eq!(inheritance_rule(&relationship), InheritanceRule::Override);
+        }
         assert_eq!(inheritance_rule(&CoreRelationshipTypeName::Variants), InheritanceRule::Local);
     }
 }
@@ -33,7 +33,7 @@ pub use effective_relationships::{effective_relationship_declaration, QualifiedR
 pub use holon_descriptor::HolonDescriptor;
 pub use holon_space_descriptor::HolonSpaceDescriptor;
 pub use inheritance::{
-    ancestors, classify_relationship_direction, walk_extends_chain, ExtendsIter,
+    ancestors, classify_relationship_direction, descendants, walk_extends_chain, ExtendsIter,
     RelationshipDirection,
 };
 pub use inverse_relationship_descriptor::InverseRelationshipDescriptor;
@@ -47,5 +47,7 @@ pub use transaction_descriptor::TransactionDescriptor;
 pub use type_header::TypeHeader;
 pub use value_descriptor::ValueDescriptor;
 pub use value_descriptor_subtypes::{
-    EnumValueDescriptor, IntegerValueDescriptor, StringValueDescriptor, ValueArrayDescriptor,
+    DateValueDescriptor, DecimalValueDescriptor, EnumValueDescriptor,
+    HolonReferenceValueDescriptor, IntegerValueDescriptor, RecordValueDescriptor,
+    StringValueDescriptor, TimestampValueDescriptor, ValueArrayDescriptor,
 };
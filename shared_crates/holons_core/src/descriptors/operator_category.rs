@@ -6,6 +6,9 @@ use core_types::HolonError;
 pub enum OperatorCategory {
     Equality,
     Ordering,
+    Membership,
+    Text,
+    Presence,
 }
 
 impl OperatorCategory {
@@ -14,6 +17,9 @@ impl OperatorCategory {
         match value.0.as_str() {
             "Equality" => Ok(Self::Equality),
             "Ordering" => Ok(Self::Ordering),
+            "Membership" => Ok(Self::Membership),
+            "Text" => Ok(Self::Text),
+            "Presence" => Ok(Self::Presence),
             _ => Err(HolonError::UnknownOperatorCategory { value: value.to_string() }),
         }
     }
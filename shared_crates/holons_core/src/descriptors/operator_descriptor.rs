@@ -270,6 +270,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn operator_category_parses_membership_text_and_presence() -> Result<(), HolonError> {
+        let context = build_context();
+        for (category, expected) in [
+            ("Membership", OperatorCategory::Membership),
+            ("Text", OperatorCategory::Text),
+            ("Presence", OperatorCategory::Presence),
+        ] {
+            let mut holon =
+                new_descriptor_holon(&context, category, "CategorizedOperator", "Holon")?;
+            holon.with_property_value(
+                CorePropertyTypeName::OperatorCategory,
+                operator_category_value(category),
+            )?;
+
+            let descriptor = OperatorDescriptor::from_holon(holon.into());
+            assert_eq!(descriptor.operator_category()?, expected);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn operator_category_errors_for_unknown_value() -> Result<(), HolonError> {
         let context = build_context();
@@ -69,7 +69,9 @@ impl PropertyDescriptor {
         Ok(())
     }
 
-    fn effective_is_value_required(&self) -> Result<bool, HolonError> {
+    /// Resolves whether a value is required, preferring `IsValueRequired` and
+    /// falling back to the legacy `IsRequired` flag and then the schema default.
+    pub(crate) fn effective_is_value_required(&self) -> Result<bool, HolonError> {
         match self.effective_property_value(CorePropertyTypeName::IsValueRequired)? {
             Some(BaseValue::BooleanValue(value)) => Ok(value.0),
             Some(other) => {
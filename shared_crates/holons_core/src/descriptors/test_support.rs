@@ -90,17 +90,26 @@ impl HolonServiceApi for TestHolonService {
 /// This mirrors the transaction-context test harness so descriptor tests can
 /// stage transient and staged holons without involving host or guest services.
 pub(crate) fn build_context() -> Arc<TransactionContext> {
+    open_transaction(&build_space_manager())
+}
+
+/// Builds the in-memory space manager behind [`build_context`], for tests that
+/// configure the space before opening a transaction in it.
+pub(crate) fn build_space_manager() -> Arc<HolonSpaceManager> {
     let holon_service: Arc<dyn HolonServiceApi> = Arc::new(TestHolonService);
-    let space_manager = Arc::new(HolonSpaceManager::new_with_managers(
+    Arc::new(HolonSpaceManager::new_with_managers(
         None,
         holon_service,
         None,
         ServiceRoutingPolicy::BlockExternal,
-    ));
+    ))
+}
 
+/// Opens a new transaction in `space_manager`.
+pub(crate) fn open_transaction(space_manager: &Arc<HolonSpaceManager>) -> Arc<TransactionContext> {
     space_manager
         .get_transaction_manager()
-        .open_new_transaction(Arc::clone(&space_manager))
+        .open_new_transaction(Arc::clone(space_manager))
         .expect("default transaction should open")
 }
 
@@ -250,3 +259,64 @@ pub(crate) fn new_declared_relationship_descriptor_holon(
     descriptor.add_related_holons(CoreRelationshipTypeName::Extends, vec![declared_type.into()])?;
     Ok(descriptor)
 }
+
+/// Creates a `ValidationRule` holon declaring its level and commit-blocking defaults.
+pub(crate) fn new_validation_rule_holon(
+    context: &Arc<TransactionContext>,
+    rule_name: &str,
+    level: &str,
+    severity: &str,
+    blocking: &str,
+) -> Result<TransientReference, HolonError> {
+    let mut rule = new_descriptor_holon(context, &format!("{rule_name}-rule"), rule_name, "Holon")?;
+    rule.with_property_value(CorePropertyTypeName::ValidationLevel, level)?
+        .with_property_value(CorePropertyTypeName::DefaultSeverity, severity)?
+        .with_property_value(CorePropertyTypeName::MinimumBlockingBehavior, blocking)?;
+    Ok(rule)
+}
+
+/// Binds `rule` to `descriptor` through a `ValidationBinding` holon, adding
+/// both the binding's outbound edges and the descriptor's inverse edge.
+pub(crate) fn bind_validation_rule(
+    context: &Arc<TransactionContext>,
+    descriptor: &mut TransientReference,
+    rule: &TransientReference,
+) -> Result<TransientReference, HolonError> {
+    let mut binding = new_test_holon(
+        context,
+        &format!("{}-binding-{}", descriptor.reference_id_string(), rule.reference_id_string()),
+    )?;
+    binding
+        .add_related_holons(CoreRelationshipTypeName::AppliesTo, vec![descriptor.clone().into()])?;
+    binding.add_related_holons(CoreRelationshipTypeName::UsesRule, vec![rule.clone().into()])?;
+    descriptor.add_related_holons(
+        CoreRelationshipTypeName::HasValidationBinding,
+        vec![binding.clone().into()],
+    )?;
+    Ok(binding)
+}
+
+/// Creates a `ValidationImplementation` holon and links `rule` to it through
+/// `ImplementedBy`.
+pub(crate) fn implement_validation_rule(
+    context: &Arc<TransactionContext>,
+    rule: &mut TransientReference,
+    engine: &str,
+    implementation_ref: &str,
+    entrypoint: Option<&str>,
+) -> Result<TransientReference, HolonError> {
+    let mut implementation =
+        new_test_holon(context, &format!("{}-implementation", rule.reference_id_string()))?;
+    implementation
+        .with_property_value(CorePropertyTypeName::ImplementationEngine, engine)?
+        .with_property_value(CorePropertyTypeName::ImplementationRef, implementation_ref)?;
+    if let Some(entrypoint) = entrypoint {
+        implementation
+            .with_property_value(CorePropertyTypeName::ImplementationEntrypoint, entrypoint)?;
+    }
+    rule.add_related_holons(
+        CoreRelationshipTypeName::ImplementedBy,
+        vec![implementation.clone().into()],
+    )?;
+    Ok(implementation)
+}
@@ -1,19 +1,23 @@
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
 use crate::descriptors::inheritance::walk_extends_chain;
 use crate::descriptors::value_descriptor_subtypes::helpers::{
+    apply_equality, base_value_kind, scalar_ordering,
     supported_operators as collect_supported_operators,
     supports_operator as descriptor_supports_operator,
     unsupported_operator as descriptor_unsupported_operator,
     value_kind_mismatch as descriptor_value_kind_mismatch,
 };
 use crate::descriptors::{
-    accessor_helpers, Descriptor, EnumValueDescriptor, IntegerValueDescriptor, OperatorDescriptor,
-    StringValueDescriptor, TypeHeader, ValueArrayDescriptor,
+    accessor_helpers, DateValueDescriptor, DecimalValueDescriptor, Descriptor, EnumValueDescriptor,
+    HolonReferenceValueDescriptor, IntegerValueDescriptor, OperatorDescriptor,
+    RecordValueDescriptor, StringValueDescriptor, TimestampValueDescriptor, TypeHeader,
+    ValueArrayDescriptor,
 };
 use crate::reference_layer::HolonReference;
 use base_types::BaseValue;
-use core_types::HolonError;
+use core_types::{HolonError, HolonId};
 use type_names::{CoreOperatorTypeName, ToOperatorName};
 
 /// Runtime wrapper for value-type descriptors.
@@ -43,17 +47,61 @@ impl ValueDescriptor {
             ValueKind::Integer => {
                 IntegerValueDescriptor::from_holon(self.holon.clone()).is_valid(value)
             }
+            ValueKind::Decimal => {
+                DecimalValueDescriptor::from_holon(self.holon.clone()).is_valid(value)
+            }
             ValueKind::String => {
                 StringValueDescriptor::from_holon(self.holon.clone()).is_valid(value)
             }
             ValueKind::Boolean => self.validate_boolean(value),
             ValueKind::Enum => EnumValueDescriptor::from_holon(self.holon.clone()).is_valid(value),
             ValueKind::Bytes => self.validate_bytes(value),
-            ValueKind::Array => Err(self.value_kind_mismatch("Array", value)),
+            ValueKind::HolonReference => {
+                HolonReferenceValueDescriptor::from_holon(self.holon.clone()).is_valid(value)
+            }
+            ValueKind::Timestamp => {
+                TimestampValueDescriptor::from_holon(self.holon.clone()).is_valid(value)
+            }
+            ValueKind::Date => DateValueDescriptor::from_holon(self.holon.clone()).is_valid(value),
+            ValueKind::Array => {
+                ValueArrayDescriptor::from_holon(self.holon.clone()).is_valid(value)
+            }
+            ValueKind::Record => {
+                RecordValueDescriptor::from_holon(self.holon.clone()).is_valid(value)
+            }
             ValueKind::Other(found) => Err(self.wrong_value_kind(found)),
         }
     }
 
+    /// Checks only that a runtime value has this descriptor's base representation.
+    ///
+    /// Unlike [`Self::is_valid`], no constraints, enum variants, elements, or
+    /// record fields are checked. Holon-reference values must still decode as a
+    /// `HolonId`, since that decoding is their representation.
+    pub fn is_valid_kind(&self, value: &BaseValue) -> Result<(), HolonError> {
+        let kind = self.value_kind()?;
+        let expected = match &kind {
+            ValueKind::Integer => "Integer",
+            ValueKind::Decimal => "Decimal",
+            ValueKind::String => "String",
+            ValueKind::Boolean => "Boolean",
+            ValueKind::Enum => "Enum",
+            ValueKind::Bytes | ValueKind::HolonReference => "Bytes",
+            ValueKind::Timestamp => "Timestamp",
+            ValueKind::Date => "Date",
+            ValueKind::Array => "Array",
+            ValueKind::Record => "Record",
+            ValueKind::Other(found) => return Err(self.wrong_value_kind(found.clone())),
+        };
+        if base_value_kind(value) != expected {
+            return Err(self.value_kind_mismatch(expected, value));
+        }
+        if kind == ValueKind::HolonReference {
+            HolonId::try_from(value)?;
+        }
+        Ok(())
+    }
+
     /// Returns operators afforded by this descriptor across its inheritance chain.
     pub fn supported_operators(&self) -> Result<Vec<OperatorDescriptor>, HolonError> {
         collect_supported_operators(&self.holon)
@@ -134,10 +182,17 @@ impl ValueDescriptor {
             return self.unsupported_operator(op);
         }
 
+        if op.operator_name()? == CoreOperatorTypeName::InOperator.as_operator_name() {
+            return self.apply_in_operator(lhs, rhs);
+        }
+
         match value_kind {
             ValueKind::Integer => {
                 IntegerValueDescriptor::from_holon(self.holon.clone()).apply_operator(op, lhs, rhs)
             }
+            ValueKind::Decimal => {
+                DecimalValueDescriptor::from_holon(self.holon.clone()).apply_operator(op, lhs, rhs)
+            }
             ValueKind::String => {
                 StringValueDescriptor::from_holon(self.holon.clone()).apply_operator(op, lhs, rhs)
             }
@@ -146,15 +201,75 @@ impl ValueDescriptor {
                 EnumValueDescriptor::from_holon(self.holon.clone()).apply_operator(op, lhs, rhs)
             }
             ValueKind::Bytes => self.apply_bytes_operator(op, lhs, rhs),
+            ValueKind::HolonReference => {
+                HolonReferenceValueDescriptor::from_holon(self.holon.clone())
+                    .apply_operator(op, lhs, rhs)
+            }
+            ValueKind::Timestamp => TimestampValueDescriptor::from_holon(self.holon.clone())
+                .apply_operator(op, lhs, rhs),
+            ValueKind::Date => {
+                DateValueDescriptor::from_holon(self.holon.clone()).apply_operator(op, lhs, rhs)
+            }
             ValueKind::Array => {
                 // Array execution is explicitly deferred; arrays may expose
                 // affordances structurally before they have runtime semantics.
                 ValueArrayDescriptor::from_holon(self.holon.clone()).apply_operator(op, lhs, rhs)
             }
+            ValueKind::Record => {
+                RecordValueDescriptor::from_holon(self.holon.clone()).apply_operator(op, lhs, rhs)
+            }
             ValueKind::Other(_) => unreachable!("Other returns before affordance checks"),
         }
     }
 
+    /// Evaluates an afforded operator over positional operands.
+    ///
+    /// The operand count must match the operator's declared `Arity`. Binary
+    /// operators route through [`Self::apply_operator`]. Of the other arities,
+    /// only `IsNullOperator` and `BetweenOperator` are executable; a present
+    /// operand is never null, and `Between` is inclusive at both ends.
+    pub fn evaluate_operator(
+        &self,
+        op: &OperatorDescriptor,
+        operands: &[BaseValue],
+    ) -> Result<bool, HolonError> {
+        let arity = op.arity()?;
+        if operands.len() != usize::from(arity) {
+            return Err(HolonError::InvalidParameter(format!(
+                "Operator {} expects {} operand(s), got {}",
+                op.operator_name()?,
+                arity,
+                operands.len()
+            )));
+        }
+
+        match operands {
+            [lhs, rhs] => self.apply_operator(op, lhs, rhs),
+            _ => {
+                if !self.supports_operator(op)? {
+                    return self.unsupported_operator(op);
+                }
+                let operator_name = op.operator_name()?;
+                if let [value] = operands {
+                    if operator_name == CoreOperatorTypeName::IsNullOperator.as_operator_name() {
+                        self.is_valid(value)?;
+                        return Ok(false);
+                    }
+                }
+                if let [value, low, high] = operands {
+                    if operator_name == CoreOperatorTypeName::BetweenOperator.as_operator_name() {
+                        return self.apply_between_operator(value, low, high);
+                    }
+                }
+                Err(HolonError::NotImplemented(format!(
+                    "Evaluation of {}-ary operator {}",
+                    arity,
+                    op.operator_name()?
+                )))
+            }
+        }
+    }
+
     fn value_kind(&self) -> Result<ValueKind, HolonError> {
         let mut first_type_name = None;
 
@@ -167,11 +282,16 @@ impl ValueDescriptor {
 
             match type_name.0.as_str() {
                 "IntegerValueType" => return Ok(ValueKind::Integer),
+                "DecimalValueType" => return Ok(ValueKind::Decimal),
                 "StringValueType" => return Ok(ValueKind::String),
                 "BooleanValueType" => return Ok(ValueKind::Boolean),
                 "EnumValueType" => return Ok(ValueKind::Enum),
                 "BytesValueType" => return Ok(ValueKind::Bytes),
+                "HolonReferenceValueType" => return Ok(ValueKind::HolonReference),
+                "TimestampValueType" => return Ok(ValueKind::Timestamp),
+                "DateValueType" => return Ok(ValueKind::Date),
                 "ValueArrayValueType" => return Ok(ValueKind::Array),
+                "RecordValueType" => return Ok(ValueKind::Record),
                 _ => {}
             }
         }
@@ -199,10 +319,6 @@ impl ValueDescriptor {
         lhs: &BaseValue,
         rhs: &BaseValue,
     ) -> Result<bool, HolonError> {
-        if op.operator_name()? != CoreOperatorTypeName::EqualsOperator.as_operator_name() {
-            return self.unsupported_operator(op);
-        }
-
         let lhs = match lhs {
             BaseValue::BooleanValue(value) => value,
             other => return Err(self.value_kind_mismatch("Boolean", other)),
@@ -211,7 +327,10 @@ impl ValueDescriptor {
             BaseValue::BooleanValue(value) => value,
             other => return Err(self.value_kind_mismatch("Boolean", other)),
         };
-        Ok(lhs == rhs)
+        match apply_equality(op, lhs == rhs)? {
+            Some(result) => Ok(result),
+            None => self.unsupported_operator(op),
+        }
     }
 
     fn apply_bytes_operator(
@@ -220,10 +339,6 @@ impl ValueDescriptor {
         lhs: &BaseValue,
         rhs: &BaseValue,
     ) -> Result<bool, HolonError> {
-        if op.operator_name()? != CoreOperatorTypeName::EqualsOperator.as_operator_name() {
-            return self.unsupported_operator(op);
-        }
-
         let lhs = match lhs {
             BaseValue::BytesValue(value) => value,
             other => return Err(self.value_kind_mismatch("Bytes", other)),
@@ -232,9 +347,62 @@ impl ValueDescriptor {
             BaseValue::BytesValue(value) => value,
             other => return Err(self.value_kind_mismatch("Bytes", other)),
         };
+
+        let operator_name = op.operator_name()?;
+        if operator_name == CoreOperatorTypeName::HasPrefixOperator.as_operator_name() {
+            return Ok(lhs.0.starts_with(&rhs.0));
+        }
+        if operator_name == CoreOperatorTypeName::IsPrefixOfOperator.as_operator_name() {
+            return Ok(rhs.0.starts_with(&lhs.0));
+        }
+        match apply_equality(op, lhs == rhs)? {
+            Some(result) => Ok(result),
+            None => self.unsupported_operator(op),
+        }
+    }
+
+    /// `lhs In rhs` holds when `rhs` is a value array with an element equal to
+    /// `lhs` under this descriptor's equality semantics.
+    fn apply_in_operator(&self, lhs: &BaseValue, rhs: &BaseValue) -> Result<bool, HolonError> {
+        let elements = match rhs {
+            BaseValue::ValueArray(elements) => elements,
+            other => return Err(self.value_kind_mismatch("Array", other)),
+        };
+
+        self.is_valid(lhs)?;
+        for element in elements.iter() {
+            if self.values_equal(lhs, element)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn apply_between_operator(
+        &self,
+        value: &BaseValue,
+        low: &BaseValue,
+        high: &BaseValue,
+    ) -> Result<bool, HolonError> {
+        self.is_valid(value)?;
+        Ok(self.ordering(value, low)?.is_ge() && self.ordering(value, high)?.is_le())
+    }
+
+    fn values_equal(&self, lhs: &BaseValue, rhs: &BaseValue) -> Result<bool, HolonError> {
+        if let Some(ordering) = scalar_ordering(lhs, rhs) {
+            return Ok(ordering.is_eq());
+        }
+        if std::mem::discriminant(lhs) != std::mem::discriminant(rhs) {
+            return Err(self.value_kind_mismatch(base_value_kind(lhs), rhs));
+        }
         Ok(lhs == rhs)
     }
 
+    fn ordering(&self, value: &BaseValue, bound: &BaseValue) -> Result<Ordering, HolonError> {
+        scalar_ordering(value, bound)
+            .ok_or_else(|| self.value_kind_mismatch(base_value_kind(value), bound))
+    }
+
     fn unsupported_operator(&self, op: &OperatorDescriptor) -> Result<bool, HolonError> {
         descriptor_unsupported_operator(&self.holon, op)
     }
@@ -245,7 +413,7 @@ impl ValueDescriptor {
 
     fn wrong_value_kind(&self, found: String) -> HolonError {
         HolonError::WrongDescriptorKind {
-            expected: "IntegerValueType, StringValueType, BooleanValueType, BytesValueType, EnumValueType, or ValueArrayValueType".to_string(),
+            expected: "IntegerValueType, DecimalValueType, StringValueType, BooleanValueType, BytesValueType, HolonReferenceValueType, EnumValueType, TimestampValueType, DateValueType, ValueArrayValueType, or RecordValueType".to_string(),
             found,
             descriptor: accessor_helpers::descriptor_label(&self.holon),
         }
@@ -255,11 +423,16 @@ impl ValueDescriptor {
 #[derive(Debug, Clone, Eq, PartialEq)]
 enum ValueKind {
     Integer,
+    Decimal,
     String,
     Boolean,
     Enum,
     Bytes,
+    HolonReference,
+    Timestamp,
+    Date,
     Array,
+    Record,
     Other(String),
 }
 
@@ -285,12 +458,18 @@ const _: fn() = || {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
     use crate::descriptors::test_support::{
         build_context, core_holon_type_name, core_value_type_name, new_descriptor_holon,
+        new_property_descriptor_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use base_types::{
+        MapBoolean, MapBytes, MapDate, MapDecimal, MapEnumValue, MapInteger, MapRecord, MapString,
+        MapTimestamp, MapValueArray,
     };
-    use crate::reference_layer::WritableHolon;
-    use base_types::{MapBoolean, MapBytes, MapEnumValue, MapInteger, MapString};
     use core_types::HolonError;
+    use std::sync::Arc;
     use type_names::{
         CoreHolonTypeName, CorePropertyTypeName, CoreRelationshipTypeName, CoreValueTypeName,
     };
@@ -400,6 +579,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn is_valid_kind_checks_representation_only() -> Result<(), HolonError> {
+        let context = build_context();
+        let enum_type = ValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "enum-value", "EnumValueType", "Value")?.into(),
+        );
+        let reference = ValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "ref-value", "HolonReferenceValueType", "Value")?.into(),
+        );
+        let undeclared = BaseValue::EnumValue(MapEnumValue(MapString("Green".to_string())));
+
+        assert!(enum_type.is_valid_kind(&undeclared).is_ok());
+        assert!(matches!(
+            enum_type.is_valid(&undeclared),
+            Err(HolonError::EnumVariantNotInSchema { .. })
+        ));
+        assert!(matches!(
+            reference.is_valid_kind(&BaseValue::BytesValue(MapBytes(vec![9, 9]))),
+            Err(HolonError::InvalidParameter(message)) if message.contains("HolonId")
+        ));
+        assert!(matches!(
+            reference.is_valid_kind(&BaseValue::StringValue(MapString("id".to_string()))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Bytes" && found == "String"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn is_valid_handles_boolean_inline() -> Result<(), HolonError> {
         let context = build_context();
@@ -434,6 +641,115 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn is_valid_routes_holon_reference_kind() -> Result<(), HolonError> {
+        let context = build_context();
+        let reference = ValueDescriptor::from_holon(
+            new_descriptor_holon(
+                &context,
+                "reference-value",
+                &core_value_type_name(CoreValueTypeName::HolonReferenceValueType),
+                "Value",
+            )?
+            .into(),
+        );
+        let holon_id = core_types::HolonId::Local(core_types::LocalId(vec![1, 2, 3]));
+
+        assert!(reference
+            .is_valid(&BaseValue::BytesValue(MapBytes(holon_id.to_canonical_bytes())))
+            .is_ok());
+        assert!(matches!(
+            reference.is_valid(&BaseValue::BytesValue(MapBytes(vec![1, 2, 3]))),
+            Err(HolonError::InvalidParameter(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_routes_record_kind_through_extends_chain() -> Result<(), HolonError> {
+        let context = build_context();
+        let family = new_descriptor_holon(
+            &context,
+            "record-family",
+            &core_value_type_name(CoreValueTypeName::RecordValueType),
+            "Value",
+        )?;
+        let city_value = new_descriptor_holon(&context, "city-value", "StringValueType", "Value")?;
+        let city = new_property_descriptor_holon(
+            &context,
+            "city-field",
+            "City",
+            "City",
+            true,
+            city_value.into(),
+        )?;
+        let mut address = new_descriptor_holon(&context, "address", "AddressValueType", "Value")?;
+        address
+            .add_related_holons(CoreRelationshipTypeName::Extends, vec![family.into()])?
+            .add_related_holons(CoreRelationshipTypeName::RecordFields, vec![city.into()])?;
+        let descriptor = ValueDescriptor::from_holon(address.into());
+        let city = |name: &str| {
+            BaseValue::Record(
+                [(MapString("City".to_string()), BaseValue::StringValue(MapString(name.into())))]
+                    .into_iter()
+                    .collect(),
+            )
+        };
+
+        assert!(descriptor.is_valid(&city("Oslo")).is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&BaseValue::Record(MapRecord::default())),
+            Err(HolonError::MissingRecordField { field, .. }) if field == "City"
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&BaseValue::StringValue(MapString("Oslo".to_string()))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Record" && found == "String"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_routes_decimal_kind() -> Result<(), HolonError> {
+        let context = build_context();
+        let decimal = ValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "decimal-value", "DecimalValueType", "Value")?.into(),
+        );
+
+        assert!(decimal.is_valid(&BaseValue::DecimalValue(MapDecimal::new(1999, 2))).is_ok());
+        assert!(matches!(
+            decimal.is_valid(&BaseValue::IntegerValue(MapInteger(19))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Decimal" && found == "Integer"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_routes_timestamp_and_date_kinds() -> Result<(), HolonError> {
+        let context = build_context();
+        let timestamp = ValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "timestamp-value", "TimestampValueType", "Value")?
+                .into(),
+        );
+        let date = ValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "date-value", "DateValueType", "Value")?.into(),
+        );
+
+        assert!(timestamp.is_valid(&BaseValue::TimestampValue(MapTimestamp::MIN)).is_ok());
+        assert!(date.is_valid(&BaseValue::DateValue(MapDate::MAX)).is_ok());
+        assert!(matches!(
+            date.is_valid(&BaseValue::StringValue(MapString("2024-03-01".to_string()))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Date" && found == "String"
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn is_valid_resolves_kind_through_extends_chain() -> Result<(), HolonError> {
         let context = build_context();
@@ -802,6 +1118,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn is_valid_routes_value_arrays_through_the_element_value_type() -> Result<(), HolonError> {
+        let context = build_context();
+        let element = new_descriptor_holon(&context, "integer-value", "IntegerValueType", "Value")?;
+        let mut array = new_descriptor_holon(&context, "scores", "ValueArrayValueType", "Value")?;
+        array
+            .add_related_holons(CoreRelationshipTypeName::ElementValueType, vec![element.into()])?;
+
+        let descriptor = ValueDescriptor::from_holon(array.into());
+        let scores = |values: Vec<BaseValue>| BaseValue::ValueArray(MapValueArray(values));
+
+        assert!(descriptor.is_valid(&scores(vec![BaseValue::IntegerValue(MapInteger(1))])).is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&scores(vec![BaseValue::BooleanValue(MapBoolean(true))])),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Integer" && found == "Boolean"
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn apply_operator_dispatches_array_to_deferred_execution() -> Result<(), HolonError> {
         let context = build_context();
@@ -827,4 +1164,176 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn evaluate_operator_checks_arity_before_dispatch() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut less_than =
+            new_descriptor_holon(&context, "less-than", "LessThanOperator", "Holon")?;
+        less_than.with_property_value(CorePropertyTypeName::Arity, 2_i64)?;
+        let mut value =
+            new_descriptor_holon(&context, "integer-value", "IntegerValueType", "Value")?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![less_than.clone().into()],
+        )?;
+
+        let descriptor = ValueDescriptor::from_holon(value.into());
+        let less_than = OperatorDescriptor::from_holon(less_than.into());
+        let two = BaseValue::IntegerValue(MapInteger(2));
+        let three = BaseValue::IntegerValue(MapInteger(3));
+
+        assert!(descriptor.evaluate_operator(&less_than, &[two.clone(), three.clone()])?);
+        assert!(!descriptor.evaluate_operator(&less_than, &[three, two.clone()])?);
+        assert!(matches!(
+            descriptor.evaluate_operator(&less_than, &[two]),
+            Err(HolonError::InvalidParameter(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_operator_rejects_unafforded_non_binary_operators() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut exists = new_descriptor_holon(&context, "exists", "ExistsOperator", "Holon")?;
+        exists.with_property_value(CorePropertyTypeName::Arity, 1_i64)?;
+        let descriptor = ValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "string-value", "StringValueType", "Value")?.into(),
+        );
+
+        assert!(matches!(
+            descriptor.evaluate_operator(
+                &OperatorDescriptor::from_holon(exists.into()),
+                &[BaseValue::StringValue(MapString("x".to_string()))],
+            ),
+            Err(HolonError::UnsupportedOperator { .. })
+        ));
+
+        Ok(())
+    }
+
+    fn afforded_operator(
+        context: &Arc<TransactionContext>,
+        value: &mut TransientReference,
+        type_name: &str,
+        arity: i64,
+    ) -> Result<OperatorDescriptor, HolonError> {
+        let mut operator = new_descriptor_holon(context, type_name, type_name, "Holon")?;
+        operator.with_property_value(CorePropertyTypeName::Arity, arity)?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![operator.clone().into()],
+        )?;
+        Ok(OperatorDescriptor::from_holon(operator.into()))
+    }
+
+    #[test]
+    fn apply_operator_evaluates_full_comparison_family() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut value =
+            new_descriptor_holon(&context, "integer-value", "IntegerValueType", "Value")?;
+        let not_equals = afforded_operator(&context, &mut value, "NotEqualsOperator", 2)?;
+        let greater_than = afforded_operator(&context, &mut value, "GreaterThanOperator", 2)?;
+        let less_or_equal = afforded_operator(&context, &mut value, "LessOrEqualOperator", 2)?;
+        let greater_or_equal =
+            afforded_operator(&context, &mut value, "GreaterOrEqualOperator", 2)?;
+        let descriptor = ValueDescriptor::from_holon(value.into());
+        let two = BaseValue::IntegerValue(MapInteger(2));
+        let three = BaseValue::IntegerValue(MapInteger(3));
+
+        assert!(descriptor.apply_operator(&not_equals, &two, &three)?);
+        assert!(!descriptor.apply_operator(&not_equals, &two, &two)?);
+        assert!(descriptor.apply_operator(&greater_than, &three, &two)?);
+        assert!(!descriptor.apply_operator(&greater_than, &two, &two)?);
+        assert!(descriptor.apply_operator(&less_or_equal, &two, &two)?);
+        assert!(!descriptor.apply_operator(&less_or_equal, &three, &two)?);
+        assert!(descriptor.apply_operator(&greater_or_equal, &two, &two)?);
+        assert!(!descriptor.apply_operator(&greater_or_equal, &two, &three)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_operator_evaluates_in_against_value_array() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut value =
+            new_descriptor_holon(&context, "decimal-value", "DecimalValueType", "Value")?;
+        let in_operator = afforded_operator(&context, &mut value, "InOperator", 2)?;
+        let descriptor = ValueDescriptor::from_holon(value.into());
+        let candidates = BaseValue::ValueArray(MapValueArray(vec![
+            BaseValue::DecimalValue(MapDecimal::new(150, 2)),
+            BaseValue::DecimalValue(MapDecimal::new(2, 0)),
+        ]));
+
+        assert!(descriptor.apply_operator(
+            &in_operator,
+            &BaseValue::DecimalValue(MapDecimal::new(15, 1)),
+            &candidates
+        )?);
+        assert!(!descriptor.apply_operator(
+            &in_operator,
+            &BaseValue::DecimalValue(MapDecimal::new(3, 0)),
+            &candidates
+        )?);
+        assert!(matches!(
+            descriptor.apply_operator(
+                &in_operator,
+                &BaseValue::DecimalValue(MapDecimal::new(2, 0)),
+                &BaseValue::DecimalValue(MapDecimal::new(2, 0)),
+            ),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Array" && found == "Decimal"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_operator_executes_between_and_is_null() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut value = new_descriptor_holon(&context, "date-value", "DateValueType", "Value")?;
+        let between = afforded_operator(&context, &mut value, "BetweenOperator", 3)?;
+        let is_null = afforded_operator(&context, &mut value, "IsNullOperator", 1)?;
+        let descriptor = ValueDescriptor::from_holon(value.into());
+        let date = |year, month, day| {
+            BaseValue::DateValue(MapDate::from_ymd(year, month, day).expect("valid test date"))
+        };
+
+        let low = date(2024, 1, 1);
+        let high = date(2024, 12, 31);
+        assert!(descriptor.evaluate_operator(&between, &[low.clone(), low.clone(), high.clone()])?);
+        assert!(descriptor.evaluate_operator(&between, &[high.clone(), low.clone(), high.clone()])?);
+        assert!(!descriptor
+            .evaluate_operator(&between, &[date(2025, 1, 1), low.clone(), high.clone()])?);
+        assert!(matches!(
+            descriptor.evaluate_operator(
+                &between,
+                &[low.clone(), BaseValue::IntegerValue(MapInteger(1)), high]
+            ),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Date" && found == "Integer"
+        ));
+        assert!(!descriptor.evaluate_operator(&is_null, &[low])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_operator_evaluates_bytes_prefix_operators() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut value = new_descriptor_holon(&context, "bytes-value", "BytesValueType", "Value")?;
+        let has_prefix = afforded_operator(&context, &mut value, "HasPrefixOperator", 2)?;
+        let is_prefix_of = afforded_operator(&context, &mut value, "IsPrefixOfOperator", 2)?;
+        let descriptor = ValueDescriptor::from_holon(value.into());
+        let whole = BaseValue::BytesValue(MapBytes(vec![1, 2, 3]));
+        let head = BaseValue::BytesValue(MapBytes(vec![1, 2]));
+
+        assert!(descriptor.apply_operator(&has_prefix, &whole, &head)?);
+        assert!(!descriptor.apply_operator(&has_prefix, &head, &whole)?);
+        assert!(descriptor.apply_operator(&is_prefix_of, &head, &whole)?);
+        assert!(!descriptor.apply_operator(&is_prefix_of, &whole, &head)?);
+
+        Ok(())
+    }
 }
@@ -1,8 +1,14 @@
-use crate::descriptors::accessor_helpers::{descriptor_label, require_bool, require_integer};
+use crate::descriptors::accessor_helpers::{
+    descriptor_label, require_bool, require_integer, require_string,
+};
 use crate::descriptors::inheritance::{effective_relationship_members, walk_extends_chain};
 use crate::descriptors::TypeHeader;
-use crate::reference_layer::HolonReference;
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use base_types::{BaseValue, MapDate, MapTimestamp};
 use core_types::{HolonError, SchemaInvalidityKind};
+use regex::{Regex, RegexBuilder};
+use std::fmt;
+use std::sync::OnceLock;
 use type_names::{CoreHolonTypeName, CorePropertyTypeName, CoreRelationshipTypeName};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -27,6 +33,71 @@ pub(crate) struct MaximumLengthConstraint {
     pub length: i64,
 }
 
+/// Upper bound, in bytes, on the compiled program of a `Pattern` constraint.
+///
+/// Matching is linear in the input for any accepted pattern; this bound keeps
+/// the per-pattern cost fixed as well, so a descriptor cannot make validation
+/// arbitrarily expensive.
+const PATTERN_SIZE_LIMIT: usize = 1 << 16;
+
+/// Requires the whole string to match a regular expression.
+///
+/// The pattern is compiled anchored at both ends when the constraint is
+/// resolved, so an invalid or oversized pattern surfaces as a schema error
+/// rather than a value error.
+#[derive(Debug, Clone)]
+pub(crate) struct PatternConstraint {
+    pub pattern: String,
+    regex: Regex,
+}
+
+impl PartialEq for PatternConstraint {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for PatternConstraint {}
+
+/// Well-known string grammars that a `*Format` constraint can require.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum StringFormat {
+    Email,
+    Uri,
+    Uuid,
+    Semver,
+    Slug,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct FormatConstraint {
+    pub format: StringFormat,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct MinimumItemsConstraint {
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct MaximumItemsConstraint {
+    pub count: i64,
+}
+
+/// Lower bound on a timestamp or date value.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct MinimumTemporalConstraint<T> {
+    pub value: T,
+    pub inclusive: bool,
+}
+
+/// Upper bound on a timestamp or date value.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct MaximumTemporalConstraint<T> {
+    pub value: T,
+    pub inclusive: bool,
+}
+
 pub(crate) trait IntegerConstraintValidation {
     /// Validates one concrete integer value against this resolved constraint.
     fn is_valid(&self, value: i64, descriptor_label: &str) -> Result<(), HolonError>;
@@ -37,24 +108,108 @@ pub(crate) trait StringConstraintValidation {
     fn is_valid(&self, value: &str, descriptor_label: &str) -> Result<(), HolonError>;
 }
 
+pub(crate) trait ValueArrayConstraintValidation {
+    /// Validates the item count of one concrete value array against this resolved constraint.
+    fn is_valid(&self, item_count: usize, descriptor_label: &str) -> Result<(), HolonError>;
+}
+
+pub(crate) trait TemporalConstraintValidation<T> {
+    /// Validates one concrete timestamp or date value against this resolved constraint.
+    fn is_valid(&self, value: T, descriptor_label: &str) -> Result<(), HolonError>;
+}
+
+/// A calendar value type that temporal range constraints can bound.
+///
+/// Each implementation owns one constraint family and the property that
+/// carries its bounds, e.g. `MapTimestamp` reads `ConstraintTimestampValue`
+/// from `TimestampValueConstraint`s.
+pub(crate) trait TemporalBound: Copy + Ord + fmt::Display {
+    const FAMILY: ConstraintFamily;
+    const BOUND_PROPERTY: CorePropertyTypeName;
+    /// Value kind label used in errors, e.g. `"Timestamp"`.
+    const KIND: &'static str;
+
+    fn from_base_value(value: &BaseValue) -> Option<Self>;
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+}
+
+impl TemporalBound for MapTimestamp {
+    const FAMILY: ConstraintFamily = ConstraintFamily::Timestamp;
+    const BOUND_PROPERTY: CorePropertyTypeName = CorePropertyTypeName::ConstraintTimestampValue;
+    const KIND: &'static str = "Timestamp";
+
+    fn from_base_value(value: &BaseValue) -> Option<Self> {
+        match value {
+            BaseValue::TimestampValue(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn successor(self) -> Option<Self> {
+        MapTimestamp::from_unix_micros(self.unix_micros().checked_add(1)?)
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        MapTimestamp::from_unix_micros(self.unix_micros().checked_sub(1)?)
+    }
+}
+
+impl TemporalBound for MapDate {
+    const FAMILY: ConstraintFamily = ConstraintFamily::Date;
+    const BOUND_PROPERTY: CorePropertyTypeName = CorePropertyTypeName::ConstraintDateValue;
+    const KIND: &'static str = "Date";
+
+    fn from_base_value(value: &BaseValue) -> Option<Self> {
+        match value {
+            BaseValue::DateValue(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn successor(self) -> Option<Self> {
+        MapDate::from_days_since_epoch(self.days_since_epoch().checked_add(1)?)
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        MapDate::from_days_since_epoch(self.days_since_epoch().checked_sub(1)?)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub(crate) enum IntegerConstraint {
     Minimum(MinimumValueConstraint),
     Maximum(MaximumValueConstraint),
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum StringConstraint {
     MinimumLength(MinimumLengthConstraint),
     MaximumLength(MaximumLengthConstraint),
+    Pattern(PatternConstraint),
+    Format(FormatConstraint),
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-enum ConstraintFamily {
+pub(crate) enum ValueArrayConstraint {
+    MinimumItems(MinimumItemsConstraint),
+    MaximumItems(MaximumItemsConstraint),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum TemporalConstraint<T> {
+    Minimum(MinimumTemporalConstraint<T>),
+    Maximum(MaximumTemporalConstraint<T>),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ConstraintFamily {
     Integer,
     String,
     Bytes,
     ValueArray,
+    Timestamp,
+    Date,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -63,6 +218,12 @@ enum ConstraintKind {
     MaximumValue,
     MinimumLength,
     MaximumLength,
+    Pattern,
+    Format(StringFormat),
+    MinimumItems,
+    MaximumItems,
+    MinimumTemporal,
+    MaximumTemporal,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -137,6 +298,159 @@ impl StringConstraintValidation for MaximumLengthConstraint {
     }
 }
 
+impl PatternConstraint {
+    pub(crate) fn new(pattern: &str) -> Result<Self, regex::Error> {
+        let regex = RegexBuilder::new(&format!("^(?:{pattern})$"))
+            .size_limit(PATTERN_SIZE_LIMIT)
+            .build()?;
+        Ok(Self { pattern: pattern.to_string(), regex })
+    }
+
+    /// Returns whether the whole of `value` matches the pattern.
+    pub(crate) fn is_match(&self, value: &str) -> bool {
+        self.regex.is_match(value)
+    }
+}
+
+impl StringConstraintValidation for PatternConstraint {
+    fn is_valid(&self, value: &str, descriptor_label: &str) -> Result<(), HolonError> {
+        if self.is_match(value) {
+            return Ok(());
+        }
+
+        Err(HolonError::StringPatternMismatch {
+            pattern: self.pattern.clone(),
+            descriptor: descriptor_label.to_string(),
+        })
+    }
+}
+
+impl StringFormat {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Email => "email address",
+            Self::Uri => "URI",
+            Self::Uuid => "UUID",
+            Self::Semver => "semantic version",
+            Self::Slug => "slug",
+        }
+    }
+
+    pub(crate) fn matches(self, value: &str) -> bool {
+        static EMAIL: OnceLock<Regex> = OnceLock::new();
+        static URI: OnceLock<Regex> = OnceLock::new();
+        static UUID: OnceLock<Regex> = OnceLock::new();
+        static SEMVER: OnceLock<Regex> = OnceLock::new();
+        static SLUG: OnceLock<Regex> = OnceLock::new();
+
+        let (cell, grammar) = match self {
+            // WHATWG HTML "valid email address": a dot-atom local part and a
+            // hostname of LDH labels. Quoted local parts are not accepted.
+            Self::Email => (
+                &EMAIL,
+                r"^[A-Za-z0-9.!#$%&'*+/=?^_`{|}~-]+@[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?)*$",
+            ),
+            // RFC 3986 absolute URI: a scheme, then only URI characters, with
+            // every `%` introducing a two-digit escape.
+            Self::Uri => (
+                &URI,
+                r"^[A-Za-z][A-Za-z0-9+.-]*:(?:[A-Za-z0-9._~!$&'()*+,;=:@/?#\[\]-]|%[0-9A-Fa-f]{2})*$",
+            ),
+            // RFC 9562 hyphenated text form, any version or variant.
+            Self::Uuid => (
+                &UUID,
+                r"^[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}$",
+            ),
+            // Semantic Versioning 2.0.0, from the grammar's reference expression.
+            Self::Semver => (
+                &SEMVER,
+                r"^(?:0|[1-9][0-9]*)\.(?:0|[1-9][0-9]*)\.(?:0|[1-9][0-9]*)(?:-(?:0|[1-9][0-9]*|[0-9]*[A-Za-z-][0-9A-Za-z-]*)(?:\.(?:0|[1-9][0-9]*|[0-9]*[A-Za-z-][0-9A-Za-z-]*))*)?(?:\+[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?$",
+            ),
+            Self::Slug => (&SLUG, r"^[a-z0-9]+(?:-[a-z0-9]+)*$"),
+        };
+
+        cell.get_or_init(|| Regex::new(grammar).expect("built-in format grammar compiles"))
+            .is_match(value)
+    }
+}
+
+impl StringConstraintValidation for FormatConstraint {
+    fn is_valid(&self, value: &str, descriptor_label: &str) -> Result<(), HolonError> {
+        if self.format.matches(value) {
+            return Ok(());
+        }
+
+        Err(HolonError::StringFormatMismatch {
+            format: self.format.name().to_string(),
+            descriptor: descriptor_label.to_string(),
+        })
+    }
+}
+
+impl ValueArrayConstraintValidation for MinimumItemsConstraint {
+    fn is_valid(&self, item_count: usize, descriptor_label: &str) -> Result<(), HolonError> {
+        if (item_count as i128) >= i128::from(self.count) {
+            return Ok(());
+        }
+
+        Err(HolonError::ItemCountOutOfRange {
+            count: item_count,
+            min: Some(self.count),
+            max: None,
+            descriptor: descriptor_label.to_string(),
+        })
+    }
+}
+
+impl ValueArrayConstraintValidation for MaximumItemsConstraint {
+    fn is_valid(&self, item_count: usize, descriptor_label: &str) -> Result<(), HolonError> {
+        if (item_count as i128) <= i128::from(self.count) {
+            return Ok(());
+        }
+
+        Err(HolonError::ItemCountOutOfRange {
+            count: item_count,
+            min: None,
+            max: Some(self.count),
+            descriptor: descriptor_label.to_string(),
+        })
+    }
+}
+
+impl<T: TemporalBound> TemporalConstraintValidation<T> for MinimumTemporalConstraint<T> {
+    fn is_valid(&self, value: T, descriptor_label: &str) -> Result<(), HolonError> {
+        if value > self.value || (self.inclusive && value == self.value) {
+            return Ok(());
+        }
+
+        Err(HolonError::TemporalOutOfRange {
+            value: value.to_string(),
+            min: Some(self.value.to_string()),
+            max: None,
+            min_inclusive: self.inclusive,
+            max_inclusive: true,
+            descriptor: descriptor_label.to_string(),
+        })
+    }
+}
+
+impl<T: TemporalBound> TemporalConstraintValidation<T> for MaximumTemporalConstraint<T> {
+    fn is_valid(&self, value: T, descriptor_label: &str) -> Result<(), HolonError> {
+        if value < self.value || (self.inclusive && value == self.value) {
+            return Ok(());
+        }
+
+        Err(HolonError::TemporalOutOfRange {
+            value: value.to_string(),
+            min: None,
+            max: Some(self.value.to_string()),
+            min_inclusive: true,
+            max_inclusive: self.inclusive,
+            descriptor: descriptor_label.to_string(),
+        })
+    }
+}
+
 impl IntegerConstraintValidation for IntegerConstraint {
     fn is_valid(&self, value: i64, descriptor_label: &str) -> Result<(), HolonError> {
         match self {
@@ -151,6 +465,26 @@ impl StringConstraintValidation for StringConstraint {
         match self {
             Self::MinimumLength(constraint) => constraint.is_valid(value, descriptor_label),
             Self::MaximumLength(constraint) => constraint.is_valid(value, descriptor_label),
+            Self::Pattern(constraint) => constraint.is_valid(value, descriptor_label),
+            Self::Format(constraint) => constraint.is_valid(value, descriptor_label),
+        }
+    }
+}
+
+impl ValueArrayConstraintValidation for ValueArrayConstraint {
+    fn is_valid(&self, item_count: usize, descriptor_label: &str) -> Result<(), HolonError> {
+        match self {
+            Self::MinimumItems(constraint) => constraint.is_valid(item_count, descriptor_label),
+            Self::MaximumItems(constraint) => constraint.is_valid(item_count, descriptor_label),
+        }
+    }
+}
+
+impl<T: TemporalBound> TemporalConstraintValidation<T> for TemporalConstraint<T> {
+    fn is_valid(&self, value: T, descriptor_label: &str) -> Result<(), HolonError> {
+        match self {
+            Self::Minimum(constraint) => constraint.is_valid(value, descriptor_label),
+            Self::Maximum(constraint) => constraint.is_valid(value, descriptor_label),
         }
     }
 }
@@ -233,6 +567,13 @@ pub(crate) fn resolve_string_constraints(
                     length: require_constraint_length(value_type, &constraint_holon)?,
                 })
             }
+            Some(ConstraintKind::Pattern) => StringConstraint::Pattern(require_constraint_pattern(
+                value_type,
+                &constraint_holon,
+            )?),
+            Some(ConstraintKind::Format(format)) => {
+                StringConstraint::Format(FormatConstraint { format })
+            }
             // Family matched String but the concrete kind is either
             // unclassified (no recognized kind anchor in the Extends chain)
             // or belongs to a different family's kind set. Both cases are
@@ -247,6 +588,84 @@ pub(crate) fn resolve_string_constraints(
     Ok(resolved_constraints)
 }
 
+/// Resolves inherited `Constraints` relationships into executable item-count constraints.
+pub(crate) fn resolve_value_array_constraints(
+    value_type: &HolonReference,
+) -> Result<Vec<ValueArrayConstraint>, HolonError> {
+    let mut resolved_constraints = Vec::new();
+
+    for constraint_holon in
+        effective_relationship_members(value_type, CoreRelationshipTypeName::Constraints)?
+    {
+        let constraint_holon = constraint_holon.member;
+        let classification = classify_constraint(&constraint_holon)?;
+        require_family(
+            value_type,
+            &constraint_holon,
+            classification.family,
+            ConstraintFamily::ValueArray,
+        )?;
+
+        let constraint = match classification.kind {
+            Some(ConstraintKind::MinimumItems) => {
+                ValueArrayConstraint::MinimumItems(MinimumItemsConstraint {
+                    count: require_constraint_item_count(value_type, &constraint_holon)?,
+                })
+            }
+            Some(ConstraintKind::MaximumItems) => {
+                ValueArrayConstraint::MaximumItems(MaximumItemsConstraint {
+                    count: require_constraint_item_count(value_type, &constraint_holon)?,
+                })
+            }
+            // Includes UniqueItems, which the schema declares but which has no
+            // runtime implementation yet.
+            _ => return Err(unsupported_constraint(value_type, &constraint_holon)),
+        };
+
+        resolved_constraints.push(constraint);
+    }
+
+    validate_value_array_coherence(value_type, &resolved_constraints)?;
+    Ok(resolved_constraints)
+}
+
+/// Resolves inherited `Constraints` relationships into executable timestamp or
+/// date range constraints, according to the family `T` belongs to.
+pub(crate) fn resolve_temporal_constraints<T: TemporalBound>(
+    value_type: &HolonReference,
+) -> Result<Vec<TemporalConstraint<T>>, HolonError> {
+    let mut resolved_constraints = Vec::new();
+
+    for constraint_holon in
+        effective_relationship_members(value_type, CoreRelationshipTypeName::Constraints)?
+    {
+        let constraint_holon = constraint_holon.member;
+        let classification = classify_constraint(&constraint_holon)?;
+        require_family(value_type, &constraint_holon, classification.family, T::FAMILY)?;
+
+        let constraint = match classification.kind {
+            Some(ConstraintKind::MinimumTemporal) => {
+                TemporalConstraint::Minimum(MinimumTemporalConstraint {
+                    value: require_constraint_temporal_value(value_type, &constraint_holon)?,
+                    inclusive: require_constraint_is_inclusive(value_type, &constraint_holon)?,
+                })
+            }
+            Some(ConstraintKind::MaximumTemporal) => {
+                TemporalConstraint::Maximum(MaximumTemporalConstraint {
+                    value: require_constraint_temporal_value(value_type, &constraint_holon)?,
+                    inclusive: require_constraint_is_inclusive(value_type, &constraint_holon)?,
+                })
+            }
+            _ => return Err(unsupported_constraint(value_type, &constraint_holon)),
+        };
+
+        resolved_constraints.push(constraint);
+    }
+
+    validate_temporal_coherence(value_type, &resolved_constraints)?;
+    Ok(resolved_constraints)
+}
+
 fn classify_constraint(
     constraint_holon: &HolonReference,
 ) -> Result<ConstraintClassification, HolonError> {
@@ -281,6 +700,10 @@ fn family_from_type_name(type_name: &str) -> Option<ConstraintFamily> {
         Some(ConstraintFamily::Bytes)
     } else if type_name == CoreHolonTypeName::ValueArrayConstraint.as_holon_name().0.as_str() {
         Some(ConstraintFamily::ValueArray)
+    } else if type_name == CoreHolonTypeName::TimestampValueConstraint.as_holon_name().0.as_str() {
+        Some(ConstraintFamily::Timestamp)
+    } else if type_name == CoreHolonTypeName::DateValueConstraint.as_holon_name().0.as_str() {
+        Some(ConstraintFamily::Date)
     } else {
         None
     }
@@ -295,6 +718,30 @@ fn kind_from_type_name(type_name: &str) -> Option<ConstraintKind> {
         Some(ConstraintKind::MinimumLength)
     } else if type_name == CoreHolonTypeName::MaximumLength.as_holon_name().0.as_str() {
         Some(ConstraintKind::MaximumLength)
+    } else if type_name == CoreHolonTypeName::Pattern.as_holon_name().0.as_str() {
+        Some(ConstraintKind::Pattern)
+    } else if type_name == CoreHolonTypeName::EmailFormat.as_holon_name().0.as_str() {
+        Some(ConstraintKind::Format(StringFormat::Email))
+    } else if type_name == CoreHolonTypeName::UriFormat.as_holon_name().0.as_str() {
+        Some(ConstraintKind::Format(StringFormat::Uri))
+    } else if type_name == CoreHolonTypeName::UuidFormat.as_holon_name().0.as_str() {
+        Some(ConstraintKind::Format(StringFormat::Uuid))
+    } else if type_name == CoreHolonTypeName::SemverFormat.as_holon_name().0.as_str() {
+        Some(ConstraintKind::Format(StringFormat::Semver))
+    } else if type_name == CoreHolonTypeName::SlugFormat.as_holon_name().0.as_str() {
+        Some(ConstraintKind::Format(StringFormat::Slug))
+    } else if type_name == CoreHolonTypeName::MinimumItems.as_holon_name().0.as_str() {
+        Some(ConstraintKind::MinimumItems)
+    } else if type_name == CoreHolonTypeName::MaximumItems.as_holon_name().0.as_str() {
+        Some(ConstraintKind::MaximumItems)
+    } else if type_name == CoreHolonTypeName::MinimumTimestamp.as_holon_name().0.as_str()
+        || type_name == CoreHolonTypeName::MinimumDate.as_holon_name().0.as_str()
+    {
+        Some(ConstraintKind::MinimumTemporal)
+    } else if type_name == CoreHolonTypeName::MaximumTimestamp.as_holon_name().0.as_str()
+        || type_name == CoreHolonTypeName::MaximumDate.as_holon_name().0.as_str()
+    {
+        Some(ConstraintKind::MaximumTemporal)
     } else {
         None
     }
@@ -353,6 +800,47 @@ fn require_constraint_length(
         .map_err(|error| map_constraint_parameter_error(value_type, constraint_holon, error))
 }
 
+fn require_constraint_pattern(
+    value_type: &HolonReference,
+    constraint_holon: &HolonReference,
+) -> Result<PatternConstraint, HolonError> {
+    let pattern = require_string(constraint_holon, CorePropertyTypeName::ConstraintPattern)
+        .map_err(|error| map_constraint_parameter_error(value_type, constraint_holon, error))?;
+    PatternConstraint::new(&pattern.0).map_err(|error| {
+        schema_invalid(
+            value_type,
+            SchemaInvalidityKind::InvalidConstraintParameter,
+            format!(
+                "Constraint {} has an invalid pattern {:?}: {error}",
+                descriptor_label(constraint_holon),
+                pattern.0
+            ),
+        )
+    })
+}
+
+fn require_constraint_item_count(
+    value_type: &HolonReference,
+    constraint_holon: &HolonReference,
+) -> Result<i64, HolonError> {
+    require_integer(constraint_holon, CorePropertyTypeName::ConstraintItemCount)
+        .map_err(|error| map_constraint_parameter_error(value_type, constraint_holon, error))
+}
+
+fn require_constraint_temporal_value<T: TemporalBound>(
+    value_type: &HolonReference,
+    constraint_holon: &HolonReference,
+) -> Result<T, HolonError> {
+    let name = T::BOUND_PROPERTY.as_property_name();
+    let bound = match constraint_holon.property_value(&name)? {
+        Some(value) => T::from_base_value(&value).ok_or_else(|| {
+            HolonError::UnexpectedValueType(format!("{:?}", value), T::KIND.to_string())
+        }),
+        None => Err(HolonError::EmptyField(name.to_string())),
+    };
+    bound.map_err(|error| map_constraint_parameter_error(value_type, constraint_holon, error))
+}
+
 fn map_constraint_parameter_error(
     value_type: &HolonReference,
     constraint_holon: &HolonReference,
@@ -413,6 +901,65 @@ fn effective_integer_maximum(constraint: &MaximumValueConstraint) -> i128 {
     i128::from(constraint.value) - if constraint.inclusive { 0 } else { 1 }
 }
 
+fn validate_temporal_coherence<T: TemporalBound>(
+    value_type: &HolonReference,
+    constraints: &[TemporalConstraint<T>],
+) -> Result<(), HolonError> {
+    // `None` marks an exclusive bound at the edge of the representable range,
+    // which no value can satisfy.
+    let mut strongest_minimum: Option<Option<T>> = None;
+    let mut strongest_maximum: Option<Option<T>> = None;
+    for constraint in constraints {
+        match constraint {
+            TemporalConstraint::Minimum(minimum) => {
+                let effective =
+                    if minimum.inclusive { Some(minimum.value) } else { minimum.value.successor() };
+                strongest_minimum = Some(match (strongest_minimum, effective) {
+                    (Some(Some(current)), Some(effective)) => Some(current.max(effective)),
+                    (None, effective) => effective,
+                    _ => None,
+                });
+            }
+            TemporalConstraint::Maximum(maximum) => {
+                let effective = if maximum.inclusive {
+                    Some(maximum.value)
+                } else {
+                    maximum.value.predecessor()
+                };
+                strongest_maximum = Some(match (strongest_maximum, effective) {
+                    (Some(Some(current)), Some(effective)) => Some(current.min(effective)),
+                    (None, effective) => effective,
+                    _ => None,
+                });
+            }
+        }
+    }
+
+    let describe = |bound: Option<T>| match bound {
+        Some(bound) => bound.to_string(),
+        None => "none".to_string(),
+    };
+    let empty = match (strongest_minimum, strongest_maximum) {
+        (Some(Some(minimum)), Some(Some(maximum))) => minimum > maximum,
+        (Some(None), _) | (_, Some(None)) => true,
+        _ => false,
+    };
+    if empty {
+        return Err(schema_invalid(
+            value_type,
+            SchemaInvalidityKind::ContradictoryConstraints,
+            format!(
+                "Effective {} interval is empty: minimum allowed value {}, maximum allowed value {}",
+                T::KIND.to_lowercase(),
+                strongest_minimum.map_or("unbounded".to_string(), describe),
+                strongest_maximum.map_or("unbounded".to_string(), describe),
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
 fn validate_string_coherence(
     value_type: &HolonReference,
     constraints: &[StringConstraint],
@@ -421,14 +968,14 @@ fn validate_string_coherence(
         .iter()
         .filter_map(|constraint| match constraint {
             StringConstraint::MinimumLength(minimum) => Some(minimum.length),
-            StringConstraint::MaximumLength(_) => None,
+            _ => None,
         })
         .max();
     let strongest_maximum = constraints
         .iter()
         .filter_map(|constraint| match constraint {
             StringConstraint::MaximumLength(maximum) => Some(maximum.length),
-            StringConstraint::MinimumLength(_) => None,
+            _ => None,
         })
         .min();
 
@@ -447,6 +994,40 @@ fn validate_string_coherence(
     Ok(())
 }
 
+fn validate_value_array_coherence(
+    value_type: &HolonReference,
+    constraints: &[ValueArrayConstraint],
+) -> Result<(), HolonError> {
+    let strongest_minimum = constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            ValueArrayConstraint::MinimumItems(minimum) => Some(minimum.count),
+            ValueArrayConstraint::MaximumItems(_) => None,
+        })
+        .max();
+    let strongest_maximum = constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            ValueArrayConstraint::MaximumItems(maximum) => Some(maximum.count),
+            ValueArrayConstraint::MinimumItems(_) => None,
+        })
+        .min();
+
+    if let (Some(minimum), Some(maximum)) = (strongest_minimum, strongest_maximum) {
+        if minimum > maximum {
+            return Err(schema_invalid(
+                value_type,
+                SchemaInvalidityKind::ContradictoryConstraints,
+                format!(
+                    "Effective item count interval is empty: minimum items {minimum}, maximum items {maximum}"
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn unsupported_constraint(
     value_type: &HolonReference,
     constraint_holon: &HolonReference,
@@ -828,6 +1409,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn value_array_resolver_reads_item_counts_and_detects_contradiction() -> Result<(), HolonError>
+    {
+        let context = build_context();
+        let mut minimum = constraint_holon_with_family(
+            &context,
+            "minimum-items",
+            CoreHolonTypeName::MinimumItems,
+            CoreHolonTypeName::ValueArrayConstraint,
+        )?;
+        minimum.with_property_value(CorePropertyTypeName::ConstraintItemCount, 3_i64)?;
+        let mut maximum = constraint_holon_with_family(
+            &context,
+            "maximum-items",
+            CoreHolonTypeName::MaximumItems,
+            CoreHolonTypeName::ValueArrayConstraint,
+        )?;
+        maximum.with_property_value(CorePropertyTypeName::ConstraintItemCount, 2_i64)?;
+
+        let mut bounded = new_descriptor_holon(&context, "bounded", "TagsValueType", "Value")?;
+        bounded.add_related_holons(
+            CoreRelationshipTypeName::Constraints,
+            vec![minimum.clone().into()],
+        )?;
+        let resolved = resolve_value_array_constraints(&bounded.into())?;
+        assert_eq!(
+            resolved,
+            vec![ValueArrayConstraint::MinimumItems(MinimumItemsConstraint { count: 3 })]
+        );
+        assert!(resolved[0].is_valid(3, "tags").is_ok());
+        assert!(matches!(
+            resolved[0].is_valid(2, "tags"),
+            Err(HolonError::ItemCountOutOfRange { count: 2, min: Some(3), max: None, .. })
+        ));
+
+        let mut contradictory =
+            new_descriptor_holon(&context, "contradictory", "TagsValueType", "Value")?;
+        contradictory.add_related_holons(
+            CoreRelationshipTypeName::Constraints,
+            vec![minimum.into(), maximum.into()],
+        )?;
+        assert_schema_invalid(
+            resolve_value_array_constraints(&contradictory.into()),
+            SchemaInvalidityKind::ContradictoryConstraints,
+        );
+        Ok(())
+    }
+
     #[test]
     fn string_constraint_validation_counts_unicode_scalar_values() -> Result<(), HolonError> {
         let minimum = StringConstraint::MinimumLength(MinimumLengthConstraint { length: 1 });
@@ -836,6 +1465,116 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn string_resolver_compiles_patterns_and_rejects_invalid_ones() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut pattern = constraint_holon_with_family(
+            &context,
+            "pattern",
+            CoreHolonTypeName::Pattern,
+            CoreHolonTypeName::StringValueConstraint,
+        )?;
+        pattern.with_property_value(CorePropertyTypeName::ConstraintPattern, "[A-Z]{3}")?;
+        let mut code = new_descriptor_holon(&context, "code", "CodeValueType", "Value")?;
+        code.add_related_holons(CoreRelationshipTypeName::Constraints, vec![pattern.into()])?;
+
+        let resolved = resolve_string_constraints(&code.into())?;
+        assert_eq!(
+            resolved,
+            vec![StringConstraint::Pattern(PatternConstraint::new("[A-Z]{3}").unwrap())]
+        );
+        assert!(resolved[0].is_valid("MAP", "code").is_ok());
+        // Patterns match the whole value, not a substring of it.
+        assert!(matches!(
+            resolved[0].is_valid("MAPS", "code"),
+            Err(HolonError::StringPatternMismatch { pattern, .. }) if pattern == "[A-Z]{3}"
+        ));
+
+        let mut unbalanced = constraint_holon_with_family(
+            &context,
+            "unbalanced",
+            CoreHolonTypeName::Pattern,
+            CoreHolonTypeName::StringValueConstraint,
+        )?;
+        unbalanced.with_property_value(CorePropertyTypeName::ConstraintPattern, "(abc")?;
+        let mut broken = new_descriptor_holon(&context, "broken", "CodeValueType", "Value")?;
+        broken
+            .add_related_holons(CoreRelationshipTypeName::Constraints, vec![unbalanced.into()])?;
+        assert_schema_invalid(
+            resolve_string_constraints(&broken.into()),
+            SchemaInvalidityKind::InvalidConstraintParameter,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn string_resolver_classifies_named_format_constraints() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut value = new_descriptor_holon(&context, "release", "ReleaseValueType", "Value")?;
+        for (key, type_name) in [
+            ("email", CoreHolonTypeName::EmailFormat),
+            ("uri", CoreHolonTypeName::UriFormat),
+            ("uuid", CoreHolonTypeName::UuidFormat),
+            ("semver", CoreHolonTypeName::SemverFormat),
+            ("slug", CoreHolonTypeName::SlugFormat),
+        ] {
+            let format = constraint_holon_with_family(
+                &context,
+                key,
+                type_name,
+                CoreHolonTypeName::StringValueConstraint,
+            )?;
+            value.add_related_holons(CoreRelationshipTypeName::Constraints, vec![format.into()])?;
+        }
+
+        let formats = resolve_string_constraints(&value.into())?
+            .into_iter()
+            .map(|constraint| match constraint {
+                StringConstraint::Format(FormatConstraint { format }) => Some(format),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            formats,
+            vec![
+                Some(StringFormat::Email),
+                Some(StringFormat::Uri),
+                Some(StringFormat::Uuid),
+                Some(StringFormat::Semver),
+                Some(StringFormat::Slug),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn string_formats_accept_canonical_examples_and_reject_near_misses() {
+        let cases = [
+            (StringFormat::Email, "ada@example.org", "ada@example..org"),
+            (StringFormat::Email, "first.last+tag@localhost", "no-at-sign.example.org"),
+            (StringFormat::Uri, "https://example.org/a?b=c#d", "example.org/path"),
+            (StringFormat::Uri, "urn:isbn:0451450523", "https://example.org/%zz"),
+            (
+                StringFormat::Uuid,
+                "123e4567-e89b-12d3-a456-426614174000",
+                "123e4567e89b12d3a456426614174000",
+            ),
+            (StringFormat::Semver, "1.0.0-alpha.1+build.5", "1.0"),
+            (StringFormat::Semver, "0.7.12", "01.2.3"),
+            (StringFormat::Slug, "map-core-schema", "Map--Core"),
+        ];
+
+        for (format, valid, invalid) in cases {
+            assert!(format.matches(valid), "{format:?} should accept {valid:?}");
+            assert!(!format.matches(invalid), "{format:?} should reject {invalid:?}");
+        }
+        assert!(matches!(
+            FormatConstraint { format: StringFormat::Slug }.is_valid("Not A Slug", "key"),
+            Err(HolonError::StringFormatMismatch { format, descriptor })
+                if format == "slug" && descriptor == "key"
+        ));
+    }
+
     #[test]
     fn missing_constraint_parameter_is_schema_invalid() -> Result<(), HolonError> {
         let context = build_context();
@@ -0,0 +1,167 @@
+use crate::descriptors::value_descriptor_subtypes::helpers::{
+    apply_comparison, require_supported_operator, supported_operators, supports_operator,
+    unsupported_operator, value_kind_mismatch,
+};
+use crate::descriptors::{Descriptor, OperatorDescriptor, TypeHeader};
+use crate::reference_layer::HolonReference;
+use base_types::BaseValue;
+use core_types::HolonError;
+
+/// Semantic wrapper for exact decimal value descriptors.
+pub struct DecimalValueDescriptor {
+    holon: HolonReference,
+}
+
+impl DecimalValueDescriptor {
+    /// Wraps an already-resolved descriptor holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Projects the shared descriptor header view for this descriptor holon.
+    pub fn header(&self) -> TypeHeader<'_> {
+        TypeHeader::new(&self.holon)
+    }
+
+    /// Validates that a runtime value is a decimal.
+    pub fn is_valid(&self, value: &BaseValue) -> Result<(), HolonError> {
+        match value {
+            BaseValue::DecimalValue(_) => Ok(()),
+            other => Err(value_kind_mismatch(&self.holon, "Decimal", other)),
+        }
+    }
+
+    /// Returns operators afforded by this value descriptor across inheritance.
+    pub fn supported_operators(&self) -> Result<Vec<OperatorDescriptor>, HolonError> {
+        supported_operators(&self.holon)
+    }
+
+    /// Returns whether this descriptor affords the supplied operator.
+    pub fn supports_operator(&self, op: &OperatorDescriptor) -> Result<bool, HolonError> {
+        supports_operator(&self.holon, op)
+    }
+
+    /// Applies an afforded decimal operator to two decimal operands.
+    ///
+    /// Comparison is numeric, so `1.5` equals `1.50` and orders below `1.51`.
+    pub fn apply_operator(
+        &self,
+        op: &OperatorDescriptor,
+        lhs: &BaseValue,
+        rhs: &BaseValue,
+    ) -> Result<bool, HolonError> {
+        require_supported_operator(&self.holon, op)?;
+
+        let lhs = match lhs {
+            BaseValue::DecimalValue(value) => value,
+            other => return Err(value_kind_mismatch(&self.holon, "Decimal", other)),
+        };
+        let rhs = match rhs {
+            BaseValue::DecimalValue(value) => value,
+            other => return Err(value_kind_mismatch(&self.holon, "Decimal", other)),
+        };
+
+        if let Some(result) = apply_comparison(op, lhs.cmp(rhs))? {
+            return Ok(result);
+        }
+        unsupported_operator(&self.holon, op)
+    }
+}
+
+impl From<HolonReference> for DecimalValueDescriptor {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+impl Descriptor for DecimalValueDescriptor {
+    fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+}
+
+#[cfg(test)]
+const _: fn() = || {
+    fn assert_impl<T: Descriptor>() {}
+    assert_impl::<DecimalValueDescriptor>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{build_context, new_descriptor_holon};
+    use crate::reference_layer::WritableHolon;
+    use base_types::{MapDecimal, MapInteger};
+    use type_names::CoreRelationshipTypeName;
+
+    fn decimal_value(text: &str) -> BaseValue {
+        BaseValue::DecimalValue(text.parse::<MapDecimal>().unwrap())
+    }
+
+    #[test]
+    fn is_valid_accepts_decimal_and_rejects_other_kinds() -> Result<(), HolonError> {
+        let context = build_context();
+        let holon = new_descriptor_holon(&context, "decimal-value", "DecimalValueType", "Value")?;
+        let descriptor = DecimalValueDescriptor::from_holon(holon.into());
+
+        assert!(descriptor.is_valid(&decimal_value("19.99")).is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&BaseValue::IntegerValue(MapInteger(19))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Decimal" && found == "Integer"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn apply_operator_compares_numerically() -> Result<(), HolonError> {
+        let context = build_context();
+        let equals = new_descriptor_holon(&context, "equals", "EqualsOperator", "Holon")?;
+        let less_than = new_descriptor_holon(&context, "less-than", "LessThanOperator", "Holon")?;
+        let mut value =
+            new_descriptor_holon(&context, "decimal-value", "DecimalValueType", "Value")?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![equals.clone().into(), less_than.clone().into()],
+        )?;
+
+        let equals = OperatorDescriptor::from_holon(equals.into());
+        let less_than = OperatorDescriptor::from_holon(less_than.into());
+        let descriptor = DecimalValueDescriptor::from_holon(value.into());
+
+        assert!(descriptor.apply_operator(
+            &equals,
+            &decimal_value("1.50"),
+            &decimal_value("1.5")
+        )?);
+        assert!(descriptor.apply_operator(
+            &less_than,
+            &decimal_value("1.5"),
+            &decimal_value("1.51")
+        )?);
+        assert!(!descriptor.apply_operator(
+            &less_than,
+            &decimal_value("-1"),
+            &decimal_value("-2")
+        )?);
+        Ok(())
+    }
+
+    #[test]
+    fn apply_operator_rejects_known_operator_when_not_afforded() -> Result<(), HolonError> {
+        let context = build_context();
+        let less_than = OperatorDescriptor::from_holon(
+            new_descriptor_holon(&context, "less-than", "LessThanOperator", "Holon")?.into(),
+        );
+        let descriptor = DecimalValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "decimal-value", "DecimalValueType", "Value")?.into(),
+        );
+
+        assert!(matches!(
+            descriptor.apply_operator(&less_than, &decimal_value("1"), &decimal_value("2")),
+            Err(HolonError::UnsupportedOperator { operator, value_type, .. })
+                if operator == "LessThanOperator" && value_type == "DecimalValueType"
+        ));
+        Ok(())
+    }
+}
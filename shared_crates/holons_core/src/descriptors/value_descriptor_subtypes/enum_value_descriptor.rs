@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use crate::descriptors::inheritance::effective_relationship_members;
 use crate::descriptors::value_descriptor_subtypes::helpers::{
-    require_supported_operator, supported_operators, supports_operator, type_name_is,
+    apply_equality, require_supported_operator, supported_operators, supports_operator,
     unsupported_operator, value_kind_mismatch, value_type_name,
 };
 use crate::descriptors::{Descriptor, OperatorDescriptor, TypeHeader};
@@ -67,16 +67,16 @@ impl EnumValueDescriptor {
     ) -> Result<bool, HolonError> {
         require_supported_operator(&self.holon, op)?;
 
-        if !type_name_is(op, "EqualsOperator")? {
-            return unsupported_operator(&self.holon, op);
-        }
-
         self.is_valid(lhs)?;
         self.is_valid(rhs)?;
 
-        match (lhs, rhs) {
-            (BaseValue::EnumValue(lhs), BaseValue::EnumValue(rhs)) => Ok(lhs == rhs),
+        let equal = match (lhs, rhs) {
+            (BaseValue::EnumValue(lhs), BaseValue::EnumValue(rhs)) => lhs == rhs,
             _ => unreachable!("is_valid guarantees enum operands"),
+        };
+        match apply_equality(op, equal)? {
+            Some(result) => Ok(result),
+            None => unsupported_operator(&self.holon, op),
         }
     }
 
@@ -3,15 +3,21 @@ use crate::descriptors::{accessor_helpers, OperatorDescriptor, TypeHeader};
 use crate::reference_layer::HolonReference;
 use base_types::BaseValue;
 use core_types::HolonError;
-use type_names::CoreRelationshipTypeName;
+use std::cmp::Ordering;
+use type_names::{CoreOperatorTypeName, CoreRelationshipTypeName};
 
-fn base_value_kind(value: &BaseValue) -> &'static str {
+pub(crate) fn base_value_kind(value: &BaseValue) -> &'static str {
     match value {
         BaseValue::StringValue(_) => "String",
         BaseValue::BooleanValue(_) => "Boolean",
         BaseValue::IntegerValue(_) => "Integer",
+        BaseValue::DecimalValue(_) => "Decimal",
         BaseValue::EnumValue(_) => "Enum",
         BaseValue::BytesValue(_) => "Bytes",
+        BaseValue::TimestampValue(_) => "Timestamp",
+        BaseValue::DateValue(_) => "Date",
+        BaseValue::ValueArray(_) => "Array",
+        BaseValue::Record(_) => "Record",
     }
 }
 
@@ -86,7 +92,62 @@ pub(crate) fn require_supported_operator(
 
 pub(crate) fn type_name_is(
     operator_descriptor: &OperatorDescriptor,
-    expected: &str,
+    expected: CoreOperatorTypeName,
 ) -> Result<bool, HolonError> {
-    Ok(operator_descriptor.operator_name()?.0 .0 == expected)
+    Ok(operator_descriptor.operator_name()? == expected.as_operator_name())
+}
+
+type OrderingTest = fn(Ordering) -> bool;
+
+/// Evaluates an equality or ordering operator from the ordering of `lhs`
+/// relative to `rhs`.
+///
+/// Returns `None` for operators outside those categories so callers can fall
+/// through to their own kind-specific operators.
+pub(crate) fn apply_comparison(
+    operator_descriptor: &OperatorDescriptor,
+    ordering: Ordering,
+) -> Result<Option<bool>, HolonError> {
+    let comparisons: [(CoreOperatorTypeName, OrderingTest); 6] = [
+        (CoreOperatorTypeName::EqualsOperator, Ordering::is_eq),
+        (CoreOperatorTypeName::NotEqualsOperator, Ordering::is_ne),
+        (CoreOperatorTypeName::LessThanOperator, Ordering::is_lt),
+        (CoreOperatorTypeName::GreaterThanOperator, Ordering::is_gt),
+        (CoreOperatorTypeName::LessOrEqualOperator, Ordering::is_le),
+        (CoreOperatorTypeName::GreaterOrEqualOperator, Ordering::is_ge),
+    ];
+    for (operator, compare) in comparisons {
+        if type_name_is(operator_descriptor, operator)? {
+            return Ok(Some(compare(ordering)));
+        }
+    }
+    Ok(None)
+}
+
+/// Evaluates `EqualsOperator` or `NotEqualsOperator` for kinds without an order.
+pub(crate) fn apply_equality(
+    operator_descriptor: &OperatorDescriptor,
+    equal: bool,
+) -> Result<Option<bool>, HolonError> {
+    if type_name_is(operator_descriptor, CoreOperatorTypeName::EqualsOperator)? {
+        return Ok(Some(equal));
+    }
+    if type_name_is(operator_descriptor, CoreOperatorTypeName::NotEqualsOperator)? {
+        return Ok(Some(!equal));
+    }
+    Ok(None)
+}
+
+/// Orders two scalar values of the same totally ordered kind.
+///
+/// Returns `None` when the kinds differ or the kind has no defined order.
+pub(crate) fn scalar_ordering(lhs: &BaseValue, rhs: &BaseValue) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (BaseValue::IntegerValue(lhs), BaseValue::IntegerValue(rhs)) => Some(lhs.0.cmp(&rhs.0)),
+        (BaseValue::DecimalValue(lhs), BaseValue::DecimalValue(rhs)) => Some(lhs.cmp(rhs)),
+        (BaseValue::StringValue(lhs), BaseValue::StringValue(rhs)) => Some(lhs.0.cmp(&rhs.0)),
+        (BaseValue::TimestampValue(lhs), BaseValue::TimestampValue(rhs)) => Some(lhs.cmp(rhs)),
+        (BaseValue::DateValue(lhs), BaseValue::DateValue(rhs)) => Some(lhs.cmp(rhs)),
+        _ => None,
+    }
 }
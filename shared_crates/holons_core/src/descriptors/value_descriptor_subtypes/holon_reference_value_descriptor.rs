@@ -0,0 +1,297 @@
+use crate::descriptors::accessor_helpers::descriptor_label;
+use crate::descriptors::inheritance::{
+    effective_relationship_members, endpoint_type_name, is_endpoint_compatible,
+};
+use crate::descriptors::value_descriptor_subtypes::helpers::{
+    apply_equality, require_supported_operator, supported_operators, supports_operator,
+    unsupported_operator, value_kind_mismatch, value_type_name,
+};
+use crate::descriptors::{Descriptor, HolonDescriptor, OperatorDescriptor, TypeHeader};
+use crate::reference_layer::HolonReference;
+use base_types::BaseValue;
+use core_types::{HolonError, HolonId};
+use type_names::CoreRelationshipTypeName;
+
+/// Semantic wrapper for holon-reference value descriptors.
+///
+/// Values are canonical `HolonId` bytes. There is no dedicated `BaseValue`
+/// variant for references, so they share `BytesValue` with plain bytes and only
+/// this descriptor tells them apart: bytes that do not decode as a `HolonId`
+/// fail `is_valid` here and `BaseValueKindMatchesHolonReference` in core
+/// validation. When the descriptor names a `ReferenceTargetType`, the
+/// referenced holon must be compatible with it under the same rule as
+/// relationship target endpoints.
+pub struct HolonReferenceValueDescriptor {
+    holon: HolonReference,
+}
+
+impl HolonReferenceValueDescriptor {
+    /// Wraps an already-resolved descriptor holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Projects the shared descriptor header view for this descriptor holon.
+    pub fn header(&self) -> TypeHeader<'_> {
+        TypeHeader::new(&self.holon)
+    }
+
+    /// Returns the effective target-type constraint, if any.
+    ///
+    /// `ReferenceTargetType` is an override relationship: the nearest
+    /// declaration along the `Extends` chain wins.
+    pub fn target_type(&self) -> Result<Option<HolonDescriptor>, HolonError> {
+        let members = effective_relationship_members(
+            &self.holon,
+            CoreRelationshipTypeName::ReferenceTargetType,
+        )?;
+        match members.as_slice() {
+            [] => Ok(None),
+            [single] => Ok(Some(HolonDescriptor::from_holon(single.member.clone()))),
+            many => Err(HolonError::MultipleRelatedHolons {
+                relationship: CoreRelationshipTypeName::ReferenceTargetType
+                    .as_relationship_name()
+                    .to_string(),
+                descriptor: descriptor_label(&self.holon),
+                count: many.len(),
+            }),
+        }
+    }
+
+    /// Validates that a runtime value decodes as a `HolonId` and, when the
+    /// descriptor is constrained, that the referenced holon has a compatible type.
+    pub fn is_valid(&self, value: &BaseValue) -> Result<(), HolonError> {
+        let holon_id = self.decode(value)?;
+
+        if self.target_type()?.is_none() {
+            return Ok(());
+        }
+
+        let target = HolonReference::smart_from_id(self.holon.context_handle(), holon_id);
+        self.validate_target(&target)
+    }
+
+    /// Checks a resolved reference target against the target-type constraint.
+    ///
+    /// Unconstrained descriptors accept any target. Otherwise the target is
+    /// accepted when its `DescribedBy` descriptor, or the target itself when it
+    /// is a descriptor, equals or extends the target type.
+    pub fn validate_target(&self, target: &HolonReference) -> Result<(), HolonError> {
+        let Some(expected) = self.target_type()? else {
+            return Ok(());
+        };
+
+        if is_endpoint_compatible(target, expected.holon())? {
+            return Ok(());
+        }
+
+        Err(HolonError::ReferenceTargetTypeMismatch {
+            expected: value_type_name(expected.holon())?,
+            found: endpoint_type_name(target)?,
+            descriptor: descriptor_label(&self.holon),
+        })
+    }
+
+    /// Returns operators afforded by this value descriptor across inheritance.
+    pub fn supported_operators(&self) -> Result<Vec<OperatorDescriptor>, HolonError> {
+        supported_operators(&self.holon)
+    }
+
+    /// Returns whether this descriptor affords the supplied operator.
+    pub fn supports_operator(&self, op: &OperatorDescriptor) -> Result<bool, HolonError> {
+        supports_operator(&self.holon, op)
+    }
+
+    /// Applies an afforded reference operator to two holon-reference operands.
+    ///
+    /// Equality compares the decoded `HolonId`s; the target-type constraint is
+    /// not re-checked because it does not affect identity.
+    pub fn apply_operator(
+        &self,
+        op: &OperatorDescriptor,
+        lhs: &BaseValue,
+        rhs: &BaseValue,
+    ) -> Result<bool, HolonError> {
+        require_supported_operator(&self.holon, op)?;
+
+        let equal = self.decode(lhs)? == self.decode(rhs)?;
+        match apply_equality(op, equal)? {
+            Some(result) => Ok(result),
+            None => unsupported_operator(&self.holon, op),
+        }
+    }
+
+    fn decode(&self, value: &BaseValue) -> Result<HolonId, HolonError> {
+        match value {
+            BaseValue::BytesValue(_) => HolonId::try_from(value),
+            other => Err(value_kind_mismatch(&self.holon, "Bytes", other)),
+        }
+    }
+}
+
+impl From<HolonReference> for HolonReferenceValueDescriptor {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+impl Descriptor for HolonReferenceValueDescriptor {
+    fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+}
+
+#[cfg(test)]
+const _: fn() = || {
+    fn assert_impl<T: Descriptor>() {}
+    assert_impl::<HolonReferenceValueDescriptor>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{
+        build_context, new_descriptor_holon, new_holon_type_descriptor, new_test_holon,
+    };
+    use crate::reference_layer::{ReadableHolon, WritableHolon};
+    use base_types::{MapBytes, MapString, ToBaseValue};
+    use core_types::{HolonNodeModel, LocalId, PropertyMap, PropertyName};
+
+    fn holon_id(byte: u8) -> HolonId {
+        HolonId::Local(LocalId(vec![byte; 4]))
+    }
+
+    #[test]
+    fn is_valid_accepts_any_holon_id_when_unconstrained() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor = HolonReferenceValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "ref", "HolonReferenceValueType", "Value")?.into(),
+        );
+
+        assert!(descriptor.target_type()?.is_none());
+        assert!(descriptor.is_valid(&holon_id(1).to_base_value()).is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&BaseValue::BytesValue(MapBytes(vec![9, 9]))),
+            Err(HolonError::InvalidParameter(message)) if message.contains("HolonId")
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&BaseValue::StringValue(MapString("id".to_string()))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Bytes" && found == "String"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn validate_target_requires_equal_or_extending_descriptor() -> Result<(), HolonError> {
+        let context = build_context();
+        let agent = new_holon_type_descriptor(&context, "agent", "AgentType")?;
+        let mut person = new_holon_type_descriptor(&context, "person", "PersonType")?;
+        person.add_related_holons(CoreRelationshipTypeName::Extends, vec![agent.clone().into()])?;
+        let place = new_holon_type_descriptor(&context, "place", "PlaceType")?;
+
+        let mut value = new_descriptor_holon(&context, "owner-ref", "OwnerReferenceType", "Value")?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::ReferenceTargetType,
+            vec![agent.clone().into()],
+        )?;
+        let descriptor = HolonReferenceValueDescriptor::from_holon(value.into());
+
+        let mut alice = new_test_holon(&context, "alice")?;
+        alice.add_related_holons(CoreRelationshipTypeName::DescribedBy, vec![person.into()])?;
+        let mut home = new_test_holon(&context, "home")?;
+        home.add_related_holons(CoreRelationshipTypeName::DescribedBy, vec![place.into()])?;
+        let untyped = new_test_holon(&context, "untyped")?;
+        let mut employee = new_holon_type_descriptor(&context, "employee", "EmployeeType")?;
+        employee.add_related_holons(CoreRelationshipTypeName::Extends, vec![agent.into()])?;
+
+        assert!(descriptor.validate_target(&alice.into()).is_ok());
+        assert!(descriptor.validate_target(&employee.into()).is_ok());
+        assert!(matches!(
+            descriptor.validate_target(&home.into()),
+            Err(HolonError::ReferenceTargetTypeMismatch { expected, found, .. })
+                if expected == "AgentType" && found == "PlaceType"
+        ));
+        assert!(matches!(
+            descriptor.validate_target(&untyped.into()),
+            Err(HolonError::ReferenceTargetTypeMismatch { found, .. }) if found == "untyped"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn target_type_is_inherited_and_overridden_by_nearest_declaration() -> Result<(), HolonError> {
+        let context = build_context();
+        let agent = new_holon_type_descriptor(&context, "agent", "AgentType")?;
+        let person = new_holon_type_descriptor(&context, "person", "PersonType")?;
+        let mut parent = new_descriptor_holon(&context, "agent-ref", "AgentRefType", "Value")?;
+        parent.add_related_holons(
+            CoreRelationshipTypeName::ReferenceTargetType,
+            vec![agent.clone().into()],
+        )?;
+        let mut inherits = new_descriptor_holon(&context, "inherits", "InheritsRefType", "Value")?;
+        inherits
+            .add_related_holons(CoreRelationshipTypeName::Extends, vec![parent.clone().into()])?;
+        let mut narrows = new_descriptor_holon(&context, "narrows", "PersonRefType", "Value")?;
+        narrows.add_related_holons(CoreRelationshipTypeName::Extends, vec![parent.into()])?;
+        narrows.add_related_holons(
+            CoreRelationshipTypeName::ReferenceTargetType,
+            vec![person.clone().into()],
+        )?;
+
+        let inherited = HolonReferenceValueDescriptor::from_holon(inherits.into()).target_type()?;
+        let narrowed = HolonReferenceValueDescriptor::from_holon(narrows.into()).target_type()?;
+
+        assert_eq!(inherited.map(|d| d.holon().clone()), Some(HolonReference::from(&agent)));
+        assert_eq!(narrowed.map(|d| d.holon().clone()), Some(HolonReference::from(&person)));
+        Ok(())
+    }
+
+    #[test]
+    fn apply_operator_compares_decoded_holon_ids() -> Result<(), HolonError> {
+        let context = build_context();
+        let equals = new_descriptor_holon(&context, "equals", "EqualsOperator", "Holon")?;
+        let less_than = OperatorDescriptor::from_holon(
+            new_descriptor_holon(&context, "less-than", "LessThanOperator", "Holon")?.into(),
+        );
+        let mut value = new_descriptor_holon(&context, "ref", "HolonReferenceValueType", "Value")?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![equals.clone().into()],
+        )?;
+
+        let equals = OperatorDescriptor::from_holon(equals.into());
+        let descriptor = HolonReferenceValueDescriptor::from_holon(value.into());
+        let first = holon_id(1).to_base_value();
+        let second = holon_id(2).to_base_value();
+
+        assert!(descriptor.apply_operator(&equals, &first, &first)?);
+        assert!(!descriptor.apply_operator(&equals, &first, &second)?);
+        assert!(matches!(
+            descriptor.apply_operator(&less_than, &first, &second),
+            Err(HolonError::UnsupportedOperator { operator, .. }) if operator == "LessThanOperator"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn holon_reference_values_round_trip_through_node_model() -> Result<(), HolonError> {
+        let context = build_context();
+        let target = holon_id(7);
+        let mut holon = new_test_holon(&context, "with-owner")?;
+        holon.with_property_value("Owner", &target)?;
+
+        let mut property_map = PropertyMap::new();
+        let owner = PropertyName(MapString("Owner".to_string()));
+        property_map.insert(owner.clone(), holon.property_value("Owner")?.expect("Owner is set"));
+        let model = HolonNodeModel::new(property_map);
+
+        let encoded = serde_json::to_string(&model).expect("node model should serialize");
+        let decoded: HolonNodeModel =
+            serde_json::from_str(&encoded).expect("node model should deserialize");
+
+        assert_eq!(decoded, model);
+        assert_eq!(HolonId::try_from(&decoded.property_map[&owner])?, target);
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use crate::descriptors::value_descriptor_subtypes::constraints::{
     resolve_integer_constraints, IntegerConstraintValidation,
 };
 use crate::descriptors::value_descriptor_subtypes::helpers::{
-    require_supported_operator, supported_operators, supports_operator, type_name_is,
+    apply_comparison, require_supported_operator, supported_operators, supports_operator,
     unsupported_operator, value_kind_mismatch,
 };
 use crate::descriptors::{Descriptor, OperatorDescriptor, TypeHeader};
@@ -73,11 +73,8 @@ impl IntegerValueDescriptor {
             other => return Err(value_kind_mismatch(&self.holon, "Integer", other)),
         };
 
-        if type_name_is(op, "EqualsOperator")? {
-            return Ok(lhs == rhs);
-        }
-        if type_name_is(op, "LessThanOperator")? {
-            return Ok(lhs.0 < rhs.0);
+        if let Some(result) = apply_comparison(op, lhs.0.cmp(&rhs.0))? {
+            return Ok(result);
         }
         unsupported_operator(&self.holon, op)
     }
@@ -1,12 +1,20 @@
 pub(crate) mod constraints;
 pub(crate) mod helpers;
 
+mod decimal_value_descriptor;
 mod enum_value_descriptor;
+mod holon_reference_value_descriptor;
 mod integer_value_descriptor;
+mod record_value_descriptor;
 mod string_value_descriptor;
+mod temporal_value_descriptor;
 mod value_array_descriptor;
 
+pub use decimal_value_descriptor::DecimalValueDescriptor;
 pub use enum_value_descriptor::EnumValueDescriptor;
+pub use holon_reference_value_descriptor::HolonReferenceValueDescriptor;
 pub use integer_value_descriptor::IntegerValueDescriptor;
+pub use record_value_descriptor::RecordValueDescriptor;
 pub use string_value_descriptor::StringValueDescriptor;
+pub use temporal_value_descriptor::{DateValueDescriptor, TimestampValueDescriptor};
 pub use value_array_descriptor::ValueArrayDescriptor;
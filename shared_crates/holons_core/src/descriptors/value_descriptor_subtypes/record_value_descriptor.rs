@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+
+use crate::descriptors::accessor_helpers::descriptor_label;
+use crate::descriptors::inheritance::effective_relationship_members;
+use crate::descriptors::value_descriptor_subtypes::helpers::{
+    apply_equality, require_supported_operator, supported_operators, supports_operator,
+    unsupported_operator, value_kind_mismatch,
+};
+use crate::descriptors::{Descriptor, OperatorDescriptor, PropertyDescriptor, TypeHeader};
+use crate::reference_layer::HolonReference;
+use base_types::{BaseValue, MapRecord, MapString};
+use core_types::HolonError;
+use type_names::CoreRelationshipTypeName;
+
+/// Semantic wrapper for record value descriptors.
+///
+/// A record's fields are declared through `RecordFields` as property
+/// descriptors, inherited additively along the `Extends` chain. Each field is
+/// named by its descriptor's type name and validated against its `ValueType`.
+pub struct RecordValueDescriptor {
+    holon: HolonReference,
+}
+
+impl RecordValueDescriptor {
+    /// Wraps an already-resolved descriptor holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Projects the shared descriptor header view for this descriptor holon.
+    pub fn header(&self) -> TypeHeader<'_> {
+        TypeHeader::new(&self.holon)
+    }
+
+    /// Returns the effective field declarations keyed by field name.
+    ///
+    /// Two declarations with the same name anywhere along the inheritance chain
+    /// are reported as a duplicate rather than silently shadowed.
+    pub fn fields(&self) -> Result<BTreeMap<MapString, PropertyDescriptor>, HolonError> {
+        let mut fields = BTreeMap::new();
+        for member in
+            effective_relationship_members(&self.holon, CoreRelationshipTypeName::RecordFields)?
+        {
+            let field = PropertyDescriptor::from_holon(member.member);
+            let name = field.header().type_name()?;
+            if fields.contains_key(&name) {
+                return Err(HolonError::DuplicateInheritedDeclaration {
+                    kind: "record field".to_string(),
+                    name: name.to_string(),
+                    descriptor: descriptor_label(&self.holon),
+                });
+            }
+            fields.insert(name, field);
+        }
+        Ok(fields)
+    }
+
+    /// Validates that a runtime value is a record whose fields are all declared,
+    /// whose required fields are present, and whose field values are valid for
+    /// their declared value types.
+    pub fn is_valid(&self, value: &BaseValue) -> Result<(), HolonError> {
+        let record = self.record(value)?;
+        let fields = self.fields()?;
+
+        if let Some(undeclared) = record.0.keys().find(|name| !fields.contains_key(*name)) {
+            return Err(HolonError::DescriptorDeclarationNotFound {
+                kind: "record field".to_string(),
+                name: undeclared.to_string(),
+                descriptor: descriptor_label(&self.holon),
+            });
+        }
+
+        for (name, field) in &fields {
+            match record.0.get(name) {
+                Some(field_value) => field.value_type()?.is_valid(field_value)?,
+                None if field.effective_is_value_required()? => {
+                    return Err(HolonError::MissingRecordField {
+                        field: name.to_string(),
+                        descriptor: descriptor_label(&self.holon),
+                    });
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns operators afforded by this value descriptor across inheritance.
+    pub fn supported_operators(&self) -> Result<Vec<OperatorDescriptor>, HolonError> {
+        supported_operators(&self.holon)
+    }
+
+    /// Returns whether this descriptor affords the supplied operator.
+    pub fn supports_operator(&self, op: &OperatorDescriptor) -> Result<bool, HolonError> {
+        supports_operator(&self.holon, op)
+    }
+
+    /// Applies an afforded record operator to two record operands.
+    ///
+    /// Records are equal when they hold the same fields with equal values.
+    pub fn apply_operator(
+        &self,
+        op: &OperatorDescriptor,
+        lhs: &BaseValue,
+        rhs: &BaseValue,
+    ) -> Result<bool, HolonError> {
+        require_supported_operator(&self.holon, op)?;
+
+        let equal = self.record(lhs)? == self.record(rhs)?;
+        match apply_equality(op, equal)? {
+            Some(result) => Ok(result),
+            None => unsupported_operator(&self.holon, op),
+        }
+    }
+
+    fn record<'a>(&self, value: &'a BaseValue) -> Result<&'a MapRecord, HolonError> {
+        match value {
+            BaseValue::Record(record) => Ok(record),
+            other => Err(value_kind_mismatch(&self.holon, "Record", other)),
+        }
+    }
+}
+
+impl From<HolonReference> for RecordValueDescriptor {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+impl Descriptor for RecordValueDescriptor {
+    fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+}
+
+#[cfg(test)]
+const _: fn() = || {
+    fn assert_impl<T: Descriptor>() {}
+    assert_impl::<RecordValueDescriptor>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        build_context, new_descriptor_holon, new_property_descriptor_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use base_types::{MapDecimal, MapInteger};
+    use std::sync::Arc;
+
+    fn field(
+        context: &Arc<TransactionContext>,
+        name: &str,
+        is_required: bool,
+        value_type: &str,
+    ) -> Result<TransientReference, HolonError> {
+        let value_type =
+            new_descriptor_holon(context, &format!("{name}-value"), value_type, "Value")?;
+        new_property_descriptor_holon(
+            context,
+            &format!("{name}-field"),
+            name,
+            name,
+            is_required,
+            value_type.into(),
+        )
+    }
+
+    fn money_type(context: &Arc<TransactionContext>) -> Result<TransientReference, HolonError> {
+        let mut money = new_descriptor_holon(context, "money", "MoneyValueType", "Value")?;
+        money.add_related_holons(
+            CoreRelationshipTypeName::RecordFields,
+            vec![
+                field(context, "Amount", true, "DecimalValueType")?.into(),
+                field(context, "Currency", true, "StringValueType")?.into(),
+                field(context, "Note", false, "StringValueType")?.into(),
+            ],
+        )?;
+        Ok(money)
+    }
+
+    fn record(fields: Vec<(&str, BaseValue)>) -> BaseValue {
+        BaseValue::Record(
+            fields.into_iter().map(|(name, value)| (MapString(name.to_string()), value)).collect(),
+        )
+    }
+
+    fn string(value: &str) -> BaseValue {
+        BaseValue::StringValue(MapString(value.to_string()))
+    }
+
+    fn amount(value: &str) -> BaseValue {
+        BaseValue::DecimalValue(value.parse::<MapDecimal>().expect("valid test decimal"))
+    }
+
+    #[test]
+    fn fields_are_inherited_additively_and_reject_duplicates() -> Result<(), HolonError> {
+        let context = build_context();
+        let money = money_type(&context)?;
+        let mut priced = new_descriptor_holon(&context, "priced", "PriceValueType", "Value")?;
+        priced.add_related_holons(CoreRelationshipTypeName::Extends, vec![money.clone().into()])?;
+        priced.add_related_holons(
+            CoreRelationshipTypeName::RecordFields,
+            vec![field(&context, "Quantity", false, "IntegerValueType")?.into()],
+        )?;
+
+        let names: Vec<String> = RecordValueDescriptor::from_holon(priced.clone().into())
+            .fields()?
+            .into_keys()
+            .map(|name| name.to_string())
+            .collect();
+        assert_eq!(names, vec!["Amount", "Currency", "Note", "Quantity"]);
+
+        priced.add_related_holons(
+            CoreRelationshipTypeName::RecordFields,
+            vec![field(&context, "Currency", false, "StringValueType")?.into()],
+        )?;
+        assert!(matches!(
+            RecordValueDescriptor::from_holon(priced.into()).fields(),
+            Err(HolonError::DuplicateInheritedDeclaration { kind, name, .. })
+                if kind == "record field" && name == "Currency"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_checks_declared_required_and_field_values() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor = RecordValueDescriptor::from_holon(money_type(&context)?.into());
+
+        assert!(descriptor
+            .is_valid(&record(vec![("Amount", amount("9.50")), ("Currency", string("EUR"))]))
+            .is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&record(vec![("Amount", amount("9.50"))])),
+            Err(HolonError::MissingRecordField { field, .. }) if field == "Currency"
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&record(vec![
+                ("Amount", amount("9.50")),
+                ("Currency", string("EUR")),
+                ("Rate", amount("1.1")),
+            ])),
+            Err(HolonError::DescriptorDeclarationNotFound { kind, name, .. })
+                if kind == "record field" && name == "Rate"
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&record(vec![
+                ("Amount", BaseValue::IntegerValue(MapInteger(9))),
+                ("Currency", string("EUR")),
+            ])),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Decimal" && found == "Integer"
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&string("9.50 EUR")),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Record" && found == "String"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn apply_operator_compares_whole_records() -> Result<(), HolonError> {
+        let context = build_context();
+        let equals = new_descriptor_holon(&context, "equals", "EqualsOperator", "Holon")?;
+        let mut money = money_type(&context)?;
+        money.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![equals.clone().into()],
+        )?;
+
+        let equals = OperatorDescriptor::from_holon(equals.into());
+        let descriptor = RecordValueDescriptor::from_holon(money.into());
+        let euros = record(vec![("Amount", amount("9.5")), ("Currency", string("EUR"))]);
+        let same = record(vec![("Currency", string("EUR")), ("Amount", amount("9.50"))]);
+        let dollars = record(vec![("Amount", amount("9.5")), ("Currency", string("USD"))]);
+
+        assert!(descriptor.apply_operator(&equals, &euros, &same)?);
+        assert!(!descriptor.apply_operator(&equals, &euros, &dollars)?);
+        Ok(())
+    }
+}
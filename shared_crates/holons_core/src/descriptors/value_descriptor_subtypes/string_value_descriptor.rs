@@ -1,15 +1,16 @@
 use crate::descriptors::accessor_helpers::descriptor_label;
 use crate::descriptors::value_descriptor_subtypes::constraints::{
-    resolve_string_constraints, StringConstraintValidation,
+    resolve_string_constraints, PatternConstraint, StringConstraintValidation,
 };
 use crate::descriptors::value_descriptor_subtypes::helpers::{
-    require_supported_operator, supported_operators, supports_operator, type_name_is,
-    unsupported_operator, value_kind_mismatch,
+    apply_comparison, require_supported_operator, supported_operators, supports_operator,
+    type_name_is, unsupported_operator, value_kind_mismatch,
 };
 use crate::descriptors::{Descriptor, OperatorDescriptor, TypeHeader};
 use crate::reference_layer::HolonReference;
 use base_types::BaseValue;
 use core_types::HolonError;
+use type_names::CoreOperatorTypeName;
 
 /// Semantic wrapper for string value descriptors.
 pub struct StringValueDescriptor {
@@ -54,6 +55,9 @@ impl StringValueDescriptor {
 
     /// Applies an afforded string operator to two string operands.
     ///
+    /// `MatchesOperator` treats `rhs` as a regular expression that must match
+    /// the whole of `lhs`, the same anchoring used by pattern constraints.
+    ///
     /// Operators must be declared through this descriptor's `AffordsOperator`
     /// relationships; otherwise execution returns `UnsupportedOperator`.
     pub fn apply_operator(
@@ -73,11 +77,23 @@ impl StringValueDescriptor {
             other => return Err(value_kind_mismatch(&self.holon, "String", other)),
         };
 
-        if type_name_is(op, "EqualsOperator")? {
-            return Ok(lhs == rhs);
+        if let Some(result) = apply_comparison(op, lhs.0.cmp(&rhs.0))? {
+            return Ok(result);
+        }
+        if type_name_is(op, CoreOperatorTypeName::StartsWithOperator)? {
+            return Ok(lhs.0.starts_with(rhs.0.as_str()));
         }
-        if type_name_is(op, "LessThanOperator")? {
-            return Ok(lhs.0 < rhs.0);
+        if type_name_is(op, CoreOperatorTypeName::ContainsOperator)? {
+            return Ok(lhs.0.contains(rhs.0.as_str()));
+        }
+        if type_name_is(op, CoreOperatorTypeName::MatchesOperator)? {
+            let pattern = PatternConstraint::new(&rhs.0).map_err(|error| {
+                HolonError::InvalidParameter(format!(
+                    "Invalid MatchesOperator pattern {}: {error}",
+                    rhs.0
+                ))
+            })?;
+            return Ok(pattern.is_match(&lhs.0));
         }
         unsupported_operator(&self.holon, op)
     }
@@ -372,6 +388,70 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn is_valid_keeps_inherited_pattern_and_format_constraints() -> Result<(), HolonError> {
+        let context = build_context();
+        let family = new_descriptor_holon(
+            &context,
+            "string-constraint-family",
+            &core_holon_type_name(CoreHolonTypeName::StringValueConstraint),
+            "Holon",
+        )?;
+        let mut slug = new_descriptor_holon(
+            &context,
+            "slug",
+            &core_holon_type_name(CoreHolonTypeName::SlugFormat),
+            "Holon",
+        )?;
+        add_extends(&mut slug, &family)?;
+        // The subtype's own pattern admits any text; it must not relax the
+        // inherited slug format, so both apply.
+        let mut anything = new_descriptor_holon(
+            &context,
+            "anything",
+            &core_holon_type_name(CoreHolonTypeName::Pattern),
+            "Holon",
+        )?;
+        anything.with_property_value(CorePropertyTypeName::ConstraintPattern, ".*")?;
+        add_extends(&mut anything, &family)?;
+        let mut prefixed = new_descriptor_holon(
+            &context,
+            "prefixed",
+            &core_holon_type_name(CoreHolonTypeName::Pattern),
+            "Holon",
+        )?;
+        prefixed.with_property_value(CorePropertyTypeName::ConstraintPattern, "map-.*")?;
+        add_extends(&mut prefixed, &family)?;
+
+        let mut parent = new_descriptor_holon(
+            &context,
+            "parent-value",
+            &core_value_type_name(CoreValueTypeName::StringValueType),
+            "Value",
+        )?;
+        parent.add_related_holons(CoreRelationshipTypeName::Constraints, vec![slug.into()])?;
+        let mut child =
+            new_descriptor_holon(&context, "child-value", "ConstrainedStringValueType", "Value")?;
+        add_extends(&mut child, &parent)?;
+        child.add_related_holons(
+            CoreRelationshipTypeName::Constraints,
+            vec![anything.into(), prefixed.into()],
+        )?;
+
+        let descriptor = StringValueDescriptor::from_holon(child.into());
+
+        assert!(descriptor.is_valid(&string_value("map-core")).is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&string_value("Map Core")),
+            Err(HolonError::StringFormatMismatch { format, .. }) if format == "slug"
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&string_value("core-map")),
+            Err(HolonError::StringPatternMismatch { pattern, .. }) if pattern == "map-.*"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn supported_operators_and_supports_operator_use_affordances() -> Result<(), HolonError> {
         let context = build_context();
@@ -420,6 +500,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn apply_operator_executes_text_operators() -> Result<(), HolonError> {
+        let context = build_context();
+        let starts_with =
+            new_descriptor_holon(&context, "starts-with", "StartsWithOperator", "Holon")?;
+        let contains = new_descriptor_holon(&context, "contains", "ContainsOperator", "Holon")?;
+        let matches = new_descriptor_holon(&context, "matches", "MatchesOperator", "Holon")?;
+        let mut value = new_descriptor_holon(&context, "string-value", "StringValueType", "Value")?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![starts_with.clone().into(), contains.clone().into(), matches.clone().into()],
+        )?;
+
+        let starts_with = OperatorDescriptor::from_holon(starts_with.into());
+        let contains = OperatorDescriptor::from_holon(contains.into());
+        let matches = OperatorDescriptor::from_holon(matches.into());
+        let descriptor = StringValueDescriptor::from_holon(value.into());
+        let holon = string_value("holon-space");
+
+        assert!(descriptor.apply_operator(&starts_with, &holon, &string_value("holon"))?);
+        assert!(!descriptor.apply_operator(&starts_with, &holon, &string_value("space"))?);
+        assert!(descriptor.apply_operator(&contains, &holon, &string_value("n-s"))?);
+        assert!(!descriptor.apply_operator(&contains, &holon, &string_value("map"))?);
+        assert!(descriptor.apply_operator(&matches, &holon, &string_value("[a-z]+-[a-z]+"))?);
+        assert!(!descriptor.apply_operator(&matches, &holon, &string_value("[a-z]+"))?);
+        assert!(matches!(
+            descriptor.apply_operator(&matches, &holon, &string_value("(")),
+            Err(HolonError::InvalidParameter(message)) if message.contains("MatchesOperator")
+        ));
+        Ok(())
+    }
+
     #[test]
     fn apply_operator_reports_kind_mismatch_and_unsupported_operator() -> Result<(), HolonError> {
         let context = build_context();
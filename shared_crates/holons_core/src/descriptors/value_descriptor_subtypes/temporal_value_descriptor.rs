@@ -0,0 +1,395 @@
+use crate::descriptors::accessor_helpers::descriptor_label;
+use crate::descriptors::value_descriptor_subtypes::constraints::{
+    resolve_temporal_constraints, TemporalBound, TemporalConstraintValidation,
+};
+use crate::descriptors::value_descriptor_subtypes::helpers::{
+    apply_comparison, require_supported_operator, supported_operators, supports_operator,
+    unsupported_operator, value_kind_mismatch,
+};
+use crate::descriptors::{Descriptor, OperatorDescriptor, TypeHeader};
+use crate::reference_layer::HolonReference;
+use base_types::{BaseValue, MapDate, MapTimestamp};
+use core_types::HolonError;
+
+/// Semantic wrapper for timestamp value descriptors.
+pub struct TimestampValueDescriptor {
+    holon: HolonReference,
+}
+
+/// Semantic wrapper for date value descriptors.
+pub struct DateValueDescriptor {
+    holon: HolonReference,
+}
+
+impl TimestampValueDescriptor {
+    /// Wraps an already-resolved descriptor holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Projects the shared descriptor header view for this descriptor holon.
+    pub fn header(&self) -> TypeHeader<'_> {
+        TypeHeader::new(&self.holon)
+    }
+
+    /// Validates that a runtime value is a timestamp within the descriptor's range constraints.
+    pub fn is_valid(&self, value: &BaseValue) -> Result<(), HolonError> {
+        validate::<MapTimestamp>(&self.holon, value)
+    }
+
+    /// Returns operators afforded by this value descriptor across inheritance.
+    pub fn supported_operators(&self) -> Result<Vec<OperatorDescriptor>, HolonError> {
+        supported_operators(&self.holon)
+    }
+
+    /// Returns whether this descriptor affords the supplied operator.
+    pub fn supports_operator(&self, op: &OperatorDescriptor) -> Result<bool, HolonError> {
+        supports_operator(&self.holon, op)
+    }
+
+    /// Applies an afforded operator to two timestamps; `LessThan` means earlier.
+    pub fn apply_operator(
+        &self,
+        op: &OperatorDescriptor,
+        lhs: &BaseValue,
+        rhs: &BaseValue,
+    ) -> Result<bool, HolonError> {
+        apply::<MapTimestamp>(&self.holon, op, lhs, rhs)
+    }
+}
+
+impl DateValueDescriptor {
+    /// Wraps an already-resolved descriptor holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Projects the shared descriptor header view for this descriptor holon.
+    pub fn header(&self) -> TypeHeader<'_> {
+        TypeHeader::new(&self.holon)
+    }
+
+    /// Validates that a runtime value is a date within the descriptor's range constraints.
+    pub fn is_valid(&self, value: &BaseValue) -> Result<(), HolonError> {
+        validate::<MapDate>(&self.holon, value)
+    }
+
+    /// Returns operators afforded by this value descriptor across inheritance.
+    pub fn supported_operators(&self) -> Result<Vec<OperatorDescriptor>, HolonError> {
+        supported_operators(&self.holon)
+    }
+
+    /// Returns whether this descriptor affords the supplied operator.
+    pub fn supports_operator(&self, op: &OperatorDescriptor) -> Result<bool, HolonError> {
+        supports_operator(&self.holon, op)
+    }
+
+    /// Applies an afforded operator to two dates; `LessThan` means earlier.
+    pub fn apply_operator(
+        &self,
+        op: &OperatorDescriptor,
+        lhs: &BaseValue,
+        rhs: &BaseValue,
+    ) -> Result<bool, HolonError> {
+        apply::<MapDate>(&self.holon, op, lhs, rhs)
+    }
+}
+
+fn require_kind<T: TemporalBound>(
+    holon: &HolonReference,
+    value: &BaseValue,
+) -> Result<T, HolonError> {
+    T::from_base_value(value).ok_or_else(|| value_kind_mismatch(holon, T::KIND, value))
+}
+
+fn validate<T: TemporalBound>(holon: &HolonReference, value: &BaseValue) -> Result<(), HolonError> {
+    let value = require_kind::<T>(holon, value)?;
+
+    let label = descriptor_label(holon);
+    for constraint in resolve_temporal_constraints::<T>(holon)? {
+        constraint.is_valid(value, &label)?;
+    }
+
+    Ok(())
+}
+
+fn apply<T: TemporalBound>(
+    holon: &HolonReference,
+    op: &OperatorDescriptor,
+    lhs: &BaseValue,
+    rhs: &BaseValue,
+) -> Result<bool, HolonError> {
+    require_supported_operator(holon, op)?;
+
+    let lhs = require_kind::<T>(holon, lhs)?;
+    let rhs = require_kind::<T>(holon, rhs)?;
+
+    if let Some(result) = apply_comparison(op, lhs.cmp(&rhs))? {
+        return Ok(result);
+    }
+    unsupported_operator(holon, op)
+}
+
+impl From<HolonReference> for TimestampValueDescriptor {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+impl From<HolonReference> for DateValueDescriptor {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+impl Descriptor for TimestampValueDescriptor {
+    fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+}
+
+impl Descriptor for DateValueDescriptor {
+    fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+}
+
+#[cfg(test)]
+const _: fn() = || {
+    fn assert_impl<T: Descriptor>() {}
+    assert_impl::<TimestampValueDescriptor>();
+    assert_impl::<DateValueDescriptor>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        build_context, core_holon_type_name, core_value_type_name, new_descriptor_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use base_types::MapString;
+    use core_types::SchemaInvalidityKind;
+    use std::sync::Arc;
+    use type_names::{
+        CoreHolonTypeName, CorePropertyTypeName, CoreRelationshipTypeName, CoreValueTypeName,
+    };
+
+    fn timestamp(text: &str) -> BaseValue {
+        BaseValue::TimestampValue(text.parse().unwrap())
+    }
+
+    fn date(text: &str) -> BaseValue {
+        BaseValue::DateValue(text.parse().unwrap())
+    }
+
+    fn bound(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        family: &TransientReference,
+        kind: CoreHolonTypeName,
+        property: CorePropertyTypeName,
+        value: BaseValue,
+        inclusive: bool,
+    ) -> Result<TransientReference, HolonError> {
+        let mut constraint =
+            new_descriptor_holon(context, key, &core_holon_type_name(kind), "Holon")?;
+        constraint
+            .with_property_value(property, value)?
+            .with_property_value(CorePropertyTypeName::ConstraintIsInclusive, inclusive)?
+            .add_related_holons(CoreRelationshipTypeName::Extends, vec![family.clone().into()])?;
+        Ok(constraint)
+    }
+
+    /// Builds a date value type constrained to `[minimum, maximum)`.
+    fn bounded_date_value(
+        context: &Arc<TransactionContext>,
+        minimum: &str,
+        maximum: &str,
+    ) -> Result<TransientReference, HolonError> {
+        let family = new_descriptor_holon(
+            context,
+            "date-constraint-family",
+            &core_holon_type_name(CoreHolonTypeName::DateValueConstraint),
+            "Holon",
+        )?;
+        let minimum = bound(
+            context,
+            "minimum",
+            &family,
+            CoreHolonTypeName::MinimumDate,
+            CorePropertyTypeName::ConstraintDateValue,
+            date(minimum),
+            true,
+        )?;
+        let maximum = bound(
+            context,
+            "maximum",
+            &family,
+            CoreHolonTypeName::MaximumDate,
+            CorePropertyTypeName::ConstraintDateValue,
+            date(maximum),
+            false,
+        )?;
+        let mut value = new_descriptor_holon(
+            context,
+            "date-value",
+            &core_value_type_name(CoreValueTypeName::DateValueType),
+            "Value",
+        )?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::Constraints,
+            vec![minimum.into(), maximum.into()],
+        )?;
+        Ok(value)
+    }
+
+    #[test]
+    fn wraps_reference_and_exposes_shared_header() -> Result<(), HolonError> {
+        let context = build_context();
+        let holon = HolonReference::from(&new_descriptor_holon(
+            &context,
+            "timestamp-value",
+            "TimestampValueType",
+            "Value",
+        )?);
+
+        let descriptor = TimestampValueDescriptor::from_holon(holon.clone());
+
+        assert_eq!(descriptor.holon(), &holon);
+        assert_eq!(descriptor.header().type_name()?, MapString("TimestampValueType".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_rejects_the_other_temporal_kind() -> Result<(), HolonError> {
+        let context = build_context();
+        let timestamp_descriptor = TimestampValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "timestamp-value", "TimestampValueType", "Value")?
+                .into(),
+        );
+        let date_descriptor = DateValueDescriptor::from_holon(
+            new_descriptor_holon(&context, "date-value", "DateValueType", "Value")?.into(),
+        );
+
+        assert!(timestamp_descriptor.is_valid(&timestamp("2024-03-01T09:30:00Z")).is_ok());
+        assert!(date_descriptor.is_valid(&date("2024-03-01")).is_ok());
+        assert!(matches!(
+            timestamp_descriptor.is_valid(&date("2024-03-01")),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Timestamp" && found == "Date"
+        ));
+        assert!(matches!(
+            date_descriptor.is_valid(&timestamp("2024-03-01T09:30:00Z")),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Date" && found == "Timestamp"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn is_valid_enforces_date_range_constraints() -> Result<(), HolonError> {
+        let context = build_context();
+        let value = bounded_date_value(&context, "2024-01-01", "2025-01-01")?;
+        let descriptor = DateValueDescriptor::from_holon(value.into());
+
+        assert!(descriptor.is_valid(&date("2024-01-01")).is_ok());
+        assert!(descriptor.is_valid(&date("2024-12-31")).is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&date("2023-12-31")),
+            Err(HolonError::TemporalOutOfRange { value, min: Some(min), min_inclusive: true, .. })
+                if value == "2023-12-31" && min == "2024-01-01"
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&date("2025-01-01")),
+            Err(HolonError::TemporalOutOfRange { max: Some(max), max_inclusive: false, .. })
+                if max == "2025-01-01"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn contradictory_exclusive_bounds_are_schema_invalid() -> Result<(), HolonError> {
+        let context = build_context();
+        // [2024-01-02, 2024-01-02) admits no date.
+        let value = bounded_date_value(&context, "2024-01-02", "2024-01-02")?;
+        let descriptor = DateValueDescriptor::from_holon(value.into());
+
+        assert!(matches!(
+            descriptor.is_valid(&date("2024-01-02")),
+            Err(HolonError::DescriptorSchemaInvalid { kind, .. })
+                if kind == SchemaInvalidityKind::ContradictoryConstraints
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_resolver_rejects_date_family_constraints() -> Result<(), HolonError> {
+        let context = build_context();
+        let family = new_descriptor_holon(
+            &context,
+            "date-constraint-family",
+            &core_holon_type_name(CoreHolonTypeName::DateValueConstraint),
+            "Holon",
+        )?;
+        let minimum = bound(
+            &context,
+            "minimum",
+            &family,
+            CoreHolonTypeName::MinimumDate,
+            CorePropertyTypeName::ConstraintDateValue,
+            date("2024-01-01"),
+            true,
+        )?;
+        let mut value = new_descriptor_holon(
+            &context,
+            "timestamp-value",
+            &core_value_type_name(CoreValueTypeName::TimestampValueType),
+            "Value",
+        )?;
+        value.add_related_holons(CoreRelationshipTypeName::Constraints, vec![minimum.into()])?;
+        let descriptor = TimestampValueDescriptor::from_holon(value.into());
+
+        assert!(matches!(
+            descriptor.is_valid(&timestamp("2024-03-01T09:30:00Z")),
+            Err(HolonError::DescriptorSchemaInvalid { kind, .. })
+                if kind == SchemaInvalidityKind::IncompatibleConstraintFamily
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn apply_operator_compares_chronologically() -> Result<(), HolonError> {
+        let context = build_context();
+        let equals = new_descriptor_holon(&context, "equals", "EqualsOperator", "Holon")?;
+        let less_than = new_descriptor_holon(&context, "less-than", "LessThanOperator", "Holon")?;
+        let mut value =
+            new_descriptor_holon(&context, "timestamp-value", "TimestampValueType", "Value")?;
+        value.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![equals.clone().into(), less_than.clone().into()],
+        )?;
+
+        let equals = OperatorDescriptor::from_holon(equals.into());
+        let less_than = OperatorDescriptor::from_holon(less_than.into());
+        let descriptor = TimestampValueDescriptor::from_holon(value.into());
+
+        assert!(descriptor.apply_operator(
+            &equals,
+            &timestamp("2024-03-01T10:30:00+01:00"),
+            &timestamp("2024-03-01T09:30:00Z")
+        )?);
+        assert!(descriptor.apply_operator(
+            &less_than,
+            &timestamp("2024-03-01T09:30:00Z"),
+            &timestamp("2024-03-01T09:30:00.000001Z")
+        )?);
+        assert!(!descriptor.apply_operator(
+            &less_than,
+            &timestamp("2024-03-01T09:30:00Z"),
+            &timestamp("2024-03-01T10:29:00+01:00")
+        )?);
+        Ok(())
+    }
+}
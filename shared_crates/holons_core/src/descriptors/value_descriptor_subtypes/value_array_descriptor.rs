@@ -1,5 +1,9 @@
+use crate::descriptors::accessor_helpers::descriptor_label;
+use crate::descriptors::value_descriptor_subtypes::constraints::{
+    resolve_value_array_constraints, ValueArrayConstraintValidation,
+};
 use crate::descriptors::value_descriptor_subtypes::helpers::{
-    supported_operators, supports_operator, unsupported_operator,
+    supported_operators, supports_operator, unsupported_operator, value_kind_mismatch,
 };
 use crate::descriptors::{
     accessor_helpers, Descriptor, OperatorDescriptor, TypeHeader, ValueDescriptor,
@@ -9,10 +13,10 @@ use base_types::BaseValue;
 use core_types::HolonError;
 use type_names::CoreRelationshipTypeName;
 
-/// Structural wrapper for value-array descriptors.
+/// Semantic wrapper for value-array descriptors.
 ///
-/// Array validation and operator execution are intentionally deferred; this
-/// phase only exposes the element value type and inherited affordances.
+/// Validation checks the array's item-count constraints and then each element
+/// against the element value type. Array operator execution is still deferred.
 pub struct ValueArrayDescriptor {
     holon: HolonReference,
 }
@@ -36,6 +40,27 @@ impl ValueArrayDescriptor {
         )?))
     }
 
+    /// Validates that a runtime value is a value array that satisfies descriptor
+    /// constraints and whose elements are all valid for the element value type.
+    pub fn is_valid(&self, value: &BaseValue) -> Result<(), HolonError> {
+        let values = match value {
+            BaseValue::ValueArray(values) => values,
+            other => return Err(value_kind_mismatch(&self.holon, "Array", other)),
+        };
+
+        let label = descriptor_label(&self.holon);
+        for constraint in resolve_value_array_constraints(&self.holon)? {
+            constraint.is_valid(values.len(), &label)?;
+        }
+
+        let element_value_type = self.element_value_type()?;
+        for element in values.iter() {
+            element_value_type.is_valid(element)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns operators afforded by this value descriptor across inheritance.
     pub fn supported_operators(&self) -> Result<Vec<OperatorDescriptor>, HolonError> {
         supported_operators(&self.holon)
@@ -81,9 +106,43 @@ const _: fn() = || {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::descriptors::test_support::{build_context, new_descriptor_holon};
-    use crate::reference_layer::WritableHolon;
-    use base_types::{MapInteger, MapString};
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        build_context, core_holon_type_name, new_descriptor_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use base_types::{MapInteger, MapString, MapValueArray};
+    use std::sync::Arc;
+    use type_names::{CoreHolonTypeName, CorePropertyTypeName};
+
+    fn strings(values: &[&str]) -> BaseValue {
+        BaseValue::ValueArray(
+            values
+                .iter()
+                .map(|value| BaseValue::StringValue(MapString(value.to_string())))
+                .collect(),
+        )
+    }
+
+    fn item_count_constraint(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        kind: CoreHolonTypeName,
+        count: i64,
+    ) -> Result<TransientReference, HolonError> {
+        let family = new_descriptor_holon(
+            context,
+            &format!("{key}-family"),
+            &core_holon_type_name(CoreHolonTypeName::ValueArrayConstraint),
+            "Holon",
+        )?;
+        let mut constraint =
+            new_descriptor_holon(context, key, &core_holon_type_name(kind), "Holon")?;
+        constraint
+            .with_property_value(CorePropertyTypeName::ConstraintItemCount, count)?
+            .add_related_holons(CoreRelationshipTypeName::Extends, vec![family.into()])?;
+        Ok(constraint)
+    }
 
     #[test]
     fn wraps_reference_and_exposes_shared_header() -> Result<(), HolonError> {
@@ -134,6 +193,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn is_valid_checks_item_counts_then_each_element() -> Result<(), HolonError> {
+        let context = build_context();
+        let element = new_descriptor_holon(&context, "string-value", "StringValueType", "Value")?;
+        let minimum =
+            item_count_constraint(&context, "minimum-items", CoreHolonTypeName::MinimumItems, 1)?;
+        let maximum =
+            item_count_constraint(&context, "maximum-items", CoreHolonTypeName::MaximumItems, 3)?;
+        let mut tags = new_descriptor_holon(&context, "tags", "ValueArrayValueType", "Value")?;
+        tags.add_related_holons(CoreRelationshipTypeName::ElementValueType, vec![element.into()])?
+            .add_related_holons(
+                CoreRelationshipTypeName::Constraints,
+                vec![minimum.into(), maximum.into()],
+            )?;
+
+        let descriptor = ValueArrayDescriptor::from_holon(tags.into());
+
+        assert!(descriptor.is_valid(&strings(&["red", "blue"])).is_ok());
+        assert!(matches!(
+            descriptor.is_valid(&strings(&[])),
+            Err(HolonError::ItemCountOutOfRange { count: 0, min: Some(1), .. })
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&strings(&["a", "b", "c", "d"])),
+            Err(HolonError::ItemCountOutOfRange { count: 4, max: Some(3), .. })
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&BaseValue::ValueArray(MapValueArray(vec![
+                BaseValue::StringValue(MapString("red".to_string())),
+                BaseValue::IntegerValue(MapInteger(7)),
+            ]))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "String" && found == "Integer"
+        ));
+        assert!(matches!(
+            descriptor.is_valid(&BaseValue::StringValue(MapString("red".to_string()))),
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Array" && found == "String"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn supported_operators_and_supports_operator_use_affordances() -> Result<(), HolonError> {
         let context = build_context();
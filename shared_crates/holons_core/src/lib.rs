@@ -10,6 +10,7 @@ pub mod core_shared_objects;
 pub mod descriptors;
 pub mod query_layer;
 pub mod reference_layer;
+pub mod validation;
 // Utility modules (if needed outside the crate)
 pub mod dances;
 pub mod utils;
@@ -17,12 +18,12 @@ pub mod utils;
 // pub use core_shared_objects::*;
 pub use core_shared_objects::{
     CollectionState, HolonCache, HolonCacheAccess, HolonCacheManager, HolonCollection, HolonPool,
-    Nursery, NurseryAccess, RelationshipCache, RelationshipMap, ServiceRoutingPolicy,
-    StagedRelationshipMap, TransientCollection,
+    Nursery, NurseryAccess, RelationshipCache, RelationshipCacheStats, RelationshipMap,
+    ServiceRoutingPolicy, StagedRelationshipMap, TransientCollection,
 };
 pub use core_types::HolonError;
 pub use descriptors::{
-    ancestors, classify_relationship_direction, effective_relationship_declaration,
+    ancestors, classify_relationship_direction, descendants, effective_relationship_declaration,
     walk_extends_chain, Descriptor, ExtendsIter, HolonDescriptor, HolonSpaceDescriptor,
     PropertyDescriptor, RelationshipDescriptor, RelationshipDirection, TransactionDescriptor,
     TypeHeader, ValueDescriptor,
@@ -33,4 +34,8 @@ pub use reference_layer::{
     ReadableHolon, SmartReference, StagedReference, TransientHolonBehavior, TransientReference,
     WritableHolon,
 };
+pub use validation::{
+    ValidationEngine, ValidationReport, ValidationResult, ValidationRule, ValidationRuleRegistry,
+    ValidationSubject,
+};
 // pub use utils::*;
@@ -0,0 +1,447 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::descriptors::accessor_helpers;
+use crate::query_layer::collection_paging::PropertyOrderings;
+use crate::reference_layer::{HolonReference, ReadableHolon, WritableHolon};
+use base_types::{BaseValue, MapInteger, MapString};
+use core_types::{HolonError, PropertyName};
+use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+/// Reductions an `Aggregation` expression applies to its input collection.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AggregateFunction {
+    Count,
+    Min,
+    Max,
+    Sum,
+    DistinctValues,
+    GroupBy,
+}
+
+impl AggregateFunction {
+    /// Returns the `AggregateFunction` enum variant name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Count => "Count",
+            Self::Min => "Min",
+            Self::Max => "Max",
+            Self::Sum => "Sum",
+            Self::DistinctValues => "DistinctValues",
+            Self::GroupBy => "GroupBy",
+        }
+    }
+
+    /// Parses the enum variant name stored on an `Aggregation` holon.
+    pub fn parse(value: &MapString) -> Result<Self, HolonError> {
+        match value.0.as_str() {
+            "Count" => Ok(Self::Count),
+            "Min" => Ok(Self::Min),
+            "Max" => Ok(Self::Max),
+            "Sum" => Ok(Self::Sum),
+            "DistinctValues" => Ok(Self::DistinctValues),
+            "GroupBy" => Ok(Self::GroupBy),
+            _ => {
+                Err(HolonError::InvalidParameter(format!("Unknown aggregate function: {}", value)))
+            }
+        }
+    }
+
+    /// Reads `AggregateFunction` from an `Aggregation` or `AggregateResult` holon.
+    pub fn of(holon: &HolonReference) -> Result<Self, HolonError> {
+        Self::parse(&accessor_helpers::require_enum_string(
+            holon,
+            CorePropertyTypeName::AggregateFunction,
+        )?)
+    }
+}
+
+/// Reduces `members` to transient `AggregateResult` holons.
+///
+/// `Count`, `Min`, `Max` and `Sum` yield one result whose `AggregateValue` is
+/// the reduced value and whose `MemberCount` is the number of contributing
+/// holons; `Min` and `Max` of no values leave `AggregateValue` unset.
+/// `DistinctValues` and `GroupBy` yield one result per distinct value in
+/// first-seen order, and `GroupBy` also links each group's `GroupMembers`.
+/// `GroupBy` without a property groups by the type name of each member's
+/// descriptor. Holons without a value for the property do not contribute.
+pub fn aggregate(
+    context: &Arc<TransactionContext>,
+    function: AggregateFunction,
+    property: Option<&PropertyName>,
+    members: &[HolonReference],
+) -> Result<Vec<HolonReference>, HolonError> {
+    let results = match function {
+        AggregateFunction::Count => {
+            let count = match property {
+                Some(property) => property_values(members, property)?.len(),
+                None => members.len(),
+            };
+            let value = BaseValue::IntegerValue(MapInteger(count as i64));
+            vec![AggregateRow { value: Some(value), members: Vec::new(), count }]
+        }
+        AggregateFunction::Min | AggregateFunction::Max => {
+            let property = require_property(function, property)?;
+            let wanted = match function {
+                AggregateFunction::Min => Ordering::Less,
+                _ => Ordering::Greater,
+            };
+            vec![extreme(members, property, wanted)?]
+        }
+        AggregateFunction::Sum => {
+            let property = require_property(function, property)?;
+            vec![sum(members, property)?]
+        }
+        AggregateFunction::DistinctValues => {
+            let property = require_property(function, property)?;
+            group(property_values(members, property)?)
+                .into_iter()
+                .map(|row| AggregateRow { members: Vec::new(), ..row })
+                .collect()
+        }
+        AggregateFunction::GroupBy => match property {
+            Some(property) => group(property_values(members, property)?),
+            None => group(descriptor_type_names(members)?),
+        },
+    };
+
+    results.into_iter().map(|row| row.into_result(context, function, property)).collect()
+}
+
+/// One reduced value before it is materialized as an `AggregateResult`.
+struct AggregateRow {
+    value: Option<BaseValue>,
+    members: Vec<HolonReference>,
+    count: usize,
+}
+
+impl AggregateRow {
+    fn into_result(
+        self,
+        context: &Arc<TransactionContext>,
+        function: AggregateFunction,
+        property: Option<&PropertyName>,
+    ) -> Result<HolonReference, HolonError> {
+        let mut result =
+            context.mutation().new_holon(Some(MapString("aggregate-result".to_string())))?;
+        result
+            .with_property_value(CorePropertyTypeName::AggregateFunction, function.as_str())?
+            .with_property_value(CorePropertyTypeName::MemberCount, self.count as i64)?;
+        if let Some(property) = property {
+            result.with_property_value(CorePropertyTypeName::AggregateProperty, &property.0)?;
+        }
+        if let Some(value) = self.value {
+            result.with_property_value(CorePropertyTypeName::AggregateValue, value)?;
+        }
+        if !self.members.is_empty() {
+            result.add_related_holons(CoreRelationshipTypeName::GroupMembers, self.members)?;
+        }
+        Ok(result.into())
+    }
+}
+
+fn require_property(
+    function: AggregateFunction,
+    property: Option<&PropertyName>,
+) -> Result<&PropertyName, HolonError> {
+    property.ok_or_else(|| {
+        HolonError::EmptyField(format!(
+            "{} (required by {})",
+            CorePropertyTypeName::AggregateProperty.as_property_name(),
+            function.as_str()
+        ))
+    })
+}
+
+fn property_values(
+    members: &[HolonReference],
+    property: &PropertyName,
+) -> Result<Vec<(BaseValue, HolonReference)>, HolonError> {
+    let mut values = Vec::new();
+    for member in members {
+        if let Some(value) = member.property_value(property)? {
+            values.push((value, member.clone()));
+        }
+    }
+    Ok(values)
+}
+
+fn descriptor_type_names(
+    members: &[HolonReference],
+) -> Result<Vec<(BaseValue, HolonReference)>, HolonError> {
+    let mut values = Vec::with_capacity(members.len());
+    for member in members {
+        let type_name = member.holon_descriptor()?.header().type_name()?;
+        values.push((BaseValue::StringValue(type_name), member.clone()));
+    }
+    Ok(values)
+}
+
+fn group(values: Vec<(BaseValue, HolonReference)>) -> Vec<AggregateRow> {
+    let mut rows: Vec<AggregateRow> = Vec::new();
+    for (value, member) in values {
+        match rows.iter_mut().find(|row| row.value.as_ref() == Some(&value)) {
+            Some(row) => {
+                row.members.push(member);
+                row.count += 1;
+            }
+            None => rows.push(AggregateRow { value: Some(value), members: vec![member], count: 1 }),
+        }
+    }
+    rows
+}
+
+fn extreme(
+    members: &[HolonReference],
+    property: &PropertyName,
+    wanted: Ordering,
+) -> Result<AggregateRow, HolonError> {
+    let mut orderings = PropertyOrderings::new(property);
+    let values = property_values(members, property)?;
+    let count = values.len();
+    let mut best: Option<BaseValue> = None;
+    for (value, member) in values {
+        let ordering = orderings.resolve(&member)?;
+        let replace = match &best {
+            Some(current) => orderings.compare(ordering, &value, current)? == wanted,
+            None => true,
+        };
+        if replace {
+            best = Some(value);
+        }
+    }
+    Ok(AggregateRow { value: best, members: Vec::new(), count })
+}
+
+fn sum(members: &[HolonReference], property: &PropertyName) -> Result<AggregateRow, HolonError> {
+    let values = property_values(members, property)?;
+    let count = values.len();
+    let overflow =
+        || HolonError::InvalidParameter(format!("Sum of {} overflows its value type", property));
+    // Integers sum to an integer and decimals to a decimal; the first value
+    // fixes the kind, and an empty sum is integer zero.
+    let mut total: Option<BaseValue> = None;
+    for (value, _) in values {
+        total = Some(match (total, value) {
+            (None, value @ (BaseValue::IntegerValue(_) | BaseValue::DecimalValue(_))) => value,
+            (
+                Some(BaseValue::IntegerValue(MapInteger(total))),
+                BaseValue::IntegerValue(MapInteger(addend)),
+            ) => {
+                BaseValue::IntegerValue(MapInteger(total.checked_add(addend).ok_or_else(overflow)?))
+            }
+            (Some(BaseValue::DecimalValue(total)), BaseValue::DecimalValue(addend)) => {
+                BaseValue::DecimalValue(total.checked_add(&addend).ok_or_else(overflow)?)
+            }
+            (total, value) => {
+                let expected = match total {
+                    Some(BaseValue::DecimalValue(_)) => "Decimal",
+                    Some(_) => "Integer",
+                    None => "Integer or Decimal",
+                };
+                return Err(HolonError::UnexpectedValueType(
+                    format!("{:?}", value),
+                    expected.to_string(),
+                ));
+            }
+        });
+    }
+    Ok(AggregateRow {
+        value: Some(total.unwrap_or(BaseValue::IntegerValue(MapInteger(0)))),
+        members: Vec::new(),
+        count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{
+        build_context, new_descriptor_holon, new_holon_type_descriptor,
+        new_property_descriptor_holon, new_test_holon,
+    };
+    use crate::reference_layer::TransientReference;
+    use base_types::MapDecimal;
+    use core_types::TypeKind;
+
+    fn page_count() -> PropertyName {
+        PropertyName(MapString("PageCount".to_string()))
+    }
+
+    fn book_type(
+        context: &Arc<TransactionContext>,
+        type_name: &str,
+    ) -> Result<TransientReference, HolonError> {
+        let mut less_than =
+            new_descriptor_holon(context, "less-than", "LessThanOperator", TypeKind::Holon)?;
+        less_than.with_property_value(CorePropertyTypeName::Arity, 2)?;
+        let mut value_type =
+            new_descriptor_holon(context, "integer-value", "IntegerValueType", "Value")?;
+        value_type.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![less_than.into()],
+        )?;
+        let property = new_property_descriptor_holon(
+            context,
+            "page-count",
+            "PageCount",
+            "page_count",
+            false,
+            value_type.into(),
+        )?;
+        let mut descriptor = new_holon_type_descriptor(context, type_name, type_name)?;
+        descriptor.add_related_holons(
+            CoreRelationshipTypeName::InstanceProperties,
+            vec![property.into()],
+        )?;
+        Ok(descriptor)
+    }
+
+    fn book(
+        context: &Arc<TransactionContext>,
+        descriptor: &TransientReference,
+        key: &str,
+        pages: Option<i64>,
+    ) -> Result<HolonReference, HolonError> {
+        let mut book = new_test_holon(context, key)?;
+        book.with_descriptor(descriptor.clone().into())?;
+        if let Some(pages) = pages {
+            book.with_property_value("PageCount", pages)?;
+        }
+        Ok(book.into())
+    }
+
+    fn aggregate_value(result: &HolonReference) -> Result<Option<BaseValue>, HolonError> {
+        result.property_value(CorePropertyTypeName::AggregateValue)
+    }
+
+    fn member_count(result: &HolonReference) -> Result<i64, HolonError> {
+        accessor_helpers::require_integer(result, CorePropertyTypeName::MemberCount)
+    }
+
+    fn integer(value: i64) -> Option<BaseValue> {
+        Some(BaseValue::IntegerValue(MapInteger(value)))
+    }
+
+    #[test]
+    fn scalar_aggregates_reduce_to_one_typed_result() -> Result<(), HolonError> {
+        let context = build_context();
+        let novel = book_type(&context, "Novel")?;
+        let books = vec![
+            book(&context, &novel, "long", Some(400))?,
+            book(&context, &novel, "short", Some(90))?,
+            book(&context, &novel, "unknown", None)?,
+            book(&context, &novel, "mid", Some(200))?,
+        ];
+        let property = page_count();
+
+        let count = aggregate(&context, AggregateFunction::Count, None, &books)?;
+        let with_pages = aggregate(&context, AggregateFunction::Count, Some(&property), &books)?;
+        let min = aggregate(&context, AggregateFunction::Min, Some(&property), &books)?;
+        let max = aggregate(&context, AggregateFunction::Max, Some(&property), &books)?;
+        let sum = aggregate(&context, AggregateFunction::Sum, Some(&property), &books)?;
+
+        assert_eq!(aggregate_value(&count[0])?, integer(4));
+        assert_eq!(aggregate_value(&with_pages[0])?, integer(3));
+        assert_eq!(aggregate_value(&min[0])?, integer(90));
+        assert_eq!(aggregate_value(&max[0])?, integer(400));
+        assert_eq!(aggregate_value(&sum[0])?, integer(690));
+        assert_eq!(member_count(&sum[0])?, 3);
+        assert_eq!(AggregateFunction::of(&sum[0])?, AggregateFunction::Sum);
+        Ok(())
+    }
+
+    #[test]
+    fn distinct_values_and_group_by_property_keep_first_seen_order() -> Result<(), HolonError> {
+        let context = build_context();
+        let novel = book_type(&context, "Novel")?;
+        let first = book(&context, &novel, "first", Some(200))?;
+        let second = book(&context, &novel, "second", Some(90))?;
+        let third = book(&context, &novel, "third", Some(200))?;
+        let books = vec![first.clone(), second.clone(), third.clone()];
+        let property = page_count();
+
+        let distinct =
+            aggregate(&context, AggregateFunction::DistinctValues, Some(&property), &books)?;
+        let groups = aggregate(&context, AggregateFunction::GroupBy, Some(&property), &books)?;
+
+        assert_eq!(distinct.len(), 2);
+        assert_eq!(aggregate_value(&distinct[0])?, integer(200));
+        assert_eq!(aggregate_value(&distinct[1])?, integer(90));
+        assert_eq!(member_count(&groups[0])?, 2);
+        let group_members = groups[0].related_holons(CoreRelationshipTypeName::GroupMembers)?;
+        assert_eq!(
+            group_members.read().map_err(accessor_helpers::lock_error)?.get_members(),
+            &vec![first, third]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn group_by_without_property_counts_per_descriptor_type() -> Result<(), HolonError> {
+        let context = build_context();
+        let novel = book_type(&context, "Novel")?;
+        let memoir = book_type(&context, "Memoir")?;
+        let books = vec![
+            book(&context, &novel, "a", None)?,
+            book(&context, &memoir, "b", None)?,
+            book(&context, &novel, "c", None)?,
+        ];
+
+        let groups = aggregate(&context, AggregateFunction::GroupBy, None, &books)?;
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            aggregate_value(&groups[0])?,
+            Some(BaseValue::StringValue(MapString("Novel".to_string())))
+        );
+        assert_eq!(member_count(&groups[0])?, 2);
+        assert_eq!(member_count(&groups[1])?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn sum_of_decimals_is_an_exact_decimal() -> Result<(), HolonError> {
+        let context = build_context();
+        let price = PropertyName(MapString("Price".to_string()));
+        let mut books = Vec::new();
+        for (key, text) in [("cheap", "0.1"), ("dear", "0.2"), ("mid", "19.70")] {
+            let mut book = new_test_holon(&context, key)?;
+            book.with_property_value("Price", text.parse::<MapDecimal>().unwrap())?;
+            books.push(HolonReference::from(book));
+        }
+
+        let sum = aggregate(&context, AggregateFunction::Sum, Some(&price), &books)?;
+
+        assert_eq!(
+            aggregate_value(&sum[0])?,
+            Some(BaseValue::DecimalValue(MapDecimal::new(200, 1)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn aggregates_report_missing_properties_and_non_integer_sums() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut titled = new_test_holon(&context, "titled")?;
+        titled.with_property_value("Title", "Dune")?;
+        let books = vec![HolonReference::from(titled)];
+
+        assert!(matches!(
+            aggregate(&context, AggregateFunction::Min, None, &books),
+            Err(HolonError::EmptyField(_))
+        ));
+        assert!(matches!(
+            aggregate(
+                &context,
+                AggregateFunction::Sum,
+                Some(&PropertyName(MapString("Title".to_string()))),
+                &books
+            ),
+            Err(HolonError::UnexpectedValueType(_, _))
+        ));
+        assert!(AggregateFunction::parse(&MapString("Median".to_string())).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::{Arc, RwLock};
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::core_shared_objects::HolonCollection;
+use crate::descriptors::accessor_helpers;
+use crate::reference_layer::{HolonReference, ReadableHolon, WritableHolon};
+use base_types::MapString;
+use core_types::HolonError;
+use type_names::CoreRelationshipTypeName;
+
+/// Typed facade over a `HolonReference` pointing at a holon-backed collection.
+///
+/// Member references live in the relationship-backed Members Collection
+/// (`CollectionMembers`), so callers use ordinary `HolonCollection` accessors
+/// rather than a facade-owned member list. The optional ItemType is carried by
+/// the schema's `ElementType` relationship and is not enforced against members.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundHolonCollection {
+    holon: HolonReference,
+}
+
+impl BoundHolonCollection {
+    /// Wraps an existing collection holon without descriptor validation.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Creates a transient collection holon whose Members Collection holds
+    /// `members` in the supplied order.
+    pub fn new_transient(
+        context: &Arc<TransactionContext>,
+        members: Vec<HolonReference>,
+    ) -> Result<Self, HolonError> {
+        let mut holon =
+            context.mutation().new_holon(Some(MapString("bound-holon-collection".to_string())))?;
+        if !members.is_empty() {
+            holon.add_related_holons(CoreRelationshipTypeName::CollectionMembers, members)?;
+        }
+        Ok(Self::from_holon(holon.into()))
+    }
+
+    /// Returns the relationship-backed Members Collection handle.
+    pub fn members(&self) -> Result<Arc<RwLock<HolonCollection>>, HolonError> {
+        self.holon.related_holons(CoreRelationshipTypeName::CollectionMembers)
+    }
+
+    /// Follows `ElementType` and returns the intended member descriptor, if any.
+    pub fn item_type(&self) -> Result<Option<HolonReference>, HolonError> {
+        accessor_helpers::optional_single_related(
+            &self.holon,
+            CoreRelationshipTypeName::ElementType,
+        )
+    }
+
+    /// Attaches the intended member descriptor through `ElementType`.
+    pub fn with_item_type(&mut self, item_type: HolonReference) -> Result<&mut Self, HolonError> {
+        self.holon.add_related_holons(CoreRelationshipTypeName::ElementType, vec![item_type])?;
+        Ok(self)
+    }
+
+    /// Returns the underlying reference.
+    pub fn as_holon_reference(&self) -> &HolonReference {
+        &self.holon
+    }
+
+    /// Consumes the wrapper and returns the underlying reference.
+    pub fn into_inner(self) -> HolonReference {
+        self.holon
+    }
+}
+
+impl From<HolonReference> for BoundHolonCollection {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+impl From<BoundHolonCollection> for HolonReference {
+    fn from(collection: BoundHolonCollection) -> Self {
+        collection.into_inner()
+    }
+}
@@ -0,0 +1,487 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core_shared_objects::HolonCollection;
+use crate::descriptors::value_descriptor_subtypes::helpers::base_value_kind;
+use crate::descriptors::{OperatorDescriptor, ValueDescriptor};
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use crate::HolonCollectionApi;
+use base_types::{BaseValue, MapDate, MapDecimal, MapString, MapTimestamp};
+use core_types::{HolonError, PropertyName};
+use type_names::CoreOperatorTypeName;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Orders holons by one property.
+///
+/// The value type is resolved per holon through its `DescribedBy` descriptor
+/// and must afford `LessThanOperator`; a value type that does not is rejected.
+/// The operator is not evaluated per comparison: values are ordered natively
+/// by their scalar kind, so heterogeneous collections sort as long as every
+/// value has the same orderable kind. Holons without a value for the property
+/// sort last in either direction.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    pub property: PropertyName,
+    pub direction: SortDirection,
+}
+
+impl SortKey {
+    pub fn ascending(property: PropertyName) -> Self {
+        Self { property, direction: SortDirection::Ascending }
+    }
+
+    pub fn descending(property: PropertyName) -> Self {
+        Self { property, direction: SortDirection::Descending }
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.direction {
+            SortDirection::Ascending => write!(f, "{}:asc", self.property),
+            SortDirection::Descending => write!(f, "{}:desc", self.property),
+        }
+    }
+}
+
+/// Opaque continuation token returned with a [`CollectionPage`].
+///
+/// Callers pass it back unchanged to fetch the following page. A cursor is
+/// only valid for the sort it was issued under. Cursors address positions in
+/// the sorted result, so holons added or removed between requests may shift
+/// page boundaries.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor(pub MapString);
+
+impl PageCursor {
+    fn encode(position: usize, sort: Option<&SortKey>) -> Self {
+        PageCursor(MapString(format!("{}@{}", position, sort_fingerprint(sort))))
+    }
+
+    fn decode(&self, sort: Option<&SortKey>) -> Result<usize, HolonError> {
+        let invalid = || HolonError::InvalidParameter(format!("Invalid page cursor `{}`", self.0));
+        let (position, fingerprint) = self.0 .0.split_once('@').ok_or_else(invalid)?;
+        if fingerprint != sort_fingerprint(sort) {
+            return Err(HolonError::InvalidParameter(format!(
+                "Page cursor `{}` was issued for a different sort",
+                self.0
+            )));
+        }
+        position.parse().map_err(|_| invalid())
+    }
+}
+
+fn sort_fingerprint(sort: Option<&SortKey>) -> String {
+    sort.map(SortKey::to_string).unwrap_or_default()
+}
+
+/// Selects a window of a collection.
+///
+/// Either `offset` or `cursor` positions the window, never both. `limit`
+/// bounds the page size; `None` returns everything after the start position.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct PageRequest {
+    pub limit: Option<usize>,
+    pub offset: usize,
+    pub cursor: Option<PageCursor>,
+}
+
+impl PageRequest {
+    pub fn first(limit: usize) -> Self {
+        Self { limit: Some(limit), offset: 0, cursor: None }
+    }
+
+    pub fn after(cursor: PageCursor, limit: usize) -> Self {
+        Self { limit: Some(limit), offset: 0, cursor: Some(cursor) }
+    }
+
+    fn start(&self, sort: Option<&SortKey>) -> Result<usize, HolonError> {
+        if self.limit == Some(0) {
+            return Err(HolonError::InvalidParameter("Page limit must be positive".to_string()));
+        }
+        match &self.cursor {
+            Some(_) if self.offset != 0 => Err(HolonError::InvalidParameter(
+                "Page requests take either an offset or a cursor, not both".to_string(),
+            )),
+            Some(cursor) => cursor.decode(sort),
+            None => Ok(self.offset),
+        }
+    }
+}
+
+/// One window of a sorted collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionPage {
+    /// Transient collection holding only this page's members, in order.
+    pub collection: HolonCollection,
+    /// Cursor for the following page, or `None` when this page reaches the end.
+    pub next_cursor: Option<PageCursor>,
+    /// Number of members in the whole collection before paging.
+    pub total_count: usize,
+}
+
+/// Sorts `collection` by `sort` (if any) and returns the requested window.
+pub fn page_collection(
+    collection: &HolonCollection,
+    sort: Option<&SortKey>,
+    request: &PageRequest,
+) -> Result<CollectionPage, HolonError> {
+    let start = request.start(sort)?;
+    let mut members = collection.get_members().clone();
+    if let Some(sort) = sort {
+        members = sort_members(members, sort)?;
+    }
+
+    let total_count = members.len();
+    let end = match request.limit {
+        Some(limit) => start.saturating_add(limit).min(total_count),
+        None => total_count,
+    };
+
+    let mut page = HolonCollection::new_transient();
+    if start < end {
+        page.add_references(members[start..end].to_vec())?;
+    }
+    let next_cursor = (end < total_count).then(|| PageCursor::encode(end, sort));
+
+    Ok(CollectionPage { collection: page, next_cursor, total_count })
+}
+
+fn sort_members(
+    members: Vec<HolonReference>,
+    sort: &SortKey,
+) -> Result<Vec<HolonReference>, HolonError> {
+    // Every key is resolved and kind-checked before sorting, so the comparator
+    // below works on a total order and cannot fail part-way through the sort.
+    let mut orderings = PropertyOrderings::new(&sort.property);
+    let mut keyed: Vec<(Option<SortValue>, HolonReference)> = Vec::with_capacity(members.len());
+    let mut first_kind: Option<&'static str> = None;
+    for member in members {
+        let key = match member.property_value(&sort.property)? {
+            Some(value) => {
+                let ordering = orderings.resolve(&member)?;
+                orderings.check_kind(ordering, &value)?;
+                let kind = base_value_kind(&value);
+                let key = SortValue::from_base_value(&value).ok_or_else(|| {
+                    HolonError::InvalidParameter(format!(
+                        "Cannot sort by {}: {} values have no defined order",
+                        sort.property, kind
+                    ))
+                })?;
+                match first_kind {
+                    Some(expected) if expected != kind => {
+                        return Err(HolonError::InvalidParameter(format!(
+                            "Cannot sort by {}: values mix {} and {} kinds",
+                            sort.property, expected, kind
+                        )));
+                    }
+                    _ => first_kind = Some(kind),
+                }
+                Some(key)
+            }
+            None => None,
+        };
+        keyed.push((key, member));
+    }
+
+    keyed.sort_by(|(lhs, _), (rhs, _)| match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) if sort.direction == SortDirection::Descending => rhs.cmp(lhs),
+        (Some(lhs), Some(rhs)) => lhs.cmp(rhs),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+
+    Ok(keyed.into_iter().map(|(_, member)| member).collect())
+}
+
+/// Totally ordered sort key for one scalar property value.
+///
+/// Mirrors the kinds `scalar_ordering` orders. Sorting compares these native
+/// keys rather than evaluating the afforded `LessThanOperator`, which keeps the
+/// comparator infallible.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum SortValue {
+    Integer(i64),
+    Decimal(MapDecimal),
+    String(String),
+    Timestamp(MapTimestamp),
+    Date(MapDate),
+}
+
+impl SortValue {
+    fn from_base_value(value: &BaseValue) -> Option<Self> {
+        match value {
+            BaseValue::IntegerValue(value) => Some(Self::Integer(value.0)),
+            BaseValue::DecimalValue(value) => Some(Self::Decimal(value.clone())),
+            BaseValue::StringValue(value) => Some(Self::String(value.0.clone())),
+            BaseValue::TimestampValue(value) => Some(Self::Timestamp(*value)),
+            BaseValue::DateValue(value) => Some(Self::Date(*value)),
+            _ => None,
+        }
+    }
+}
+
+/// Resolved ordering for one descriptor's value type.
+struct PropertyOrdering {
+    descriptor: MapString,
+    value_type: ValueDescriptor,
+    less_than: OperatorDescriptor,
+}
+
+/// Per-descriptor cache of the `LessThanOperator` afforded by one property's
+/// value type.
+pub(crate) struct PropertyOrderings<'a> {
+    property: &'a PropertyName,
+    resolved: Vec<PropertyOrdering>,
+}
+
+impl<'a> PropertyOrderings<'a> {
+    pub(crate) fn new(property: &'a PropertyName) -> Self {
+        Self { property, resolved: Vec::new() }
+    }
+
+    /// Resolves the ordering for `member`'s descriptor and returns its index.
+    pub(crate) fn resolve(&mut self, member: &HolonReference) -> Result<usize, HolonError> {
+        let descriptor = member.holon_descriptor()?;
+        let descriptor_name = descriptor.header().type_name()?;
+        if let Some(index) = self.resolved.iter().position(|o| o.descriptor == descriptor_name) {
+            return Ok(index);
+        }
+
+        let value_type = descriptor.get_property_by_name(self.property)?.value_type()?;
+        let less_than = value_type.affords_operator(CoreOperatorTypeName::LessThanOperator)?;
+        self.resolved.push(PropertyOrdering { descriptor: descriptor_name, value_type, less_than });
+        Ok(self.resolved.len() - 1)
+    }
+
+    /// Checks that `value` has the base kind of the value type at `index`.
+    pub(crate) fn check_kind(&self, index: usize, value: &BaseValue) -> Result<(), HolonError> {
+        self.resolved[index].value_type.is_valid_kind(value)
+    }
+
+    /// Compares two values using the ordering at `index`.
+    pub(crate) fn compare(
+        &self,
+        index: usize,
+        lhs: &BaseValue,
+        rhs: &BaseValue,
+    ) -> Result<Ordering, HolonError> {
+        let ordering = &self.resolved[index];
+        if ordering.value_type.apply_operator(&ordering.less_than, lhs, rhs)? {
+            Ok(Ordering::Less)
+        } else if ordering.value_type.apply_operator(&ordering.less_than, rhs, lhs)? {
+            Ok(Ordering::Greater)
+        } else {
+            Ok(Ordering::Equal)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        build_context, new_descriptor_holon, new_holon_type_descriptor,
+        new_property_descriptor_holon, new_test_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use core_types::TypeKind;
+    use std::sync::Arc;
+    use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+    fn book_descriptor(
+        context: &Arc<TransactionContext>,
+        afford_ordering: bool,
+    ) -> Result<TransientReference, HolonError> {
+        let mut value_type =
+            new_descriptor_holon(context, "integer-value", "IntegerValueType", "Value")?;
+        if afford_ordering {
+            let mut less_than =
+                new_descriptor_holon(context, "less-than", "LessThanOperator", TypeKind::Holon)?;
+            less_than.with_property_value(CorePropertyTypeName::Arity, 2)?;
+            value_type.add_related_holons(
+                CoreRelationshipTypeName::AffordsOperator,
+                vec![less_than.into()],
+            )?;
+        }
+        let property = new_property_descriptor_holon(
+            context,
+            "page-count",
+            "PageCount",
+            "page_count",
+            false,
+            value_type.into(),
+        )?;
+        let mut descriptor = new_holon_type_descriptor(context, "book-type", "BookType")?;
+        descriptor.add_related_holons(
+            CoreRelationshipTypeName::InstanceProperties,
+            vec![property.into()],
+        )?;
+        Ok(descriptor)
+    }
+
+    fn books(
+        context: &Arc<TransactionContext>,
+        descriptor: &TransientReference,
+        pages: &[(&str, Option<i64>)],
+    ) -> Result<(HolonCollection, Vec<HolonReference>), HolonError> {
+        let mut references = Vec::new();
+        for (key, pages) in pages {
+            let mut book = new_test_holon(context, key)?;
+            book.add_related_holons(
+                CoreRelationshipTypeName::DescribedBy,
+                vec![descriptor.clone().into()],
+            )?;
+            if let Some(pages) = pages {
+                book.with_property_value("PageCount", *pages)?;
+            }
+            references.push(book.into());
+        }
+        let mut collection = HolonCollection::new_transient();
+        collection.add_references(references.clone())?;
+        Ok((collection, references))
+    }
+
+    fn page_count() -> PropertyName {
+        PropertyName(MapString("PageCount".to_string()))
+    }
+
+    #[test]
+    fn sorts_by_property_with_missing_values_last() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor = book_descriptor(&context, true)?;
+        let (collection, refs) = books(
+            &context,
+            &descriptor,
+            &[("long", Some(400)), ("unknown", None), ("short", Some(90)), ("mid", Some(200))],
+        )?;
+
+        let ascending = page_collection(
+            &collection,
+            Some(&SortKey::ascending(page_count())),
+            &PageRequest::default(),
+        )?;
+        assert_eq!(
+            ascending.collection.get_members(),
+            &vec![refs[2].clone(), refs[3].clone(), refs[0].clone(), refs[1].clone()]
+        );
+
+        let descending = page_collection(
+            &collection,
+            Some(&SortKey::descending(page_count())),
+            &PageRequest::default(),
+        )?;
+        assert_eq!(
+            descending.collection.get_members(),
+            &vec![refs[0].clone(), refs[3].clone(), refs[2].clone(), refs[1].clone()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn limit_and_offset_select_a_window() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor = book_descriptor(&context, true)?;
+        let (collection, refs) =
+            books(&context, &descriptor, &[("a", None), ("b", None), ("c", None), ("d", None)])?;
+
+        let page = page_collection(
+            &collection,
+            None,
+            &PageRequest { limit: Some(2), offset: 1, cursor: None },
+        )?;
+
+        assert_eq!(page.collection.get_members(), &refs[1..3].to_vec());
+        assert_eq!(page.total_count, 4);
+        assert!(page.next_cursor.is_some());
+        assert!(matches!(
+            page_collection(&collection, None, &PageRequest::first(0)),
+            Err(HolonError::InvalidParameter(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn cursors_walk_to_the_end_and_are_bound_to_their_sort() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor = book_descriptor(&context, true)?;
+        let (collection, refs) =
+            books(&context, &descriptor, &[("c", Some(3)), ("a", Some(1)), ("b", Some(2))])?;
+        let sort = SortKey::ascending(page_count());
+
+        let first = page_collection(&collection, Some(&sort), &PageRequest::first(2))?;
+        let cursor = first.next_cursor.clone().expect("more pages remain");
+        let second =
+            page_collection(&collection, Some(&sort), &PageRequest::after(cursor.clone(), 2))?;
+
+        assert_eq!(first.collection.get_members(), &vec![refs[1].clone(), refs[2].clone()]);
+        assert_eq!(second.collection.get_members(), &vec![refs[0].clone()]);
+        assert_eq!(second.next_cursor, None);
+        assert!(matches!(
+            page_collection(&collection, None, &PageRequest::after(cursor, 2)),
+            Err(HolonError::InvalidParameter(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn sorting_requires_an_afforded_ordering_operator() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor = book_descriptor(&context, false)?;
+        let (collection, _) = books(&context, &descriptor, &[("a", Some(1)), ("b", Some(2))])?;
+
+        let result = page_collection(
+            &collection,
+            Some(&SortKey::ascending(page_count())),
+            &PageRequest::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(HolonError::DescriptorDeclarationNotFound { kind, name, .. })
+                if kind == "operator" && name == "LessThanOperator"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn sorting_rejects_values_of_the_wrong_kind_before_sorting() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor = book_descriptor(&context, true)?;
+        let pages: Vec<(String, Option<i64>)> =
+            (0..40).map(|index| (format!("book-{index}"), Some(40 - index))).collect();
+        let pages: Vec<(&str, Option<i64>)> =
+            pages.iter().map(|(key, pages)| (key.as_str(), *pages)).collect();
+        let (mut collection, _) = books(&context, &descriptor, &pages)?;
+        let mut odd = new_test_holon(&context, "odd")?;
+        odd.add_related_holons(
+            CoreRelationshipTypeName::DescribedBy,
+            vec![descriptor.clone().into()],
+        )?;
+        odd.with_property_value("PageCount", "many")?;
+        collection.add_references(vec![odd.into()])?;
+
+        let result = page_collection(
+            &collection,
+            Some(&SortKey::ascending(page_count())),
+            &PageRequest::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(HolonError::ValueKindMismatch { expected, found, .. })
+                if expected == "Integer" && found == "String"
+        ));
+        Ok(())
+    }
+}
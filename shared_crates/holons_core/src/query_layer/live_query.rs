@@ -0,0 +1,504 @@
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::core_shared_objects::HolonCollection;
+use crate::query_layer::query_executor::{evaluate_query, ExpressionObserver};
+use crate::query_layer::BoundHolonCollection;
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use core_types::{
+    ActionEvent, HolonError, HolonId, LocalId, MutationKind, RelationshipName, TemporaryId,
+};
+
+/// Link type string the guest reports for relationship links between holons.
+const SMART_LINK_TYPE: &str = "SmartLink";
+
+/// What a `LiveQuery` re-evaluates when a relevant `ActionEvent` arrives.
+#[derive(Debug, Clone)]
+pub enum LiveQueryWatch {
+    /// A `Query` holon executed against `input`.
+    Query { query: HolonReference, input: BoundHolonCollection },
+    /// The holons related to `source` through `relationship`.
+    Relationship { source: HolonReference, relationship: RelationshipName },
+}
+
+/// Membership change between two evaluations of a live query.
+#[derive(Debug, Clone, Default)]
+pub struct ResultDelta {
+    /// Members in the new result that were not in the previous one, in result order.
+    pub added: Vec<HolonReference>,
+    /// Members of the previous result that are no longer present, in previous order.
+    pub removed: Vec<HolonReference>,
+    /// Members present in both results whose holon the triggering event updated.
+    /// Consumers re-read these through the usual reference path.
+    pub changed: Vec<HolonReference>,
+}
+
+impl ResultDelta {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A registered watch whose result set is kept current from storage `ActionEvent`s.
+///
+/// `ActionEvent`s carry identities only, and link events do not name their base
+/// holon, so relevance is decided from the watch's footprint: for a `Query`
+/// watch, the saved holons its last evaluation read and the saved holons whose
+/// relationships it traversed.
+///
+/// | Event                         | `Query` watch                             | `Relationship` watch                   |
+/// | ----------------------------- | ----------------------------------------- | -------------------------------------- |
+/// | `HolonCreated`                | no (membership needs a link)              | no (membership needs a link)           |
+/// | `HolonUpdated`/`HolonDeleted` | when it touches a holon the query read    | when it touches the source or a member |
+/// | `LinkCreated`/`LinkDeleted`   | `SmartLink` links, if the query traverses | `SmartLink` links only                 |
+///
+/// Re-evaluation first drops the relationship cache entries of the traversed
+/// sources only, so links committed by other agents are fetched again rather
+/// than served from memory. Query execution artifacts are released after each
+/// evaluation; `AggregateResult` members are kept until the evaluation that
+/// replaces them, so an aggregate in `ResultDelta::removed` can no longer be read.
+#[derive(Debug)]
+pub struct LiveQuery {
+    context: Arc<TransactionContext>,
+    watch: LiveQueryWatch,
+    members: Vec<HolonReference>,
+    footprint: QueryFootprint,
+    /// Transient members created by the last evaluation, released when replaced.
+    retained: Vec<TemporaryId>,
+}
+
+/// Saved holons one evaluation of a `Query` watch depended on.
+#[derive(Debug, Default)]
+struct QueryFootprint {
+    /// Saved holons that were an expression's input or result, or were traversed.
+    holons: Vec<LocalId>,
+    /// Saved holons whose relationships a traversal read.
+    sources: Vec<HolonId>,
+}
+
+impl QueryFootprint {
+    fn record(&mut self, holon: &HolonReference) {
+        if let Some(id) = saved_local_id(holon) {
+            if !self.holons.contains(&id) {
+                self.holons.push(id);
+            }
+        }
+    }
+
+    fn record_members(&mut self, collection: &BoundHolonCollection) -> Result<(), HolonError> {
+        for member in collection_members(collection)? {
+            self.record(&member);
+        }
+        Ok(())
+    }
+}
+
+impl ExpressionObserver for QueryFootprint {
+    fn enter(
+        &mut self,
+        _expression: &HolonReference,
+        input: &BoundHolonCollection,
+        _depth: usize,
+    ) -> Result<(), HolonError> {
+        self.record_members(input)
+    }
+
+    fn exit(&mut self, result: Option<&BoundHolonCollection>) -> Result<(), HolonError> {
+        match result {
+            Some(result) => self.record_members(result),
+            None => Ok(()),
+        }
+    }
+
+    fn expanded(
+        &mut self,
+        source: &HolonReference,
+        _relationship: &RelationshipName,
+    ) -> Result<(), HolonError> {
+        self.record(source);
+        if let HolonReference::Smart(smart) = source {
+            let id = smart.holon_id();
+            if !self.sources.contains(&id) {
+                self.sources.push(id);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl LiveQuery {
+    /// Evaluates `watch` once and returns the live query holding that initial result.
+    pub fn register(
+        context: &Arc<TransactionContext>,
+        watch: LiveQueryWatch,
+    ) -> Result<Self, HolonError> {
+        let evaluation = evaluate(context, &watch)?;
+        Ok(Self {
+            context: Arc::clone(context),
+            watch,
+            members: evaluation.members,
+            footprint: evaluation.footprint,
+            retained: evaluation.retained,
+        })
+    }
+
+    pub fn watch(&self) -> &LiveQueryWatch {
+        &self.watch
+    }
+
+    /// Returns the result set as of the last evaluation.
+    pub fn members(&self) -> &[HolonReference] {
+        &self.members
+    }
+
+    /// Returns whether `event` could change this live query's result.
+    pub fn is_relevant(&self, event: &ActionEvent) -> bool {
+        let touched = touched_holons(event);
+        match event.mutation_kind {
+            MutationKind::HolonCreated => false,
+            MutationKind::HolonUpdated | MutationKind::HolonDeleted => match &self.watch {
+                LiveQueryWatch::Query { .. } => {
+                    self.footprint.holons.iter().any(|id| touched.contains(id))
+                }
+                LiveQueryWatch::Relationship { source, .. } => {
+                    saved_local_id(source).is_some_and(|id| touched.contains(&id))
+                        || self
+                            .members
+                            .iter()
+                            .filter_map(saved_local_id)
+                            .any(|id| touched.contains(&id))
+                }
+            },
+            MutationKind::LinkCreated | MutationKind::LinkDeleted => {
+                let traverses = match &self.watch {
+                    LiveQueryWatch::Query { .. } => !self.footprint.sources.is_empty(),
+                    LiveQueryWatch::Relationship { .. } => true,
+                };
+                traverses
+                    && event
+                        .link_type
+                        .as_deref()
+                        .is_none_or(|link_type| link_type == SMART_LINK_TYPE)
+            }
+        }
+    }
+
+    /// Re-evaluates after a relevant `event` and returns the resulting delta.
+    ///
+    /// Returns `None` when the event is not relevant or did not change the result.
+    pub fn apply(&mut self, event: &ActionEvent) -> Result<Option<ResultDelta>, HolonError> {
+        if !self.is_relevant(event) {
+            return Ok(None);
+        }
+        let delta = self.reevaluate(&touched_holons(event))?;
+        Ok((!delta.is_empty()).then_some(delta))
+    }
+
+    /// Re-evaluates unconditionally, e.g. after events were missed.
+    ///
+    /// Without a triggering event, `changed` is always empty.
+    pub fn refresh(&mut self) -> Result<ResultDelta, HolonError> {
+        self.reevaluate(&[])
+    }
+
+    fn reevaluate(&mut self, touched: &[LocalId]) -> Result<ResultDelta, HolonError> {
+        match &self.watch {
+            LiveQueryWatch::Query { .. } => {
+                for source in &self.footprint.sources {
+                    self.context.invalidate_related_holons(Some(source))?;
+                }
+            }
+            LiveQueryWatch::Relationship { source, .. } => {
+                if let HolonReference::Smart(smart) = source {
+                    self.context.invalidate_related_holons(Some(&smart.holon_id()))?;
+                }
+            }
+        }
+
+        let evaluation = evaluate(&self.context, &self.watch)?;
+        let delta = diff_members(&self.members, &evaluation.members, touched);
+        let replaced = std::mem::replace(&mut self.retained, evaluation.retained);
+        self.context.release_transient_holons(&replaced)?;
+        self.members = evaluation.members;
+        self.footprint = evaluation.footprint;
+        Ok(delta)
+    }
+}
+
+impl Drop for LiveQuery {
+    fn drop(&mut self) {
+        // Nothing else refers to the retained members once the live query is gone.
+        let _ = self.context.release_transient_holons(&self.retained);
+    }
+}
+
+/// The outcome of one evaluation of a watch.
+struct Evaluation {
+    members: Vec<HolonReference>,
+    footprint: QueryFootprint,
+    retained: Vec<TemporaryId>,
+}
+
+fn evaluate(
+    context: &Arc<TransactionContext>,
+    watch: &LiveQueryWatch,
+) -> Result<Evaluation, HolonError> {
+    match watch {
+        LiveQueryWatch::Query { query, input } => {
+            let mut footprint = QueryFootprint::default();
+            let (members, retained) = evaluate_query(context, query, input, Some(&mut footprint))?;
+            Ok(Evaluation { members, footprint, retained })
+        }
+        LiveQueryWatch::Relationship { source, relationship } => Ok(Evaluation {
+            members: read_members(source.related_holons(relationship)?)?,
+            footprint: QueryFootprint::default(),
+            retained: Vec::new(),
+        }),
+    }
+}
+
+fn collection_members(
+    collection: &BoundHolonCollection,
+) -> Result<Vec<HolonReference>, HolonError> {
+    read_members(collection.members()?)
+}
+
+fn read_members(
+    collection: Arc<RwLock<HolonCollection>>,
+) -> Result<Vec<HolonReference>, HolonError> {
+    let members = collection
+        .read()
+        .map_err(|e| {
+            HolonError::FailedToAcquireLock(format!(
+                "Failed to acquire read lock on live query result: {}",
+                e
+            ))
+        })?
+        .get_members()
+        .clone();
+    Ok(members)
+}
+
+fn diff_members(
+    previous: &[HolonReference],
+    current: &[HolonReference],
+    touched: &[LocalId],
+) -> ResultDelta {
+    let previous_keys: HashSet<String> = previous.iter().map(member_key).collect();
+    let current_keys: HashSet<String> = current.iter().map(member_key).collect();
+
+    let mut delta = ResultDelta::default();
+    for member in current {
+        if !previous_keys.contains(&member_key(member)) {
+            delta.added.push(member.clone());
+        } else if saved_local_id(member).is_some_and(|id| touched.contains(&id)) {
+            delta.changed.push(member.clone());
+        }
+    }
+    delta.removed = previous
+        .iter()
+        .filter(|member| !current_keys.contains(&member_key(member)))
+        .cloned()
+        .collect();
+    delta
+}
+
+fn member_key(member: &HolonReference) -> String {
+    format!("{}:{}", member.reference_kind_string(), member.reference_id_string())
+}
+
+fn saved_local_id(reference: &HolonReference) -> Option<LocalId> {
+    match reference {
+        HolonReference::Smart(smart) => match smart.holon_id() {
+            HolonId::Local(local_id) => Some(local_id),
+            HolonId::External(external_id) => Some(external_id.local_id),
+        },
+        _ => None,
+    }
+}
+
+fn touched_holons(event: &ActionEvent) -> Vec<LocalId> {
+    if event.is_link_event() {
+        return Vec::new();
+    }
+    std::iter::once(event.affected_holon.clone()).chain(event.previous_holon.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{
+        build_context, core_holon_type_name, new_descriptor_holon, new_test_holon,
+    };
+    use crate::reference_layer::{SmartReference, TransientReference, WritableHolon};
+    use base_types::{BaseValue, MapString};
+    use core_types::{PersistenceTimestamp, PropertyMap, TypeKind};
+    use type_names::{CoreHolonTypeName, CorePropertyTypeName, CoreRelationshipTypeName};
+
+    fn saved_holon(context: &Arc<TransactionContext>, seed: u8) -> HolonReference {
+        SmartReference::new_with_properties(
+            context.context_handle(),
+            LocalId(vec![seed; 39]).into(),
+            PropertyMap::from([(
+                CorePropertyTypeName::Key.as_property_name(),
+                BaseValue::StringValue(MapString(format!("saved-{seed}"))),
+            )]),
+        )
+        .into()
+    }
+
+    fn event(mutation_kind: MutationKind, affected: u8, link_type: Option<&str>) -> ActionEvent {
+        let previous_holon =
+            matches!(mutation_kind, MutationKind::HolonUpdated | MutationKind::HolonDeleted)
+                .then(|| LocalId(vec![affected.wrapping_add(100); 39]));
+        ActionEvent {
+            mutation_kind,
+            action_id: LocalId(vec![0xAA; 39]),
+            affected_holon: LocalId(vec![affected; 39]),
+            previous_holon,
+            timestamp: PersistenceTimestamp(0),
+            link_type: link_type.map(str::to_string),
+        }
+    }
+
+    fn watch_members(
+        context: &Arc<TransactionContext>,
+        source: &TransientReference,
+    ) -> Result<LiveQuery, HolonError> {
+        LiveQuery::register(
+            context,
+            LiveQueryWatch::Relationship {
+                source: source.clone().into(),
+                relationship: RelationshipName(MapString("Members".to_string())),
+            },
+        )
+    }
+
+    /// Registers a `Query` watch that traverses `Members` from `source`.
+    fn watch_members_query(
+        context: &Arc<TransactionContext>,
+        source: &TransientReference,
+    ) -> Result<LiveQuery, HolonError> {
+        let descriptor = new_descriptor_holon(
+            context,
+            "members-traversal-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::RelationshipTraversal),
+            TypeKind::Holon,
+        )?;
+        let mut traversal = new_test_holon(context, "members-traversal")?;
+        traversal.with_descriptor(descriptor.into())?;
+        traversal.with_property_value(CorePropertyTypeName::RelationshipName, "Members")?;
+        let mut query = new_test_holon(context, "members-query")?;
+        query
+            .add_related_holons(CoreRelationshipTypeName::RootExpression, vec![traversal.into()])?;
+        let input = BoundHolonCollection::new_transient(context, vec![source.clone().into()])?;
+
+        LiveQuery::register(context, LiveQueryWatch::Query { query: query.into(), input })
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn live_query_can_move_to_a_background_task() {
+        assert_send::<LiveQuery>();
+        assert_send::<ResultDelta>();
+    }
+
+    #[test]
+    fn relationship_watch_reports_added_and_removed_members() -> Result<(), HolonError> {
+        let context = build_context();
+        let first = saved_holon(&context, 1);
+        let second = saved_holon(&context, 2);
+        let mut source = new_test_holon(&context, "source")?;
+        source.add_related_holons("Members", vec![first.clone()])?;
+
+        let mut live = watch_members(&context, &source)?;
+        assert_eq!(live.members().len(), 1);
+
+        source.add_related_holons("Members", vec![second.clone()])?;
+        let delta =
+            live.apply(&event(MutationKind::LinkCreated, 0xAA, Some(SMART_LINK_TYPE)))?.unwrap();
+        assert_eq!(delta.added.len(), 1);
+        assert_eq!(member_key(&delta.added[0]), member_key(&second));
+        assert!(delta.removed.is_empty() && delta.changed.is_empty());
+
+        source.remove_related_holons("Members", vec![first.clone()])?;
+        let delta =
+            live.apply(&event(MutationKind::LinkDeleted, 0xAA, Some(SMART_LINK_TYPE)))?.unwrap();
+        assert_eq!(delta.removed.len(), 1);
+        assert_eq!(member_key(&delta.removed[0]), member_key(&first));
+        assert_eq!(live.members().len(), 1);
+
+        // Nothing changed since the last evaluation.
+        assert!(live.apply(&event(MutationKind::LinkCreated, 0xAA, None))?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn relationship_watch_reports_updated_members_as_changed() -> Result<(), HolonError> {
+        let context = build_context();
+        let member = saved_holon(&context, 1);
+        let mut source = new_test_holon(&context, "source")?;
+        source.add_related_holons("Members", vec![member.clone()])?;
+        let mut live = watch_members(&context, &source)?;
+
+        let update = event(MutationKind::HolonUpdated, 1, None);
+        assert!(live.is_relevant(&update));
+        let delta = live.apply(&update)?.unwrap();
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(member_key(&delta.changed[0]), member_key(&member));
+        assert!(delta.added.is_empty() && delta.removed.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn relationship_watch_ignores_unrelated_events() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut source = new_test_holon(&context, "source")?;
+        source.add_related_holons("Members", vec![saved_holon(&context, 1)])?;
+        let live = watch_members(&context, &source)?;
+
+        assert!(!live.is_relevant(&event(MutationKind::HolonCreated, 1, None)));
+        assert!(!live.is_relevant(&event(MutationKind::HolonUpdated, 9, None)));
+        assert!(!live.is_relevant(&event(MutationKind::LinkCreated, 0xAA, Some("AllHolonNodes"))));
+        assert!(live.is_relevant(&event(MutationKind::LinkDeleted, 0xAA, Some(SMART_LINK_TYPE))));
+        Ok(())
+    }
+
+    #[test]
+    fn query_watch_ignores_events_outside_its_footprint() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut source = new_test_holon(&context, "source")?;
+        source.add_related_holons("Members", vec![saved_holon(&context, 1)])?;
+        let live = watch_members_query(&context, &source)?;
+
+        assert!(live.is_relevant(&event(MutationKind::HolonUpdated, 1, None)));
+        assert!(!live.is_relevant(&event(MutationKind::HolonUpdated, 9, None)));
+        assert!(!live.is_relevant(&event(MutationKind::HolonCreated, 1, None)));
+        // The only traversed source is transient, so no saved link can change the result.
+        assert!(!live.is_relevant(&event(MutationKind::LinkCreated, 0xAA, Some(SMART_LINK_TYPE))));
+        Ok(())
+    }
+
+    #[test]
+    fn query_watch_reevaluation_does_not_grow_the_transient_pool() -> Result<(), HolonError> {
+        let context = build_context();
+        let member = saved_holon(&context, 1);
+        let mut source = new_test_holon(&context, "source")?;
+        source.add_related_holons("Members", vec![member.clone()])?;
+        let mut live = watch_members_query(&context, &source)?;
+        let transient_count = context.lookup().transient_count()?;
+
+        for _ in 0..3 {
+            let delta = live.apply(&event(MutationKind::HolonUpdated, 1, None))?.unwrap();
+            assert_eq!(delta.changed.len(), 1);
+            assert_eq!(member_key(&delta.changed[0]), member_key(&member));
+            assert_eq!(context.lookup().transient_count()?, transient_count);
+        }
+        assert!(live.refresh()?.is_empty());
+        assert_eq!(context.lookup().transient_count()?, transient_count);
+        assert_eq!(live.members().len(), 1);
+        Ok(())
+    }
+}
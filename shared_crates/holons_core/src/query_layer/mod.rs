@@ -1,2 +1,32 @@
+pub mod aggregation;
+pub mod bound_holon_collection;
+pub mod collection_paging;
+pub mod live_query;
+pub mod navigation_binding_set;
+pub mod property_predicate;
 pub mod query;
+pub mod query_executor;
+pub mod query_parameters;
+pub mod query_profile;
+pub mod relationship_path;
+pub use aggregation::{aggregate, AggregateFunction};
+pub use bound_holon_collection::BoundHolonCollection;
+pub use collection_paging::{
+    page_collection, CollectionPage, PageCursor, PageRequest, SortDirection, SortKey,
+};
+pub use live_query::{LiveQuery, LiveQueryWatch, ResultDelta};
+pub use navigation_binding_set::{
+    BindingLink, BindingOrigin, NavigationBinding, NavigationBindingSet, NavigationTopology,
+    VariableName,
+};
+pub use property_predicate::PropertyPredicate;
 pub use query::*;
+pub use query_executor::{
+    execute_query, execute_query_with_parameters, QueryExecution, QueryExecutionStatus,
+};
+pub use query_parameters::QueryParameterBinder;
+pub use query_profile::{
+    explain_query, profile_query, ExpansionForecast, ExplainNode, ExpressionProfile,
+    QueryExplanation, QueryProfile,
+};
+pub use relationship_path::{PathStep, RelationshipPath, DEFAULT_MAX_PATH_DEPTH};
@@ -0,0 +1,401 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::descriptors::accessor_helpers;
+use crate::query_layer::{BoundHolonCollection, RelationshipPath};
+use crate::reference_layer::HolonReference;
+use base_types::MapString;
+use core_types::HolonError;
+
+/// Plan and binding-layer symbol naming a value during navigation execution.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VariableName(pub MapString);
+
+impl fmt::Display for VariableName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for VariableName {
+    fn from(name: &str) -> Self {
+        Self(MapString(name.to_string()))
+    }
+}
+
+/// A value bound to a [`VariableName`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavigationBinding {
+    Holon(HolonReference),
+    Collection(BoundHolonCollection),
+}
+
+impl NavigationBinding {
+    /// Returns the bound holons: the single holon, or the collection's members.
+    pub fn members(&self) -> Result<Vec<HolonReference>, HolonError> {
+        match self {
+            Self::Holon(holon) => Ok(vec![holon.clone()]),
+            Self::Collection(collection) => {
+                let members_lock = collection.members()?;
+                let members = members_lock.read().map_err(accessor_helpers::lock_error)?;
+                Ok(members.get_members().clone())
+            }
+        }
+    }
+}
+
+/// How a binding came to exist.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindingOrigin {
+    /// Supplied directly by the caller.
+    Bound,
+    /// Produced by following `path` from each member of `source`.
+    Expanded { source: VariableName, path: RelationshipPath },
+    /// Members of `left` that are also members of `right`.
+    Intersected { left: VariableName, right: VariableName },
+    /// Members of the expansion source of `expanded` linked to a member of `matching`.
+    Joined { expanded: VariableName, matching: VariableName },
+}
+
+impl BindingOrigin {
+    /// Returns the variables this binding was produced from.
+    pub fn inputs(&self) -> Vec<&VariableName> {
+        match self {
+            Self::Bound => Vec::new(),
+            Self::Expanded { source, .. } => vec![source],
+            Self::Intersected { left, right } => vec![left, right],
+            Self::Joined { expanded, matching } => vec![expanded, matching],
+        }
+    }
+}
+
+/// One source-to-target hop recorded while expanding a binding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingLink {
+    pub source: HolonReference,
+    pub target: HolonReference,
+}
+
+/// Provenance for a [`NavigationBindingSet`]: which binding produced which,
+/// and for expansions, which source member reached which target member.
+#[derive(Debug, Clone, Default)]
+pub struct NavigationTopology {
+    origins: BTreeMap<VariableName, BindingOrigin>,
+    links: BTreeMap<VariableName, Vec<BindingLink>>,
+}
+
+impl NavigationTopology {
+    pub fn origin(&self, name: &VariableName) -> Option<&BindingOrigin> {
+        self.origins.get(name)
+    }
+
+    /// Returns the member-level links recorded for an expanded binding.
+    pub fn links(&self, name: &VariableName) -> &[BindingLink] {
+        self.links.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns the bindings that were produced from `name`.
+    pub fn derived_from(&self, name: &VariableName) -> Vec<&VariableName> {
+        self.origins
+            .iter()
+            .filter(|(_, origin)| origin.inputs().contains(&name))
+            .map(|(derived, _)| derived)
+            .collect()
+    }
+}
+
+/// In-memory closure object for bound-first navigation execution.
+///
+/// Carries named holon and collection bindings plus the topology needed to
+/// compose later steps. Derived collections are created as transient
+/// `BoundHolonCollection` holons in the owning transaction; the set itself is
+/// never holon-backed. Variables are bound once.
+pub struct NavigationBindingSet {
+    context: Arc<TransactionContext>,
+    bindings: BTreeMap<VariableName, NavigationBinding>,
+    topology: NavigationTopology,
+}
+
+impl NavigationBindingSet {
+    pub fn new(context: Arc<TransactionContext>) -> Self {
+        Self { context, bindings: BTreeMap::new(), topology: NavigationTopology::default() }
+    }
+
+    pub fn bind_holon(
+        &mut self,
+        name: VariableName,
+        holon: HolonReference,
+    ) -> Result<&NavigationBinding, HolonError> {
+        self.insert(name, NavigationBinding::Holon(holon), BindingOrigin::Bound, Vec::new())
+    }
+
+    pub fn bind_collection(
+        &mut self,
+        name: VariableName,
+        collection: BoundHolonCollection,
+    ) -> Result<&NavigationBinding, HolonError> {
+        self.insert(
+            name,
+            NavigationBinding::Collection(collection),
+            BindingOrigin::Bound,
+            Vec::new(),
+        )
+    }
+
+    pub fn get(&self, name: &VariableName) -> Option<&NavigationBinding> {
+        self.bindings.get(name)
+    }
+
+    /// Returns the binding for `name` or reports it as unbound.
+    pub fn require(&self, name: &VariableName) -> Result<&NavigationBinding, HolonError> {
+        self.bindings.get(name).ok_or_else(|| {
+            HolonError::InvalidParameter(format!("Navigation variable {name} is not bound"))
+        })
+    }
+
+    pub fn variables(&self) -> impl Iterator<Item = &VariableName> {
+        self.bindings.keys()
+    }
+
+    pub fn topology(&self) -> &NavigationTopology {
+        &self.topology
+    }
+
+    /// Binds `target` to the distinct holons reached by following `path` from
+    /// each member of `source`, recording one link per member-level hop.
+    pub fn expand(
+        &mut self,
+        source: &VariableName,
+        path: RelationshipPath,
+        target: VariableName,
+    ) -> Result<&NavigationBinding, HolonError> {
+        self.ensure_unbound(&target)?;
+        let mut links = Vec::new();
+        let mut targets: Vec<HolonReference> = Vec::new();
+        for member in self.require(source)?.members()? {
+            for reached in path.traverse(std::slice::from_ref(&member))? {
+                if !targets.contains(&reached) {
+                    targets.push(reached.clone());
+                }
+                links.push(BindingLink { source: member.clone(), target: reached });
+            }
+        }
+
+        let collection = BoundHolonCollection::new_transient(&self.context, targets)?;
+        self.insert(
+            target,
+            NavigationBinding::Collection(collection),
+            BindingOrigin::Expanded { source: source.clone(), path },
+            links,
+        )
+    }
+
+    /// Binds `target` to the members of `left` that are also members of
+    /// `right`, in `left` order.
+    pub fn intersect(
+        &mut self,
+        left: &VariableName,
+        right: &VariableName,
+        target: VariableName,
+    ) -> Result<&NavigationBinding, HolonError> {
+        self.ensure_unbound(&target)?;
+        let right_members = self.require(right)?.members()?;
+        let mut members: Vec<HolonReference> = Vec::new();
+        for member in self.require(left)?.members()? {
+            if right_members.contains(&member) && !members.contains(&member) {
+                members.push(member);
+            }
+        }
+
+        let collection = BoundHolonCollection::new_transient(&self.context, members)?;
+        self.insert(
+            target,
+            NavigationBinding::Collection(collection),
+            BindingOrigin::Intersected { left: left.clone(), right: right.clone() },
+            Vec::new(),
+        )
+    }
+
+    /// Binds `target` to the members of `expanded`'s source binding that
+    /// reached at least one member of `matching` during that expansion.
+    ///
+    /// For "books whose author also edited X": expand `books` to `authors`,
+    /// expand `x` to `edited_by`, then join `authors` with `edited_by`.
+    pub fn join(
+        &mut self,
+        expanded: &VariableName,
+        matching: &VariableName,
+        target: VariableName,
+    ) -> Result<&NavigationBinding, HolonError> {
+        self.ensure_unbound(&target)?;
+        let Some(BindingOrigin::Expanded { source, .. }) = self.topology.origin(expanded) else {
+            return Err(HolonError::InvalidParameter(format!(
+                "Navigation variable {expanded} was not produced by an expansion"
+            )));
+        };
+        let source_members = self.require(source)?.members()?;
+        let matching_members = self.require(matching)?.members()?;
+
+        let links = self.topology.links(expanded);
+        let mut members: Vec<HolonReference> = Vec::new();
+        for member in source_members {
+            let linked = links
+                .iter()
+                .any(|link| link.source == member && matching_members.contains(&link.target));
+            if linked && !members.contains(&member) {
+                members.push(member);
+            }
+        }
+
+        let collection = BoundHolonCollection::new_transient(&self.context, members)?;
+        self.insert(
+            target,
+            NavigationBinding::Collection(collection),
+            BindingOrigin::Joined { expanded: expanded.clone(), matching: matching.clone() },
+            Vec::new(),
+        )
+    }
+
+    fn ensure_unbound(&self, name: &VariableName) -> Result<(), HolonError> {
+        if self.bindings.contains_key(name) {
+            return Err(HolonError::DuplicateError(
+                "navigation bindings".to_string(),
+                name.to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn insert(
+        &mut self,
+        name: VariableName,
+        binding: NavigationBinding,
+        origin: BindingOrigin,
+        links: Vec<BindingLink>,
+    ) -> Result<&NavigationBinding, HolonError> {
+        self.ensure_unbound(&name)?;
+        self.topology.origins.insert(name.clone(), origin);
+        if !links.is_empty() {
+            self.topology.links.insert(name.clone(), links);
+        }
+        Ok(self.bindings.entry(name).or_insert(binding))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{build_context, new_test_holon};
+    use crate::reference_layer::{TransientReference, WritableHolon};
+
+    fn link(
+        source: &mut TransientReference,
+        relationship_name: &str,
+        targets: &[&TransientReference],
+    ) -> Result<(), HolonError> {
+        source.add_related_holons(
+            relationship_name,
+            targets.iter().map(|target| (*target).clone().into()).collect(),
+        )?;
+        Ok(())
+    }
+
+    fn references(holons: &[&TransientReference]) -> Vec<HolonReference> {
+        holons.iter().map(|holon| (*holon).clone().into()).collect()
+    }
+
+    #[test]
+    fn bindings_are_named_once_and_resolvable() -> Result<(), HolonError> {
+        let context = build_context();
+        let holon = new_test_holon(&context, "x")?;
+        let mut bindings = NavigationBindingSet::new(Arc::clone(&context));
+
+        bindings.bind_holon("x".into(), holon.clone().into())?;
+
+        assert_eq!(bindings.require(&"x".into())?.members()?, references(&[&holon]));
+        assert_eq!(bindings.topology().origin(&"x".into()), Some(&BindingOrigin::Bound));
+        assert!(matches!(
+            bindings.bind_holon("x".into(), holon.into()),
+            Err(HolonError::DuplicateError(_, _))
+        ));
+        assert!(matches!(bindings.require(&"y".into()), Err(HolonError::InvalidParameter(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn expand_records_origin_and_member_links() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut first = new_test_holon(&context, "first")?;
+        let mut second = new_test_holon(&context, "second")?;
+        let author = new_test_holon(&context, "author")?;
+        link(&mut first, "Authors", &[&author])?;
+        link(&mut second, "Authors", &[&author])?;
+
+        let mut bindings = NavigationBindingSet::new(Arc::clone(&context));
+        let books = BoundHolonCollection::new_transient(&context, references(&[&first, &second]))?;
+        bindings.bind_collection("books".into(), books)?;
+        bindings.expand(&"books".into(), RelationshipPath::parse("Authors")?, "authors".into())?;
+
+        let authors: VariableName = "authors".into();
+        assert_eq!(bindings.require(&authors)?.members()?, references(&[&author]));
+        assert_eq!(bindings.topology().links(&authors).len(), 2);
+        assert_eq!(bindings.topology().derived_from(&"books".into()), vec![&authors]);
+        Ok(())
+    }
+
+    #[test]
+    fn join_finds_books_whose_author_also_edited_x() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut novel = new_test_holon(&context, "novel")?;
+        let mut memoir = new_test_holon(&context, "memoir")?;
+        let ada = new_test_holon(&context, "ada")?;
+        let grace = new_test_holon(&context, "grace")?;
+        let mut anthology = new_test_holon(&context, "anthology")?;
+        link(&mut novel, "Authors", &[&ada])?;
+        link(&mut memoir, "Authors", &[&grace])?;
+        link(&mut anthology, "EditedBy", &[&ada])?;
+
+        let mut bindings = NavigationBindingSet::new(Arc::clone(&context));
+        let books = BoundHolonCollection::new_transient(&context, references(&[&novel, &memoir]))?;
+        bindings.bind_collection("books".into(), books)?;
+        bindings.bind_holon("x".into(), anthology.into())?;
+        bindings.expand(&"books".into(), RelationshipPath::parse("Authors")?, "authors".into())?;
+        bindings.expand(&"x".into(), RelationshipPath::parse("EditedBy")?, "editors".into())?;
+        bindings.join(&"authors".into(), &"editors".into(), "result".into())?;
+
+        assert_eq!(bindings.require(&"result".into())?.members()?, references(&[&novel]));
+        assert_eq!(
+            bindings.topology().origin(&"result".into()).map(BindingOrigin::inputs),
+            Some(vec![&VariableName::from("authors"), &VariableName::from("editors")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_keeps_left_order_and_rejects_unexpanded_joins() -> Result<(), HolonError> {
+        let context = build_context();
+        let a = new_test_holon(&context, "a")?;
+        let b = new_test_holon(&context, "b")?;
+        let c = new_test_holon(&context, "c")?;
+
+        let mut bindings = NavigationBindingSet::new(Arc::clone(&context));
+        bindings.bind_collection(
+            "left".into(),
+            BoundHolonCollection::new_transient(&context, references(&[&c, &b, &a]))?,
+        )?;
+        bindings.bind_collection(
+            "right".into(),
+            BoundHolonCollection::new_transient(&context, references(&[&a, &c]))?,
+        )?;
+        bindings.intersect(&"left".into(), &"right".into(), "both".into())?;
+
+        assert_eq!(bindings.require(&"both".into())?.members()?, references(&[&c, &a]));
+        assert!(matches!(
+            bindings.join(&"left".into(), &"right".into(), "joined".into()),
+            Err(HolonError::InvalidParameter(_))
+        ));
+        Ok(())
+    }
+}
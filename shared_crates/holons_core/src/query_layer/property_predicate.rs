@@ -0,0 +1,240 @@
+use crate::descriptors::{OperatorDescriptor, PropertyDescriptor, ValueDescriptor};
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use base_types::BaseValue;
+use core_types::{HolonError, PropertyName};
+use type_names::CoreOperatorTypeName;
+
+/// A descriptor-checked predicate of the form `<property> <operator> <operands>`.
+///
+/// The holon's property value is the first operand; `operands` supply the
+/// rest, so a binary operator such as `EqualsOperator` takes one operand here.
+/// Construction verifies the operand count against the operator's `Arity` and
+/// that the property's value type affords the operator, so evaluation only
+/// fails on runtime value mismatches.
+pub struct PropertyPredicate {
+    property_name: PropertyName,
+    value_type: ValueDescriptor,
+    operator: OperatorDescriptor,
+    operands: Vec<BaseValue>,
+}
+
+impl PropertyPredicate {
+    pub fn new(
+        property: &PropertyDescriptor,
+        operator: OperatorDescriptor,
+        operands: Vec<BaseValue>,
+    ) -> Result<Self, HolonError> {
+        let property_name = PropertyName(property.header().type_name()?);
+        let value_type = property.value_type()?;
+
+        let arity = usize::from(operator.arity()?);
+        if operands.len() + 1 != arity {
+            return Err(HolonError::InvalidParameter(format!(
+                "Operator {} on property {} expects {} operand(s) besides the property value, got {}",
+                operator.operator_name()?,
+                property_name,
+                arity.saturating_sub(1),
+                operands.len()
+            )));
+        }
+        if !value_type.supports_operator(&operator)? {
+            return Err(HolonError::UnsupportedOperator {
+                operator: operator.operator_name()?.to_string(),
+                value_type: value_type.header().type_name()?.to_string(),
+                descriptor: property_name.to_string(),
+            });
+        }
+
+        Ok(Self { property_name, value_type, operator, operands })
+    }
+
+    pub fn property_name(&self) -> &PropertyName {
+        &self.property_name
+    }
+
+    pub fn operator(&self) -> &OperatorDescriptor {
+        &self.operator
+    }
+
+    pub fn operands(&self) -> &[BaseValue] {
+        &self.operands
+    }
+
+    /// Evaluates the predicate against one holon.
+    ///
+    /// A holon without a value for the property satisfies only `IsNullOperator`.
+    pub fn evaluate<H: ReadableHolon + ?Sized>(&self, holon: &H) -> Result<bool, HolonError> {
+        let Some(value) = holon.property_value(&self.property_name)? else {
+            return Ok(self.operator.operator_name()?
+                == CoreOperatorTypeName::IsNullOperator.as_operator_name());
+        };
+
+        let mut operands = Vec::with_capacity(self.operands.len() + 1);
+        operands.push(value);
+        operands.extend(self.operands.iter().cloned());
+        self.value_type.evaluate_operator(&self.operator, &operands)
+    }
+
+    /// Returns the holons that satisfy the predicate, preserving input order.
+    pub fn filter(&self, holons: &[HolonReference]) -> Result<Vec<HolonReference>, HolonError> {
+        let mut matches = Vec::new();
+        for holon in holons {
+            if self.evaluate(holon)? {
+                matches.push(holon.clone());
+            }
+        }
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        build_context, new_descriptor_holon, new_property_descriptor_holon, new_test_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use base_types::{MapInteger, MapString};
+    use core_types::TypeKind;
+    use std::sync::Arc;
+    use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+    fn new_operator(
+        context: &Arc<TransactionContext>,
+        type_name: &str,
+        arity: i64,
+    ) -> Result<TransientReference, HolonError> {
+        let mut operator = new_descriptor_holon(context, type_name, type_name, TypeKind::Holon)?;
+        operator.with_property_value(CorePropertyTypeName::Arity, arity)?;
+        Ok(operator)
+    }
+
+    fn page_count_property(
+        context: &Arc<TransactionContext>,
+        afforded: &[&TransientReference],
+    ) -> Result<PropertyDescriptor, HolonError> {
+        let mut value_type =
+            new_descriptor_holon(context, "integer-value", "IntegerValueType", "Value")?;
+        value_type.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            afforded.iter().map(|operator| (*operator).clone().into()).collect(),
+        )?;
+        let property = new_property_descriptor_holon(
+            context,
+            "page-count",
+            "PageCount",
+            "page_count",
+            false,
+            value_type.into(),
+        )?;
+        Ok(PropertyDescriptor::from_holon(property.into()))
+    }
+
+    fn book(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        pages: Option<i64>,
+    ) -> Result<HolonReference, HolonError> {
+        let mut book = new_test_holon(context, key)?;
+        if let Some(pages) = pages {
+            book.with_property_value("PageCount", pages)?;
+        }
+        Ok(book.into())
+    }
+
+    #[test]
+    fn filter_keeps_holons_satisfying_afforded_operator() -> Result<(), HolonError> {
+        let context = build_context();
+        let less_than = new_operator(&context, "LessThanOperator", 2)?;
+        let property = page_count_property(&context, &[&less_than])?;
+        let short = book(&context, "short", Some(90))?;
+        let long = book(&context, "long", Some(400))?;
+        let unknown = book(&context, "unknown", None)?;
+
+        let predicate = PropertyPredicate::new(
+            &property,
+            OperatorDescriptor::from_holon(less_than.into()),
+            vec![BaseValue::IntegerValue(MapInteger(100))],
+        )?;
+
+        assert_eq!(predicate.filter(&[short.clone(), long, unknown])?, vec![short]);
+        Ok(())
+    }
+
+    #[test]
+    fn new_rejects_operators_not_afforded_by_property_value_type() -> Result<(), HolonError> {
+        let context = build_context();
+        let equals = new_operator(&context, "EqualsOperator", 2)?;
+        let less_than = new_operator(&context, "LessThanOperator", 2)?;
+        let property = page_count_property(&context, &[&equals])?;
+
+        let result = PropertyPredicate::new(
+            &property,
+            OperatorDescriptor::from_holon(less_than.into()),
+            vec![BaseValue::IntegerValue(MapInteger(100))],
+        );
+
+        assert!(matches!(
+            result,
+            Err(HolonError::UnsupportedOperator { operator, value_type, .. })
+                if operator == "LessThanOperator" && value_type == "IntegerValueType"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn new_rejects_operand_counts_that_do_not_match_arity() -> Result<(), HolonError> {
+        let context = build_context();
+        let equals = new_operator(&context, "EqualsOperator", 2)?;
+        let property = page_count_property(&context, &[&equals])?;
+
+        let result = PropertyPredicate::new(
+            &property,
+            OperatorDescriptor::from_holon(equals.into()),
+            vec![
+                BaseValue::IntegerValue(MapInteger(1)),
+                BaseValue::StringValue(MapString("extra".to_string())),
+            ],
+        );
+
+        assert!(matches!(result, Err(HolonError::InvalidParameter(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_surfaces_operand_kind_mismatches() -> Result<(), HolonError> {
+        let context = build_context();
+        let equals = new_operator(&context, "EqualsOperator", 2)?;
+        let property = page_count_property(&context, &[&equals])?;
+        let predicate = PropertyPredicate::new(
+            &property,
+            OperatorDescriptor::from_holon(equals.into()),
+            vec![BaseValue::StringValue(MapString("ninety".to_string()))],
+        )?;
+
+        assert!(matches!(
+            predicate.evaluate(&book(&context, "short", Some(90))?),
+            Err(HolonError::ValueKindMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn is_null_matches_only_holons_without_a_value() -> Result<(), HolonError> {
+        let context = build_context();
+        let is_null = new_operator(&context, "IsNullOperator", 1)?;
+        let property = page_count_property(&context, &[&is_null])?;
+        let short = book(&context, "short", Some(90))?;
+        let unknown = book(&context, "unknown", None)?;
+
+        let predicate = PropertyPredicate::new(
+            &property,
+            OperatorDescriptor::from_holon(is_null.into()),
+            Vec::new(),
+        )?;
+
+        assert_eq!(predicate.filter(&[short, unknown.clone()])?, vec![unknown]);
+        Ok(())
+    }
+}
@@ -0,0 +1,1272 @@
+use std::sync::Arc;
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::descriptors::accessor_helpers;
+use crate::query_layer::{
+    aggregate, AggregateFunction, BoundHolonCollection, PathStep, PropertyPredicate,
+    QueryParameterBinder, RelationshipPath, DEFAULT_MAX_PATH_DEPTH,
+};
+use crate::reference_layer::{HolonReference, ReadableHolon, TransientReference, WritableHolon};
+use base_types::MapString;
+use core_types::{HolonError, PropertyName, RelationshipName, TemporaryId};
+use type_names::{CoreHolonTypeName, CorePropertyTypeName, CoreRelationshipTypeName};
+
+/// Lifecycle states recorded in `ExecutionStatus` on execution artifacts.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum QueryExecutionStatus {
+    Pending,
+    Running,
+    Complete,
+    Failed,
+}
+
+impl QueryExecutionStatus {
+    /// Returns the `QueryExecutionStatus` enum variant name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Running => "Running",
+            Self::Complete => "Complete",
+            Self::Failed => "Failed",
+        }
+    }
+
+    /// Parses the enum variant name stored on an execution artifact.
+    pub fn parse(value: &MapString) -> Result<Self, HolonError> {
+        match value.0.as_str() {
+            "Pending" => Ok(Self::Pending),
+            "Running" => Ok(Self::Running),
+            "Complete" => Ok(Self::Complete),
+            "Failed" => Ok(Self::Failed),
+            _ => Err(HolonError::InvalidParameter(format!(
+                "Unknown query execution status: {}",
+                value
+            ))),
+        }
+    }
+
+    /// Reads `ExecutionStatus` from an `ExecutionInstance` or
+    /// `QueryExpressionExecution` holon.
+    pub fn of(execution: &HolonReference) -> Result<Self, HolonError> {
+        Self::parse(&accessor_helpers::require_enum_string(
+            execution,
+            CorePropertyTypeName::ExecutionStatus,
+        )?)
+    }
+}
+
+/// Outcome of a successful Query execution.
+#[derive(Debug, Clone)]
+pub struct QueryExecution {
+    execution_instance: HolonReference,
+    result: BoundHolonCollection,
+}
+
+impl QueryExecution {
+    /// Returns the transient `ExecutionInstance` recording this run.
+    pub fn execution_instance(&self) -> &HolonReference {
+        &self.execution_instance
+    }
+
+    /// Returns the final bound collection produced by the root expression chain.
+    pub fn result(&self) -> &BoundHolonCollection {
+        &self.result
+    }
+
+    /// Consumes the outcome and returns the final bound collection.
+    pub fn into_result(self) -> BoundHolonCollection {
+        self.result
+    }
+}
+
+/// Concrete expression operators the executor knows how to run.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ExpressionKind {
+    SubTree,
+    RelationshipTraversal,
+    PathTraversal,
+    Aggregation,
+    TypeFilter,
+    PropertyFilter,
+    ExistsFilter,
+}
+
+impl ExpressionKind {
+    fn from_type_name(type_name: &MapString) -> Option<Self> {
+        if *type_name == CoreHolonTypeName::QuerySubTree.as_holon_name() {
+            Some(Self::SubTree)
+        } else if *type_name == CoreHolonTypeName::RelationshipTraversal.as_holon_name() {
+            Some(Self::RelationshipTraversal)
+        } else if *type_name == CoreHolonTypeName::PathTraversal.as_holon_name() {
+            Some(Self::PathTraversal)
+        } else if *type_name == CoreHolonTypeName::Aggregation.as_holon_name() {
+            Some(Self::Aggregation)
+        } else if *type_name == CoreHolonTypeName::TypeFilter.as_holon_name() {
+            Some(Self::TypeFilter)
+        } else if *type_name == CoreHolonTypeName::PropertyFilter.as_holon_name() {
+            Some(Self::PropertyFilter)
+        } else if *type_name == CoreHolonTypeName::ExistsFilter.as_holon_name() {
+            Some(Self::ExistsFilter)
+        } else {
+            None
+        }
+    }
+
+    /// Resolves the operator for `expression` from its descriptor's `Extends` chain.
+    pub(crate) fn of(expression: &HolonReference) -> Result<Self, HolonError> {
+        let descriptor = expression.get_descriptor()?.ok_or_else(|| {
+            HolonError::MissingDescribedBy { holon: accessor_helpers::descriptor_label(expression) }
+        })?;
+        accessor_helpers::search_extends_chain(
+            &descriptor,
+            &Self::expected_type_names(),
+            Self::from_type_name,
+        )
+    }
+
+    fn expected_type_names() -> [MapString; 7] {
+        [
+            CoreHolonTypeName::QuerySubTree.as_holon_name(),
+            CoreHolonTypeName::RelationshipTraversal.as_holon_name(),
+            CoreHolonTypeName::PathTraversal.as_holon_name(),
+            CoreHolonTypeName::Aggregation.as_holon_name(),
+            CoreHolonTypeName::TypeFilter.as_holon_name(),
+            CoreHolonTypeName::PropertyFilter.as_holon_name(),
+            CoreHolonTypeName::ExistsFilter.as_holon_name(),
+        ]
+    }
+}
+
+/// Executes a saved or transient `Query` holon against `input`.
+///
+/// Execution starts at the query's `RootExpression` and follows `Next` until
+/// the chain ends. A `QuerySubTree` runs its ordered `Subtree` members as a
+/// pipeline, each member chain consuming the previous member's result, before
+/// its own `Next` continuation runs. Concrete expressions are dispatched by the
+/// type name reached on their descriptor's `Extends` chain. An `Aggregation`
+/// replaces its input with transient `AggregateResult` holons. The filter
+/// expressions preserve input order: `TypeFilter` and `PropertyFilter` test
+/// each holon directly, while `ExistsFilter` runs its `Condition` chain once
+/// per input holon and keeps those that reach at least one holon.
+///
+/// Every run creates a transient `ExecutionInstance` holding one
+/// `QueryExpressionExecution` per invoked expression, in invocation order, each
+/// with its `Input` and `Result` collections. These are Transient Execution
+/// Artifacts: nothing is staged. When an expression fails, its execution and
+/// the instance are marked `Failed` before the error is returned.
+pub fn execute_query(
+    context: &Arc<TransactionContext>,
+    query: &HolonReference,
+    input: &BoundHolonCollection,
+) -> Result<QueryExecution, HolonError> {
+    run_query(context, query, input, &[], None)
+}
+
+/// Executes `query` like [`execute_query`], applying `QueryParameterBinding` holons.
+///
+/// `parameters` are checked by a [`QueryParameterBinder`] before anything runs;
+/// declarations left unbound take their `DefaultBinding`. A binding applies to
+/// every expression that declares, through `ExpressionParameters`, a
+/// `QueryParameterDeclaration` with the same `ParameterName` as the declaration
+/// it names through `BindsParameter`; it is recorded as one of that
+/// expression's `RuntimeParameters` on its `QueryExpressionExecution`, and any
+/// expression property it carries (e.g. `RelationshipName` or `FilterOperand`)
+/// replaces the expression's own value for that execution.
+pub fn execute_query_with_parameters(
+    context: &Arc<TransactionContext>,
+    query: &HolonReference,
+    input: &BoundHolonCollection,
+    parameters: &[HolonReference],
+) -> Result<QueryExecution, HolonError> {
+    run_query(context, query, input, parameters, None)
+}
+
+/// Receives a callback around every expression invocation of a run.
+///
+/// `enter` and `exit` calls nest like the invocations themselves: an
+/// expression that runs nested chains (`QuerySubTree`, `ExistsFilter`) exits
+/// after all of its nested expressions.
+pub(crate) trait ExpressionObserver {
+    fn enter(
+        &mut self,
+        expression: &HolonReference,
+        input: &BoundHolonCollection,
+        depth: usize,
+    ) -> Result<(), HolonError>;
+
+    /// `result` is `None` when the expression failed.
+    fn exit(&mut self, result: Option<&BoundHolonCollection>) -> Result<(), HolonError>;
+
+    /// Called, between `enter` and `exit` of a traversing expression, for each
+    /// holon whose `relationship` the traversal read.
+    fn expanded(
+        &mut self,
+        _source: &HolonReference,
+        _relationship: &RelationshipName,
+    ) -> Result<(), HolonError> {
+        Ok(())
+    }
+}
+
+/// Runs `query` like [`execute_query_with_parameters`], reporting each expression
+/// invocation to `observer`.
+pub(crate) fn run_query(
+    context: &Arc<TransactionContext>,
+    query: &HolonReference,
+    input: &BoundHolonCollection,
+    parameters: &[HolonReference],
+    observer: Option<&mut dyn ExpressionObserver>,
+) -> Result<QueryExecution, HolonError> {
+    run_query_recording_artifacts(context, query, input, parameters, observer, &mut Vec::new())
+}
+
+/// Runs `query` against `input` for its result members alone, reporting each
+/// expression invocation to `observer`.
+///
+/// The `ExecutionInstance` and every other transient holon the run creates are
+/// released before returning, whether or not the run succeeds, except those that
+/// are themselves result members (`AggregateResult` holons). Their ids are
+/// returned with the members; the caller releases them once it no longer needs
+/// the members.
+pub(crate) fn evaluate_query(
+    context: &Arc<TransactionContext>,
+    query: &HolonReference,
+    input: &BoundHolonCollection,
+    observer: Option<&mut dyn ExpressionObserver>,
+) -> Result<(Vec<HolonReference>, Vec<TemporaryId>), HolonError> {
+    let mut artifacts = Vec::new();
+    let outcome =
+        run_query_recording_artifacts(context, query, input, &[], observer, &mut artifacts)
+            .and_then(|execution| collection_members(execution.result()));
+
+    let retained: Vec<TemporaryId> = match &outcome {
+        Ok(members) => members
+            .iter()
+            .filter_map(|member| match member {
+                HolonReference::Transient(transient) => Some(transient.temporary_id()),
+                _ => None,
+            })
+            .filter(|id| artifacts.contains(id))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    artifacts.retain(|id| !retained.contains(id));
+    context.release_transient_holons(&artifacts)?;
+
+    Ok((outcome?, retained))
+}
+
+/// Runs `query` like [`run_query`], appending the id of every transient holon
+/// the run creates to `artifacts`, including when the run fails.
+fn run_query_recording_artifacts(
+    context: &Arc<TransactionContext>,
+    query: &HolonReference,
+    input: &BoundHolonCollection,
+    parameters: &[HolonReference],
+    observer: Option<&mut dyn ExpressionObserver>,
+    artifacts: &mut Vec<TemporaryId>,
+) -> Result<QueryExecution, HolonError> {
+    let root =
+        accessor_helpers::require_single_related(query, CoreRelationshipTypeName::RootExpression)?;
+    let parameters = QueryParameterBinder::for_query(query)?.bind(parameters)?;
+
+    let mut instance =
+        context.mutation().new_holon(Some(MapString("execution-instance".to_string())))?;
+    instance
+        .with_property_value(
+            CorePropertyTypeName::ExecutionStatus,
+            QueryExecutionStatus::Running.as_str(),
+        )?
+        .add_related_holons(CoreRelationshipTypeName::ExecutesQuery, vec![query.clone()])?;
+
+    let mut run = QueryRun {
+        context,
+        instance: instance.clone(),
+        parameters,
+        bindings_in_scope: Vec::new(),
+        active: Vec::new(),
+        open_chains: 0,
+        observer,
+        artifacts: vec![instance.temporary_id()],
+    };
+    let outcome = run.execute_chain(&root, input.clone());
+    artifacts.append(&mut run.artifacts);
+    match outcome {
+        Ok(result) => {
+            instance
+                .add_related_holons(
+                    CoreRelationshipTypeName::ExecutionResult,
+                    vec![result.as_holon_reference().clone()],
+                )?
+                .with_property_value(
+                    CorePropertyTypeName::ExecutionStatus,
+                    QueryExecutionStatus::Complete.as_str(),
+                )?;
+            Ok(QueryExecution { execution_instance: instance.into(), result })
+        }
+        Err(error) => {
+            instance.with_property_value(
+                CorePropertyTypeName::ExecutionStatus,
+                QueryExecutionStatus::Failed.as_str(),
+            )?;
+            Err(error)
+        }
+    }
+}
+
+/// Per-run executor state.
+struct QueryRun<'a, 'o> {
+    context: &'a Arc<TransactionContext>,
+    instance: TransientReference,
+    /// Effective `QueryParameterBinding`s keyed by the `ParameterName` they bind.
+    parameters: Vec<(MapString, HolonReference)>,
+    /// Runtime parameters of each expression being executed, innermost last.
+    bindings_in_scope: Vec<Vec<HolonReference>>,
+    /// Expressions on the current containment/continuation path, used to
+    /// reject cyclic `Next` or `Subtree` structures.
+    active: Vec<HolonReference>,
+    /// Number of chains being executed; the root chain is the first.
+    open_chains: usize,
+    observer: Option<&'o mut dyn ExpressionObserver>,
+    /// Ids of the transient holons this run has created.
+    artifacts: Vec<TemporaryId>,
+}
+
+impl QueryRun<'_, '_> {
+    fn execute_chain(
+        &mut self,
+        start: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let depth = self.active.len();
+        let mut expression = start.clone();
+        let mut current = input;
+        self.open_chains += 1;
+
+        loop {
+            if self.active.contains(&expression) {
+                return Err(HolonError::InvalidState(format!(
+                    "QueryExpression {} is reachable from itself through Next or Subtree",
+                    accessor_helpers::descriptor_label(&expression)
+                )));
+            }
+            self.active.push(expression.clone());
+            current = self.execute_expression(&expression, current)?;
+
+            match accessor_helpers::optional_single_related(
+                &expression,
+                CoreRelationshipTypeName::Next,
+            )? {
+                Some(next) => expression = next,
+                None => break,
+            }
+        }
+
+        self.active.truncate(depth);
+        self.open_chains -= 1;
+        Ok(current)
+    }
+
+    fn execute_expression(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let mut execution = self
+            .context
+            .mutation()
+            .new_holon(Some(MapString("query-expression-execution".to_string())))?;
+        self.artifacts.push(execution.temporary_id());
+        execution
+            .with_property_value(
+                CorePropertyTypeName::ExecutionStatus,
+                QueryExecutionStatus::Running.as_str(),
+            )?
+            .add_related_holons(
+                CoreRelationshipTypeName::ExecutesExpression,
+                vec![expression.clone()],
+            )?
+            .add_related_holons(
+                CoreRelationshipTypeName::Input,
+                vec![input.as_holon_reference().clone()],
+            )?;
+        let runtime_parameters = self.runtime_parameters(expression)?;
+        if !runtime_parameters.is_empty() {
+            execution.add_related_holons(
+                CoreRelationshipTypeName::RuntimeParameters,
+                runtime_parameters.clone(),
+            )?;
+        }
+        self.instance.add_related_holons(
+            CoreRelationshipTypeName::ExpressionExecutions,
+            vec![execution.clone().into()],
+        )?;
+
+        if let Some(observer) = self.observer.as_deref_mut() {
+            observer.enter(expression, &input, self.open_chains - 1)?;
+        }
+        self.bindings_in_scope.push(runtime_parameters);
+        let outcome = self.dispatch(expression, input);
+        self.bindings_in_scope.pop();
+        if let Some(observer) = self.observer.as_deref_mut() {
+            observer.exit(outcome.as_ref().ok())?;
+        }
+
+        match outcome {
+            Ok(result) => {
+                execution
+                    .add_related_holons(
+                        CoreRelationshipTypeName::Result,
+                        vec![result.as_holon_reference().clone()],
+                    )?
+                    .with_property_value(
+                        CorePropertyTypeName::ExecutionStatus,
+                        QueryExecutionStatus::Complete.as_str(),
+                    )?;
+                Ok(result)
+            }
+            Err(error) => {
+                execution.with_property_value(
+                    CorePropertyTypeName::ExecutionStatus,
+                    QueryExecutionStatus::Failed.as_str(),
+                )?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns the supplied bindings whose names `expression` declares.
+    fn runtime_parameters(
+        &self,
+        expression: &HolonReference,
+    ) -> Result<Vec<HolonReference>, HolonError> {
+        if self.parameters.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut bindings = Vec::new();
+        for declaration in
+            related_members(expression, CoreRelationshipTypeName::ExpressionParameters)?
+        {
+            let name = accessor_helpers::require_string(
+                &declaration,
+                CorePropertyTypeName::ParameterName,
+            )?;
+            bindings.extend(
+                self.parameters
+                    .iter()
+                    .filter(|(bound_name, _)| *bound_name == name)
+                    .map(|(_, binding)| binding.clone()),
+            );
+        }
+        Ok(bindings)
+    }
+
+    /// Returns the holon `property` is read from for the executing `expression`:
+    /// the first of its runtime parameters that carries the property, otherwise
+    /// the expression itself.
+    fn property_source(
+        &self,
+        expression: &HolonReference,
+        property: &CorePropertyTypeName,
+    ) -> Result<HolonReference, HolonError> {
+        for binding in self.bindings_in_scope.last().into_iter().flatten() {
+            if binding.property_value(property)?.is_some() {
+                return Ok(binding.clone());
+            }
+        }
+        Ok(expression.clone())
+    }
+
+    fn require_string(
+        &self,
+        expression: &HolonReference,
+        property: CorePropertyTypeName,
+    ) -> Result<MapString, HolonError> {
+        accessor_helpers::require_string(&self.property_source(expression, &property)?, property)
+    }
+
+    fn optional_string(
+        &self,
+        expression: &HolonReference,
+        property: CorePropertyTypeName,
+    ) -> Result<Option<MapString>, HolonError> {
+        accessor_helpers::optional_string(&self.property_source(expression, &property)?, property)
+    }
+
+    fn dispatch(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        match ExpressionKind::of(expression)? {
+            ExpressionKind::SubTree => self.execute_subtree(expression, input),
+            ExpressionKind::RelationshipTraversal => self.traverse_relationship(expression, input),
+            ExpressionKind::PathTraversal => self.traverse_path_expression(expression, input),
+            ExpressionKind::Aggregation => self.aggregate(expression, input),
+            ExpressionKind::TypeFilter => self.filter_by_type(expression, input),
+            ExpressionKind::PropertyFilter => self.filter_by_property(expression, input),
+            ExpressionKind::ExistsFilter => self.filter_by_condition(expression, input),
+        }
+    }
+
+    fn execute_subtree(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let subtree = related_members(expression, CoreRelationshipTypeName::Subtree)?;
+        if subtree.is_empty() {
+            return Err(HolonError::MissingRequiredRelationship {
+                relationship: CoreRelationshipTypeName::Subtree.as_relationship_name().to_string(),
+                descriptor: accessor_helpers::descriptor_label(expression),
+            });
+        }
+
+        let mut current = input;
+        for member in &subtree {
+            current = self.execute_chain(member, current)?;
+        }
+        Ok(current)
+    }
+
+    fn traverse_relationship(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let relationship_name = RelationshipName(
+            self.require_string(expression, CorePropertyTypeName::RelationshipName)?,
+        );
+        let path = RelationshipPath::new(vec![PathStep::single(relationship_name)])?;
+        self.traverse_path(&path, input)
+    }
+
+    fn traverse_path_expression(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let path = RelationshipPath::parse(
+            &self.require_string(expression, CorePropertyTypeName::PathExpression)?.0,
+        )?;
+        self.traverse_path(&path, input)
+    }
+
+    fn traverse_path(
+        &mut self,
+        path: &RelationshipPath,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let mut expansions = Vec::new();
+        let targets = path.traverse_recording_expansions(
+            &collection_members(&input)?,
+            DEFAULT_MAX_PATH_DEPTH,
+            &mut expansions,
+        )?;
+        if let Some(observer) = self.observer.as_deref_mut() {
+            for (source, relationship) in &expansions {
+                observer.expanded(source, relationship)?;
+            }
+        }
+        self.new_collection(targets)
+    }
+
+    fn aggregate(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let function = AggregateFunction::of(expression)?;
+        let property = self
+            .optional_string(expression, CorePropertyTypeName::AggregateProperty)?
+            .map(PropertyName);
+        let results =
+            aggregate(self.context, function, property.as_ref(), &collection_members(&input)?)?;
+        for result in &results {
+            if let HolonReference::Transient(transient) = result {
+                self.artifacts.push(transient.temporary_id());
+            }
+        }
+        self.new_collection(results)
+    }
+
+    fn filter_by_type(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let type_name = self.require_string(expression, CorePropertyTypeName::FilterTypeName)?;
+        let mut matches = Vec::new();
+        for member in collection_members(&input)? {
+            if is_described_as(&member, &type_name)? {
+                matches.push(member);
+            }
+        }
+        self.new_collection(matches)
+    }
+
+    fn filter_by_property(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let property_name =
+            PropertyName(self.require_string(expression, CorePropertyTypeName::FilterProperty)?);
+        let operator_name =
+            self.require_string(expression, CorePropertyTypeName::FilterOperator)?;
+        let operands: Vec<_> = self
+            .property_source(expression, &CorePropertyTypeName::FilterOperand)?
+            .property_value(CorePropertyTypeName::FilterOperand)?
+            .into_iter()
+            .collect();
+
+        // Predicates are resolved once per member descriptor. Members that are
+        // undescribed, or whose descriptor declares no such property, have no
+        // value for it and are removed rather than failing the query.
+        let mut predicates: Vec<(MapString, Option<PropertyPredicate>)> = Vec::new();
+        let mut matches = Vec::new();
+        for member in collection_members(&input)? {
+            let descriptor = match member.holon_descriptor() {
+                Ok(descriptor) => descriptor,
+                Err(HolonError::MissingDescribedBy { .. }) => continue,
+                Err(error) => return Err(error),
+            };
+            let descriptor_name = descriptor.header().type_name()?;
+            let index = match predicates.iter().position(|(name, _)| *name == descriptor_name) {
+                Some(index) => index,
+                None => {
+                    let predicate = match descriptor.get_property_by_name(&property_name) {
+                        Ok(property) => {
+                            let operator =
+                                property.value_type()?.affords_operator(&operator_name)?;
+                            Some(PropertyPredicate::new(&property, operator, operands.clone())?)
+                        }
+                        Err(HolonError::DescriptorDeclarationNotFound { kind, .. })
+                            if kind == "property" =>
+                        {
+                            None
+                        }
+                        Err(error) => return Err(error),
+                    };
+                    predicates.push((descriptor_name, predicate));
+                    predicates.len() - 1
+                }
+            };
+            if let Some(predicate) = &predicates[index].1 {
+                if predicate.evaluate(&member)? {
+                    matches.push(member);
+                }
+            }
+        }
+        self.new_collection(matches)
+    }
+
+    /// Creates a transient collection holding `members`, recorded as an
+    /// artifact of this run.
+    fn new_collection(
+        &mut self,
+        members: Vec<HolonReference>,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let collection = BoundHolonCollection::new_transient(self.context, members)?;
+        if let HolonReference::Transient(transient) = collection.as_holon_reference() {
+            self.artifacts.push(transient.temporary_id());
+        }
+        Ok(collection)
+    }
+
+    fn filter_by_condition(
+        &mut self,
+        expression: &HolonReference,
+        input: BoundHolonCollection,
+    ) -> Result<BoundHolonCollection, HolonError> {
+        let condition = accessor_helpers::require_single_related(
+            expression,
+            CoreRelationshipTypeName::Condition,
+        )?;
+        let mut matches = Vec::new();
+        for member in collection_members(&input)? {
+            let single = self.new_collection(vec![member.clone()])?;
+            let reached = self.execute_chain(&condition, single)?;
+            if !collection_members(&reached)?.is_empty() {
+                matches.push(member);
+            }
+        }
+        self.new_collection(matches)
+    }
+}
+
+/// Returns whether `holon`'s descriptor is, or extends, the type named `type_name`.
+/// Holons without a descriptor never match.
+pub(crate) fn is_described_as(
+    holon: &HolonReference,
+    type_name: &MapString,
+) -> Result<bool, HolonError> {
+    let Some(descriptor) = holon.get_descriptor()? else {
+        return Ok(false);
+    };
+    match accessor_helpers::search_extends_chain(
+        &descriptor,
+        std::slice::from_ref(type_name),
+        |candidate| (candidate == type_name).then_some(()),
+    ) {
+        Ok(()) => Ok(true),
+        Err(HolonError::WrongDescriptorKind { .. }) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+fn collection_members(
+    collection: &BoundHolonCollection,
+) -> Result<Vec<HolonReference>, HolonError> {
+    let members_lock = collection.members()?;
+    let members = members_lock.read().map_err(accessor_helpers::lock_error)?;
+    Ok(members.get_members().clone())
+}
+
+fn related_members(
+    holon: &HolonReference,
+    relationship_name: CoreRelationshipTypeName,
+) -> Result<Vec<HolonReference>, HolonError> {
+    let collection_lock = holon.related_holons(relationship_name)?;
+    let collection = collection_lock.read().map_err(accessor_helpers::lock_error)?;
+    Ok(collection.get_members().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{
+        build_context, core_holon_type_name, new_descriptor_holon, new_holon_type_descriptor,
+        new_property_descriptor_holon, new_test_holon,
+    };
+    use base_types::{BaseValue, MapInteger};
+    use core_types::TypeKind;
+
+    fn new_expression(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        descriptor: &TransientReference,
+    ) -> Result<TransientReference, HolonError> {
+        let mut expression = new_test_holon(context, key)?;
+        expression.with_descriptor(descriptor.clone().into())?;
+        Ok(expression)
+    }
+
+    fn new_traversal(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        relationship_name: &str,
+    ) -> Result<TransientReference, HolonError> {
+        let descriptor = new_descriptor_holon(
+            context,
+            &format!("{key}-descriptor"),
+            &core_holon_type_name(CoreHolonTypeName::RelationshipTraversal),
+            TypeKind::Holon,
+        )?;
+        let mut traversal = new_expression(context, key, &descriptor)?;
+        traversal.with_property_value(CorePropertyTypeName::RelationshipName, relationship_name)?;
+        Ok(traversal)
+    }
+
+    fn new_query(
+        context: &Arc<TransactionContext>,
+        root: &TransientReference,
+    ) -> Result<HolonReference, HolonError> {
+        let mut query = new_test_holon(context, "query")?;
+        query.add_related_holons(
+            CoreRelationshipTypeName::RootExpression,
+            vec![root.clone().into()],
+        )?;
+        Ok(query.into())
+    }
+
+    fn link(
+        source: &mut TransientReference,
+        relationship_name: CoreRelationshipTypeName,
+        targets: &[&TransientReference],
+    ) -> Result<(), HolonError> {
+        source.add_related_holons(
+            relationship_name,
+            targets.iter().map(|target| (*target).clone().into()).collect(),
+        )?;
+        Ok(())
+    }
+
+    /// Returns a declaration of `name` and a binding of the declared type.
+    fn new_parameter(
+        context: &Arc<TransactionContext>,
+        name: &str,
+    ) -> Result<(TransientReference, TransientReference), HolonError> {
+        let binding_type = new_descriptor_holon(
+            context,
+            &format!("{name}-binding-type"),
+            "TestBinding",
+            TypeKind::Holon,
+        )?;
+        let mut declaration = new_test_holon(context, &format!("{name}-declaration"))?;
+        declaration
+            .with_property_value(CorePropertyTypeName::ParameterName, name)?
+            .add_related_holons(
+                CoreRelationshipTypeName::ParameterBindingType,
+                vec![binding_type.clone().into()],
+            )?;
+        let mut binding = new_test_holon(context, &format!("{name}-binding"))?;
+        binding.add_related_holons(
+            CoreRelationshipTypeName::BindsParameter,
+            vec![declaration.clone().into()],
+        )?;
+        binding.with_descriptor(binding_type.into())?;
+        Ok((declaration, binding))
+    }
+
+    fn members_of(collection: &BoundHolonCollection) -> Result<Vec<HolonReference>, HolonError> {
+        collection_members(collection)
+    }
+
+    #[test]
+    fn execute_query_follows_next_chain_and_records_artifacts() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut book = new_test_holon(&context, "book")?;
+        let mut author = new_test_holon(&context, "author")?;
+        let publisher = new_test_holon(&context, "publisher")?;
+        link(&mut book, CoreRelationshipTypeName::Contains, &[&author])?;
+        author.add_related_holons("Publisher", vec![publisher.clone().into()])?;
+
+        let mut first = new_traversal(&context, "authors", "Contains")?;
+        let second = new_traversal(&context, "publishers", "Publisher")?;
+        link(&mut first, CoreRelationshipTypeName::Next, &[&second])?;
+        let query = new_query(&context, &first)?;
+
+        let input = BoundHolonCollection::new_transient(&context, vec![book.into()])?;
+        let execution = execute_query(&context, &query, &input)?;
+
+        assert_eq!(members_of(execution.result())?, vec![HolonReference::from(publisher)]);
+
+        let instance = execution.execution_instance();
+        assert_eq!(QueryExecutionStatus::of(instance)?, QueryExecutionStatus::Complete);
+        assert_eq!(
+            accessor_helpers::require_single_related(
+                instance,
+                CoreRelationshipTypeName::ExecutesQuery
+            )?,
+            query
+        );
+        assert_eq!(
+            accessor_helpers::require_single_related(
+                instance,
+                CoreRelationshipTypeName::ExecutionResult
+            )?,
+            execution.result().as_holon_reference().clone()
+        );
+
+        let expression_executions =
+            related_members(instance, CoreRelationshipTypeName::ExpressionExecutions)?;
+        assert_eq!(expression_executions.len(), 2);
+        let first_execution = &expression_executions[0];
+        assert_eq!(QueryExecutionStatus::of(first_execution)?, QueryExecutionStatus::Complete);
+        assert_eq!(
+            accessor_helpers::require_single_related(
+                first_execution,
+                CoreRelationshipTypeName::ExecutesExpression
+            )?,
+            HolonReference::from(first)
+        );
+        assert_eq!(
+            accessor_helpers::require_single_related(
+                first_execution,
+                CoreRelationshipTypeName::Input
+            )?,
+            input.as_holon_reference().clone()
+        );
+        let first_result =
+            BoundHolonCollection::from_holon(accessor_helpers::require_single_related(
+                first_execution,
+                CoreRelationshipTypeName::Result,
+            )?);
+        assert_eq!(members_of(&first_result)?, vec![HolonReference::from(author)]);
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_with_parameters_records_declared_bindings() -> Result<(), HolonError> {
+        let context = build_context();
+        let book = new_test_holon(&context, "book")?;
+        let (declaration, author) = new_parameter(&context, "author")?;
+        let mut first = new_traversal(&context, "authors", "Authors")?;
+        let second = new_traversal(&context, "publishers", "Publisher")?;
+        link(&mut first, CoreRelationshipTypeName::ExpressionParameters, &[&declaration])?;
+        link(&mut first, CoreRelationshipTypeName::Next, &[&second])?;
+        let query = new_query(&context, &first)?;
+
+        let input = BoundHolonCollection::new_transient(&context, vec![book.into()])?;
+        let execution =
+            execute_query_with_parameters(&context, &query, &input, &[author.clone().into()])?;
+
+        let expression_executions = related_members(
+            execution.execution_instance(),
+            CoreRelationshipTypeName::ExpressionExecutions,
+        )?;
+        assert_eq!(
+            related_members(
+                &expression_executions[0],
+                CoreRelationshipTypeName::RuntimeParameters
+            )?,
+            vec![HolonReference::from(author)]
+        );
+        assert!(related_members(
+            &expression_executions[1],
+            CoreRelationshipTypeName::RuntimeParameters
+        )?
+        .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_with_parameters_substitutes_bound_properties() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut book = new_test_holon(&context, "book")?;
+        let author = new_test_holon(&context, "author")?;
+        let editor = new_test_holon(&context, "editor")?;
+        book.add_related_holons("Authors", vec![author.clone().into()])?;
+        book.add_related_holons("Editors", vec![editor.clone().into()])?;
+
+        let (declaration, mut binding) = new_parameter(&context, "role")?;
+        let mut traversal = new_traversal(&context, "contributors", "Authors")?;
+        link(&mut traversal, CoreRelationshipTypeName::ExpressionParameters, &[&declaration])?;
+        let query = new_query(&context, &traversal)?;
+        let input = BoundHolonCollection::new_transient(&context, vec![book.into()])?;
+
+        // A binding without the property leaves the expression's own value in place.
+        let execution =
+            execute_query_with_parameters(&context, &query, &input, &[binding.clone().into()])?;
+        assert_eq!(members_of(execution.result())?, vec![HolonReference::from(author)]);
+
+        binding.with_property_value(CorePropertyTypeName::RelationshipName, "Editors")?;
+        let execution = execute_query_with_parameters(&context, &query, &input, &[binding.into()])?;
+        assert_eq!(members_of(execution.result())?, vec![HolonReference::from(editor)]);
+
+        assert!(matches!(
+            execute_query(&context, &query, &input),
+            Err(HolonError::MissingQueryParameter { parameter, .. }) if parameter == "role"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_runs_subtree_as_pipeline_before_next() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut root_holon = new_test_holon(&context, "root")?;
+        let mut child = new_test_holon(&context, "child")?;
+        let mut grandchild = new_test_holon(&context, "grandchild")?;
+        let sibling = new_test_holon(&context, "sibling")?;
+        root_holon.add_related_holons("Children", vec![child.clone().into()])?;
+        child.add_related_holons("Children", vec![grandchild.clone().into()])?;
+        grandchild.add_related_holons("Siblings", vec![sibling.clone().into()])?;
+
+        let subtree_descriptor = new_descriptor_holon(
+            &context,
+            "subtree-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::QuerySubTree),
+            TypeKind::Holon,
+        )?;
+        let mut subtree = new_expression(&context, "subtree", &subtree_descriptor)?;
+        let children = new_traversal(&context, "children", "Children")?;
+        let grandchildren = new_traversal(&context, "grandchildren", "Children")?;
+        let siblings = new_traversal(&context, "siblings", "Siblings")?;
+        link(&mut subtree, CoreRelationshipTypeName::Subtree, &[&children, &grandchildren])?;
+        link(&mut subtree, CoreRelationshipTypeName::Next, &[&siblings])?;
+        let query = new_query(&context, &subtree)?;
+
+        let input = BoundHolonCollection::new_transient(&context, vec![root_holon.into()])?;
+        let execution = execute_query(&context, &query, &input)?;
+
+        assert_eq!(members_of(execution.result())?, vec![HolonReference::from(sibling)]);
+        let expression_executions = related_members(
+            execution.execution_instance(),
+            CoreRelationshipTypeName::ExpressionExecutions,
+        )?;
+        assert_eq!(expression_executions.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_runs_path_traversal_expressions() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut book = new_test_holon(&context, "book")?;
+        let mut author = new_test_holon(&context, "author")?;
+        let mut mentor = new_test_holon(&context, "mentor")?;
+        let grand_mentor = new_test_holon(&context, "grand-mentor")?;
+        book.add_related_holons("Authors", vec![author.clone().into()])?;
+        author.add_related_holons("MentoredBy", vec![mentor.clone().into()])?;
+        mentor.add_related_holons("MentoredBy", vec![grand_mentor.clone().into()])?;
+
+        let descriptor = new_descriptor_holon(
+            &context,
+            "path-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::PathTraversal),
+            TypeKind::Holon,
+        )?;
+        let mut path = new_expression(&context, "path", &descriptor)?;
+        path.with_property_value(CorePropertyTypeName::PathExpression, "Authors/MentoredBy+")?;
+        let query = new_query(&context, &path)?;
+        let input = BoundHolonCollection::new_transient(&context, vec![book.into()])?;
+
+        let execution = execute_query(&context, &query, &input)?;
+
+        assert_eq!(
+            members_of(execution.result())?,
+            vec![HolonReference::from(mentor), HolonReference::from(grand_mentor)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_aggregates_traversal_results() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut shelf = new_test_holon(&context, "shelf")?;
+        let mut thin = new_test_holon(&context, "thin")?;
+        let mut thick = new_test_holon(&context, "thick")?;
+        thin.with_property_value("PageCount", 90)?;
+        thick.with_property_value("PageCount", 400)?;
+        link(&mut shelf, CoreRelationshipTypeName::Contains, &[&thin, &thick])?;
+
+        let mut books = new_traversal(&context, "books", "Contains")?;
+        let descriptor = new_descriptor_holon(
+            &context,
+            "aggregation-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::Aggregation),
+            TypeKind::Holon,
+        )?;
+        let mut total = new_expression(&context, "total-pages", &descriptor)?;
+        total
+            .with_property_value(CorePropertyTypeName::AggregateFunction, "Sum")?
+            .with_property_value(CorePropertyTypeName::AggregateProperty, "PageCount")?;
+        link(&mut books, CoreRelationshipTypeName::Next, &[&total])?;
+        let query = new_query(&context, &books)?;
+        let input = BoundHolonCollection::new_transient(&context, vec![shelf.into()])?;
+
+        let execution = execute_query(&context, &query, &input)?;
+
+        let results = members_of(execution.result())?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].property_value(CorePropertyTypeName::AggregateValue)?,
+            Some(BaseValue::IntegerValue(MapInteger(490)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_applies_type_property_and_exists_filters() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut less_than =
+            new_descriptor_holon(&context, "less-than", "LessThanOperator", TypeKind::Holon)?;
+        less_than.with_property_value(CorePropertyTypeName::Arity, 2)?;
+        let mut value_type =
+            new_descriptor_holon(&context, "integer-value", "IntegerValueType", "Value")?;
+        value_type.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![less_than.into()],
+        )?;
+        let page_count = new_property_descriptor_holon(
+            &context,
+            "page-count",
+            "PageCount",
+            "page_count",
+            false,
+            value_type.into(),
+        )?;
+        let mut book_type = new_holon_type_descriptor(&context, "book-type", "Book")?;
+        book_type.add_related_holons(
+            CoreRelationshipTypeName::InstanceProperties,
+            vec![page_count.into()],
+        )?;
+        let person_type = new_holon_type_descriptor(&context, "person-type", "Person")?;
+
+        let mut holons = Vec::new();
+        for (key, pages, has_author) in
+            [("novella", 90, true), ("anonymous", 80, false), ("epic", 900, true)]
+        {
+            let mut book = new_test_holon(&context, key)?;
+            book.with_descriptor(book_type.clone().into())?;
+            book.with_property_value("PageCount", pages)?;
+            if has_author {
+                let mut author = new_test_holon(&context, &format!("{key}-author"))?;
+                author.with_descriptor(person_type.clone().into())?;
+                book.add_related_holons("AuthoredBy", vec![author.into()])?;
+            }
+            holons.push(HolonReference::from(book));
+        }
+        holons.push(new_test_holon(&context, "undescribed")?.into());
+
+        let type_filter_descriptor = new_descriptor_holon(
+            &context,
+            "type-filter-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::TypeFilter),
+            TypeKind::Holon,
+        )?;
+        let mut books = new_expression(&context, "books", &type_filter_descriptor)?;
+        books.with_property_value(CorePropertyTypeName::FilterTypeName, "Book")?;
+        let mut authors = new_traversal(&context, "authors", "AuthoredBy")?;
+        let mut people = new_expression(&context, "people", &type_filter_descriptor)?;
+        people.with_property_value(CorePropertyTypeName::FilterTypeName, "Person")?;
+        link(&mut authors, CoreRelationshipTypeName::Next, &[&people])?;
+
+        let exists_descriptor = new_descriptor_holon(
+            &context,
+            "exists-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::ExistsFilter),
+            TypeKind::Holon,
+        )?;
+        let mut authored = new_expression(&context, "authored", &exists_descriptor)?;
+        link(&mut authored, CoreRelationshipTypeName::Condition, &[&authors])?;
+
+        let property_filter_descriptor = new_descriptor_holon(
+            &context,
+            "property-filter-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::PropertyFilter),
+            TypeKind::Holon,
+        )?;
+        let mut short = new_expression(&context, "short", &property_filter_descriptor)?;
+        short
+            .with_property_value(CorePropertyTypeName::FilterProperty, "PageCount")?
+            .with_property_value(CorePropertyTypeName::FilterOperator, "LessThanOperator")?
+            .with_property_value(CorePropertyTypeName::FilterOperand, 100)?;
+
+        link(&mut books, CoreRelationshipTypeName::Next, &[&authored])?;
+        link(&mut authored, CoreRelationshipTypeName::Next, &[&short])?;
+        let query = new_query(&context, &books)?;
+        let input = BoundHolonCollection::new_transient(&context, holons.clone())?;
+
+        let execution = execute_query(&context, &query, &input)?;
+
+        assert_eq!(members_of(execution.result())?, vec![holons[0].clone()]);
+        Ok(())
+    }
+
+    #[test]
+    fn property_filter_removes_holons_without_the_property() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut less_than =
+            new_descriptor_holon(&context, "less-than", "LessThanOperator", TypeKind::Holon)?;
+        less_than.with_property_value(CorePropertyTypeName::Arity, 2)?;
+        let mut value_type =
+            new_descriptor_holon(&context, "integer-value", "IntegerValueType", "Value")?;
+        value_type.add_related_holons(
+            CoreRelationshipTypeName::AffordsOperator,
+            vec![less_than.into()],
+        )?;
+        let page_count = new_property_descriptor_holon(
+            &context,
+            "page-count",
+            "PageCount",
+            "page_count",
+            false,
+            value_type.into(),
+        )?;
+        let mut book_type = new_holon_type_descriptor(&context, "book-type", "Book")?;
+        book_type.add_related_holons(
+            CoreRelationshipTypeName::InstanceProperties,
+            vec![page_count.into()],
+        )?;
+        let person_type = new_holon_type_descriptor(&context, "person-type", "Person")?;
+
+        let mut short = new_test_holon(&context, "short")?;
+        short.with_descriptor(book_type.clone().into())?;
+        short.with_property_value("PageCount", 90)?;
+        let mut unpaged = new_test_holon(&context, "unpaged")?;
+        unpaged.with_descriptor(book_type.into())?;
+        let mut person = new_test_holon(&context, "person")?;
+        person.with_descriptor(person_type.into())?;
+        let undescribed = new_test_holon(&context, "undescribed")?;
+
+        let property_filter_descriptor = new_descriptor_holon(
+            &context,
+            "property-filter-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::PropertyFilter),
+            TypeKind::Holon,
+        )?;
+        let mut filter = new_expression(&context, "short-books", &property_filter_descriptor)?;
+        filter
+            .with_property_value(CorePropertyTypeName::FilterProperty, "PageCount")?
+            .with_property_value(CorePropertyTypeName::FilterOperator, "LessThanOperator")?
+            .with_property_value(CorePropertyTypeName::FilterOperand, 100)?;
+        let query = new_query(&context, &filter)?;
+        let input = BoundHolonCollection::new_transient(
+            &context,
+            vec![undescribed.into(), person.into(), unpaged.into(), short.clone().into()],
+        )?;
+
+        let execution = execute_query(&context, &query, &input)?;
+
+        assert_eq!(members_of(execution.result())?, vec![HolonReference::from(short)]);
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_deduplicates_traversal_targets() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut left = new_test_holon(&context, "left")?;
+        let mut right = new_test_holon(&context, "right")?;
+        let shared = new_test_holon(&context, "shared")?;
+        left.add_related_holons("Friends", vec![shared.clone().into()])?;
+        right.add_related_holons("Friends", vec![shared.clone().into()])?;
+
+        let traversal = new_traversal(&context, "friends", "Friends")?;
+        let query = new_query(&context, &traversal)?;
+        let input = BoundHolonCollection::new_transient(&context, vec![left.into(), right.into()])?;
+
+        let execution = execute_query(&context, &query, &input)?;
+
+        assert_eq!(members_of(execution.result())?, vec![HolonReference::from(shared)]);
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_rejects_unknown_expression_kinds() -> Result<(), HolonError> {
+        let context = build_context();
+        let descriptor =
+            new_descriptor_holon(&context, "unknown-descriptor", "MysteryExpression", "Holon")?;
+        let expression = new_expression(&context, "mystery", &descriptor)?;
+        let query = new_query(&context, &expression)?;
+        let input = BoundHolonCollection::new_transient(&context, Vec::new())?;
+
+        let error = execute_query(&context, &query, &input).unwrap_err();
+
+        assert!(matches!(error, HolonError::WrongDescriptorKind { .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn execute_query_rejects_cyclic_next_chains() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut first = new_traversal(&context, "first", "Friends")?;
+        let mut second = new_traversal(&context, "second", "Friends")?;
+        link(&mut first, CoreRelationshipTypeName::Next, &[&second])?;
+        link(&mut second, CoreRelationshipTypeName::Next, &[&first])?;
+        let query = new_query(&context, &first)?;
+        let input = BoundHolonCollection::new_transient(&context, Vec::new())?;
+
+        let error = execute_query(&context, &query, &input).unwrap_err();
+
+        assert!(matches!(error, HolonError::InvalidState(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn query_execution_status_round_trips_variant_names() -> Result<(), HolonError> {
+        for status in [
+            QueryExecutionStatus::Pending,
+            QueryExecutionStatus::Running,
+            QueryExecutionStatus::Complete,
+            QueryExecutionStatus::Failed,
+        ] {
+            assert_eq!(
+                QueryExecutionStatus::parse(&MapString(status.as_str().to_string()))?,
+                status
+            );
+        }
+        assert!(QueryExecutionStatus::parse(&MapString("Paused".to_string())).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+use crate::descriptors::{accessor_helpers, TypeHeader};
+use crate::query_layer::query_executor::is_described_as;
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use base_types::MapString;
+use core_types::HolonError;
+use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+/// Checks `QueryParameterBinding`s against the `QueryParameterDeclaration`s of one `Query`.
+///
+/// Declarations are collected from every expression reachable from the query's
+/// `RootExpression` through `Next`, `Subtree` and `Condition`. Expressions may
+/// share a declaration or declare the same `ParameterName`; a name is bound once
+/// and the first declaration reached for it decides its binding type. Each
+/// binding names the declaration it satisfies through `BindsParameter`, and is
+/// bound under that declaration's `ParameterName`.
+#[derive(Debug, Clone)]
+pub struct QueryParameterBinder {
+    query: String,
+    declarations: Vec<(MapString, HolonReference)>,
+}
+
+impl QueryParameterBinder {
+    /// Collects the parameter declarations of `query`.
+    pub fn for_query(query: &HolonReference) -> Result<Self, HolonError> {
+        let root = accessor_helpers::require_single_related(
+            query,
+            CoreRelationshipTypeName::RootExpression,
+        )?;
+
+        let mut declarations: Vec<(MapString, HolonReference)> = Vec::new();
+        let mut visited: Vec<HolonReference> = Vec::new();
+        let mut pending = vec![root];
+        while let Some(expression) = pending.pop() {
+            if visited.contains(&expression) {
+                continue;
+            }
+            for declaration in
+                related_members(&expression, CoreRelationshipTypeName::ExpressionParameters)?
+            {
+                let name = accessor_helpers::require_string(
+                    &declaration,
+                    CorePropertyTypeName::ParameterName,
+                )?;
+                if !declarations.iter().any(|(declared, _)| *declared == name) {
+                    declarations.push((name, declaration));
+                }
+            }
+            // Reversed so that, popping from the end, `Subtree` members run in
+            // order before `Condition` and `Next`.
+            pending.extend(related_members(&expression, CoreRelationshipTypeName::Next)?);
+            pending.extend(related_members(&expression, CoreRelationshipTypeName::Condition)?);
+            pending.extend(
+                related_members(&expression, CoreRelationshipTypeName::Subtree)?.into_iter().rev(),
+            );
+            visited.push(expression);
+        }
+
+        Ok(Self { query: accessor_helpers::descriptor_label(query), declarations })
+    }
+
+    /// Returns the declared parameter names, in discovery order.
+    pub fn parameter_names(&self) -> Vec<MapString> {
+        self.declarations.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// Validates `bindings` and returns the effective binding of every declared
+    /// parameter with its name, in declaration order.
+    ///
+    /// A declaration left unbound takes its `DefaultBinding`; without one it is
+    /// reported as `MissingQueryParameter`. A binding without exactly one
+    /// `BindsParameter` declaration fails like any other required relationship.
+    /// Bindings for undeclared names are
+    /// `UnexpectedQueryParameter`, a second binding for the same name is a
+    /// `DuplicateError`, and a binding whose descriptor is not, and does not
+    /// extend, the declaration's `ParameterBindingType` is
+    /// `QueryParameterTypeMismatch`. Defaults are type-checked like supplied
+    /// bindings.
+    pub fn bind(
+        &self,
+        bindings: &[HolonReference],
+    ) -> Result<Vec<(MapString, HolonReference)>, HolonError> {
+        let mut supplied: Vec<(MapString, &HolonReference)> = Vec::new();
+        for binding in bindings {
+            let declaration = accessor_helpers::require_single_related(
+                binding,
+                CoreRelationshipTypeName::BindsParameter,
+            )?;
+            let name = accessor_helpers::require_string(
+                &declaration,
+                CorePropertyTypeName::ParameterName,
+            )?;
+            if !self.declarations.iter().any(|(declared, _)| *declared == name) {
+                return Err(HolonError::UnexpectedQueryParameter {
+                    parameter: name.to_string(),
+                    query: self.query.clone(),
+                });
+            }
+            if supplied.iter().any(|(bound, _)| *bound == name) {
+                return Err(HolonError::DuplicateError(
+                    "QueryParameterBinding".to_string(),
+                    format!("ParameterName: {}", name),
+                ));
+            }
+            supplied.push((name, binding));
+        }
+
+        let mut effective = Vec::with_capacity(self.declarations.len());
+        for (name, declaration) in &self.declarations {
+            let binding = match supplied.iter().find(|(bound, _)| bound == name) {
+                Some((_, binding)) => (*binding).clone(),
+                None => accessor_helpers::optional_single_related(
+                    declaration,
+                    CoreRelationshipTypeName::DefaultBinding,
+                )?
+                .ok_or_else(|| HolonError::MissingQueryParameter {
+                    parameter: name.to_string(),
+                    query: self.query.clone(),
+                })?,
+            };
+            self.check_binding_type(name, declaration, &binding)?;
+            effective.push((name.clone(), binding));
+        }
+        Ok(effective)
+    }
+
+    fn check_binding_type(
+        &self,
+        name: &MapString,
+        declaration: &HolonReference,
+        binding: &HolonReference,
+    ) -> Result<(), HolonError> {
+        let binding_type = accessor_helpers::require_single_related(
+            declaration,
+            CoreRelationshipTypeName::ParameterBindingType,
+        )?;
+        let expected = TypeHeader::new(&binding_type).type_name()?;
+        if is_described_as(binding, &expected)? {
+            return Ok(());
+        }
+        let found = match binding.get_descriptor()? {
+            Some(descriptor) => TypeHeader::new(&descriptor).type_name()?.to_string(),
+            None => "no descriptor".to_string(),
+        };
+        Err(HolonError::QueryParameterTypeMismatch {
+            parameter: name.to_string(),
+            query: self.query.clone(),
+            expected: expected.to_string(),
+            found,
+        })
+    }
+}
+
+fn related_members(
+    holon: &HolonReference,
+    relationship_name: CoreRelationshipTypeName,
+) -> Result<Vec<HolonReference>, HolonError> {
+    let collection_lock = holon.related_holons(relationship_name)?;
+    let collection = collection_lock.read().map_err(accessor_helpers::lock_error)?;
+    Ok(collection.get_members().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        build_context, core_holon_type_name, new_descriptor_holon, new_test_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use core_types::TypeKind;
+    use std::sync::Arc;
+    use type_names::CoreHolonTypeName;
+
+    struct Fixture {
+        context: Arc<TransactionContext>,
+        query: HolonReference,
+        declaration: TransientReference,
+        binding_type: TransientReference,
+    }
+
+    /// Builds `root -Next-> filter`, where only `filter` declares `author`.
+    fn fixture() -> Result<Fixture, HolonError> {
+        let context = build_context();
+        let binding_type = new_descriptor_holon(
+            &context,
+            "author-binding-type",
+            "AuthorBinding",
+            TypeKind::Holon,
+        )?;
+        let mut declaration = new_test_holon(&context, "author-declaration")?;
+        declaration
+            .with_property_value(CorePropertyTypeName::ParameterName, "author")?
+            .add_related_holons(
+                CoreRelationshipTypeName::ParameterBindingType,
+                vec![binding_type.clone().into()],
+            )?;
+
+        let descriptor = new_descriptor_holon(
+            &context,
+            "filter-descriptor",
+            &core_holon_type_name(CoreHolonTypeName::TypeFilter),
+            TypeKind::Holon,
+        )?;
+        let mut filter = new_test_holon(&context, "filter")?;
+        filter.with_descriptor(descriptor.into())?;
+        filter.add_related_holons(
+            CoreRelationshipTypeName::ExpressionParameters,
+            vec![declaration.clone().into()],
+        )?;
+        let mut root = new_test_holon(&context, "root")?;
+        root.add_related_holons(CoreRelationshipTypeName::Next, vec![filter.into()])?;
+        let mut query = new_test_holon(&context, "query")?;
+        query.add_related_holons(CoreRelationshipTypeName::RootExpression, vec![root.into()])?;
+
+        Ok(Fixture { context, query: query.into(), declaration, binding_type })
+    }
+
+    fn new_binding(
+        fixture: &Fixture,
+        key: &str,
+        declaration: &TransientReference,
+        descriptor: Option<&TransientReference>,
+    ) -> Result<HolonReference, HolonError> {
+        let mut binding = new_test_holon(&fixture.context, key)?;
+        binding.add_related_holons(
+            CoreRelationshipTypeName::BindsParameter,
+            vec![declaration.clone().into()],
+        )?;
+        if let Some(descriptor) = descriptor {
+            binding.with_descriptor(descriptor.clone().into())?;
+        }
+        Ok(binding.into())
+    }
+
+    #[test]
+    fn binder_collects_declarations_across_next() -> Result<(), HolonError> {
+        let fixture = fixture()?;
+        let binder = QueryParameterBinder::for_query(&fixture.query)?;
+        assert_eq!(binder.parameter_names(), vec![MapString("author".to_string())]);
+        Ok(())
+    }
+
+    #[test]
+    fn bind_accepts_a_binding_of_the_declared_type() -> Result<(), HolonError> {
+        let fixture = fixture()?;
+        let binding =
+            new_binding(&fixture, "binding", &fixture.declaration, Some(&fixture.binding_type))?;
+
+        let effective = QueryParameterBinder::for_query(&fixture.query)?
+            .bind(std::slice::from_ref(&binding))?;
+
+        assert_eq!(effective, vec![(MapString("author".to_string()), binding)]);
+        Ok(())
+    }
+
+    #[test]
+    fn bind_reports_missing_unexpected_and_mismatched_parameters() -> Result<(), HolonError> {
+        let fixture = fixture()?;
+        let binder = QueryParameterBinder::for_query(&fixture.query)?;
+
+        assert!(matches!(
+            binder.bind(&[]),
+            Err(HolonError::MissingQueryParameter { parameter, .. }) if parameter == "author"
+        ));
+
+        let mut stray = new_test_holon(&fixture.context, "editor-declaration")?;
+        stray.with_property_value(CorePropertyTypeName::ParameterName, "editor")?;
+        let extra = new_binding(&fixture, "extra", &stray, Some(&fixture.binding_type))?;
+        assert!(matches!(
+            binder.bind(&[extra]),
+            Err(HolonError::UnexpectedQueryParameter { parameter, .. }) if parameter == "editor"
+        ));
+
+        let untyped = new_binding(&fixture, "untyped", &fixture.declaration, None)?;
+        assert!(matches!(
+            binder.bind(&[untyped]),
+            Err(HolonError::QueryParameterTypeMismatch { expected, found, .. })
+                if expected == "AuthorBinding" && found == "no descriptor"
+        ));
+
+        let first =
+            new_binding(&fixture, "first", &fixture.declaration, Some(&fixture.binding_type))?;
+        let second =
+            new_binding(&fixture, "second", &fixture.declaration, Some(&fixture.binding_type))?;
+        assert!(matches!(binder.bind(&[first, second]), Err(HolonError::DuplicateError(..))));
+
+        let mut unbound = new_test_holon(&fixture.context, "unbound")?;
+        unbound.with_property_value(CorePropertyTypeName::ParameterName, "author")?;
+        assert!(matches!(
+            binder.bind(&[unbound.into()]),
+            Err(HolonError::MissingRequiredRelationship { relationship, .. })
+                if relationship == "BindsParameter"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn bind_falls_back_to_the_default_binding() -> Result<(), HolonError> {
+        let mut fixture = fixture()?;
+        let default =
+            new_binding(&fixture, "default", &fixture.declaration, Some(&fixture.binding_type))?;
+        fixture
+            .declaration
+            .add_related_holons(CoreRelationshipTypeName::DefaultBinding, vec![default.clone()])?;
+
+        let effective = QueryParameterBinder::for_query(&fixture.query)?.bind(&[])?;
+
+        assert_eq!(effective, vec![(MapString("author".to_string()), default)]);
+        Ok(())
+    }
+}
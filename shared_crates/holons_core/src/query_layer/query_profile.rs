@@ -0,0 +1,520 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::core_shared_objects::RelationshipCacheStats;
+use crate::descriptors::accessor_helpers;
+use crate::query_layer::query_executor::{run_query, ExpressionKind, ExpressionObserver};
+use crate::query_layer::{
+    AggregateFunction, BoundHolonCollection, QueryExecution, RelationshipPath,
+};
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use base_types::MapString;
+use core_types::{HolonError, RelationshipName};
+use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+/// One `QueryExpression` in a [`QueryExplanation`].
+#[derive(Debug, Clone)]
+pub struct ExplainNode {
+    pub expression: HolonReference,
+    /// Type name of the expression's descriptor.
+    pub expression_type: MapString,
+    /// Nesting under `Subtree` and `Condition`; root-chain expressions are at depth 0.
+    pub depth: usize,
+    /// Relationships the expression reads from each input member, in order.
+    pub expands: Vec<RelationshipName>,
+    /// How the first expanded relationship will be served, for expressions
+    /// whose input is the query input. Later inputs are only known at run time.
+    pub forecast: Option<ExpansionForecast>,
+}
+
+/// How input members will resolve one relationship.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExpansionForecast {
+    pub relationship: RelationshipName,
+    /// Transient and staged members, which hold their relationships in memory.
+    pub in_memory: usize,
+    /// Saved members whose relationship is already in the `RelationshipCache`.
+    pub cache_hits: usize,
+    /// Saved members that will go through `HolonServiceApi::fetch_related_holons_internal`.
+    pub fetches: usize,
+}
+
+/// The expression tree of a Query, in execution (pre-)order.
+#[derive(Debug, Clone, Default)]
+pub struct QueryExplanation {
+    pub nodes: Vec<ExplainNode>,
+}
+
+impl fmt::Display for QueryExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.nodes {
+            write!(f, "{:indent$}{}", "", node.expression_type, indent = node.depth * 2)?;
+            if !node.expands.is_empty() {
+                let expands: Vec<String> =
+                    node.expands.iter().map(|relationship| relationship.to_string()).collect();
+                write!(f, " expands [{}]", expands.join(", "))?;
+            }
+            if let Some(forecast) = &node.forecast {
+                write!(
+                    f,
+                    " ({}: {} in memory, {} cached, {} fetched)",
+                    forecast.relationship,
+                    forecast.in_memory,
+                    forecast.cache_hits,
+                    forecast.fetches
+                )?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Describes how `query` would run over `input` without running it.
+///
+/// Walks `RootExpression`, `Subtree`, `Condition` and `Next` in the order the
+/// executor does. Only relationships of the Query structure and the
+/// relationship cache are read; input members are never expanded.
+pub fn explain_query(
+    context: &Arc<TransactionContext>,
+    query: &HolonReference,
+    input: &BoundHolonCollection,
+) -> Result<QueryExplanation, HolonError> {
+    let root =
+        accessor_helpers::require_single_related(query, CoreRelationshipTypeName::RootExpression)?;
+    let members = {
+        let members_lock = input.members()?;
+        let members = members_lock.read().map_err(accessor_helpers::lock_error)?;
+        members.get_members().clone()
+    };
+
+    let mut explanation = QueryExplanation::default();
+    explain_chain(context, &root, Some(&members), &mut Vec::new(), &mut explanation.nodes)?;
+    Ok(explanation)
+}
+
+/// Timing, cardinality and cache use of one expression invocation.
+///
+/// Counts and timings include expressions nested under it. An `ExistsFilter`
+/// runs its `Condition` once per input member, so nested expressions may
+/// appear many times.
+#[derive(Debug, Clone)]
+pub struct ExpressionProfile {
+    pub expression: HolonReference,
+    pub expression_type: MapString,
+    pub depth: usize,
+    pub input_count: usize,
+    pub output_count: usize,
+    pub elapsed: Duration,
+    pub cache: RelationshipCacheStats,
+}
+
+impl ExpressionProfile {
+    pub fn cache_hit_ratio(&self) -> Option<f64> {
+        self.cache.hit_ratio()
+    }
+}
+
+/// A completed execution together with one profile per expression invocation.
+#[derive(Debug, Clone)]
+pub struct QueryProfile {
+    pub execution: QueryExecution,
+    /// Invocations in the order they started.
+    pub expressions: Vec<ExpressionProfile>,
+    pub elapsed: Duration,
+    pub cache: RelationshipCacheStats,
+}
+
+impl QueryProfile {
+    pub fn cache_hit_ratio(&self) -> Option<f64> {
+        self.cache.hit_ratio()
+    }
+}
+
+/// Runs `query` like `execute_query`, recording per-expression timing,
+/// cardinality and relationship cache hits and misses.
+///
+/// `clock` returns a monotonic time since any fixed origin; it is injected
+/// because guest builds have no system clock. Cache counts are those of the
+/// whole holon space during the run.
+pub fn profile_query(
+    context: &Arc<TransactionContext>,
+    query: &HolonReference,
+    input: &BoundHolonCollection,
+    clock: &dyn Fn() -> Duration,
+) -> Result<QueryProfile, HolonError> {
+    let started_at = clock();
+    let cache_before = context.relationship_cache_stats()?;
+
+    let mut profiler = Profiler { context, clock, open: Vec::new(), expressions: Vec::new() };
+    let execution = run_query(context, query, input, &[], Some(&mut profiler))?;
+
+    Ok(QueryProfile {
+        execution,
+        expressions: profiler.expressions,
+        elapsed: clock().saturating_sub(started_at),
+        cache: context.relationship_cache_stats()?.since(&cache_before),
+    })
+}
+
+struct Profiler<'a> {
+    context: &'a Arc<TransactionContext>,
+    clock: &'a dyn Fn() -> Duration,
+    /// Index into `expressions`, start time and cache counts of each
+    /// invocation that has entered but not exited.
+    open: Vec<(usize, Duration, RelationshipCacheStats)>,
+    expressions: Vec<ExpressionProfile>,
+}
+
+impl ExpressionObserver for Profiler<'_> {
+    fn enter(
+        &mut self,
+        expression: &HolonReference,
+        input: &BoundHolonCollection,
+        depth: usize,
+    ) -> Result<(), HolonError> {
+        self.expressions.push(ExpressionProfile {
+            expression: expression.clone(),
+            expression_type: expression_type(expression)?,
+            depth,
+            input_count: member_count(input)?,
+            output_count: 0,
+            elapsed: Duration::ZERO,
+            cache: RelationshipCacheStats::default(),
+        });
+        self.open.push((
+            self.expressions.len() - 1,
+            (self.clock)(),
+            self.context.relationship_cache_stats()?,
+        ));
+        Ok(())
+    }
+
+    fn exit(&mut self, result: Option<&BoundHolonCollection>) -> Result<(), HolonError> {
+        let Some((index, started_at, cache_before)) = self.open.pop() else {
+            return Err(HolonError::InvalidState(
+                "Query profiler exited an expression it never entered".to_string(),
+            ));
+        };
+        let profile = &mut self.expressions[index];
+        profile.elapsed = (self.clock)().saturating_sub(started_at);
+        profile.cache = self.context.relationship_cache_stats()?.since(&cache_before);
+        if let Some(result) = result {
+            profile.output_count = member_count(result)?;
+        }
+        Ok(())
+    }
+}
+
+/// Explains the chain starting at `start`. `input` is the chain's input when
+/// it is the query input; `active` holds the enclosing expressions.
+fn explain_chain(
+    context: &Arc<TransactionContext>,
+    start: &HolonReference,
+    mut input: Option<&[HolonReference]>,
+    active: &mut Vec<HolonReference>,
+    nodes: &mut Vec<ExplainNode>,
+) -> Result<(), HolonError> {
+    let depth = active.len();
+    let mut expression = start.clone();
+    loop {
+        if active.contains(&expression) {
+            return Err(HolonError::InvalidState(format!(
+                "QueryExpression {} is reachable from itself through Next or Subtree",
+                accessor_helpers::descriptor_label(&expression)
+            )));
+        }
+        let kind = ExpressionKind::of(&expression)?;
+        let expands = expanded_relationships(kind, &expression)?;
+        let forecast = match (input, expands.first()) {
+            (Some(members), Some(relationship)) => {
+                Some(forecast_expansion(context, members, relationship)?)
+            }
+            _ => None,
+        };
+        nodes.push(ExplainNode {
+            expression: expression.clone(),
+            expression_type: expression_type(&expression)?,
+            depth,
+            expands,
+            forecast,
+        });
+
+        // Nested chains start from this expression's input; the members of a
+        // Subtree run as a pipeline, so only the first one sees it.
+        active.push(expression.clone());
+        match kind {
+            ExpressionKind::SubTree => {
+                let subtree = related_members(&expression, CoreRelationshipTypeName::Subtree)?;
+                if subtree.is_empty() {
+                    return Err(HolonError::MissingRequiredRelationship {
+                        relationship: CoreRelationshipTypeName::Subtree
+                            .as_relationship_name()
+                            .to_string(),
+                        descriptor: accessor_helpers::descriptor_label(&expression),
+                    });
+                }
+                let mut nested_input = input;
+                for member in &subtree {
+                    explain_chain(context, member, nested_input, active, nodes)?;
+                    nested_input = None;
+                }
+            }
+            ExpressionKind::ExistsFilter => {
+                let condition = accessor_helpers::require_single_related(
+                    &expression,
+                    CoreRelationshipTypeName::Condition,
+                )?;
+                explain_chain(context, &condition, input, active, nodes)?;
+            }
+            _ => {}
+        }
+
+        input = None;
+        match accessor_helpers::optional_single_related(
+            &expression,
+            CoreRelationshipTypeName::Next,
+        )? {
+            Some(next) => expression = next,
+            None => break,
+        }
+    }
+    active.truncate(depth);
+    Ok(())
+}
+
+fn expanded_relationships(
+    kind: ExpressionKind,
+    expression: &HolonReference,
+) -> Result<Vec<RelationshipName>, HolonError> {
+    let described_by = || vec![CoreRelationshipTypeName::DescribedBy.as_relationship_name()];
+    Ok(match kind {
+        ExpressionKind::RelationshipTraversal => vec![RelationshipName(
+            accessor_helpers::require_string(expression, CorePropertyTypeName::RelationshipName)?,
+        )],
+        ExpressionKind::PathTraversal => RelationshipPath::parse(
+            &accessor_helpers::require_string(expression, CorePropertyTypeName::PathExpression)?.0,
+        )?
+        .steps()
+        .iter()
+        .map(|step| step.relationship_name().clone())
+        .collect(),
+        ExpressionKind::TypeFilter | ExpressionKind::PropertyFilter => described_by(),
+        ExpressionKind::Aggregation => {
+            let by_descriptor = match AggregateFunction::of(expression)? {
+                AggregateFunction::Min | AggregateFunction::Max => true,
+                AggregateFunction::GroupBy => accessor_helpers::optional_string(
+                    expression,
+                    CorePropertyTypeName::AggregateProperty,
+                )?
+                .is_none(),
+                _ => false,
+            };
+            if by_descriptor {
+                described_by()
+            } else {
+                Vec::new()
+            }
+        }
+        ExpressionKind::SubTree | ExpressionKind::ExistsFilter => Vec::new(),
+    })
+}
+
+fn forecast_expansion(
+    context: &Arc<TransactionContext>,
+    members: &[HolonReference],
+    relationship: &RelationshipName,
+) -> Result<ExpansionForecast, HolonError> {
+    let mut forecast = ExpansionForecast {
+        relationship: relationship.clone(),
+        in_memory: 0,
+        cache_hits: 0,
+        fetches: 0,
+    };
+    for member in members {
+        match member {
+            HolonReference::Transient(_) | HolonReference::Staged(_) => forecast.in_memory += 1,
+            HolonReference::Smart(smart) => {
+                if context.is_related_holons_cached(&smart.holon_id(), relationship)? {
+                    forecast.cache_hits += 1;
+                } else {
+                    forecast.fetches += 1;
+                }
+            }
+        }
+    }
+    Ok(forecast)
+}
+
+fn expression_type(expression: &HolonReference) -> Result<MapString, HolonError> {
+    expression.holon_descriptor()?.header().type_name()
+}
+
+fn member_count(collection: &BoundHolonCollection) -> Result<usize, HolonError> {
+    let members_lock = collection.members()?;
+    let members = members_lock.read().map_err(accessor_helpers::lock_error)?;
+    Ok(members.get_members().len())
+}
+
+fn related_members(
+    holon: &HolonReference,
+    relationship_name: CoreRelationshipTypeName,
+) -> Result<Vec<HolonReference>, HolonError> {
+    let collection_lock = holon.related_holons(relationship_name)?;
+    let collection = collection_lock.read().map_err(accessor_helpers::lock_error)?;
+    Ok(collection.get_members().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{
+        build_context, core_holon_type_name, new_descriptor_holon, new_test_holon,
+    };
+    use crate::reference_layer::{SmartReference, TransientReference, WritableHolon};
+    use base_types::BaseValue;
+    use core_types::{LocalId, PropertyMap, TypeKind};
+    use std::cell::Cell;
+    use type_names::CoreHolonTypeName;
+
+    fn new_expression(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        holon_type: CoreHolonTypeName,
+    ) -> Result<TransientReference, HolonError> {
+        let descriptor = new_descriptor_holon(
+            context,
+            &format!("{key}-descriptor"),
+            &core_holon_type_name(holon_type),
+            TypeKind::Holon,
+        )?;
+        let mut expression = new_test_holon(context, key)?;
+        expression.with_descriptor(descriptor.into())?;
+        Ok(expression)
+    }
+
+    fn new_query(
+        context: &Arc<TransactionContext>,
+        root: &TransientReference,
+    ) -> Result<HolonReference, HolonError> {
+        let mut query = new_test_holon(context, "query")?;
+        query.add_related_holons(
+            CoreRelationshipTypeName::RootExpression,
+            vec![root.clone().into()],
+        )?;
+        Ok(query.into())
+    }
+
+    #[test]
+    fn explain_query_lists_expansions_and_forecasts_root_input() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut path = new_expression(&context, "path", CoreHolonTypeName::PathTraversal)?;
+        path.with_property_value(CorePropertyTypeName::PathExpression, "Authors/MentoredBy+")?;
+        let mut type_filter = new_expression(&context, "books", CoreHolonTypeName::TypeFilter)?;
+        type_filter.with_property_value(CorePropertyTypeName::FilterTypeName, "Book")?;
+        path.add_related_holons(CoreRelationshipTypeName::Next, vec![type_filter.into()])?;
+        let query = new_query(&context, &path)?;
+
+        let book = new_test_holon(&context, "book")?;
+        let saved = SmartReference::new_with_properties(
+            context.context_handle(),
+            LocalId(vec![1, 2, 3]).into(),
+            PropertyMap::from([(
+                CorePropertyTypeName::Key.as_property_name(),
+                BaseValue::StringValue(MapString("saved-book".to_string())),
+            )]),
+        );
+        let input = BoundHolonCollection::new_transient(&context, vec![book.into(), saved.into()])?;
+
+        let explanation = explain_query(&context, &query, &input)?;
+
+        let authors = RelationshipName(MapString("Authors".to_string()));
+        assert_eq!(explanation.nodes.len(), 2);
+        assert_eq!(
+            explanation.nodes[0].expands,
+            vec![authors.clone(), RelationshipName(MapString("MentoredBy".to_string()))]
+        );
+        assert_eq!(
+            explanation.nodes[0].forecast,
+            Some(ExpansionForecast {
+                relationship: authors,
+                in_memory: 1,
+                cache_hits: 0,
+                fetches: 1
+            })
+        );
+        assert_eq!(
+            explanation.nodes[1].expands,
+            vec![CoreRelationshipTypeName::DescribedBy.as_relationship_name()]
+        );
+        assert_eq!(explanation.nodes[1].forecast, None);
+        assert_eq!(
+            explanation.to_string(),
+            "PathTraversal expands [Authors, MentoredBy] \
+             (Authors: 1 in memory, 0 cached, 1 fetched)\n\
+             TypeFilter expands [DescribedBy]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn profile_query_reports_per_expression_cardinality_and_timing() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut shelf = new_test_holon(&context, "shelf")?;
+        let first = new_test_holon(&context, "first")?;
+        let second = new_test_holon(&context, "second")?;
+        shelf.add_related_holons(
+            CoreRelationshipTypeName::Contains,
+            vec![first.into(), second.into()],
+        )?;
+
+        let mut subtree = new_expression(&context, "subtree", CoreHolonTypeName::QuerySubTree)?;
+        let mut books =
+            new_expression(&context, "books", CoreHolonTypeName::RelationshipTraversal)?;
+        books.with_property_value(CorePropertyTypeName::RelationshipName, "Contains")?;
+        subtree.add_related_holons(CoreRelationshipTypeName::Subtree, vec![books.into()])?;
+        let mut count = new_expression(&context, "count", CoreHolonTypeName::Aggregation)?;
+        count.with_property_value(CorePropertyTypeName::AggregateFunction, "Count")?;
+        subtree.add_related_holons(CoreRelationshipTypeName::Next, vec![count.into()])?;
+        let query = new_query(&context, &subtree)?;
+        let input = BoundHolonCollection::new_transient(&context, vec![shelf.into()])?;
+
+        let ticks = Cell::new(0);
+        let clock = || {
+            ticks.set(ticks.get() + 1);
+            Duration::from_millis(ticks.get())
+        };
+        let profile = profile_query(&context, &query, &input, &clock)?;
+
+        let shape: Vec<_> = profile
+            .expressions
+            .iter()
+            .map(|expression| {
+                (
+                    expression.expression_type.0.as_str(),
+                    expression.depth,
+                    expression.input_count,
+                    expression.output_count,
+                )
+            })
+            .collect();
+        assert_eq!(
+            shape,
+            vec![
+                ("QuerySubTree", 0, 1, 2),
+                ("RelationshipTraversal", 1, 1, 2),
+                ("Aggregation", 0, 2, 1)
+            ]
+        );
+        // Clock reads: query start, subtree enter, traversal enter/exit, subtree exit,
+        // aggregation enter/exit, query end.
+        assert_eq!(profile.expressions[0].elapsed, Duration::from_millis(3));
+        assert_eq!(profile.expressions[1].elapsed, Duration::from_millis(1));
+        assert_eq!(profile.elapsed, Duration::from_millis(7));
+        assert_eq!(profile.cache, RelationshipCacheStats::default());
+        assert_eq!(profile.cache_hit_ratio(), None);
+        Ok(())
+    }
+}
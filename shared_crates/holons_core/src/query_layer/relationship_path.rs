@@ -0,0 +1,409 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::descriptors::accessor_helpers;
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use core_types::{HolonError, RelationshipName};
+use type_names::ToRelationshipName;
+
+/// Upper bound on hops for a single unbounded path step (`*`, `+`, `{n,}`).
+/// Steps that declare more hops than this are rejected at traversal.
+pub const DEFAULT_MAX_PATH_DEPTH: usize = 32;
+
+/// One relationship in a [`RelationshipPath`], repeated between `min_hops`
+/// and `max_hops` times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathStep {
+    relationship_name: RelationshipName,
+    min_hops: usize,
+    max_hops: Option<usize>,
+}
+
+impl PathStep {
+    /// Creates a step; `max_hops` of `None` means unbounded transitive closure.
+    pub fn new(
+        relationship_name: RelationshipName,
+        min_hops: usize,
+        max_hops: Option<usize>,
+    ) -> Result<Self, HolonError> {
+        if let Some(max) = max_hops {
+            if max < min_hops {
+                return Err(HolonError::InvalidParameter(format!(
+                    "Path step {} has max hops {} below min hops {}",
+                    relationship_name, max, min_hops
+                )));
+            }
+        }
+        Ok(Self { relationship_name, min_hops, max_hops })
+    }
+
+    /// Creates a plain one-hop step.
+    pub fn single(relationship_name: RelationshipName) -> Self {
+        Self { relationship_name, min_hops: 1, max_hops: Some(1) }
+    }
+
+    pub fn relationship_name(&self) -> &RelationshipName {
+        &self.relationship_name
+    }
+
+    pub fn min_hops(&self) -> usize {
+        self.min_hops
+    }
+
+    pub fn max_hops(&self) -> Option<usize> {
+        self.max_hops
+    }
+
+    /// Expands `sources` breadth-first along this step's relationship.
+    ///
+    /// Holons reached at a depth within `min_hops..=max_hops` are emitted once,
+    /// in discovery order. Each holon is expanded at most once, so revisits
+    /// (cycles or diamonds) stop the walk rather than repeating it.
+    ///
+    /// A declared `max_hops` above `max_depth` is an error rather than a
+    /// silent truncation, so a bounded step never returns partial results.
+    /// Every holon whose relationship is read is appended to `expansions`.
+    fn traverse(
+        &self,
+        sources: &[HolonReference],
+        max_depth: usize,
+        expansions: &mut Vec<(HolonReference, RelationshipName)>,
+    ) -> Result<Vec<HolonReference>, HolonError> {
+        let max_hops = match self.max_hops {
+            Some(max) if max > max_depth => {
+                return Err(HolonError::InvalidParameter(format!(
+                    "Path step {} declares {} max hops, above the depth limit of {}",
+                    self, max, max_depth
+                )));
+            }
+            Some(max) => max,
+            None => max_depth,
+        };
+        let mut emitted = Vec::new();
+        let mut expanded: Vec<HolonReference> = Vec::new();
+        let mut frontier = Vec::new();
+        push_unique(&mut frontier, sources.iter().cloned());
+
+        if self.min_hops == 0 {
+            push_unique(&mut emitted, frontier.iter().cloned());
+        }
+
+        let mut depth = 0;
+        while depth < max_hops && !frontier.is_empty() {
+            depth += 1;
+            let mut next = Vec::new();
+            for holon in &frontier {
+                if expanded.contains(holon) {
+                    continue;
+                }
+                expanded.push(holon.clone());
+                expansions.push((holon.clone(), self.relationship_name.clone()));
+
+                let related_lock = holon.related_holons(&self.relationship_name)?;
+                let related = related_lock.read().map_err(accessor_helpers::lock_error)?;
+                push_unique(&mut next, related.get_members().iter().cloned());
+            }
+            if depth >= self.min_hops {
+                push_unique(&mut emitted, next.iter().cloned());
+            }
+            frontier = next;
+        }
+
+        Ok(emitted)
+    }
+}
+
+impl fmt::Display for PathStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.relationship_name)?;
+        match (self.min_hops, self.max_hops) {
+            (1, Some(1)) => Ok(()),
+            (0, None) => write!(f, "*"),
+            (1, None) => write!(f, "+"),
+            (0, Some(1)) => write!(f, "?"),
+            (min, None) => write!(f, "{{{min},}}"),
+            (min, Some(max)) if min == max => write!(f, "{{{min}}}"),
+            (min, Some(max)) => write!(f, "{{{min},{max}}}"),
+        }
+    }
+}
+
+/// A sequence of relationship steps such as `Authors/WrittenBy*` or `Extends{1,5}`.
+///
+/// Steps are separated by `/`. Each relationship name may carry a quantifier:
+/// `*` (zero or more), `+` (one or more), `?` (zero or one), `{n}`, `{n,}` or
+/// `{n,m}`. Traversal follows `ReadableHolon::related_holons`, so inverse
+/// relationships are addressed by their inverse names like any other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationshipPath {
+    steps: Vec<PathStep>,
+}
+
+impl RelationshipPath {
+    pub fn new(steps: Vec<PathStep>) -> Result<Self, HolonError> {
+        if steps.is_empty() {
+            return Err(HolonError::InvalidParameter(
+                "Relationship path must have at least one step".to_string(),
+            ));
+        }
+        Ok(Self { steps })
+    }
+
+    /// Parses the textual path syntax described on [`RelationshipPath`].
+    pub fn parse(expression: &str) -> Result<Self, HolonError> {
+        let steps = expression
+            .split('/')
+            .map(|segment| parse_step(segment.trim(), expression))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(steps)
+    }
+
+    pub fn steps(&self) -> &[PathStep] {
+        &self.steps
+    }
+
+    /// Traverses the path from `sources`, limiting each step to
+    /// [`DEFAULT_MAX_PATH_DEPTH`] hops.
+    pub fn traverse(&self, sources: &[HolonReference]) -> Result<Vec<HolonReference>, HolonError> {
+        self.traverse_with_max_depth(sources, DEFAULT_MAX_PATH_DEPTH)
+    }
+
+    /// Traverses the path from `sources`, limiting each step to `max_depth` hops.
+    ///
+    /// Unbounded steps stop at `max_depth`; a step whose declared bound exceeds
+    /// it fails with `InvalidParameter`.
+    ///
+    /// Each step consumes the previous step's distinct results. Results are
+    /// distinct by `HolonReference` identity and keep discovery order.
+    pub fn traverse_with_max_depth(
+        &self,
+        sources: &[HolonReference],
+        max_depth: usize,
+    ) -> Result<Vec<HolonReference>, HolonError> {
+        self.traverse_recording_expansions(sources, max_depth, &mut Vec::new())
+    }
+
+    /// Traverses like [`Self::traverse_with_max_depth`], appending each holon
+    /// whose relationship was read to `expansions` together with that
+    /// relationship's name.
+    pub(crate) fn traverse_recording_expansions(
+        &self,
+        sources: &[HolonReference],
+        max_depth: usize,
+        expansions: &mut Vec<(HolonReference, RelationshipName)>,
+    ) -> Result<Vec<HolonReference>, HolonError> {
+        let mut current = sources.to_vec();
+        for step in &self.steps {
+            current = step.traverse(&current, max_depth, expansions)?;
+        }
+        Ok(current)
+    }
+}
+
+impl fmt::Display for RelationshipPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            if index > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{step}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RelationshipPath {
+    type Err = HolonError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        Self::parse(expression)
+    }
+}
+
+fn parse_step(segment: &str, expression: &str) -> Result<PathStep, HolonError> {
+    let invalid = |reason: &str| {
+        HolonError::InvalidParameter(format!("Invalid relationship path `{expression}`: {reason}"))
+    };
+
+    let name_end =
+        segment.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(segment.len());
+    let (name, quantifier) = segment.split_at(name_end);
+    if name.is_empty() {
+        return Err(invalid("expected a relationship name"));
+    }
+
+    let (min_hops, max_hops) = match quantifier {
+        "" => (1, Some(1)),
+        "*" => (0, None),
+        "+" => (1, None),
+        "?" => (0, Some(1)),
+        bounds if bounds.starts_with('{') && bounds.ends_with('}') => {
+            let inner = &bounds[1..bounds.len() - 1];
+            let parse_bound = |bound: &str| {
+                bound.trim().parse::<usize>().map_err(|_| invalid("expected a numeric bound"))
+            };
+            match inner.split_once(',') {
+                None => {
+                    let exact = parse_bound(inner)?;
+                    (exact, Some(exact))
+                }
+                Some((min, max)) if max.trim().is_empty() => (parse_bound(min)?, None),
+                Some((min, max)) => (parse_bound(min)?, Some(parse_bound(max)?)),
+            }
+        }
+        _ => return Err(invalid(&format!("unsupported quantifier `{quantifier}`"))),
+    };
+
+    PathStep::new(name.to_relationship_name(), min_hops, max_hops)
+}
+
+fn push_unique(
+    target: &mut Vec<HolonReference>,
+    candidates: impl IntoIterator<Item = HolonReference>,
+) {
+    for candidate in candidates {
+        if !target.contains(&candidate) {
+            target.push(candidate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{build_context, new_test_holon};
+    use crate::reference_layer::{TransientReference, WritableHolon};
+
+    fn link(
+        source: &mut TransientReference,
+        relationship_name: &str,
+        targets: &[&TransientReference],
+    ) -> Result<(), HolonError> {
+        source.add_related_holons(
+            relationship_name,
+            targets.iter().map(|target| (*target).clone().into()).collect(),
+        )?;
+        Ok(())
+    }
+
+    fn references(holons: &[&TransientReference]) -> Vec<HolonReference> {
+        holons.iter().map(|holon| (*holon).clone().into()).collect()
+    }
+
+    #[test]
+    fn parse_supports_sequences_and_quantifiers() -> Result<(), HolonError> {
+        let path = RelationshipPath::parse("Authors/WrittenBy*/Extends{1,5}/Owns+/Next?/Parts{2}")?;
+        let bounds: Vec<_> =
+            path.steps().iter().map(|step| (step.min_hops(), step.max_hops())).collect();
+
+        assert_eq!(
+            bounds,
+            vec![(1, Some(1)), (0, None), (1, Some(5)), (1, None), (0, Some(1)), (2, Some(2))]
+        );
+        assert_eq!(path.steps()[0].relationship_name().to_string(), "Authors");
+        Ok(())
+    }
+
+    #[test]
+    fn display_round_trips_parsed_paths() -> Result<(), HolonError> {
+        for expression in
+            ["Authors/WrittenBy*", "Extends{1,5}", "Owns+/Parts{2,}", "Next?/Parts{3}"]
+        {
+            assert_eq!(RelationshipPath::parse(expression)?.to_string(), expression);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rejects_malformed_paths() {
+        for expression in ["", "Authors//WrittenBy", "Extends{5,1}", "Extends{a}", "Owns#", "*"] {
+            assert!(
+                matches!(RelationshipPath::parse(expression), Err(HolonError::InvalidParameter(_))),
+                "expected `{expression}` to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn traverse_follows_steps_in_sequence() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut book = new_test_holon(&context, "book")?;
+        let mut author = new_test_holon(&context, "author")?;
+        let publisher = new_test_holon(&context, "publisher")?;
+        link(&mut book, "Authors", &[&author])?;
+        link(&mut author, "PublishedBy", &[&publisher])?;
+
+        let result =
+            RelationshipPath::parse("Authors/PublishedBy")?.traverse(&references(&[&book]))?;
+
+        assert_eq!(result, references(&[&publisher]));
+        Ok(())
+    }
+
+    #[test]
+    fn traverse_closure_stops_on_cycles() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut a = new_test_holon(&context, "a")?;
+        let mut b = new_test_holon(&context, "b")?;
+        let mut c = new_test_holon(&context, "c")?;
+        link(&mut a, "Extends", &[&b])?;
+        link(&mut b, "Extends", &[&c])?;
+        link(&mut c, "Extends", &[&a])?;
+
+        let plus = RelationshipPath::parse("Extends+")?.traverse(&references(&[&a]))?;
+        let star = RelationshipPath::parse("Extends*")?.traverse(&references(&[&a]))?;
+
+        assert_eq!(plus, references(&[&b, &c, &a]));
+        assert_eq!(star, references(&[&a, &b, &c]));
+        Ok(())
+    }
+
+    #[test]
+    fn traverse_honors_hop_bounds_and_depth_limit() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut chain = Vec::new();
+        for index in 0..6 {
+            chain.push(new_test_holon(&context, &format!("node-{index}"))?);
+        }
+        for index in 0..5 {
+            let target = chain[index + 1].clone();
+            link(&mut chain[index], "Extends", &[&target])?;
+        }
+        let start = references(&[&chain[0]]);
+
+        let bounded = RelationshipPath::parse("Extends{2,3}")?.traverse(&start)?;
+        let limited = RelationshipPath::parse("Extends+")?.traverse_with_max_depth(&start, 2)?;
+
+        assert_eq!(bounded, references(&[&chain[2], &chain[3]]));
+        assert_eq!(limited, references(&[&chain[1], &chain[2]]));
+        Ok(())
+    }
+
+    #[test]
+    fn traverse_rejects_declared_bound_above_depth_limit() -> Result<(), HolonError> {
+        let context = build_context();
+        let start = references(&[&new_test_holon(&context, "start")?]);
+
+        let over_default = RelationshipPath::parse("Extends{1,33}")?.traverse(&start);
+        let over_limit = RelationshipPath::parse("Extends{3}")?.traverse_with_max_depth(&start, 2);
+
+        assert!(matches!(over_default, Err(HolonError::InvalidParameter(_))));
+        assert!(matches!(over_limit, Err(HolonError::InvalidParameter(_))));
+        assert!(RelationshipPath::parse("Extends{1,32}")?.traverse(&start)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn traverse_keeps_mutual_neighbours_for_single_hop() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut left = new_test_holon(&context, "left")?;
+        let mut right = new_test_holon(&context, "right")?;
+        link(&mut left, "Friends", &[&right])?;
+        link(&mut right, "Friends", &[&left])?;
+
+        let result = RelationshipPath::parse("Friends")?.traverse(&references(&[&left, &right]))?;
+
+        assert_eq!(result, references(&[&right, &left]));
+        Ok(())
+    }
+}
@@ -128,6 +128,15 @@ impl HolonReference {
         }
     }
 
+    /// Returns the transaction handle this reference is bound to.
+    pub(crate) fn context_handle(&self) -> TransactionContextHandle {
+        match self {
+            HolonReference::Smart(smart_reference) => smart_reference.context_handle(),
+            HolonReference::Staged(staged_reference) => staged_reference.context_handle(),
+            HolonReference::Transient(transient_reference) => transient_reference.context_handle(),
+        }
+    }
+
     /// Creates a tx-bound `HolonReference::Smart` for the given `HolonId`.
     pub fn smart_from_id(
         transaction_handle: TransactionContextHandle,
@@ -3,7 +3,7 @@ use crate::core_shared_objects::transactions::TransactionContext;
 use crate::core_shared_objects::{Holon, HolonCollection};
 use crate::RelationshipMap;
 use crate::StagedReference;
-use core_types::{HolonError, HolonId, LocalId, RelationshipName};
+use core_types::{HolonError, HolonId, KeyMatch, LocalId, RelationshipName};
 use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -65,6 +65,34 @@ pub trait HolonServiceApi: Debug + Any + Send + Sync {
         context: &Arc<TransactionContext>,
     ) -> Result<HolonCollection, HolonError>;
 
+    /// Retrieves the persisted Holons described by `descriptor_id`, one per lineage, from the
+    /// descriptor's instance index rather than by scanning every holon. The index is keyed on
+    /// the descriptor's lineage root, so any version of the descriptor finds the same instances.
+    ///
+    /// Subtypes are not included; see `LookupFacade::get_holons_by_descriptor`.
+    /// Default implementation is unsupported outside guest/client contexts.
+    fn get_holons_by_descriptor_internal(
+        &self,
+        _context: &Arc<TransactionContext>,
+        _descriptor_id: &HolonId,
+    ) -> Result<HolonCollection, HolonError> {
+        Err(HolonError::NotImplemented("get_holons_by_descriptor_internal".to_string()))
+    }
+
+    /// Retrieves the persisted Holons whose key satisfies `key_match`, one per lineage, from the
+    /// space's key index.
+    ///
+    /// Keys are indexed as the lineage root recorded them, so holons whose effective key rule
+    /// is keyless never appear. Default implementation is unsupported outside guest/client
+    /// contexts.
+    fn get_saved_holons_by_key_internal(
+        &self,
+        _context: &Arc<TransactionContext>,
+        _key_match: &KeyMatch,
+    ) -> Result<HolonCollection, HolonError> {
+        Err(HolonError::NotImplemented("get_saved_holons_by_key_internal".to_string()))
+    }
+
     /// Execute a Holon Loader import using a HolonLoadSet (transient) reference.
     /// Returns a transient reference to a HolonLoadResponse holon.
     fn load_holons_internal(
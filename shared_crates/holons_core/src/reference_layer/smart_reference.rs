@@ -69,6 +69,11 @@ impl SmartReference {
         self.context_handle.tx_id()
     }
 
+    /// Returns the transaction handle this reference is bound to.
+    pub(crate) fn context_handle(&self) -> TransactionContextHandle {
+        self.context_handle.clone()
+    }
+
     /// Returns the persistent holon id for this smart reference.
     pub fn holon_id(&self) -> HolonId {
         self.holon_id.clone()
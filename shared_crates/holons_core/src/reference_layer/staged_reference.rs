@@ -2,15 +2,20 @@ use derive_new::new;
 use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 use std::{fmt, sync::Arc};
-use tracing::info;
+use tracing::{info, warn};
 use type_names::relationship_names::{CoreRelationshipTypeName, ToRelationshipName};
+use type_names::CorePropertyTypeName;
 
 use crate::core_shared_objects::holon::StagedState;
 use crate::core_shared_objects::transactions::{
-    TransactionContext, TransactionContextHandle, TxId,
+    RelationshipEnforcement, TransactionContext, TransactionContextHandle, TxId,
+};
+use crate::descriptors::accessor_helpers::{descriptor_label, lock_error, optional_integer};
+use crate::descriptors::inheritance::{
+    described_by_descriptor, endpoint_type_name, is_endpoint_compatible,
 };
 use crate::descriptors::{
-    effective_relationship_declaration, inheritance::described_by_descriptor, RelationshipDirection,
+    effective_relationship_declaration, Descriptor, RelationshipDescriptor, RelationshipDirection,
 };
 use crate::reference_layer::readable_impl::ReadableHolonImpl;
 use crate::reference_layer::writable_impl::WritableHolonImpl;
@@ -38,10 +43,13 @@ enum DuplicatePolicy {
     Ungoverned,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone)]
 struct RelationshipMutationPolicy {
     note_definitional: Option<bool>,
     duplicate_policy: DuplicatePolicy,
+    /// Governing relationship descriptor whose cardinality and endpoint
+    /// contract is enforced, when the relationship is governed.
+    contract: Option<HolonReference>,
 }
 
 #[derive(new, Debug, Clone)]
@@ -219,6 +227,11 @@ impl StagedReference {
         self.context_handle.tx_id()
     }
 
+    /// Returns the transaction handle this reference is bound to.
+    pub(crate) fn context_handle(&self) -> TransactionContextHandle {
+        self.context_handle.clone()
+    }
+
     // Simple string representations for errors/logging
     pub fn reference_kind_string(&self) -> String {
         "StagedReference".to_string()
@@ -266,6 +279,7 @@ impl StagedReference {
                 return Ok(RelationshipMutationPolicy {
                     note_definitional: None,
                     duplicate_policy: DuplicatePolicy::Ungoverned,
+                    contract: None,
                 });
             }
             Err(
@@ -280,6 +294,7 @@ impl StagedReference {
                     return Ok(RelationshipMutationPolicy {
                         note_definitional: None,
                         duplicate_policy: DuplicatePolicy::Ungoverned,
+                        contract: None,
                     });
                 }
 
@@ -317,7 +332,11 @@ impl StagedReference {
             Some(relationship_descriptor.is_definitional()?)
         };
 
-        Ok(RelationshipMutationPolicy { note_definitional, duplicate_policy })
+        Ok(RelationshipMutationPolicy {
+            note_definitional,
+            duplicate_policy,
+            contract: Some(relationship_descriptor.holon().clone()),
+        })
     }
 
     fn classify_relationship_removal(
@@ -397,6 +416,66 @@ impl StagedReference {
         Ok(filtered)
     }
 
+    /// Checks an add against the max cardinality and target endpoint type declared
+    /// by the governing relationship `descriptor`, before the staged holon is mutated.
+    ///
+    /// Under [`RelationshipEnforcement::Strict`] the first violation fails the add;
+    /// under [`RelationshipEnforcement::Advisory`] each violation is logged and the
+    /// add proceeds. Targets without a `DescribedBy` descriptor are left to
+    /// commit-time validation, as undescribed sources are. Like the duplicate
+    /// check, the member count sees only the staged collection.
+    fn enforce_relationship_contract(
+        &self,
+        relationship_name: &RelationshipName,
+        descriptor: &HolonReference,
+        entries: &[(HolonReference, Option<MapString>)],
+    ) -> Result<(), HolonError> {
+        let mut violations = Vec::new();
+
+        if let Some(max) = optional_integer(descriptor, CorePropertyTypeName::MaxCardinality)? {
+            let collection_arc = self.related_holons(relationship_name)?;
+            let existing = collection_arc.read().map_err(lock_error)?.get_members().len();
+            let count = existing + entries.len();
+            if count as i64 > max {
+                violations.push(HolonError::RelationshipCardinalityExceeded {
+                    relationship: relationship_name.to_string(),
+                    count,
+                    max,
+                    descriptor: descriptor_label(descriptor),
+                });
+            }
+        }
+
+        let target_type = RelationshipDescriptor::from_holon(descriptor.clone()).target_type()?;
+        for (target, _) in entries {
+            if is_endpoint_compatible(target, target_type.holon())?
+                || described_by_descriptor(target)?.is_none()
+            {
+                continue;
+            }
+            violations.push(HolonError::RelationshipEndpointMismatch {
+                relationship: relationship_name.to_string(),
+                target: descriptor_label(target),
+                expected: target_type.header().type_name()?.to_string(),
+                found: endpoint_type_name(target)?,
+                descriptor: descriptor_label(descriptor),
+            });
+        }
+
+        match self.context_handle.context().relationship_enforcement() {
+            RelationshipEnforcement::Strict => match violations.into_iter().next() {
+                Some(violation) => Err(violation),
+                None => Ok(()),
+            },
+            RelationshipEnforcement::Advisory => {
+                for violation in violations {
+                    warn!("Staging relationship despite contract violation: {}", violation);
+                }
+                Ok(())
+            }
+        }
+    }
+
     fn related_holons_with_keys(
         holons: Vec<HolonReference>,
     ) -> Result<Vec<(HolonReference, Option<MapString>)>, HolonError> {
@@ -428,6 +507,10 @@ impl StagedReference {
             return Ok(());
         }
 
+        if let Some(descriptor) = &policy.contract {
+            self.enforce_relationship_contract(&relationship_name, descriptor, &entries)?;
+        }
+
         let rc_holon = self.get_rc_holon()?;
         let mut holon_mut = rc_holon.write().map_err(|e| {
             HolonError::FailedToAcquireLock(format!(
@@ -496,6 +579,7 @@ impl StagedReference {
             RelationshipMutationPolicy {
                 note_definitional: None,
                 duplicate_policy: DuplicatePolicy::Ungoverned,
+                contract: None,
             },
         )
     }
@@ -892,7 +976,7 @@ mod tests {
         reference_layer::WritableHolon,
     };
     use core_types::LocalId;
-    use type_names::{CoreHolonTypeName, CorePropertyTypeName};
+    use type_names::CoreHolonTypeName;
 
     fn force_staged_reference_for_update(
         context: &Arc<TransactionContext>,
@@ -1017,6 +1101,8 @@ mod tests {
         )?;
         relationship_descriptor.remove_property_value(CorePropertyTypeName::IsDefinitional)?;
         relationship_descriptor.remove_property_value(CorePropertyTypeName::AllowsDuplicates)?;
+        // Unbounded, so repeated-target adds exercise duplicate policy alone.
+        relationship_descriptor.remove_property_value(CorePropertyTypeName::MaxCardinality)?;
         if let Some(is_definitional) = is_definitional {
             relationship_descriptor.with_property_value_impl(
                 CorePropertyTypeName::IsDefinitional.as_property_name(),
@@ -1401,6 +1487,101 @@ mod tests {
         Ok(())
     }
 
+    fn described_target(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        descriptor: &StagedReference,
+    ) -> Result<StagedReference, HolonError> {
+        let mut target = staged_target(context, key)?;
+        target.with_descriptor(descriptor.into())?;
+        Ok(target)
+    }
+
+    #[test]
+    fn add_beyond_max_cardinality_errors_before_mutation() -> Result<(), HolonError> {
+        let context = build_context();
+        context.set_relationship_enforcement(RelationshipEnforcement::Strict);
+        let (source_descriptor, _) =
+            staged_relationship_descriptor(&context, "AuthoredBy", Some(false))?;
+        let mut staged_source = staged_update_source(&context, source_descriptor)?;
+        let first_author = staged_target(&context, "first-author")?;
+        let second_author = staged_target(&context, "second-author")?;
+
+        staged_source.add_related_holons("AuthoredBy", vec![first_author.into()])?;
+        let result = staged_source.add_related_holons("AuthoredBy", vec![second_author.into()]);
+
+        assert!(matches!(
+            result,
+            Err(HolonError::RelationshipCardinalityExceeded { relationship, count: 2, max: 1, descriptor })
+                if relationship == "AuthoredBy" && !descriptor.is_empty()
+        ));
+        assert_eq!(relationship_member_count(&staged_source, "AuthoredBy")?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn incompatible_target_type_errors_before_mutation() -> Result<(), HolonError> {
+        let context = build_context();
+        context.set_relationship_enforcement(RelationshipEnforcement::Strict);
+        let (source_descriptor, target_type) =
+            staged_relationship_descriptor(&context, "AuthoredBy", Some(false))?;
+        let place_type = context.mutation().stage_new_holon(new_holon_type_descriptor(
+            &context,
+            "place-type",
+            "PlaceType",
+        )?)?;
+        let source = new_test_holon(&context, "source-instance")?;
+        let mut staged_source = context.mutation().stage_new_holon(source)?;
+        staged_source.with_descriptor(source_descriptor.into())?;
+        let place = described_target(&context, "library", &place_type)?;
+
+        let result = staged_source.add_related_holons("AuthoredBy", vec![place.into()]);
+
+        assert!(matches!(
+            result,
+            Err(HolonError::RelationshipEndpointMismatch { relationship, expected, found, .. })
+                if relationship == "AuthoredBy" && expected == "TargetType" && found == "PlaceType"
+        ));
+        assert_eq!(relationship_member_count(&staged_source, "AuthoredBy")?, 0);
+
+        let mut author_type = context.mutation().stage_new_holon(new_holon_type_descriptor(
+            &context,
+            "author-type",
+            "AuthorType",
+        )?)?;
+        author_type.add_related_holons_ungoverned(
+            CoreRelationshipTypeName::Extends,
+            vec![target_type.into()],
+        )?;
+        let author = described_target(&context, "author", &author_type)?;
+
+        staged_source.add_related_holons("AuthoredBy", vec![author.into()])?;
+
+        assert_eq!(relationship_member_count(&staged_source, "AuthoredBy")?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn advisory_enforcement_applies_contract_violations() -> Result<(), HolonError> {
+        let context = build_context();
+        assert_eq!(context.relationship_enforcement(), RelationshipEnforcement::Advisory);
+        let (source_descriptor, _) =
+            staged_relationship_descriptor(&context, "AuthoredBy", Some(false))?;
+        let place_type = context.mutation().stage_new_holon(new_holon_type_descriptor(
+            &context,
+            "place-type",
+            "PlaceType",
+        )?)?;
+        let mut staged_source = staged_update_source(&context, source_descriptor)?;
+        let author = staged_target(&context, "author")?;
+        let place = described_target(&context, "library", &place_type)?;
+
+        staged_source.add_related_holons("AuthoredBy", vec![author.into(), place.into()])?;
+
+        assert_eq!(relationship_member_count(&staged_source, "AuthoredBy")?, 2);
+        Ok(())
+    }
+
     #[test]
     fn inverse_relationship_name_is_rejected_as_mutation_input() -> Result<(), HolonError> {
         let context = build_context();
@@ -123,6 +123,11 @@ impl TransientReference {
         self.context_handle.tx_id()
     }
 
+    /// Returns the transaction handle this reference is bound to.
+    pub(crate) fn context_handle(&self) -> TransactionContextHandle {
+        self.context_handle.clone()
+    }
+
     /// ⚠️ Returns a snapshot of the raw property map of this holon.
     ///
     /// Intended **only** for the holon loader, specifically for LoaderHolons whose
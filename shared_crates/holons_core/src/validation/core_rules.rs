@@ -0,0 +1,763 @@
+//! Built-in implementations of the seeded Core `ValidationRule`s.
+//!
+//! Each implementation checks one narrow aspect of its subject and reports
+//! every violation it finds, so a subject that breaks several constraints of
+//! one rule yields several results. Value-level constraint rules skip values of
+//! the wrong base kind; the matching `BaseValueKindMatches*` rule reports those.
+
+use std::collections::HashSet;
+
+use base_types::{BaseValue, MapDate, MapTimestamp};
+use core_types::{HolonError, HolonId, PropertyName, RelationshipName, ValidationError};
+use type_names::{CorePropertyTypeName, CoreRelationshipTypeName, CoreValidationRuleName as Rule};
+
+use crate::descriptors::accessor_helpers::{descriptor_label, lock_error, optional_integer};
+use crate::descriptors::inheritance::{endpoint_type_name, extends_parent, is_endpoint_compatible};
+use crate::descriptors::value_descriptor_subtypes::constraints::{
+    resolve_integer_constraints, resolve_string_constraints, resolve_temporal_constraints,
+    resolve_value_array_constraints, IntegerConstraintValidation, StringConstraint,
+    StringConstraintValidation, TemporalBound, TemporalConstraintValidation,
+    ValueArrayConstraintValidation,
+};
+use crate::descriptors::{
+    walk_extends_chain, Descriptor, EnumValueDescriptor, HolonDescriptor,
+    HolonReferenceValueDescriptor, PropertyDescriptor, RecordValueDescriptor,
+    RelationshipDescriptor, ValueArrayDescriptor, ValueDescriptor,
+};
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use crate::validation::{ValidationLevel, ValidationRuleRegistry, ValidationSubject};
+
+type Violations = Result<Vec<HolonError>, HolonError>;
+
+/// Registers every built-in rule under its `CoreValidationRuleName`.
+pub(crate) fn register(registry: &mut ValidationRuleRegistry) {
+    registry
+        .register(Rule::AtMostOneDirectParent.as_rule_name(), at_most_one_direct_parent)
+        .register(Rule::AcyclicExtendsLineage.as_rule_name(), acyclic_extends_lineage)
+        .register(Rule::ExactlyOneDescribedBy.as_rule_name(), exactly_one_described_by)
+        .register(Rule::ConcreteDescribingType.as_rule_name(), concrete_describing_type)
+        .register(Rule::NoUndescribedProperties.as_rule_name(), no_undescribed_properties)
+        .register(Rule::ExplicitKeylessness.as_rule_name(), explicit_keylessness)
+        .register(Rule::KeyPresenceAndValue.as_rule_name(), key_presence_and_value)
+        .register(Rule::RequiredPropertyPresence.as_rule_name(), required_property_presence)
+        .register(Rule::PropertyValueConformance.as_rule_name(), property_value_conformance)
+        .register(Rule::StringLength.as_rule_name(), string_length)
+        .register(Rule::StringPattern.as_rule_name(), string_pattern)
+        .register(Rule::StringFormat.as_rule_name(), string_format)
+        .register(Rule::IntegerRange.as_rule_name(), integer_range)
+        .register(Rule::TimestampRange.as_rule_name(), temporal_range::<MapTimestamp>)
+        .register(Rule::DateRange.as_rule_name(), temporal_range::<MapDate>)
+        .register(Rule::EnumTokenMembership.as_rule_name(), enum_token_membership)
+        .register(Rule::ValueArrayItemCount.as_rule_name(), value_array_item_count)
+        .register(Rule::ValueArrayElementConformance.as_rule_name(), value_array_elements)
+        .register(Rule::HolonReferenceTargetType.as_rule_name(), holon_reference_target_type)
+        .register(Rule::RecordFieldsDeclared.as_rule_name(), record_fields_declared)
+        .register(Rule::RecordFieldConformance.as_rule_name(), record_field_conformance)
+        .register(
+            Rule::RelationshipEndpointCompatibility.as_rule_name(),
+            relationship_endpoint_compatibility,
+        )
+        .register(Rule::RelationshipCollectionPolicy.as_rule_name(), relationship_collection_policy)
+        .register(Rule::RelationshipCardinality.as_rule_name(), relationship_cardinality);
+
+    for rule in [
+        Rule::BaseValueKindMatchesString,
+        Rule::BaseValueKindMatchesInteger,
+        Rule::BaseValueKindMatchesDecimal,
+        Rule::BaseValueKindMatchesTimestamp,
+        Rule::BaseValueKindMatchesDate,
+        Rule::BaseValueKindMatchesBoolean,
+        Rule::BaseValueKindMatchesEnum,
+        Rule::BaseValueKindMatchesBytes,
+        Rule::BaseValueKindMatchesValueArray,
+        Rule::BaseValueKindMatchesHolonReference,
+        Rule::BaseValueKindMatchesRecord,
+    ] {
+        registry.register(rule.as_rule_name(), base_value_kind_matches);
+    }
+}
+
+// ===== Holon level =====
+
+fn at_most_one_direct_parent(subject: &ValidationSubject) -> Violations {
+    let (holon, _) = holon_subject(subject)?;
+    match extends_parent(holon) {
+        Ok(_) => Ok(Vec::new()),
+        Err(error @ HolonError::MultipleExtends { .. }) => Ok(vec![error]),
+        Err(error) => Err(error),
+    }
+}
+
+fn acyclic_extends_lineage(subject: &ValidationSubject) -> Violations {
+    let (holon, _) = holon_subject(subject)?;
+    for ancestor in walk_extends_chain(holon) {
+        match ancestor {
+            Ok(_) => {}
+            Err(error @ HolonError::CyclicExtends { .. }) => return Ok(vec![error]),
+            // Reported by AtMostOneDirectParent.
+            Err(HolonError::MultipleExtends { .. }) => break,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(Vec::new())
+}
+
+fn exactly_one_described_by(subject: &ValidationSubject) -> Violations {
+    let (holon, _) = holon_subject(subject)?;
+    match holon.holon_descriptor() {
+        Ok(_) => Ok(Vec::new()),
+        Err(
+            error
+            @ (HolonError::MissingDescribedBy { .. } | HolonError::MultipleDescribedBy { .. }),
+        ) => Ok(vec![error]),
+        Err(error) => Err(error),
+    }
+}
+
+fn concrete_describing_type(subject: &ValidationSubject) -> Violations {
+    let (holon, descriptor) = holon_subject(subject)?;
+    if !descriptor.header().is_abstract_type()? {
+        return Ok(Vec::new());
+    }
+    Ok(vec![descriptor_violation(format!(
+        "{} is described by abstract type {}",
+        descriptor_label(holon),
+        descriptor.header().type_name()?
+    ))])
+}
+
+/// `Key` is governed by the key rules rather than by instance property
+/// declarations, so it never counts as undescribed.
+fn no_undescribed_properties(subject: &ValidationSubject) -> Violations {
+    let (holon, descriptor) = holon_subject(subject)?;
+    if descriptor.allows_additional_properties()? {
+        return Ok(Vec::new());
+    }
+
+    let mut declared = HashSet::from([CorePropertyTypeName::Key.as_property_name()]);
+    for property in descriptor.instance_properties()? {
+        declared.insert(PropertyName(property.header().type_name()?));
+    }
+
+    let mut violations = Vec::new();
+    for name in holon.into_model()?.property_map.keys() {
+        if !declared.contains(name) {
+            violations.push(property_violation(format!(
+                "{} is not declared for {} and {} does not allow additional properties",
+                name,
+                descriptor_label(holon),
+                descriptor.header().type_name()?
+            )));
+        }
+    }
+    Ok(violations)
+}
+
+fn explicit_keylessness(subject: &ValidationSubject) -> Violations {
+    let (holon, descriptor) = holon_subject(subject)?;
+    if !is_keyless(descriptor)?.unwrap_or(false) {
+        return Ok(Vec::new());
+    }
+    match holon.key()? {
+        Some(key) => Ok(vec![property_violation(format!(
+            "{} carries key {} but {} is keyless",
+            descriptor_label(holon),
+            key,
+            descriptor.header().type_name()?
+        ))]),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Checks key presence only; computing the expected key from the key rule is
+/// not yet available at runtime.
+fn key_presence_and_value(subject: &ValidationSubject) -> Violations {
+    let (holon, descriptor) = holon_subject(subject)?;
+    if is_keyless(descriptor)?.unwrap_or(true) {
+        return Ok(Vec::new());
+    }
+    match holon.key()? {
+        Some(key) if !key.0.is_empty() => Ok(Vec::new()),
+        _ => Ok(vec![property_violation(format!(
+            "{} has no key but {} is keyed",
+            descriptor_label(holon),
+            descriptor.header().type_name()?
+        ))]),
+    }
+}
+
+/// Returns whether the descriptor's effective key rule is keyless, or `None`
+/// when it declares no key rule at all.
+fn is_keyless(descriptor: &HolonDescriptor) -> Result<Option<bool>, HolonError> {
+    match descriptor.effective_key_rule() {
+        Ok(rule) => Ok(Some(rule.is_keyless()?)),
+        Err(HolonError::NoEffectiveKeyRule { .. }) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+// ===== Property level =====
+
+fn required_property_presence(subject: &ValidationSubject) -> Violations {
+    let (holon, property, name) = property_subject(subject)?;
+    if !property.effective_is_value_required()? || holon.property_value(name)?.is_some() {
+        return Ok(Vec::new());
+    }
+    Ok(vec![property_violation(format!(
+        "Required property {} is missing on {}",
+        name,
+        descriptor_label(holon)
+    ))])
+}
+
+/// Checks that a populated property selects a value type; the value itself is
+/// checked by the value-level rules bound to that value type.
+fn property_value_conformance(subject: &ValidationSubject) -> Violations {
+    let (holon, property, name) = property_subject(subject)?;
+    if holon.property_value(name)?.is_none() {
+        return Ok(Vec::new());
+    }
+    match property.value_type() {
+        Ok(_) => Ok(Vec::new()),
+        Err(
+            error @ (HolonError::MissingRequiredRelationship { .. }
+            | HolonError::MultipleRelatedHolons { .. }),
+        ) => Ok(vec![error]),
+        Err(error) => Err(error),
+    }
+}
+
+// ===== Value level =====
+
+fn base_value_kind_matches(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    violations(value_type.is_valid_kind(value))
+}
+
+fn string_length(subject: &ValidationSubject) -> Violations {
+    check_string_constraints(subject, |constraint| {
+        matches!(
+            constraint,
+            StringConstraint::MinimumLength(_) | StringConstraint::MaximumLength(_)
+        )
+    })
+}
+
+fn string_pattern(subject: &ValidationSubject) -> Violations {
+    check_string_constraints(subject, |constraint| {
+        matches!(constraint, StringConstraint::Pattern(_))
+    })
+}
+
+fn string_format(subject: &ValidationSubject) -> Violations {
+    check_string_constraints(subject, |constraint| {
+        matches!(constraint, StringConstraint::Format(_))
+    })
+}
+
+fn check_string_constraints(
+    subject: &ValidationSubject,
+    selects: fn(&StringConstraint) -> bool,
+) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    let BaseValue::StringValue(value) = value else {
+        return Ok(Vec::new());
+    };
+    let label = descriptor_label(value_type.holon());
+    collect_violations(
+        resolve_string_constraints(value_type.holon())?
+            .iter()
+            .filter(|constraint| selects(constraint))
+            .map(|constraint| constraint.is_valid(&value.0, &label)),
+    )
+}
+
+fn integer_range(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    let BaseValue::IntegerValue(value) = value else {
+        return Ok(Vec::new());
+    };
+    let label = descriptor_label(value_type.holon());
+    collect_violations(
+        resolve_integer_constraints(value_type.holon())?
+            .iter()
+            .map(|constraint| constraint.is_valid(value.0, &label)),
+    )
+}
+
+fn temporal_range<T: TemporalBound>(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    let Some(value) = T::from_base_value(value) else {
+        return Ok(Vec::new());
+    };
+    let label = descriptor_label(value_type.holon());
+    collect_violations(
+        resolve_temporal_constraints::<T>(value_type.holon())?
+            .iter()
+            .map(|constraint| constraint.is_valid(value, &label)),
+    )
+}
+
+fn enum_token_membership(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    if !matches!(value, BaseValue::EnumValue(_)) {
+        return Ok(Vec::new());
+    }
+    violations(EnumValueDescriptor::from_holon(value_type.holon().clone()).is_valid(value))
+}
+
+fn value_array_item_count(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    let BaseValue::ValueArray(values) = value else {
+        return Ok(Vec::new());
+    };
+    let label = descriptor_label(value_type.holon());
+    collect_violations(
+        resolve_value_array_constraints(value_type.holon())?
+            .iter()
+            .map(|constraint| constraint.is_valid(values.len(), &label)),
+    )
+}
+
+fn value_array_elements(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    let BaseValue::ValueArray(values) = value else {
+        return Ok(Vec::new());
+    };
+    let element_value_type =
+        ValueArrayDescriptor::from_holon(value_type.holon().clone()).element_value_type()?;
+    collect_violations(values.iter().map(|element| element_value_type.is_valid(element)))
+}
+
+fn holon_reference_target_type(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    // Undecodable bytes are reported once, by `BaseValueKindMatchesHolonReference`.
+    if !matches!(value, BaseValue::BytesValue(_)) || HolonId::try_from(value).is_err() {
+        return Ok(Vec::new());
+    }
+    violations(
+        HolonReferenceValueDescriptor::from_holon(value_type.holon().clone()).is_valid(value),
+    )
+}
+
+fn record_fields_declared(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    let BaseValue::Record(record) = value else {
+        return Ok(Vec::new());
+    };
+    let fields = RecordValueDescriptor::from_holon(value_type.holon().clone()).fields()?;
+    let label = descriptor_label(value_type.holon());
+
+    let mut violations = Vec::new();
+    for name in record.0.keys().filter(|name| !fields.contains_key(*name)) {
+        violations.push(HolonError::DescriptorDeclarationNotFound {
+            kind: "record field".to_string(),
+            name: name.to_string(),
+            descriptor: label.clone(),
+        });
+    }
+    for (name, field) in &fields {
+        if !record.0.contains_key(name) && field.effective_is_value_required()? {
+            violations.push(HolonError::MissingRecordField {
+                field: name.to_string(),
+                descriptor: label.clone(),
+            });
+        }
+    }
+    Ok(violations)
+}
+
+fn record_field_conformance(subject: &ValidationSubject) -> Violations {
+    let (value_type, value) = value_subject(subject)?;
+    let BaseValue::Record(record) = value else {
+        return Ok(Vec::new());
+    };
+    let fields = RecordValueDescriptor::from_holon(value_type.holon().clone()).fields()?;
+
+    let mut results = Vec::new();
+    for (name, field) in &fields {
+        if let Some(field_value) = record.0.get(name) {
+            results.push(field.value_type()?.is_valid(field_value));
+        }
+    }
+    collect_violations(results)
+}
+
+// ===== Relationship level =====
+
+/// `OwnedBy` is supplied by the space at commit, so its minimum is only
+/// enforced once the holon is saved.
+fn relationship_cardinality(subject: &ValidationSubject) -> Violations {
+    let (holon, relationship, name) = relationship_subject(subject)?;
+    let count = related_members(holon, name)?.len() as i64;
+    let min = relationship.min_cardinality()?;
+    let max = optional_integer(relationship.holon(), CorePropertyTypeName::MaxCardinality)?;
+    let supplied_at_commit =
+        name == &CoreRelationshipTypeName::OwnedBy.as_relationship_name() && !holon.is_saved();
+
+    if (count >= min || supplied_at_commit) && max.is_none_or(|max| count <= max) {
+        return Ok(Vec::new());
+    }
+    Ok(vec![relationship_violation(format!(
+        "{} on {} has {} members, outside cardinality {}..{} declared by {}",
+        name,
+        descriptor_label(holon),
+        count,
+        min,
+        max.map_or_else(|| "*".to_string(), |max| max.to_string()),
+        descriptor_label(relationship.holon())
+    ))])
+}
+
+fn relationship_endpoint_compatibility(subject: &ValidationSubject) -> Violations {
+    let (holon, relationship, name) = relationship_subject(subject)?;
+    let target_type = relationship.target_type()?;
+
+    let mut violations = Vec::new();
+    for member in related_members(holon, name)? {
+        if is_endpoint_compatible(&member, target_type.holon())? {
+            continue;
+        }
+        let found = endpoint_type_name(&member)?;
+        violations.push(relationship_violation(format!(
+            "{} target {} on {} has type {}, expected {} for {}",
+            name,
+            descriptor_label(&member),
+            descriptor_label(holon),
+            found,
+            target_type.header().type_name()?,
+            descriptor_label(relationship.holon())
+        )));
+    }
+    Ok(violations)
+}
+
+fn relationship_collection_policy(subject: &ValidationSubject) -> Violations {
+    let (holon, relationship, name) = relationship_subject(subject)?;
+    if relationship.allows_duplicates()? {
+        return Ok(Vec::new());
+    }
+
+    let mut seen = HashSet::new();
+    let mut violations = Vec::new();
+    for member in related_members(holon, name)? {
+        if !seen.insert(member.reference_id_string()) {
+            violations.push(relationship_violation(format!(
+                "{} on {} holds {} more than once, but {} does not allow duplicates",
+                name,
+                descriptor_label(holon),
+                descriptor_label(&member),
+                descriptor_label(relationship.holon())
+            )));
+        }
+    }
+    Ok(violations)
+}
+
+fn related_members(
+    holon: &HolonReference,
+    name: &RelationshipName,
+) -> Result<Vec<HolonReference>, HolonError> {
+    let collection_arc = holon.related_holons(name)?;
+    let members = collection_arc.read().map_err(lock_error)?.get_members().clone();
+    Ok(members)
+}
+
+// ===== Subjects and violations =====
+
+fn holon_subject(
+    subject: &ValidationSubject,
+) -> Result<(&HolonReference, &HolonDescriptor), HolonError> {
+    match subject {
+        ValidationSubject::Holon { holon, descriptor } => Ok((holon, descriptor)),
+        other => Err(wrong_subject(ValidationLevel::Holon, other)),
+    }
+}
+
+fn property_subject(
+    subject: &ValidationSubject,
+) -> Result<(&HolonReference, &PropertyDescriptor, &PropertyName), HolonError> {
+    match subject {
+        ValidationSubject::Property { holon, property, name } => Ok((holon, property, name)),
+        other => Err(wrong_subject(ValidationLevel::Property, other)),
+    }
+}
+
+fn value_subject(
+    subject: &ValidationSubject,
+) -> Result<(&ValueDescriptor, &BaseValue), HolonError> {
+    match subject {
+        ValidationSubject::Value { value_type, value, .. } => Ok((value_type, value)),
+        other => Err(wrong_subject(ValidationLevel::Value, other)),
+    }
+}
+
+fn relationship_subject(
+    subject: &ValidationSubject,
+) -> Result<(&HolonReference, &RelationshipDescriptor, &RelationshipName), HolonError> {
+    match subject {
+        ValidationSubject::Relationship { holon, relationship, name } => {
+            Ok((holon, relationship, name))
+        }
+        other => Err(wrong_subject(ValidationLevel::Relationship, other)),
+    }
+}
+
+fn wrong_subject(expected: ValidationLevel, found: &ValidationSubject) -> HolonError {
+    HolonError::InvalidParameter(format!(
+        "Validation rule expects a {} subject, found a {} subject",
+        expected.as_str(),
+        found.level().as_str()
+    ))
+}
+
+fn descriptor_violation(message: String) -> HolonError {
+    HolonError::ValidationError(ValidationError::DescriptorError(message))
+}
+
+fn property_violation(message: String) -> HolonError {
+    HolonError::ValidationError(ValidationError::PropertyError(message))
+}
+
+fn relationship_violation(message: String) -> HolonError {
+    HolonError::ValidationError(ValidationError::RelationshipError(message))
+}
+
+/// Splits the outcome of a descriptor check into a violation by the value and
+/// a failure to evaluate the check at all.
+fn violations(result: Result<(), HolonError>) -> Violations {
+    collect_violations([result])
+}
+
+fn collect_violations(results: impl IntoIterator<Item = Result<(), HolonError>>) -> Violations {
+    let mut violations = Vec::new();
+    for result in results {
+        match result {
+            Ok(()) => {}
+            Err(error) if is_value_violation(&error) => violations.push(error),
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(violations)
+}
+
+fn is_value_violation(error: &HolonError) -> bool {
+    matches!(
+        error,
+        HolonError::ValueKindMismatch { .. }
+            | HolonError::IntegerOutOfRange { .. }
+            | HolonError::StringLengthOutOfRange { .. }
+            | HolonError::StringPatternMismatch { .. }
+            | HolonError::StringFormatMismatch { .. }
+            | HolonError::TemporalOutOfRange { .. }
+            | HolonError::ItemCountOutOfRange { .. }
+            | HolonError::EnumVariantNotInSchema { .. }
+            | HolonError::ReferenceTargetTypeMismatch { .. }
+            | HolonError::MissingRecordField { .. }
+            | HolonError::DescriptorDeclarationNotFound { .. }
+            // Bytes that do not decode as a `HolonId`.
+            | HolonError::InvalidParameter(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        build_context, new_declared_relationship_descriptor_holon, new_descriptor_holon,
+        new_holon_type_descriptor, new_property_descriptor_holon, new_test_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use base_types::{MapBytes, MapInteger, MapString};
+    use std::sync::Arc;
+
+    fn described(
+        context: &Arc<TransactionContext>,
+        key: &str,
+        descriptor: &TransientReference,
+    ) -> Result<TransientReference, HolonError> {
+        let mut holon = new_test_holon(context, key)?;
+        holon.add_related_holons(
+            CoreRelationshipTypeName::DescribedBy,
+            vec![descriptor.clone().into()],
+        )?;
+        Ok(holon)
+    }
+
+    fn relationship_subject_for(
+        holon: &TransientReference,
+        relationship: &TransientReference,
+        name: &str,
+    ) -> ValidationSubject {
+        ValidationSubject::Relationship {
+            holon: holon.into(),
+            relationship: RelationshipDescriptor::from_holon(relationship.into()),
+            name: RelationshipName(MapString(name.to_string())),
+        }
+    }
+
+    fn is_relationship_error(error: &HolonError) -> bool {
+        matches!(error, HolonError::ValidationError(ValidationError::RelationshipError(_)))
+    }
+
+    #[test]
+    fn relationship_rules_report_cardinality_endpoint_and_duplicate_violations(
+    ) -> Result<(), HolonError> {
+        let context = build_context();
+        let book_type = new_holon_type_descriptor(&context, "book", "BookType")?;
+        let person_type = new_holon_type_descriptor(&context, "person", "PersonType")?;
+        let place_type = new_holon_type_descriptor(&context, "place", "PlaceType")?;
+        let authored_by = new_declared_relationship_descriptor_holon(
+            &context,
+            "authored-by",
+            "AuthoredBy",
+            book_type.clone().into(),
+            person_type.clone().into(),
+        )?;
+
+        let alice = described(&context, "alice", &person_type)?;
+        let home = described(&context, "home", &place_type)?;
+        let mut book = described(&context, "book-1", &book_type)?;
+        book.add_related_holons("AuthoredBy", vec![alice.clone().into()])?;
+        let subject = relationship_subject_for(&book, &authored_by, "AuthoredBy");
+
+        assert!(relationship_cardinality(&subject)?.is_empty());
+        assert!(relationship_endpoint_compatibility(&subject)?.is_empty());
+        assert!(relationship_collection_policy(&subject)?.is_empty());
+
+        book.add_related_holons("AuthoredBy", vec![home.into()])?;
+        let cardinality = relationship_cardinality(&subject)?;
+        assert_eq!(cardinality.len(), 1);
+        assert!(is_relationship_error(&cardinality[0]));
+        assert!(cardinality[0].to_string().contains("outside cardinality 0..1"));
+
+        let endpoint = relationship_endpoint_compatibility(&subject)?;
+        assert_eq!(endpoint.len(), 1);
+        assert!(endpoint[0].to_string().contains("has type PlaceType, expected PersonType"));
+        Ok(())
+    }
+
+    #[test]
+    fn relationship_cardinality_waives_owned_by_minimum_until_saved() -> Result<(), HolonError> {
+        let context = build_context();
+        let book_type = new_holon_type_descriptor(&context, "book", "BookType")?;
+        let space_type = new_holon_type_descriptor(&context, "space", "HolonSpaceType")?;
+        let mut owned_by = new_declared_relationship_descriptor_holon(
+            &context,
+            "owned-by",
+            "OwnedBy",
+            book_type.clone().into(),
+            space_type.into(),
+        )?;
+        owned_by.with_property_value(CorePropertyTypeName::MinCardinality, 1_i64)?;
+        let book = described(&context, "book-1", &book_type)?;
+
+        assert!(relationship_cardinality(&relationship_subject_for(&book, &owned_by, "OwnedBy"))?
+            .is_empty());
+        let shelved =
+            relationship_cardinality(&relationship_subject_for(&book, &owned_by, "ShelvedIn"))?;
+        assert_eq!(shelved.len(), 1);
+        assert!(is_relationship_error(&shelved[0]));
+        Ok(())
+    }
+
+    #[test]
+    fn record_fields_declared_reports_every_field_violation() -> Result<(), HolonError> {
+        let context = build_context();
+        let string_value = new_descriptor_holon(&context, "text", "StringValueType", "Value")?;
+        let amount = new_property_descriptor_holon(
+            &context,
+            "amount-field",
+            "Amount",
+            "Amount",
+            true,
+            new_descriptor_holon(&context, "amount", "IntegerValueType", "Value")?.into(),
+        )?;
+        let currency = new_property_descriptor_holon(
+            &context,
+            "currency-field",
+            "Currency",
+            "Currency",
+            true,
+            string_value.into(),
+        )?;
+        let mut money = new_descriptor_holon(&context, "money", "RecordValueType", "Value")?;
+        money.add_related_holons(
+            CoreRelationshipTypeName::RecordFields,
+            vec![amount.into(), currency.into()],
+        )?;
+        let holon = new_test_holon(&context, "price")?;
+        let subject = ValidationSubject::Value {
+            holon: holon.into(),
+            property: PropertyName(MapString("Price".to_string())),
+            value_type: ValueDescriptor::from_holon(money.into()),
+            value: BaseValue::Record(
+                [
+                    (
+                        MapString("Amount".to_string()),
+                        BaseValue::StringValue(MapString("9".into())),
+                    ),
+                    (MapString("Rate".to_string()), BaseValue::IntegerValue(MapInteger(1))),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        };
+
+        let declared = record_fields_declared(&subject)?;
+        assert_eq!(declared.len(), 2);
+        assert!(matches!(
+            &declared[0],
+            HolonError::DescriptorDeclarationNotFound { name, .. } if name == "Rate"
+        ));
+        assert!(matches!(
+            &declared[1],
+            HolonError::MissingRecordField { field, .. } if field == "Currency"
+        ));
+        assert!(matches!(
+            record_field_conformance(&subject)?.as_slice(),
+            [HolonError::ValueKindMismatch { expected, .. }] if expected == "Integer"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn holon_reference_bytes_that_are_not_a_holon_id_are_reported_once() -> Result<(), HolonError> {
+        let context = build_context();
+        let reference =
+            new_descriptor_holon(&context, "ref-value", "HolonReferenceValueType", "Value")?;
+        let holon = new_test_holon(&context, "owned")?;
+        let subject = ValidationSubject::Value {
+            holon: holon.into(),
+            property: PropertyName(MapString("Owner".to_string())),
+            value_type: ValueDescriptor::from_holon(reference.into()),
+            value: BaseValue::BytesValue(MapBytes(vec![9, 9])),
+        };
+
+        assert!(matches!(
+            base_value_kind_matches(&subject)?.as_slice(),
+            [HolonError::InvalidParameter(message)] if message.contains("HolonId")
+        ));
+        assert!(holon_reference_target_type(&subject)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn rules_reject_subjects_of_the_wrong_level() -> Result<(), HolonError> {
+        let context = build_context();
+        let holon = new_test_holon(&context, "holon")?;
+        let descriptor = new_holon_type_descriptor(&context, "thing", "ThingType")?;
+        let subject = ValidationSubject::Holon {
+            holon: holon.into(),
+            descriptor: HolonDescriptor::from_holon(descriptor.into()),
+        };
+
+        assert!(at_most_one_direct_parent(&subject)?.is_empty());
+        assert!(matches!(
+            string_length(&subject),
+            Err(HolonError::InvalidParameter(message)) if message.contains("Value subject")
+        ));
+        Ok(())
+    }
+}
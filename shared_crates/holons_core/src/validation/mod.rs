@@ -0,0 +1,22 @@
+mod core_rules;
+pub mod validation_binding;
+pub mod validation_engine;
+pub mod validation_implementation;
+pub mod validation_report;
+pub mod validation_result;
+pub mod validation_rule;
+pub mod validation_subject;
+
+pub use validation_binding::{bound_rules, ValidationBinding};
+pub use validation_engine::{
+    ValidationEngine, ValidationRuleImplementation, ValidationRuleRegistry, WasmValidationSandbox,
+};
+pub use validation_implementation::{
+    ValidationImplementation, ValidationImplementationEngine, DEFAULT_VALIDATION_ENTRYPOINT,
+};
+pub use validation_report::ValidationReport;
+pub use validation_result::{materialize_results, ValidationResult};
+pub use validation_rule::{
+    ValidationBlockingBehavior, ValidationLevel, ValidationRule, ValidationSeverity,
+};
+pub use validation_subject::ValidationSubject;
@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use core_types::HolonError;
+use type_names::CoreRelationshipTypeName;
+
+use crate::descriptors::accessor_helpers::{lock_error, require_single_related};
+use crate::descriptors::{walk_extends_chain, Descriptor};
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use crate::validation::ValidationRule;
+
+/// Runtime wrapper for a `ValidationBinding` holon.
+///
+/// A binding commits the descriptor it `AppliesTo` to the rule it `UsesRule`.
+/// Descriptors reach their bindings through the inverse `HasValidationBinding`.
+#[derive(Debug, Clone)]
+pub struct ValidationBinding {
+    holon: HolonReference,
+}
+
+impl ValidationBinding {
+    /// Wraps an already-resolved binding holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Returns the binding holon.
+    pub fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+
+    /// Returns the descriptor this binding applies to.
+    pub fn applies_to(&self) -> Result<HolonReference, HolonError> {
+        require_single_related(&self.holon, CoreRelationshipTypeName::AppliesTo)
+    }
+
+    /// Returns the rule this binding commits its descriptor to.
+    pub fn uses_rule(&self) -> Result<ValidationRule, HolonError> {
+        Ok(ValidationRule::from_holon(require_single_related(
+            &self.holon,
+            CoreRelationshipTypeName::UsesRule,
+        )?))
+    }
+}
+
+impl From<HolonReference> for ValidationBinding {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+/// Returns the rules bound to `descriptor` or to any descriptor it extends.
+///
+/// Bindings are inherited additively: the walk starts at `descriptor` and
+/// follows `Extends`, so rules bound closer to the descriptor come first. A
+/// rule bound more than once along the lineage is returned once.
+pub fn bound_rules(descriptor: &HolonReference) -> Result<Vec<ValidationRule>, HolonError> {
+    let mut seen = HashSet::new();
+    let mut rules = Vec::new();
+
+    for ancestor in walk_extends_chain(descriptor) {
+        let collection_arc =
+            ancestor?.related_holons(CoreRelationshipTypeName::HasValidationBinding)?;
+        let bindings = collection_arc.read().map_err(lock_error)?.get_members().clone();
+        for binding in bindings {
+            let rule = ValidationBinding::from_holon(binding).uses_rule()?;
+            if seen.insert(rule.holon().reference_id_string()) {
+                rules.push(rule);
+            }
+        }
+    }
+
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{
+        bind_validation_rule, build_context, new_descriptor_holon, new_validation_rule_holon,
+    };
+    use crate::reference_layer::WritableHolon;
+    use base_types::MapString;
+
+    #[test]
+    fn bound_rules_are_inherited_nearest_first_and_deduplicated() -> Result<(), HolonError> {
+        let context = build_context();
+        let shared = new_validation_rule_holon(&context, "Shared", "Holon", "Error", "Advisory")?;
+        let inherited =
+            new_validation_rule_holon(&context, "Inherited", "Holon", "Error", "Advisory")?;
+        let local = new_validation_rule_holon(&context, "Local", "Holon", "Error", "Advisory")?;
+
+        let mut parent = new_descriptor_holon(&context, "parent", "ParentType", "Holon")?;
+        bind_validation_rule(&context, &mut parent, &inherited)?;
+        bind_validation_rule(&context, &mut parent, &shared)?;
+        let mut child = new_descriptor_holon(&context, "child", "ChildType", "Holon")?;
+        child.add_related_holons(CoreRelationshipTypeName::Extends, vec![parent.into()])?;
+        let binding = bind_validation_rule(&context, &mut child, &local)?;
+        bind_validation_rule(&context, &mut child, &shared)?;
+
+        let names = bound_rules(&child.clone().into())?
+            .iter()
+            .map(ValidationRule::rule_name)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            names,
+            vec![
+                MapString("Local".to_string()),
+                MapString("Shared".to_string()),
+                MapString("Inherited".to_string()),
+            ]
+        );
+        assert_eq!(
+            ValidationBinding::from_holon(binding.into()).applies_to()?,
+            HolonReference::from(&child)
+        );
+        Ok(())
+    }
+}
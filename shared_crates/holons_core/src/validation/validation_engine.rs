@@ -0,0 +1,817 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+use base_types::{BaseValue, MapString};
+use core_types::{HolonError, PropertyName};
+use tracing::debug;
+use type_names::CoreRelationshipTypeName;
+
+use crate::descriptors::accessor_helpers::lock_error;
+use crate::descriptors::{Descriptor, HolonDescriptor, ValueDescriptor};
+use crate::reference_layer::{HolonReference, ReadableHolon};
+use crate::validation::{
+    bound_rules, core_rules, ValidationBlockingBehavior, ValidationImplementationEngine,
+    ValidationLevel, ValidationResult, ValidationRule, ValidationSeverity, ValidationSubject,
+};
+
+/// Rust implementation of one `ValidationRule`.
+///
+/// `Ok` lists every way the subject violates the rule and is empty when the
+/// subject conforms. `Err` means the rule could not be evaluated at all, e.g.
+/// because a descriptor it reads is malformed.
+pub trait ValidationRuleImplementation: Send + Sync {
+    fn validate(&self, subject: &ValidationSubject) -> Result<Vec<HolonError>, HolonError>;
+}
+
+impl<F> ValidationRuleImplementation for F
+where
+    F: Fn(&ValidationSubject) -> Result<Vec<HolonError>, HolonError> + Send + Sync,
+{
+    fn validate(&self, subject: &ValidationSubject) -> Result<Vec<HolonError>, HolonError> {
+        self(subject)
+    }
+}
+
+/// Executes `WasmModule` validation implementations.
+///
+/// `holons_core` also runs inside guest zomes and cannot embed a WASM runtime,
+/// so the host that owns one supplies it. The sandbox is responsible for
+/// isolating module execution, bounding its fuel and memory, and projecting
+/// the subject across the module boundary; like a Rust implementation it
+/// returns every violation found, or `Err` when the module could not be
+/// evaluated.
+///
+/// The host workspace provides one in the `wasm_validation_sandbox` crate,
+/// which also documents the module ABI. Until a host installs a sandbox,
+/// every rule implemented by a `WasmModule` reports a blocking result
+/// instead of running.
+pub trait WasmValidationSandbox: Send + Sync {
+    fn validate(
+        &self,
+        module_ref: &MapString,
+        entrypoint: &MapString,
+        subject: &ValidationSubject,
+    ) -> Result<Vec<HolonError>, HolonError>;
+}
+
+/// Rule implementations keyed by the `type_name` of the rule they implement,
+/// plus the functions and WASM sandbox that schema-declared
+/// `ValidationImplementation`s dispatch to.
+#[derive(Clone, Default)]
+pub struct ValidationRuleRegistry {
+    implementations: HashMap<MapString, Arc<dyn ValidationRuleImplementation>>,
+    functions: HashMap<MapString, Arc<dyn ValidationRuleImplementation>>,
+    wasm_sandbox: Option<Arc<dyn WasmValidationSandbox>>,
+}
+
+impl ValidationRuleRegistry {
+    /// Creates a registry with no implementations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with the built-in implementations of the seeded Core rules.
+    pub fn with_core_rules() -> Self {
+        let mut registry = Self::new();
+        core_rules::register(&mut registry);
+        registry
+    }
+
+    /// Registers `implementation` for the rule named `rule_name`, replacing any
+    /// implementation already registered under that name.
+    pub fn register(
+        &mut self,
+        rule_name: MapString,
+        implementation: impl ValidationRuleImplementation + 'static,
+    ) -> &mut Self {
+        self.implementations.insert(rule_name, Arc::new(implementation));
+        self
+    }
+
+    /// Returns the implementation registered for `rule_name`, if any.
+    pub fn get(&self, rule_name: &MapString) -> Option<&Arc<dyn ValidationRuleImplementation>> {
+        self.implementations.get(rule_name)
+    }
+
+    /// Registers `function` under the `ImplementationRef` that `RustFunction`
+    /// implementations name, replacing any function already registered there.
+    pub fn register_function(
+        &mut self,
+        implementation_ref: MapString,
+        function: impl ValidationRuleImplementation + 'static,
+    ) -> &mut Self {
+        self.functions.insert(implementation_ref, Arc::new(function));
+        self
+    }
+
+    /// Returns the function registered under `implementation_ref`, if any.
+    pub fn function(
+        &self,
+        implementation_ref: &MapString,
+    ) -> Option<&Arc<dyn ValidationRuleImplementation>> {
+        self.functions.get(implementation_ref)
+    }
+
+    /// Installs the sandbox that executes `WasmModule` implementations.
+    pub fn set_wasm_sandbox(&mut self, sandbox: impl WasmValidationSandbox + 'static) -> &mut Self {
+        self.wasm_sandbox = Some(Arc::new(sandbox));
+        self
+    }
+
+    /// Returns the installed WASM sandbox, if any.
+    pub fn wasm_sandbox(&self) -> Option<&Arc<dyn WasmValidationSandbox>> {
+        self.wasm_sandbox.as_ref()
+    }
+
+    /// Runs the implementation `dispatch` selects for `rule_name`, or returns
+    /// `None` when none is available.
+    fn run(
+        &self,
+        rule_name: &MapString,
+        dispatch: &Dispatch,
+        subject: &ValidationSubject,
+    ) -> Result<Option<Vec<HolonError>>, HolonError> {
+        match dispatch {
+            Dispatch::Registered => {
+                self.get(rule_name).map(|implementation| implementation.validate(subject))
+            }
+            Dispatch::Function(implementation_ref) => {
+                self.function(implementation_ref).map(|function| function.validate(subject))
+            }
+            Dispatch::Wasm { module_ref, entrypoint } => {
+                self.wasm_sandbox().map(|sandbox| sandbox.validate(module_ref, entrypoint, subject))
+            }
+        }
+        .transpose()
+    }
+}
+
+impl fmt::Debug for ValidationRuleRegistry {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rule_names: Vec<&MapString> = self.implementations.keys().collect();
+        rule_names.sort();
+        let mut function_refs: Vec<&MapString> = self.functions.keys().collect();
+        function_refs.sort();
+        formatter
+            .debug_struct("ValidationRuleRegistry")
+            .field("rules", &rule_names)
+            .field("functions", &function_refs)
+            .field("wasm_sandbox", &self.wasm_sandbox.is_some())
+            .finish()
+    }
+}
+
+/// Executes the `ValidationRule`s that schema `ValidationBinding`s attach to
+/// descriptors.
+///
+/// Rule selection is data-driven: a subject is checked against every rule
+/// bound to its descriptor's `Extends` lineage whose `ValidationLevel` matches
+/// the subject. A rule that declares a `ValidationImplementation` runs the
+/// registered function or WASM module it names; any other rule runs the
+/// implementation registered under its own name. A declared implementation
+/// that cannot be run (an unregistered function, or a WASM module without a
+/// sandbox) is reported as a commit-blocking `Error` result; a rule that
+/// declares none and has no registered implementation is skipped.
+///
+/// A holon without a `DescribedBy` descriptor has no bindings to select rules
+/// from; it is reported against the rule supplied through
+/// [`Self::with_described_by_rule`], if any.
+///
+/// Results are report-only. Severity and blocking behavior describe what a
+/// caller should do with a violation; nothing here, and not
+/// `TransactionContext::commit`, refuses to commit on their account.
+#[derive(Debug, Clone)]
+pub struct ValidationEngine {
+    registry: ValidationRuleRegistry,
+    described_by_rule: Option<ValidationRule>,
+}
+
+impl Default for ValidationEngine {
+    fn default() -> Self {
+        Self::new(ValidationRuleRegistry::with_core_rules())
+    }
+}
+
+impl ValidationEngine {
+    /// Creates an engine that dispatches to the implementations in `registry`.
+    pub fn new(registry: ValidationRuleRegistry) -> Self {
+        Self { registry, described_by_rule: None }
+    }
+
+    /// Reports each holon without a `DescribedBy` descriptor as a violation of
+    /// `rule`, normally the core `ExactlyOneDescribedBy` rule.
+    pub fn with_described_by_rule(mut self, rule: ValidationRule) -> Self {
+        self.described_by_rule = Some(rule);
+        self
+    }
+
+    /// Returns the registry rules are dispatched through.
+    pub fn registry(&self) -> &ValidationRuleRegistry {
+        &self.registry
+    }
+
+    /// Validates one holon and returns every violation found.
+    pub fn validate_holon(
+        &self,
+        holon: &HolonReference,
+    ) -> Result<Vec<ValidationResult>, HolonError> {
+        self.validate_holons(std::slice::from_ref(holon))
+    }
+
+    /// Validates each holon in turn and returns every violation found.
+    ///
+    /// Subjects are visited per holon in a fixed order: the holon itself, each
+    /// effective instance property followed by its value when populated, then
+    /// each effective instance relationship. A holon without a `DescribedBy`
+    /// descriptor produces a single result for the described-by rule, or none
+    /// when the engine has no such rule.
+    pub fn validate_holons(
+        &self,
+        holons: &[HolonReference],
+    ) -> Result<Vec<ValidationResult>, HolonError> {
+        let mut run = ValidationRun { engine: self, bound: HashMap::new(), results: Vec::new() };
+        for holon in holons {
+            run.validate_holon(holon)?;
+        }
+        Ok(run.results)
+    }
+}
+
+/// Where a bound rule's implementation is looked up.
+#[derive(Clone)]
+enum Dispatch {
+    /// The implementation registered under the rule's own name.
+    Registered,
+    /// The function registered under a `RustFunction` implementation's reference.
+    Function(MapString),
+    /// An export of a `WasmModule` implementation, run in the registry's sandbox.
+    Wasm { module_ref: MapString, entrypoint: MapString },
+}
+
+impl Dispatch {
+    /// Explains why a declared implementation could not be run. Returns
+    /// `None` for rules that declare no implementation.
+    fn unavailable(&self, rule_name: &MapString) -> Option<HolonError> {
+        match self {
+            Dispatch::Registered => None,
+            Dispatch::Function(implementation_ref) => Some(HolonError::NotImplemented(format!(
+                "RustFunction `{implementation_ref}` declared by validation rule {rule_name} \
+                 is not registered"
+            ))),
+            Dispatch::Wasm { module_ref, .. } => Some(HolonError::ServiceNotAvailable(format!(
+                "WasmModule `{module_ref}` declared by validation rule {rule_name} cannot run \
+                 without a WASM sandbox"
+            ))),
+        }
+    }
+}
+
+/// A bound rule with its declared metadata read once per run.
+#[derive(Clone)]
+struct BoundRule {
+    rule: ValidationRule,
+    name: MapString,
+    level: ValidationLevel,
+    severity: ValidationSeverity,
+    blocking: ValidationBlockingBehavior,
+    dispatch: Dispatch,
+}
+
+impl BoundRule {
+    fn read(rule: ValidationRule) -> Result<Self, HolonError> {
+        let dispatch = match rule.implemented_by()? {
+            None => Dispatch::Registered,
+            Some(implementation) => match implementation.engine()? {
+                ValidationImplementationEngine::RustFunction => {
+                    Dispatch::Function(implementation.implementation_ref()?)
+                }
+                ValidationImplementationEngine::WasmModule => Dispatch::Wasm {
+                    module_ref: implementation.implementation_ref()?,
+                    entrypoint: implementation.entrypoint()?,
+                },
+            },
+        };
+        Ok(Self {
+            name: rule.rule_name()?,
+            level: rule.validation_level()?,
+            severity: rule.default_severity()?,
+            blocking: rule.minimum_blocking_behavior()?,
+            dispatch,
+            rule,
+        })
+    }
+}
+
+/// Per-call state: bound rules are cached by descriptor so shared descriptors
+/// are resolved once however many holons use them.
+struct ValidationRun<'a> {
+    engine: &'a ValidationEngine,
+    bound: HashMap<String, Vec<BoundRule>>,
+    results: Vec<ValidationResult>,
+}
+
+impl ValidationRun<'_> {
+    fn validate_holon(&mut self, holon: &HolonReference) -> Result<(), HolonError> {
+        let Some(descriptor_holon) = first_described_by(holon)? else {
+            return self.report_undescribed(holon);
+        };
+
+        let holon_subject = ValidationSubject::Holon {
+            holon: holon.clone(),
+            descriptor: HolonDescriptor::from_holon(descriptor_holon.clone()),
+        };
+        self.apply(&descriptor_holon, &holon_subject)?;
+
+        let descriptor = HolonDescriptor::from_holon(descriptor_holon);
+
+        for property in descriptor.instance_properties()? {
+            let name = PropertyName(property.header().type_name()?);
+            let value = holon.property_value(&name)?;
+            let value_type = property.value_type().ok();
+            let property_holon = property.holon().clone();
+            let subject =
+                ValidationSubject::Property { holon: holon.clone(), property, name: name.clone() };
+            self.apply(&property_holon, &subject)?;
+
+            // An unresolvable value type is reported by PropertyValueConformance.
+            if let (Some(value), Some(value_type)) = (value, value_type) {
+                self.apply_value(holon, name, value_type, value)?;
+            }
+        }
+
+        for relationship in descriptor.instance_relationships()? {
+            let name = relationship.base_relationship_name()?;
+            let relationship_holon = relationship.holon().clone();
+            let subject =
+                ValidationSubject::Relationship { holon: holon.clone(), relationship, name };
+            self.apply(&relationship_holon, &subject)?;
+        }
+
+        Ok(())
+    }
+
+    fn report_undescribed(&mut self, holon: &HolonReference) -> Result<(), HolonError> {
+        let Some(rule) = &self.engine.described_by_rule else {
+            debug!("Skipping validation of undescribed holon {}", holon.reference_id_string());
+            return Ok(());
+        };
+        let bound = BoundRule::read(rule.clone())?;
+        self.results.push(ValidationResult {
+            rule: bound.rule,
+            rule_name: bound.name,
+            level: bound.level,
+            severity: bound.severity,
+            blocking: bound.blocking,
+            holon: holon.clone(),
+            path: None,
+            violation: HolonError::MissingDescribedBy { holon: holon.summarize()? },
+        });
+        Ok(())
+    }
+
+    fn apply_value(
+        &mut self,
+        holon: &HolonReference,
+        property: PropertyName,
+        value_type: ValueDescriptor,
+        value: BaseValue,
+    ) -> Result<(), HolonError> {
+        let value_type_holon = value_type.holon().clone();
+        let subject =
+            ValidationSubject::Value { holon: holon.clone(), property, value_type, value };
+        self.apply(&value_type_holon, &subject)
+    }
+
+    /// Runs each rule bound to `descriptor` at the subject's level.
+    fn apply(
+        &mut self,
+        descriptor: &HolonReference,
+        subject: &ValidationSubject,
+    ) -> Result<(), HolonError> {
+        let level = subject.level();
+        let mut applied = HashSet::new();
+
+        for bound in self.bound_rules(descriptor)? {
+            if bound.level != level || !applied.insert(bound.name.clone()) {
+                continue;
+            }
+            let Some(violations) =
+                self.engine.registry.run(&bound.name, &bound.dispatch, subject)?
+            else {
+                match bound.dispatch.unavailable(&bound.name) {
+                    Some(violation) => self.results.push(ValidationResult {
+                        rule: bound.rule.clone(),
+                        rule_name: bound.name.clone(),
+                        level: bound.level,
+                        severity: ValidationSeverity::Error,
+                        blocking: ValidationBlockingBehavior::CommitBlocking,
+                        holon: subject.holon().clone(),
+                        path: subject.path(),
+                        violation,
+                    }),
+                    None => {
+                        debug!("No implementation available for validation rule {}", bound.name)
+                    }
+                }
+                continue;
+            };
+            for violation in violations {
+                self.results.push(ValidationResult {
+                    rule: bound.rule.clone(),
+                    rule_name: bound.name.clone(),
+                    level: bound.level,
+                    severity: bound.severity,
+                    blocking: bound.blocking,
+                    holon: subject.holon().clone(),
+                    path: subject.path(),
+                    violation,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn bound_rules(&mut self, descriptor: &HolonReference) -> Result<Vec<BoundRule>, HolonError> {
+        let descriptor_id = descriptor.reference_id_string();
+        if let Some(bound) = self.bound.get(&descriptor_id) {
+            return Ok(bound.clone());
+        }
+        let bound = bound_rules(descriptor)?
+            .into_iter()
+            .map(BoundRule::read)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.bound.insert(descriptor_id, bound.clone());
+        Ok(bound)
+    }
+}
+
+fn first_described_by(holon: &HolonReference) -> Result<Option<HolonReference>, HolonError> {
+    let collection_arc = holon.related_holons(CoreRelationshipTypeName::DescribedBy)?;
+    let collection = collection_arc.read().map_err(lock_error)?;
+    Ok(collection.get_members().first().cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_shared_objects::transactions::TransactionContext;
+    use crate::descriptors::test_support::{
+        bind_validation_rule, build_context, core_holon_type_name, implement_validation_rule,
+        new_descriptor_holon, new_holon_type_descriptor, new_property_descriptor_holon,
+        new_test_holon, new_validation_rule_holon,
+    };
+    use crate::reference_layer::{TransientReference, WritableHolon};
+    use crate::validation::DEFAULT_VALIDATION_ENTRYPOINT;
+    use std::sync::Mutex;
+    use type_names::{CoreHolonTypeName, CorePropertyTypeName, CoreValidationRuleName as Rule};
+
+    fn rule(
+        context: &Arc<TransactionContext>,
+        name: &str,
+        level: &str,
+    ) -> Result<TransientReference, HolonError> {
+        new_validation_rule_holon(context, name, level, "Error", "CommitBlocking")
+    }
+
+    /// PersonType declares a required `Name` whose value type requires at
+    /// least three characters. Rules bound to the ancestors `Name` and its
+    /// value type extend are reached through inheritance.
+    fn person_type(context: &Arc<TransactionContext>) -> Result<TransientReference, HolonError> {
+        let family = new_descriptor_holon(
+            context,
+            "string-constraint-family",
+            &core_holon_type_name(CoreHolonTypeName::StringValueConstraint),
+            "Holon",
+        )?;
+        let mut minimum = new_descriptor_holon(
+            context,
+            "minimum",
+            &core_holon_type_name(CoreHolonTypeName::MinimumLength),
+            "Holon",
+        )?;
+        minimum.with_property_value(CorePropertyTypeName::ConstraintLength, 3_i64)?;
+        minimum.add_related_holons(CoreRelationshipTypeName::Extends, vec![family.into()])?;
+
+        let mut string_value =
+            new_descriptor_holon(context, "string-value", "StringValueType", "Value")?;
+        bind_validation_rule(
+            context,
+            &mut string_value,
+            &rule(context, Rule::BaseValueKindMatchesString.as_rule_name().0.as_str(), "Value")?,
+        )?;
+        let mut name_value = new_descriptor_holon(context, "name-value", "NameValueType", "Value")?;
+        name_value
+            .add_related_holons(CoreRelationshipTypeName::Extends, vec![string_value.into()])?;
+        name_value
+            .add_related_holons(CoreRelationshipTypeName::Constraints, vec![minimum.into()])?;
+        bind_validation_rule(
+            context,
+            &mut name_value,
+            &rule(context, Rule::StringLength.as_rule_name().0.as_str(), "Value")?,
+        )?;
+
+        let mut property_type =
+            new_descriptor_holon(context, "property-type", "PropertyType", "Property")?;
+        bind_validation_rule(
+            context,
+            &mut property_type,
+            &rule(context, Rule::RequiredPropertyPresence.as_rule_name().0.as_str(), "Property")?,
+        )?;
+        // A value-level rule bound to a property descriptor is never applied
+        // to a property subject.
+        bind_validation_rule(
+            context,
+            &mut property_type,
+            &rule(context, "MisboundValueRule", "Value")?,
+        )?;
+        let mut name = new_property_descriptor_holon(
+            context,
+            "name-property",
+            "Name",
+            "Name",
+            true,
+            name_value.into(),
+        )?;
+        name.add_related_holons(CoreRelationshipTypeName::Extends, vec![property_type.into()])?;
+
+        let mut person = new_holon_type_descriptor(context, "person", "PersonType")?;
+        person
+            .add_related_holons(CoreRelationshipTypeName::InstanceProperties, vec![name.into()])?;
+        bind_validation_rule(
+            context,
+            &mut person,
+            &rule(context, Rule::ConcreteDescribingType.as_rule_name().0.as_str(), "Holon")?,
+        )?;
+        bind_validation_rule(context, &mut person, &rule(context, "UnimplementedRule", "Holon")?)?;
+        Ok(person)
+    }
+
+    fn person(
+        context: &Arc<TransactionContext>,
+        person_type: &TransientReference,
+        key: &str,
+        name: Option<&str>,
+    ) -> Result<HolonReference, HolonError> {
+        let mut holon = new_test_holon(context, key)?;
+        if let Some(name) = name {
+            holon.with_property_value("Name", name)?;
+        }
+        holon.add_related_holons(
+            CoreRelationshipTypeName::DescribedBy,
+            vec![person_type.clone().into()],
+        )?;
+        Ok(holon.into())
+    }
+
+    #[test]
+    fn validate_holons_applies_bound_rules_at_matching_levels() -> Result<(), HolonError> {
+        let context = build_context();
+        let person_type = person_type(&context)?;
+        let mut registry = ValidationRuleRegistry::with_core_rules();
+        registry.register(
+            MapString("MisboundValueRule".to_string()),
+            |_: &ValidationSubject| -> Result<Vec<HolonError>, HolonError> {
+                Err(HolonError::InvalidParameter("misbound rule was applied".to_string()))
+            },
+        );
+        let engine = ValidationEngine::new(registry);
+
+        let holons = vec![
+            person(&context, &person_type, "alice", Some("Al"))?,
+            person(&context, &person_type, "bob", None)?,
+            person(&context, &person_type, "carol", Some("Carol"))?,
+            new_test_holon(&context, "undescribed")?.into(),
+        ];
+        let results = engine.validate_holons(&holons)?;
+
+        let summary: Vec<(String, String, Option<String>)> = results
+            .iter()
+            .map(|result| {
+                (
+                    result.holon.key().unwrap().unwrap().to_string(),
+                    result.rule_name.to_string(),
+                    result.path.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("alice".to_string(), "StringLength".to_string(), Some("Name".to_string())),
+                (
+                    "bob".to_string(),
+                    "RequiredPropertyPresence".to_string(),
+                    Some("Name".to_string())
+                ),
+            ]
+        );
+        assert!(matches!(
+            results[0].violation,
+            HolonError::StringLengthOutOfRange { length: 2, min: Some(3), .. }
+        ));
+        assert_eq!(results[1].level, ValidationLevel::Property);
+        assert!(results.iter().all(ValidationResult::is_blocking));
+        Ok(())
+    }
+
+    #[test]
+    fn validate_holon_takes_severity_and_blocking_from_the_rule() -> Result<(), HolonError> {
+        let context = build_context();
+        let mut person_type = person_type(&context)?;
+        let audit = new_validation_rule_holon(&context, "Audit", "Holon", "Warning", "Advisory")?;
+        bind_validation_rule(&context, &mut person_type, &audit)?;
+
+        // Only `Audit` is registered, so the Core rules bound above are skipped.
+        let mut registry = ValidationRuleRegistry::new();
+        registry.register(
+            MapString("Audit".to_string()),
+            |_: &ValidationSubject| -> Result<Vec<HolonError>, HolonError> {
+                Ok(vec![
+                    HolonError::InvalidParameter("first".to_string()),
+                    HolonError::InvalidParameter("second".to_string()),
+                ])
+            },
+        );
+        let engine = ValidationEngine::new(registry);
+        let alice = person(&context, &person_type, "alice", Some("Al"))?;
+        let results = engine.validate_holon(&alice)?;
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|result| {
+            result.severity == ValidationSeverity::Warning
+                && !result.is_blocking()
+                && result.path.is_none()
+                && result.rule.holon() == &HolonReference::from(&audit)
+        }));
+        assert!(results[0].message().contains("first"));
+        assert!(results[1].message().contains("second"));
+        Ok(())
+    }
+
+    /// BookType binds a holon-level `IsbnChecksum` rule. The returned rule is
+    /// left unimplemented so each test can declare its own implementation.
+    fn book_type(
+        context: &Arc<TransactionContext>,
+    ) -> Result<(TransientReference, TransientReference), HolonError> {
+        let mut book_type = new_holon_type_descriptor(context, "book", "BookType")?;
+        let isbn_rule = rule(context, "IsbnChecksum", "Holon")?;
+        bind_validation_rule(context, &mut book_type, &isbn_rule)?;
+        Ok((book_type, isbn_rule))
+    }
+
+    fn book(
+        context: &Arc<TransactionContext>,
+        book_type: &TransientReference,
+        key: &str,
+        isbn: &str,
+    ) -> Result<HolonReference, HolonError> {
+        let mut holon = new_test_holon(context, key)?;
+        holon.with_property_value("Isbn", isbn)?;
+        holon.add_related_holons(
+            CoreRelationshipTypeName::DescribedBy,
+            vec![book_type.clone().into()],
+        )?;
+        Ok(holon.into())
+    }
+
+    fn isbn13_checksum(subject: &ValidationSubject) -> Result<Vec<HolonError>, HolonError> {
+        let isbn = match subject.holon().property_value("Isbn")? {
+            Some(BaseValue::StringValue(isbn)) => isbn.0,
+            _ => return Ok(Vec::new()),
+        };
+        let sum: u32 = isbn
+            .chars()
+            .filter_map(|digit| digit.to_digit(10))
+            .enumerate()
+            .map(|(index, digit)| if index % 2 == 0 { digit } else { digit * 3 })
+            .sum();
+        if sum.is_multiple_of(10) {
+            Ok(Vec::new())
+        } else {
+            Ok(vec![HolonError::InvalidParameter(format!("`{isbn}` fails the ISBN-13 checksum"))])
+        }
+    }
+
+    #[test]
+    fn validate_holons_dispatches_declared_rust_function_implementations() -> Result<(), HolonError>
+    {
+        let context = build_context();
+        let (book_type, mut isbn_rule) = book_type(&context)?;
+        implement_validation_rule(&context, &mut isbn_rule, "RustFunction", "isbn13", None)?;
+
+        // The declared implementation takes precedence over one registered
+        // under the rule's own name.
+        let mut registry = ValidationRuleRegistry::new();
+        registry.register(
+            MapString("IsbnChecksum".to_string()),
+            |_: &ValidationSubject| -> Result<Vec<HolonError>, HolonError> {
+                Err(HolonError::InvalidParameter(
+                    "rule-name implementation was applied".to_string(),
+                ))
+            },
+        );
+        registry.register_function(MapString("isbn13".to_string()), isbn13_checksum);
+        let engine = ValidationEngine::new(registry);
+
+        let holons = vec![
+            book(&context, &book_type, "valid", "978-0-306-40615-7")?,
+            book(&context, &book_type, "invalid", "978-0-306-40615-8")?,
+        ];
+        let results = engine.validate_holons(&holons)?;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].holon, holons[1]);
+        assert_eq!(results[0].rule_name, MapString("IsbnChecksum".to_string()));
+        assert!(results[0].message().contains("ISBN-13 checksum"));
+        assert!(results[0].is_blocking());
+
+        // Without the named function every subject gets a blocking result.
+        let results =
+            ValidationEngine::new(ValidationRuleRegistry::new()).validate_holons(&holons)?;
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(ValidationResult::is_blocking));
+        assert!(matches!(
+            &results[0].violation,
+            HolonError::NotImplemented(message) if message.contains("`isbn13`")
+        ));
+        Ok(())
+    }
+
+    /// Records each module call and reports one violation per call.
+    #[derive(Default)]
+    struct RecordingSandbox {
+        calls: Arc<Mutex<Vec<(String, String, String)>>>,
+    }
+
+    impl WasmValidationSandbox for RecordingSandbox {
+        fn validate(
+            &self,
+            module_ref: &MapString,
+            entrypoint: &MapString,
+            subject: &ValidationSubject,
+        ) -> Result<Vec<HolonError>, HolonError> {
+            let key = subject.holon().key()?.map(|key| key.to_string()).unwrap_or_default();
+            self.calls.lock().expect("calls lock").push((
+                module_ref.to_string(),
+                entrypoint.to_string(),
+                key,
+            ));
+            Ok(vec![HolonError::InvalidParameter(format!("{module_ref} rejected the holon"))])
+        }
+    }
+
+    #[test]
+    fn validate_holon_runs_wasm_module_implementations_in_the_installed_sandbox(
+    ) -> Result<(), HolonError> {
+        let context = build_context();
+        let (first_type, mut first_rule) = book_type(&context)?;
+        implement_validation_rule(
+            &context,
+            &mut first_rule,
+            "WasmModule",
+            "isbn-rules.wasm",
+            Some("check_isbn"),
+        )?;
+        let (second_type, mut second_rule) = book_type(&context)?;
+        implement_validation_rule(&context, &mut second_rule, "WasmModule", "default.wasm", None)?;
+
+        let sandbox = RecordingSandbox::default();
+        let calls = sandbox.calls.clone();
+        let mut registry = ValidationRuleRegistry::new();
+        registry.set_wasm_sandbox(sandbox);
+        let engine = ValidationEngine::new(registry);
+
+        let results = engine.validate_holons(&[
+            book(&context, &first_type, "first", "978-0-306-40615-7")?,
+            book(&context, &second_type, "second", "978-0-306-40615-7")?,
+        ])?;
+
+        assert_eq!(
+            *calls.lock().expect("calls lock"),
+            vec![
+                ("isbn-rules.wasm".to_string(), "check_isbn".to_string(), "first".to_string()),
+                (
+                    "default.wasm".to_string(),
+                    DEFAULT_VALIDATION_ENTRYPOINT.to_string(),
+                    "second".to_string()
+                ),
+            ]
+        );
+        assert_eq!(results.len(), 2);
+        assert!(results[0].message().contains("isbn-rules.wasm rejected the holon"));
+
+        // Without a sandbox WASM implementations report a blocking result.
+        let alone = book(&context, &first_type, "third", "978-0-306-40615-7")?;
+        let results = ValidationEngine::default().validate_holon(&alone)?;
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_blocking());
+        assert_eq!(results[0].severity, ValidationSeverity::Error);
+        assert!(matches!(
+            &results[0].violation,
+            HolonError::ServiceNotAvailable(message) if message.contains("isbn-rules.wasm")
+        ));
+        Ok(())
+    }
+}
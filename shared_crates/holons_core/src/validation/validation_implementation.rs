@@ -0,0 +1,90 @@
+use base_types::MapString;
+use core_types::HolonError;
+use type_names::CorePropertyTypeName;
+
+use crate::descriptors::accessor_helpers;
+use crate::reference_layer::HolonReference;
+
+/// Export invoked in a `WasmModule` implementation that declares no entrypoint.
+pub const DEFAULT_VALIDATION_ENTRYPOINT: &str = "validate";
+
+/// Engine that executes a `ValidationImplementation`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ValidationImplementationEngine {
+    RustFunction,
+    WasmModule,
+}
+
+impl ValidationImplementationEngine {
+    /// Returns the `ValidationImplementationEngine` enum variant name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RustFunction => "RustFunction",
+            Self::WasmModule => "WasmModule",
+        }
+    }
+
+    /// Parses the enum variant name stored on a `ValidationImplementation` holon.
+    pub fn parse(value: &MapString) -> Result<Self, HolonError> {
+        match value.0.as_str() {
+            "RustFunction" => Ok(Self::RustFunction),
+            "WasmModule" => Ok(Self::WasmModule),
+            _ => Err(HolonError::InvalidParameter(format!(
+                "Unknown validation implementation engine: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Runtime wrapper for a `ValidationImplementation` holon.
+///
+/// A rule reaches its implementation through `ImplementedBy`. The
+/// `ImplementationRef` names a function registered with the
+/// `ValidationRuleRegistry` or locates a WASM module the registry's sandbox
+/// can execute.
+#[derive(Debug, Clone)]
+pub struct ValidationImplementation {
+    holon: HolonReference,
+}
+
+impl ValidationImplementation {
+    /// Wraps an already-resolved implementation holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Returns the implementation holon.
+    pub fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+
+    /// Returns the engine that executes this implementation.
+    pub fn engine(&self) -> Result<ValidationImplementationEngine, HolonError> {
+        ValidationImplementationEngine::parse(&accessor_helpers::require_enum_string(
+            &self.holon,
+            CorePropertyTypeName::ImplementationEngine,
+        )?)
+    }
+
+    /// Returns the registered function name or WASM module locator.
+    pub fn implementation_ref(&self) -> Result<MapString, HolonError> {
+        accessor_helpers::require_string(&self.holon, CorePropertyTypeName::ImplementationRef)
+    }
+
+    /// Returns the WASM export to invoke, defaulting to
+    /// [`DEFAULT_VALIDATION_ENTRYPOINT`] when none is declared.
+    pub fn entrypoint(&self) -> Result<MapString, HolonError> {
+        Ok(accessor_helpers::optional_string(
+            &self.holon,
+            CorePropertyTypeName::ImplementationEntrypoint,
+        )?
+        .unwrap_or_else(|| MapString(DEFAULT_VALIDATION_ENTRYPOINT.to_string())))
+    }
+}
+
+impl From<HolonReference> for ValidationImplementation {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
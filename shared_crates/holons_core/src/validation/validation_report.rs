@@ -0,0 +1,340 @@
+use std::sync::Arc;
+
+use base_types::MapString;
+use core_types::HolonError;
+use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::reference_layer::{TransientReference, WritableHolon};
+use crate::validation::{materialize_results, ValidationResult};
+
+/// Aggregated outcome of validating a set of holons.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    /// Number of holons the report covers, including those with no results.
+    pub validated_holon_count: usize,
+    /// Every violation found, grouped by holon in validation order.
+    pub results: Vec<ValidationResult>,
+}
+
+impl ValidationReport {
+    /// Returns the number of results that should prevent a commit.
+    pub fn blocking_count(&self) -> usize {
+        self.results.iter().filter(|result| result.is_blocking()).count()
+    }
+
+    /// Returns whether any result should prevent a commit.
+    ///
+    /// Commit does not check this itself; callers that enforce validation
+    /// test it before committing.
+    pub fn is_commit_blocking(&self) -> bool {
+        self.results.iter().any(ValidationResult::is_blocking)
+    }
+
+    /// Materializes this report as a transient `ValidationReport` holon.
+    ///
+    /// Each result is materialized as a transient `ValidationResult` holon and
+    /// linked, in order, through `ReportsResult`.
+    pub fn to_holon(
+        &self,
+        context: &Arc<TransactionContext>,
+    ) -> Result<TransientReference, HolonError> {
+        let mut report =
+            context.mutation().new_holon(Some(MapString("validation-report".to_string())))?;
+        report
+            .with_property_value(
+                CorePropertyTypeName::ValidatedHolonCount,
+                self.validated_holon_count as i64,
+            )?
+            .with_property_value(
+                CorePropertyTypeName::BlockingResultCount,
+                self.blocking_count() as i64,
+            )?;
+        report.add_related_holons(
+            CoreRelationshipTypeName::ReportsResult,
+            materialize_results(context, &self.results)?,
+        )?;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::descriptors::test_support::{
+        bind_validation_rule, build_context, build_space_manager, new_descriptor_holon,
+        new_holon_type_descriptor, new_property_descriptor_holon, new_test_holon,
+        new_validation_rule_holon, open_transaction,
+    };
+    use crate::reference_layer::{HolonReference, ReadableHolon, StagedReference};
+    use crate::validation::{ValidationEngine, ValidationRuleRegistry, ValidationSubject};
+    use base_types::{BaseValue, MapInteger};
+    use type_names::CoreValidationRuleName;
+
+    fn person(
+        context: &Arc<TransactionContext>,
+        person_type: &TransientReference,
+        key: &str,
+        name: Option<&str>,
+    ) -> Result<HolonReference, HolonError> {
+        let mut holon = new_test_holon(context, key)?;
+        if let Some(name) = name {
+            holon.with_property_value("Name", name)?;
+        }
+        holon.add_related_holons(
+            CoreRelationshipTypeName::DescribedBy,
+            vec![person_type.clone().into()],
+        )?;
+        Ok(holon.into())
+    }
+
+    /// PersonType declares a required `Name`; presence is checked by a rule
+    /// bound to the property type `Name` extends.
+    fn person_type(context: &Arc<TransactionContext>) -> Result<TransientReference, HolonError> {
+        let text = new_descriptor_holon(context, "text", "StringValueType", "Value")?;
+        let mut property_type =
+            new_descriptor_holon(context, "property-type", "PropertyType", "Property")?;
+        let required = new_validation_rule_holon(
+            context,
+            CoreValidationRuleName::RequiredPropertyPresence.as_rule_name().0.as_str(),
+            "Property",
+            "Error",
+            "CommitBlocking",
+        )?;
+        bind_validation_rule(context, &mut property_type, &required)?;
+        let mut name =
+            new_property_descriptor_holon(context, "name", "Name", "Name", true, text.into())?;
+        name.add_related_holons(CoreRelationshipTypeName::Extends, vec![property_type.into()])?;
+        let mut person_type = new_holon_type_descriptor(context, "person", "PersonType")?;
+        person_type
+            .add_related_holons(CoreRelationshipTypeName::InstanceProperties, vec![name.into()])?;
+        Ok(person_type)
+    }
+
+    #[test]
+    fn to_holon_counts_blocking_results_and_links_each_result() -> Result<(), HolonError> {
+        let context = build_context();
+        let person_type = person_type(&context)?;
+        let holons = vec![
+            person(&context, &person_type, "alice", Some("Alice"))?,
+            person(&context, &person_type, "bob", None)?,
+        ];
+        let report = ValidationReport {
+            validated_holon_count: holons.len(),
+            results: ValidationEngine::default().validate_holons(&holons)?,
+        };
+        assert!(report.is_commit_blocking());
+
+        let holon = HolonReference::from(report.to_holon(&context)?);
+
+        assert_eq!(
+            holon.property_value(CorePropertyTypeName::ValidatedHolonCount)?,
+            Some(BaseValue::IntegerValue(MapInteger(2)))
+        );
+        assert_eq!(
+            holon.property_value(CorePropertyTypeName::BlockingResultCount)?,
+            Some(BaseValue::IntegerValue(MapInteger(1)))
+        );
+        let collection = holon.related_holons(CoreRelationshipTypeName::ReportsResult)?;
+        let results = collection.read().expect("collection lock").get_members().clone();
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].property_value(CorePropertyTypeName::HolonKey)?,
+            Some(BaseValue::StringValue(MapString("bob".to_string())))
+        );
+        assert_eq!(
+            results[0].property_value(CorePropertyTypeName::SubjectPath)?,
+            Some(BaseValue::StringValue(MapString("Name".to_string())))
+        );
+        Ok(())
+    }
+
+    /// Stages the core `ExactlyOneDescribedBy` rule under its schema key so
+    /// `TransactionContext::validate` can report undescribed holons against it.
+    /// The staged rule is itself undescribed and is reported as well.
+    fn stage_described_by_rule(context: &Arc<TransactionContext>) -> Result<(), HolonError> {
+        let mut rule = new_descriptor_holon(
+            context,
+            "ExactlyOneDescribedBy.ValidationRule",
+            CoreValidationRuleName::ExactlyOneDescribedBy.as_rule_name().0.as_str(),
+            "Holon",
+        )?;
+        rule.with_property_value(CorePropertyTypeName::ValidationLevel, "Holon")?
+            .with_property_value(CorePropertyTypeName::DefaultSeverity, "Error")?
+            .with_property_value(CorePropertyTypeName::MinimumBlockingBehavior, "CommitBlocking")?;
+        context.mutation().stage_new_holon(rule)?;
+        Ok(())
+    }
+
+    /// Staged counterpart of `person_type`. Staging copies only properties and
+    /// staged holons may not link to transients, so each descriptor is staged
+    /// first and linked afterwards.
+    fn staged_person_type(
+        context: &Arc<TransactionContext>,
+    ) -> Result<StagedReference, HolonError> {
+        let stage = |holon: TransientReference| context.mutation().stage_new_holon(holon);
+        let text = stage(new_descriptor_holon(context, "text", "StringValueType", "Value")?)?;
+        let required = stage(new_validation_rule_holon(
+            context,
+            CoreValidationRuleName::RequiredPropertyPresence.as_rule_name().0.as_str(),
+            "Property",
+            "Error",
+            "CommitBlocking",
+        )?)?;
+        let mut property_type =
+            stage(new_descriptor_holon(context, "property-type", "PropertyType", "Property")?)?;
+        let mut binding = stage(new_test_holon(context, "property-type-required-binding")?)?;
+        binding.add_related_holons(
+            CoreRelationshipTypeName::AppliesTo,
+            vec![property_type.clone().into()],
+        )?;
+        binding.add_related_holons(CoreRelationshipTypeName::UsesRule, vec![required.into()])?;
+        property_type.add_related_holons(
+            CoreRelationshipTypeName::HasValidationBinding,
+            vec![binding.into()],
+        )?;
+
+        let mut name = stage(new_property_descriptor_holon(
+            context,
+            "name",
+            "Name",
+            "Name",
+            true,
+            text.clone().into(),
+        )?)?;
+        name.add_related_holons(CoreRelationshipTypeName::ValueType, vec![text.into()])?;
+        name.add_related_holons(CoreRelationshipTypeName::Extends, vec![property_type.into()])?;
+        let mut person_type = stage(new_holon_type_descriptor(context, "person", "PersonType")?)?;
+        person_type
+            .add_related_holons(CoreRelationshipTypeName::InstanceProperties, vec![name.into()])?;
+        Ok(person_type)
+    }
+
+    fn result_for_key(
+        results: &[HolonReference],
+        key: &str,
+    ) -> Result<Option<HolonReference>, HolonError> {
+        let key = Some(BaseValue::StringValue(MapString(key.to_string())));
+        for result in results {
+            if result.property_value(CorePropertyTypeName::HolonKey)? == key {
+                return Ok(Some(result.clone()));
+            }
+        }
+        Ok(None)
+    }
+
+    #[test]
+    fn context_validate_covers_live_staged_holons_without_committing() -> Result<(), HolonError> {
+        let context = build_context();
+        context.mutation().stage_new_holon(new_test_holon(&context, "alice")?)?;
+        context.mutation().stage_new_holon(new_test_holon(&context, "bob")?)?;
+        context
+            .mutation()
+            .stage_new_holon(new_test_holon(&context, "carol")?)?
+            .abandon_staged_changes(&context)?;
+        stage_described_by_rule(&context)?;
+
+        let report = HolonReference::from(context.validate()?);
+
+        assert_eq!(
+            report.property_value(CorePropertyTypeName::ValidatedHolonCount)?,
+            Some(BaseValue::IntegerValue(MapInteger(3)))
+        );
+        assert_eq!(
+            report.property_value(CorePropertyTypeName::BlockingResultCount)?,
+            Some(BaseValue::IntegerValue(MapInteger(3)))
+        );
+        assert_eq!(context.lookup().staged_count()?, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn context_validate_reports_staged_violations_with_key_and_subject_path(
+    ) -> Result<(), HolonError> {
+        let context = build_context();
+        stage_described_by_rule(&context)?;
+        let person_type = staged_person_type(&context)?;
+        context
+            .mutation()
+            .stage_new_holon(new_test_holon(&context, "nameless")?)?
+            .add_related_holons(CoreRelationshipTypeName::DescribedBy, vec![person_type.into()])?;
+        context.mutation().stage_new_holon(new_test_holon(&context, "stray")?)?;
+
+        let report = HolonReference::from(context.validate()?);
+        let collection = report.related_holons(CoreRelationshipTypeName::ReportsResult)?;
+        let results = collection.read().expect("collection lock").get_members().clone();
+
+        let missing_name = result_for_key(&results, "nameless")?.expect("nameless is reported");
+        assert_eq!(
+            missing_name.property_value(CorePropertyTypeName::SubjectPath)?,
+            Some(BaseValue::StringValue(MapString("Name".to_string())))
+        );
+        assert_eq!(
+            missing_name.property_value(CorePropertyTypeName::BlockingBehavior)?,
+            Some(BaseValue::StringValue(MapString("CommitBlocking".to_string())))
+        );
+
+        let undescribed = result_for_key(&results, "stray")?.expect("stray is reported");
+        assert_eq!(undescribed.property_value(CorePropertyTypeName::SubjectPath)?, None);
+        assert_eq!(
+            undescribed.property_value(CorePropertyTypeName::ValidationLevel)?,
+            Some(BaseValue::StringValue(MapString("Holon".to_string())))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn context_validate_dispatches_through_the_space_validation_registry() -> Result<(), HolonError>
+    {
+        let space_manager = build_space_manager();
+        let context = open_transaction(&space_manager);
+        stage_described_by_rule(&context)?;
+        let stage = |holon: TransientReference| context.mutation().stage_new_holon(holon);
+        let audit =
+            stage(new_validation_rule_holon(&context, "Audit", "Holon", "Warning", "Advisory")?)?;
+        let mut book_type = stage(new_holon_type_descriptor(&context, "book", "BookType")?)?;
+        let mut binding = stage(new_test_holon(&context, "book-audit-binding")?)?;
+        binding.add_related_holons(
+            CoreRelationshipTypeName::AppliesTo,
+            vec![book_type.clone().into()],
+        )?;
+        binding.add_related_holons(CoreRelationshipTypeName::UsesRule, vec![audit.into()])?;
+        book_type.add_related_holons(
+            CoreRelationshipTypeName::HasValidationBinding,
+            vec![binding.into()],
+        )?;
+        context
+            .mutation()
+            .stage_new_holon(new_test_holon(&context, "draft")?)?
+            .add_related_holons(CoreRelationshipTypeName::DescribedBy, vec![book_type.into()])?;
+
+        let audit_results = |report: ValidationReport| -> Vec<ValidationResult> {
+            report
+                .results
+                .into_iter()
+                .filter(|result| result.rule_name == MapString("Audit".to_string()))
+                .collect()
+        };
+
+        // The default registry holds only the Core rules, so `Audit` is skipped.
+        assert!(audit_results(context.validation_report()?).is_empty());
+
+        let mut registry = ValidationRuleRegistry::with_core_rules();
+        registry.register(
+            MapString("Audit".to_string()),
+            |subject: &ValidationSubject| -> Result<Vec<HolonError>, HolonError> {
+                Ok(vec![HolonError::InvalidParameter(format!(
+                    "{} needs review",
+                    subject.holon().key()?.map(|key| key.to_string()).unwrap_or_default()
+                ))])
+            },
+        );
+        space_manager.set_validation_registry(registry)?;
+
+        let results = audit_results(context.validation_report()?);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].message().contains("draft needs review"));
+        assert!(!results[0].is_blocking());
+        Ok(())
+    }
+}
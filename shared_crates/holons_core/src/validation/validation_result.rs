@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use base_types::MapString;
+use core_types::HolonError;
+use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+use crate::core_shared_objects::transactions::TransactionContext;
+use crate::descriptors::Descriptor;
+use crate::reference_layer::{HolonReference, ReadableHolon, WritableHolon};
+use crate::validation::{
+    ValidationBlockingBehavior, ValidationLevel, ValidationRule, ValidationSeverity,
+};
+
+/// One violation of a bound `ValidationRule` by one subject.
+///
+/// Severity and blocking behavior are the rule's declared defaults; bindings
+/// and rule sets do not adjust them yet.
+#[derive(Debug, Clone)]
+pub struct ValidationResult {
+    pub rule: ValidationRule,
+    pub rule_name: MapString,
+    pub level: ValidationLevel,
+    pub severity: ValidationSeverity,
+    pub blocking: ValidationBlockingBehavior,
+    /// The holon that violated the rule.
+    pub holon: HolonReference,
+    /// Property or relationship name within `holon`, for non-holon subjects.
+    pub path: Option<String>,
+    pub violation: HolonError,
+}
+
+impl ValidationResult {
+    /// Returns whether this result prevents a commit.
+    pub fn is_blocking(&self) -> bool {
+        self.blocking == ValidationBlockingBehavior::CommitBlocking
+    }
+
+    /// Returns the human-readable violation message.
+    pub fn message(&self) -> String {
+        self.violation.to_string()
+    }
+
+    /// Materializes this result as a transient `ValidationResult` holon.
+    ///
+    /// The holon links the rule through `ProducedByRule` and the violating
+    /// holon through `ValidatedHolon`, and records that holon's key, if any.
+    pub fn to_holon(
+        &self,
+        context: &Arc<TransactionContext>,
+    ) -> Result<HolonReference, HolonError> {
+        let mut result =
+            context.mutation().new_holon(Some(MapString("validation-result".to_string())))?;
+        result
+            .with_property_value(CorePropertyTypeName::ValidationLevel, self.level.as_str())?
+            .with_property_value(CorePropertyTypeName::ResultSeverity, self.severity.as_str())?
+            .with_property_value(CorePropertyTypeName::BlockingBehavior, self.blocking.as_str())?
+            .with_property_value(CorePropertyTypeName::ValidationMessage, self.message())?;
+        if let Some(path) = &self.path {
+            result.with_property_value(CorePropertyTypeName::SubjectPath, path.as_str())?;
+        }
+        if let Some(key) = self.holon.key()? {
+            result.with_property_value(CorePropertyTypeName::HolonKey, key)?;
+        }
+        result.add_related_holons(
+            CoreRelationshipTypeName::ProducedByRule,
+            vec![self.rule.holon().clone()],
+        )?;
+        result.add_related_holons(
+            CoreRelationshipTypeName::ValidatedHolon,
+            vec![self.holon.clone()],
+        )?;
+        Ok(result.into())
+    }
+}
+
+/// Materializes `results` as transient `ValidationResult` holons, in order.
+pub fn materialize_results(
+    context: &Arc<TransactionContext>,
+    results: &[ValidationResult],
+) -> Result<Vec<HolonReference>, HolonError> {
+    results.iter().map(|result| result.to_holon(context)).collect()
+}
@@ -0,0 +1,196 @@
+use base_types::MapString;
+use core_types::HolonError;
+use type_names::{CorePropertyTypeName, CoreRelationshipTypeName};
+
+use crate::descriptors::{accessor_helpers, Descriptor, TypeHeader};
+use crate::reference_layer::HolonReference;
+use crate::validation::ValidationImplementation;
+
+/// Validator level at which a `ValidationRule` is evaluated.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ValidationLevel {
+    Holon,
+    Property,
+    Value,
+    Relationship,
+    Transaction,
+    Command,
+    Dance,
+    Agreement,
+}
+
+impl ValidationLevel {
+    /// Returns the `ValidationLevel` enum variant name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Holon => "Holon",
+            Self::Property => "Property",
+            Self::Value => "Value",
+            Self::Relationship => "Relationship",
+            Self::Transaction => "Transaction",
+            Self::Command => "Command",
+            Self::Dance => "Dance",
+            Self::Agreement => "Agreement",
+        }
+    }
+
+    /// Parses the enum variant name stored on a `ValidationRule` holon.
+    pub fn parse(value: &MapString) -> Result<Self, HolonError> {
+        match value.0.as_str() {
+            "Holon" => Ok(Self::Holon),
+            "Property" => Ok(Self::Property),
+            "Value" => Ok(Self::Value),
+            "Relationship" => Ok(Self::Relationship),
+            "Transaction" => Ok(Self::Transaction),
+            "Command" => Ok(Self::Command),
+            "Dance" => Ok(Self::Dance),
+            "Agreement" => Ok(Self::Agreement),
+            _ => Err(HolonError::InvalidParameter(format!("Unknown validation level: {}", value))),
+        }
+    }
+}
+
+/// Severity a failing `ValidationRule` emits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ValidationSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl ValidationSeverity {
+    /// Returns the `ValidationSeverity` enum variant name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+        }
+    }
+
+    /// Parses the enum variant name stored on a `ValidationRule` holon.
+    pub fn parse(value: &MapString) -> Result<Self, HolonError> {
+        match value.0.as_str() {
+            "Info" => Ok(Self::Info),
+            "Warning" => Ok(Self::Warning),
+            "Error" => Ok(Self::Error),
+            _ => {
+                Err(HolonError::InvalidParameter(format!("Unknown validation severity: {}", value)))
+            }
+        }
+    }
+}
+
+/// Whether a failing `ValidationRule` blocks the operation it validates.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ValidationBlockingBehavior {
+    Advisory,
+    CommitBlocking,
+}
+
+impl ValidationBlockingBehavior {
+    /// Returns the `ValidationBlockingBehavior` enum variant name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Advisory => "Advisory",
+            Self::CommitBlocking => "CommitBlocking",
+        }
+    }
+
+    /// Parses the enum variant name stored on a `ValidationRule` holon.
+    pub fn parse(value: &MapString) -> Result<Self, HolonError> {
+        match value.0.as_str() {
+            "Advisory" => Ok(Self::Advisory),
+            "CommitBlocking" => Ok(Self::CommitBlocking),
+            _ => Err(HolonError::InvalidParameter(format!(
+                "Unknown validation blocking behavior: {}",
+                value
+            ))),
+        }
+    }
+}
+
+/// Runtime wrapper for a `ValidationRule` holon.
+///
+/// The rule's `type_name` (e.g. `RequiredPropertyPresence`) is the name its
+/// Rust implementation is registered under, unless the rule declares its own
+/// `ValidationImplementation` through `ImplementedBy`.
+#[derive(Debug, Clone)]
+pub struct ValidationRule {
+    holon: HolonReference,
+}
+
+impl ValidationRule {
+    /// Wraps an already-resolved rule holon reference.
+    pub fn from_holon(holon: HolonReference) -> Self {
+        Self { holon }
+    }
+
+    /// Projects the shared descriptor header view for this rule holon.
+    pub fn header(&self) -> TypeHeader<'_> {
+        TypeHeader::new(&self.holon)
+    }
+
+    /// Returns the rule name implementations are registered under.
+    pub fn rule_name(&self) -> Result<MapString, HolonError> {
+        self.header().type_name()
+    }
+
+    /// Returns the level of subject this rule evaluates.
+    pub fn validation_level(&self) -> Result<ValidationLevel, HolonError> {
+        ValidationLevel::parse(&accessor_helpers::require_enum_string(
+            &self.holon,
+            CorePropertyTypeName::ValidationLevel,
+        )?)
+    }
+
+    /// Returns the severity emitted when this rule fails.
+    pub fn default_severity(&self) -> Result<ValidationSeverity, HolonError> {
+        ValidationSeverity::parse(&accessor_helpers::require_enum_string(
+            &self.holon,
+            CorePropertyTypeName::DefaultSeverity,
+        )?)
+    }
+
+    /// Returns the weakest blocking behavior a binding may apply to this rule.
+    pub fn minimum_blocking_behavior(&self) -> Result<ValidationBlockingBehavior, HolonError> {
+        ValidationBlockingBehavior::parse(&accessor_helpers::require_enum_string(
+            &self.holon,
+            CorePropertyTypeName::MinimumBlockingBehavior,
+        )?)
+    }
+
+    /// Returns the normative source that defines this rule's meaning, when declared.
+    pub fn semantic_authority(&self) -> Result<Option<MapString>, HolonError> {
+        accessor_helpers::optional_string(&self.holon, CorePropertyTypeName::SemanticAuthority)
+    }
+
+    /// Returns the human-readable rule description, when declared.
+    pub fn description(&self) -> Result<Option<MapString>, HolonError> {
+        accessor_helpers::optional_string(
+            &self.holon,
+            CorePropertyTypeName::ValidationRuleDescription,
+        )
+    }
+
+    /// Returns the implementation this rule declares through `ImplementedBy`, if any.
+    pub fn implemented_by(&self) -> Result<Option<ValidationImplementation>, HolonError> {
+        Ok(accessor_helpers::optional_single_related(
+            &self.holon,
+            CoreRelationshipTypeName::ImplementedBy,
+        )?
+        .map(ValidationImplementation::from_holon))
+    }
+}
+
+impl From<HolonReference> for ValidationRule {
+    fn from(holon: HolonReference) -> Self {
+        Self::from_holon(holon)
+    }
+}
+
+impl Descriptor for ValidationRule {
+    fn holon(&self) -> &HolonReference {
+        &self.holon
+    }
+}
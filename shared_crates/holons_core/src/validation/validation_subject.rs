@@ -0,0 +1,65 @@
+use base_types::BaseValue;
+use core_types::{PropertyName, RelationshipName};
+
+use crate::descriptors::{
+    HolonDescriptor, PropertyDescriptor, RelationshipDescriptor, ValueDescriptor,
+};
+use crate::reference_layer::HolonReference;
+use crate::validation::ValidationLevel;
+
+/// The thing a `ValidationRule` is evaluated against.
+///
+/// Every subject belongs to one holon. Property, value, and relationship
+/// subjects also carry the descriptor that selected their bound rules.
+pub enum ValidationSubject {
+    /// A holon, checked against its describing holon descriptor.
+    Holon { holon: HolonReference, descriptor: HolonDescriptor },
+    /// An effective instance property of a holon, whether or not it is populated.
+    Property { holon: HolonReference, property: PropertyDescriptor, name: PropertyName },
+    /// A populated property value, checked against the property's value type.
+    Value {
+        holon: HolonReference,
+        property: PropertyName,
+        value_type: ValueDescriptor,
+        value: BaseValue,
+    },
+    /// An effective instance relationship of a holon, whether or not it has members.
+    Relationship {
+        holon: HolonReference,
+        relationship: RelationshipDescriptor,
+        name: RelationshipName,
+    },
+}
+
+impl ValidationSubject {
+    /// Returns the holon this subject belongs to.
+    pub fn holon(&self) -> &HolonReference {
+        match self {
+            Self::Holon { holon, .. }
+            | Self::Property { holon, .. }
+            | Self::Value { holon, .. }
+            | Self::Relationship { holon, .. } => holon,
+        }
+    }
+
+    /// Returns the level of rules that apply to this subject.
+    pub fn level(&self) -> ValidationLevel {
+        match self {
+            Self::Holon { .. } => ValidationLevel::Holon,
+            Self::Property { .. } => ValidationLevel::Property,
+            Self::Value { .. } => ValidationLevel::Value,
+            Self::Relationship { .. } => ValidationLevel::Relationship,
+        }
+    }
+
+    /// Returns the property or relationship name within the holon, if any.
+    pub fn path(&self) -> Option<String> {
+        match self {
+            Self::Holon { .. } => None,
+            Self::Property { name, .. } | Self::Value { property: name, .. } => {
+                Some(name.to_string())
+            }
+            Self::Relationship { name, .. } => Some(name.to_string()),
+        }
+    }
+}
@@ -33,7 +33,8 @@
 //! - ❌ Internal modules within `holons_core` (should import directly)
 
 pub use base_types::{
-    BaseValue, MapBoolean, MapBytes, MapEnumValue, MapInteger, MapString, ToBaseValue,
+    BaseValue, MapBoolean, MapBytes, MapDate, MapDecimal, MapEnumValue, MapInteger, MapString,
+    MapRecord, MapTimestamp, MapValueArray, ToBaseValue,
 };
 pub use core_types::HolonId;
 pub use integrity_core_types::{
@@ -49,7 +50,15 @@ pub use holons_core::core_shared_objects::{HolonCollection, RelationshipMap};
 pub use holons_core::dances::{
     DanceInitiator, DanceRequest, DanceResponse, ResponseBody, ResponseStatusCode,
 };
-pub use holons_core::query_layer::{Node, NodeCollection, QueryExpression, QueryPathMap};
+pub use holons_core::query_layer::{
+    AggregateFunction, BoundHolonCollection, CollectionPage, ExpansionForecast, ExplainNode,
+    ExpressionProfile, LiveQuery, LiveQueryWatch, NavigationBinding, NavigationBindingSet,
+    NavigationTopology, Node, NodeCollection, PageCursor, PageRequest, PathStep,
+    PropertyPredicate, QueryExecution, QueryExecutionStatus, QueryExplanation, QueryExpression,
+    QueryParameterBinder, QueryPathMap, QueryProfile, RelationshipPath, ResultDelta,
+    SortDirection, SortKey, VariableName, execute_query, execute_query_with_parameters,
+    explain_query, profile_query,
+};
 pub use holons_core::reference_layer::{
     Divergence, EquivalenceOutcome, EquivalenceResolver, HolonCollectionApi, HolonReference,
     HolonSpaceBehavior, HolonStagingBehavior, NoOpResolver, NodeResolution, ReadableHolon,
@@ -58,7 +67,7 @@ pub use holons_core::reference_layer::{
 pub use holons_core::{
     Descriptor, ExtendsIter, HolonDescriptor, HolonSpaceDescriptor, PropertyDescriptor,
     RelationshipDescriptor, RelationshipDirection, TransactionDescriptor, TypeHeader,
-    ValueDescriptor, ancestors, classify_relationship_direction, walk_extends_chain,
+    ValueDescriptor, ancestors, classify_relationship_direction, descendants, walk_extends_chain,
 };
 
 pub use type_names::{
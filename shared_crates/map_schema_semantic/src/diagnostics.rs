@@ -38,6 +38,28 @@ pub enum DiagnosticKind {
     },
     /// A descriptor is missing a semantic field required by its descriptor kind.
     MissingRequiredField { descriptor: String, field: String },
+    /// A descriptor's Extends lineage returns to itself (`AcyclicExtendsLineage`).
+    CyclicExtendsLineage { descriptor: String, cycle: Vec<String> },
+    /// A descriptor names more than one direct Extends parent (`AtMostOneDirectParent`).
+    MultipleDirectParents { descriptor: String, parents: Vec<String> },
+    /// A value type loosens a bound inherited through its Extends lineage
+    /// (`InheritedValueConstraintNonRelaxation`).
+    RelaxedInheritedConstraint {
+        descriptor: String,
+        constraint: String,
+        value: i64,
+        inherited: i64,
+    },
+    /// A property's `DefaultValue` does not conform to its value type (`DefaultValueConformance`).
+    NonConformingDefaultValue { descriptor: String, detail: String },
+    /// An inverse relationship's endpoints do not mirror its declared relationship
+    /// (`InverseEndpointCorrespondence`).
+    InverseEndpointMismatch { declared: String, inverse: String },
+    /// A descriptor redeclares an instance member its lineage already provides
+    /// (`NoInheritedMemberRedeclaration`).
+    InheritedMemberRedeclared { descriptor: String, member: String },
+    /// An effective enum definition repeats a member name (`EnumMemberNamesUnique`).
+    DuplicateEnumMemberName { descriptor: String, member: String },
 }
 
 /// A semantic diagnostic with optional source origin metadata.
@@ -83,6 +105,48 @@ impl fmt::Display for DiagnosticKind {
             Self::MissingRequiredField { descriptor, field } => {
                 write!(f, "descriptor `{descriptor}` is missing required field `{field}`")
             }
+            Self::CyclicExtendsLineage { descriptor, cycle } => {
+                write!(
+                    f,
+                    "AcyclicExtendsLineage: descriptor `{descriptor}` has a cyclic Extends lineage {}",
+                    cycle.join(" -> ")
+                )
+            }
+            Self::MultipleDirectParents { descriptor, parents } => {
+                write!(
+                    f,
+                    "AtMostOneDirectParent: descriptor `{descriptor}` extends {} direct parents: {}",
+                    parents.len(),
+                    parents.join(", ")
+                )
+            }
+            Self::RelaxedInheritedConstraint { descriptor, constraint, value, inherited } => {
+                write!(
+                    f,
+                    "InheritedValueConstraintNonRelaxation: value type `{descriptor}` relaxes inherited {constraint} {inherited} to {value}"
+                )
+            }
+            Self::NonConformingDefaultValue { descriptor, detail } => {
+                write!(f, "DefaultValueConformance: property `{descriptor}` default value {detail}")
+            }
+            Self::InverseEndpointMismatch { declared, inverse } => {
+                write!(
+                    f,
+                    "InverseEndpointCorrespondence: inverse relationship `{inverse}` does not mirror the endpoints of `{declared}`"
+                )
+            }
+            Self::InheritedMemberRedeclared { descriptor, member } => {
+                write!(
+                    f,
+                    "NoInheritedMemberRedeclaration: descriptor `{descriptor}` redeclares inherited member `{member}`"
+                )
+            }
+            Self::DuplicateEnumMemberName { descriptor, member } => {
+                write!(
+                    f,
+                    "EnumMemberNamesUnique: enum `{descriptor}` declares member name `{member}` more than once"
+                )
+            }
         }
     }
 }
@@ -11,8 +11,10 @@ pub mod diagnostics;
 pub mod literal_value;
 pub mod schema_index;
 pub mod schema_ir;
+pub mod schema_rules;
 
 pub use diagnostics::*;
 pub use literal_value::*;
 pub use schema_index::*;
 pub use schema_ir::*;
+pub use schema_rules::*;
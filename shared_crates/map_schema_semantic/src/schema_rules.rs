@@ -0,0 +1,945 @@
+//! Meta-level schema rules checked over the Canonical Holon IR.
+//!
+//! These checks mirror the descriptor-level `ValidationRule`s seeded by the validation schema so
+//! authoring tools can report violations before a schema reaches the loader or guest. They read
+//! references already resolved by [`SymbolIndex`]; references that failed to resolve are skipped
+//! here and reported by the index's own diagnostics instead.
+
+use crate::{
+    diagnostics::{Diagnostic, DiagnosticKind},
+    literal_value::LiteralValue,
+    schema_index::{SymbolId, SymbolIndex},
+    schema_ir::{
+        DescriptorKind, RelationshipFlavor, SemanticModel, SemanticReference, TypeDescriptor,
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+/// Runs every meta-level schema rule and returns their diagnostics in rule order.
+///
+/// `index` must have been built from `model` so that reference `resolved` fields are current.
+pub fn check_schema_rules(model: &SemanticModel, index: &SymbolIndex) -> Vec<Diagnostic> {
+    let rules = SchemaRules::new(model, index);
+    let mut diagnostics = Vec::new();
+    rules.acyclic_extends_lineage(&mut diagnostics);
+    rules.at_most_one_direct_parent(&mut diagnostics);
+    rules.inherited_value_constraint_non_relaxation(&mut diagnostics);
+    rules.default_value_conformance(&mut diagnostics);
+    rules.inverse_endpoint_correspondence(&mut diagnostics);
+    rules.no_inherited_member_redeclaration(&mut diagnostics);
+    rules.enum_member_names_unique(&mut diagnostics);
+    diagnostics
+}
+
+/// One integer bound contributed by a value constraint holon.
+///
+/// Exclusive value bounds are normalized to their inclusive equivalent so bounds of one
+/// constraint kind compare directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bound {
+    constraint: &'static str,
+    is_upper: bool,
+    value: i64,
+}
+
+/// Value families a `DefaultValue` literal can be checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueFamily {
+    String,
+    Integer,
+    Decimal,
+    Boolean,
+    Temporal,
+    Enum,
+}
+
+struct SchemaRules<'a> {
+    model: &'a SemanticModel,
+    index: &'a SymbolIndex,
+    by_symbol: HashMap<SymbolId, usize>,
+}
+
+impl<'a> SchemaRules<'a> {
+    fn new(model: &'a SemanticModel, index: &'a SymbolIndex) -> Self {
+        let mut by_symbol = HashMap::new();
+        for (position, descriptor) in model.descriptors.iter().enumerate() {
+            if let Some(symbol) = index.lookup_by_key(&descriptor.key) {
+                by_symbol.entry(symbol.id).or_insert(position);
+            }
+        }
+        Self { model, index, by_symbol }
+    }
+
+    fn descriptor(&self, position: usize) -> &'a TypeDescriptor {
+        &self.model.descriptors[position]
+    }
+
+    fn resolve(&self, reference: &SemanticReference) -> Option<usize> {
+        reference.resolved.and_then(|id| self.by_symbol.get(&id).copied())
+    }
+
+    fn lookup(&self, target: &str) -> Option<usize> {
+        self.index
+            .lookup_reference_target(target)
+            .and_then(|symbol| self.by_symbol.get(&symbol.id))
+            .copied()
+    }
+
+    fn parent(&self, position: usize) -> Option<usize> {
+        self.descriptor(position).extends.as_ref().and_then(|reference| self.resolve(reference))
+    }
+
+    /// Returns the Extends lineage above `position`, nearest first, stopping before any repeat.
+    fn ancestors(&self, position: usize) -> Vec<usize> {
+        let mut seen = HashSet::from([position]);
+        let mut ancestors = Vec::new();
+        let mut current = self.parent(position);
+        while let Some(ancestor) = current {
+            if !seen.insert(ancestor) {
+                break;
+            }
+            ancestors.push(ancestor);
+            current = self.parent(ancestor);
+        }
+        ancestors
+    }
+
+    /// Returns `position` followed by its ancestors.
+    fn lineage(&self, position: usize) -> Vec<usize> {
+        let mut lineage = vec![position];
+        lineage.extend(self.ancestors(position));
+        lineage
+    }
+
+    fn acyclic_extends_lineage(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for start in 0..self.model.descriptors.len() {
+            let mut path = vec![start];
+            let mut current = self.parent(start);
+            while let Some(next) = current {
+                if next == start {
+                    // Report each cycle once, at its first-declared member.
+                    if path.iter().all(|member| *member >= start) {
+                        let descriptor = self.descriptor(start);
+                        let mut cycle = path
+                            .iter()
+                            .map(|member| self.descriptor(*member).key.clone())
+                            .collect::<Vec<_>>();
+                        cycle.push(descriptor.key.clone());
+                        diagnostics.push(Diagnostic::error(
+                            DiagnosticKind::CyclicExtendsLineage {
+                                descriptor: descriptor.key.clone(),
+                                cycle,
+                            },
+                            Some(descriptor.origin.clone()),
+                        ));
+                    }
+                    break;
+                }
+                if path.contains(&next) {
+                    break;
+                }
+                path.push(next);
+                current = self.parent(next);
+            }
+        }
+    }
+
+    /// Counts the `extends` slot together with any literal `Extends` relationship targets, since
+    /// holon-shaped sources can author parents either way.
+    fn at_most_one_direct_parent(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for descriptor in &self.model.descriptors {
+            let mut parents = Vec::<String>::new();
+            let literal_targets = descriptor
+                .literal_relationships
+                .iter()
+                .filter(|relationship| relationship.name == "Extends")
+                .flat_map(|relationship| relationship.targets.iter());
+            for target in
+                descriptor.extends.iter().map(|reference| &reference.target).chain(literal_targets)
+            {
+                if !parents.contains(target) {
+                    parents.push(target.clone());
+                }
+            }
+            if parents.len() > 1 {
+                diagnostics.push(Diagnostic::error(
+                    DiagnosticKind::MultipleDirectParents {
+                        descriptor: descriptor.key.clone(),
+                        parents,
+                    },
+                    Some(descriptor.origin.clone()),
+                ));
+            }
+        }
+    }
+
+    fn inherited_value_constraint_non_relaxation(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for (position, descriptor) in self.model.descriptors.iter().enumerate() {
+            if !matches!(descriptor.kind, DescriptorKind::ValueType | DescriptorKind::Enum) {
+                continue;
+            }
+            let own = self.declared_bounds(position);
+            if own.is_empty() {
+                continue;
+            }
+            let inherited = self.tightest_bounds(&self.ancestors(position));
+            for bound in own {
+                let Some((tightest, _)) = inherited.get(bound.constraint) else {
+                    continue;
+                };
+                let relaxes = if bound.is_upper {
+                    bound.value > tightest.value
+                } else {
+                    bound.value < tightest.value
+                };
+                if relaxes {
+                    diagnostics.push(Diagnostic::error(
+                        DiagnosticKind::RelaxedInheritedConstraint {
+                            descriptor: descriptor.key.clone(),
+                            constraint: bound.constraint.to_string(),
+                            value: bound.value,
+                            inherited: tightest.value,
+                        },
+                        Some(descriptor.origin.clone()),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn default_value_conformance(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for descriptor in &self.model.descriptors {
+            if descriptor.kind != DescriptorKind::PropertyType {
+                continue;
+            }
+            let Some(default) = descriptor.literal_properties.get("DefaultValue") else {
+                continue;
+            };
+            let Some(value_type) =
+                descriptor.value_type.as_ref().and_then(|reference| self.resolve(reference))
+            else {
+                continue;
+            };
+            if let Some(reason) = self.default_value_violation(value_type, default) {
+                diagnostics.push(Diagnostic::error(
+                    DiagnosticKind::NonConformingDefaultValue {
+                        descriptor: descriptor.key.clone(),
+                        detail: format!("`{default}` {reason}"),
+                    },
+                    Some(descriptor.origin.clone()),
+                ));
+            }
+        }
+    }
+
+    fn default_value_violation(&self, value_type: usize, default: &LiteralValue) -> Option<String> {
+        let lineage = self.lineage(value_type);
+        let family = self.value_family(&lineage)?;
+        let conforms = match family {
+            ValueFamily::String | ValueFamily::Temporal | ValueFamily::Enum => {
+                default.as_str().is_some()
+            }
+            ValueFamily::Integer => matches!(default, LiteralValue::Integer(_)),
+            ValueFamily::Decimal => {
+                matches!(default, LiteralValue::Integer(_) | LiteralValue::Number(_))
+            }
+            ValueFamily::Boolean => default.as_bool().is_some(),
+        };
+        if !conforms {
+            return Some(format!(
+                "is not a {} literal for `{}`",
+                family_label(family),
+                self.descriptor(value_type).key
+            ));
+        }
+
+        if family == ValueFamily::Enum {
+            let members = self
+                .effective_enum_members(&lineage)
+                .into_iter()
+                .map(|member| self.descriptor(member).name.as_str())
+                .collect::<Vec<_>>();
+            let token = default.as_str().unwrap_or_default();
+            if !members.is_empty() && !members.contains(&token) {
+                return Some(format!(
+                    "is not a member of `{}` (expected one of {})",
+                    self.descriptor(value_type).key,
+                    members.join(", ")
+                ));
+            }
+            return None;
+        }
+
+        let measured = match family {
+            ValueFamily::Integer => default.as_i64(),
+            ValueFamily::String => default.as_str().map(|text| text.chars().count() as i64),
+            _ => None,
+        }?;
+        self.tightest_bounds(&lineage).into_values().find_map(|(bound, declared_on)| {
+            let applies = match family {
+                ValueFamily::Integer => bound.constraint.ends_with("Value"),
+                _ => bound.constraint.ends_with("Length"),
+            };
+            let violated =
+                if bound.is_upper { measured > bound.value } else { measured < bound.value };
+            (applies && violated).then(|| {
+                format!(
+                    "violates {} {} declared on `{}`",
+                    bound.constraint,
+                    bound.value,
+                    self.descriptor(declared_on).key
+                )
+            })
+        })
+    }
+
+    /// Classifies a value type by the first abstract family root found on its lineage.
+    fn value_family(&self, lineage: &[usize]) -> Option<ValueFamily> {
+        for position in lineage {
+            let descriptor = self.descriptor(*position);
+            if descriptor.kind == DescriptorKind::Enum {
+                return Some(ValueFamily::Enum);
+            }
+            let family = match local_name(&descriptor.key) {
+                "StringValueType" => ValueFamily::String,
+                "IntegerValueType" => ValueFamily::Integer,
+                "DecimalValueType" => ValueFamily::Decimal,
+                "BooleanValueType" => ValueFamily::Boolean,
+                "TimestampValueType" | "DateValueType" => ValueFamily::Temporal,
+                _ => continue,
+            };
+            return Some(family);
+        }
+        None
+    }
+
+    /// Compares endpoints for each declared/inverse pair once.
+    ///
+    /// A declared-side `HasInverse` that names a missing key is still checked against inverse
+    /// descriptors of the same relationship name, since mismatched endpoints are the usual reason
+    /// the normalized inverse key fails to resolve.
+    fn inverse_endpoint_correspondence(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let mut checked = HashSet::new();
+        for (position, descriptor) in self.model.descriptors.iter().enumerate() {
+            let pair = if let Some(has_inverse) = &descriptor.has_inverse {
+                self.resolve(has_inverse)
+                    .or_else(|| self.inverse_by_name(&has_inverse.target))
+                    .map(|inverse| (position, inverse))
+            } else {
+                descriptor
+                    .inverse_of
+                    .as_ref()
+                    .and_then(|reference| self.resolve(reference))
+                    .map(|declared| (declared, position))
+            };
+            let Some((declared, inverse)) = pair else {
+                continue;
+            };
+            if !checked.insert((declared, inverse)) {
+                continue;
+            }
+            let declared_descriptor = self.descriptor(declared);
+            let inverse_descriptor = self.descriptor(inverse);
+            let (Some(source), Some(target)) =
+                (&declared_descriptor.source_type, &declared_descriptor.target_type)
+            else {
+                continue;
+            };
+            let mirrors = same_target(inverse_descriptor.source_type.as_ref(), target)
+                && same_target(inverse_descriptor.target_type.as_ref(), source);
+            if !mirrors {
+                diagnostics.push(Diagnostic::error(
+                    DiagnosticKind::InverseEndpointMismatch {
+                        declared: declared_descriptor.key.clone(),
+                        inverse: inverse_descriptor.key.clone(),
+                    },
+                    Some(inverse_descriptor.origin.clone()),
+                ));
+            }
+        }
+    }
+
+    fn inverse_by_name(&self, target: &str) -> Option<usize> {
+        let name = relationship_name(target).unwrap_or(target);
+        self.model.descriptors.iter().position(|descriptor| {
+            descriptor.relationship_flavor == Some(RelationshipFlavor::Inverse)
+                && relationship_name(&descriptor.key) == Some(name)
+        })
+    }
+
+    fn no_inherited_member_redeclaration(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for (position, descriptor) in self.model.descriptors.iter().enumerate() {
+            let ancestors = self.ancestors(position);
+            if ancestors.is_empty() {
+                continue;
+            }
+            let inherited = ancestors
+                .iter()
+                .flat_map(|ancestor| instance_members(self.descriptor(*ancestor)))
+                .map(MemberId::of)
+                .collect::<HashSet<_>>();
+            for member in instance_members(descriptor) {
+                if inherited.contains(&MemberId::of(member)) {
+                    diagnostics.push(Diagnostic::error(
+                        DiagnosticKind::InheritedMemberRedeclared {
+                            descriptor: descriptor.key.clone(),
+                            member: member.target.clone(),
+                        },
+                        Some(descriptor.origin.clone()),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn enum_member_names_unique(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for (position, descriptor) in self.model.descriptors.iter().enumerate() {
+            if descriptor.kind != DescriptorKind::Enum {
+                continue;
+            }
+            let mut names = HashSet::new();
+            for member in self.effective_enum_members(&self.lineage(position)) {
+                let variant = self.descriptor(member);
+                if !names.insert(variant.name.as_str()) {
+                    diagnostics.push(Diagnostic::error(
+                        DiagnosticKind::DuplicateEnumMemberName {
+                            descriptor: descriptor.key.clone(),
+                            member: variant.name.clone(),
+                        },
+                        Some(variant.origin.clone()),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Returns the members of every enum on `lineage`, root-most enum first.
+    ///
+    /// Variants that point back at their enum through `VariantOf` are listed individually, so two
+    /// declarations that share a key are both visible even though only one owns the symbol.
+    fn effective_enum_members(&self, lineage: &[usize]) -> Vec<usize> {
+        let mut members = Vec::new();
+        for enum_position in lineage.iter().rev() {
+            let enum_descriptor = self.descriptor(*enum_position);
+            if enum_descriptor.kind != DescriptorKind::Enum {
+                continue;
+            }
+            let own =
+                self.model.descriptors.iter().enumerate().filter_map(|(position, variant)| {
+                    let variant_of = variant.variant_of.as_ref()?;
+                    (self.resolve(variant_of) == Some(*enum_position)).then_some(position)
+                });
+            let listed =
+                enum_descriptor.variants.iter().filter_map(|reference| self.resolve(reference));
+            for member in own.chain(listed) {
+                if !members.contains(&member) {
+                    members.push(member);
+                }
+            }
+        }
+        members
+    }
+
+    /// Reads the integer bounds declared directly on a value type through `Constraints`.
+    fn declared_bounds(&self, position: usize) -> Vec<Bound> {
+        self.descriptor(position)
+            .literal_relationships
+            .iter()
+            .filter(|relationship| relationship.name == "Constraints")
+            .flat_map(|relationship| relationship.targets.iter())
+            .filter_map(|target| self.lookup(target))
+            .filter_map(|constraint| constraint_bound(self.descriptor(constraint)))
+            .collect()
+    }
+
+    /// Returns the tightest bound of each constraint kind declared on `positions`, with the
+    /// descriptor that declared it.
+    fn tightest_bounds(&self, positions: &[usize]) -> HashMap<&'static str, (Bound, usize)> {
+        let mut tightest = HashMap::<&'static str, (Bound, usize)>::new();
+        for position in positions {
+            for bound in self.declared_bounds(*position) {
+                let tighter = tightest.get(bound.constraint).is_none_or(|(current, _)| {
+                    if bound.is_upper {
+                        bound.value < current.value
+                    } else {
+                        bound.value > current.value
+                    }
+                });
+                if tighter {
+                    tightest.insert(bound.constraint, (bound, *position));
+                }
+            }
+        }
+        tightest
+    }
+}
+
+/// Identity used to compare instance members across a lineage.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum MemberId {
+    Resolved(SymbolId),
+    Unresolved(String),
+}
+
+impl MemberId {
+    fn of(reference: &SemanticReference) -> Self {
+        match reference.resolved {
+            Some(id) => Self::Resolved(id),
+            None => Self::Unresolved(reference.target.clone()),
+        }
+    }
+}
+
+fn instance_members(descriptor: &TypeDescriptor) -> impl Iterator<Item = &SemanticReference> {
+    descriptor.instance_properties.iter().chain(descriptor.instance_relationships.iter())
+}
+
+fn same_target(reference: Option<&SemanticReference>, expected: &SemanticReference) -> bool {
+    let Some(reference) = reference else {
+        return false;
+    };
+    match (reference.resolved, expected.resolved) {
+        (Some(actual), Some(expected)) => actual == expected,
+        _ => reference.target == expected.target,
+    }
+}
+
+/// Interprets a constraint holon by its describing type, e.g. `MaximumLength.StringValueConstraint`.
+fn constraint_bound(constraint: &TypeDescriptor) -> Option<Bound> {
+    let described_by = constraint
+        .literal_relationships
+        .iter()
+        .find(|relationship| relationship.name == "DescribedBy")?
+        .targets
+        .first()?;
+    let (constraint_kind, is_upper, property) = match local_name(described_by) {
+        "MinimumLength" => ("MinimumLength", false, "ConstraintLength"),
+        "MaximumLength" => ("MaximumLength", true, "ConstraintLength"),
+        "MinimumItems" => ("MinimumItems", false, "ConstraintItemCount"),
+        "MaximumItems" => ("MaximumItems", true, "ConstraintItemCount"),
+        "MinimumValue" => ("MinimumValue", false, "ConstraintIntegerValue"),
+        "MaximumValue" => ("MaximumValue", true, "ConstraintIntegerValue"),
+        _ => return None,
+    };
+    let mut value = constraint.literal_properties.get(property)?.as_i64()?;
+    let inclusive = constraint
+        .literal_properties
+        .get("ConstraintIsInclusive")
+        .and_then(LiteralValue::as_bool)
+        .unwrap_or(true);
+    if !inclusive {
+        value = if is_upper { value.saturating_sub(1) } else { value.saturating_add(1) };
+    }
+    Some(Bound { constraint: constraint_kind, is_upper, value })
+}
+
+fn family_label(family: ValueFamily) -> &'static str {
+    match family {
+        ValueFamily::String => "string",
+        ValueFamily::Integer => "integer",
+        ValueFamily::Decimal => "decimal",
+        ValueFamily::Boolean => "boolean",
+        ValueFamily::Temporal => "temporal text",
+        ValueFamily::Enum => "enum token",
+    }
+}
+
+fn local_name(key: &str) -> &str {
+    key.split('.').next().unwrap_or(key)
+}
+
+fn relationship_name(key: &str) -> Option<&str> {
+    let (_, rest) = key.split_once(")-[")?;
+    let (name, _) = rest.split_once("]->(")?;
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::literal_value::LiteralObject;
+    use crate::schema_ir::{push_reference, LiteralRelationship, Origin, ReferenceRole, Schema};
+
+    fn origin(line: u32) -> Origin {
+        Origin::tdl_file("schema.tdl", Some(line), None)
+    }
+
+    fn descriptor(key: &str, kind: DescriptorKind, line: u32) -> TypeDescriptor {
+        let name = local_name(key);
+        TypeDescriptor::new(key, name, kind, "Test Schema", origin(line))
+    }
+
+    fn with_ref(
+        mut descriptor: TypeDescriptor,
+        role: ReferenceRole,
+        target: &str,
+    ) -> TypeDescriptor {
+        push_reference(&mut descriptor, SemanticReference::unresolved(role, target));
+        descriptor
+    }
+
+    fn with_literal_relationship(
+        mut descriptor: TypeDescriptor,
+        name: &str,
+        targets: &[&str],
+    ) -> TypeDescriptor {
+        descriptor.literal_relationships.push(LiteralRelationship {
+            name: name.to_string(),
+            targets: targets.iter().map(ToString::to_string).collect(),
+        });
+        descriptor
+    }
+
+    fn with_property(
+        mut descriptor: TypeDescriptor,
+        name: &str,
+        value: LiteralValue,
+    ) -> TypeDescriptor {
+        descriptor.literal_properties.insert(name, value);
+        descriptor
+    }
+
+    fn check(descriptors: Vec<TypeDescriptor>) -> Vec<Diagnostic> {
+        let mut model = SemanticModel::new();
+        model.push_schema(Schema {
+            name: "Test Schema".to_string(),
+            key: "Test Schema".to_string(),
+            origin: origin(1),
+            dependencies: Vec::new(),
+            literal_properties: LiteralObject::new(),
+            literal_relationships: Vec::new(),
+            header: None,
+            allows_additional_properties: false,
+            allows_additional_relationships: false,
+        });
+        for descriptor in descriptors {
+            model.push_descriptor(descriptor);
+        }
+        let (index, _) = SymbolIndex::build(&mut model);
+        check_schema_rules(&model, &index)
+    }
+
+    fn string_types() -> Vec<TypeDescriptor> {
+        vec![
+            descriptor("TypeDescriptor", DescriptorKind::TypeDescriptor, 2),
+            with_ref(
+                descriptor("StringValueType.ValueType", DescriptorKind::ValueType, 3),
+                ReferenceRole::Extends,
+                "TypeDescriptor",
+            ),
+            with_property(
+                with_literal_relationship(
+                    descriptor("Max10", DescriptorKind::HolonType, 4),
+                    "DescribedBy",
+                    &["MaximumLength.StringValueConstraint"],
+                ),
+                "ConstraintLength",
+                LiteralValue::Integer(10),
+            ),
+            with_literal_relationship(
+                with_ref(
+                    descriptor("ShortText.StringValueType", DescriptorKind::ValueType, 5),
+                    ReferenceRole::Extends,
+                    "StringValueType.ValueType",
+                ),
+                "Constraints",
+                &["Max10"],
+            ),
+        ]
+    }
+
+    #[test]
+    fn conforming_schema_reports_no_diagnostics() {
+        let mut descriptors = string_types();
+        descriptors.push(with_property(
+            with_ref(
+                descriptor("Nickname.PropertyType", DescriptorKind::PropertyType, 6),
+                ReferenceRole::ValueType,
+                "ShortText.StringValueType",
+            ),
+            "DefaultValue",
+            LiteralValue::String("anon".to_string()),
+        ));
+
+        assert_eq!(check(descriptors), Vec::new());
+    }
+
+    #[test]
+    fn reports_each_extends_cycle_once_at_its_first_member() {
+        let diagnostics = check(vec![
+            with_ref(
+                descriptor("A.HolonType", DescriptorKind::HolonType, 2),
+                ReferenceRole::Extends,
+                "B.HolonType",
+            ),
+            with_ref(
+                descriptor("B.HolonType", DescriptorKind::HolonType, 3),
+                ReferenceRole::Extends,
+                "A.HolonType",
+            ),
+        ]);
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                DiagnosticKind::CyclicExtendsLineage {
+                    descriptor: "A.HolonType".to_string(),
+                    cycle: vec![
+                        "A.HolonType".to_string(),
+                        "B.HolonType".to_string(),
+                        "A.HolonType".to_string(),
+                    ],
+                },
+                Some(origin(2)),
+            )]
+        );
+    }
+
+    #[test]
+    fn counts_literal_extends_targets_as_direct_parents() {
+        let diagnostics = check(vec![
+            descriptor("TypeDescriptor", DescriptorKind::TypeDescriptor, 2),
+            descriptor("Agent.HolonType", DescriptorKind::HolonType, 3),
+            with_literal_relationship(
+                with_ref(
+                    descriptor("Person.HolonType", DescriptorKind::HolonType, 4),
+                    ReferenceRole::Extends,
+                    "TypeDescriptor",
+                ),
+                "Extends",
+                &["TypeDescriptor", "Agent.HolonType"],
+            ),
+        ]);
+
+        assert!(matches!(
+            &diagnostics[..],
+            [Diagnostic { kind: DiagnosticKind::MultipleDirectParents { descriptor, parents }, .. }]
+                if descriptor == "Person.HolonType" && parents.len() == 2
+        ));
+    }
+
+    #[test]
+    fn rejects_subtype_bounds_looser_than_inherited_bounds() {
+        let mut descriptors = string_types();
+        descriptors.push(with_property(
+            with_literal_relationship(
+                descriptor("Max20", DescriptorKind::HolonType, 6),
+                "DescribedBy",
+                &["MaximumLength.StringValueConstraint"],
+            ),
+            "ConstraintLength",
+            LiteralValue::Integer(20),
+        ));
+        descriptors.push(with_literal_relationship(
+            with_ref(
+                descriptor("Tag.ShortText", DescriptorKind::ValueType, 7),
+                ReferenceRole::Extends,
+                "ShortText.StringValueType",
+            ),
+            "Constraints",
+            &["Max20"],
+        ));
+
+        let diagnostics = check(descriptors);
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                DiagnosticKind::RelaxedInheritedConstraint {
+                    descriptor: "Tag.ShortText".to_string(),
+                    constraint: "MaximumLength".to_string(),
+                    value: 20,
+                    inherited: 10,
+                },
+                Some(origin(7)),
+            )]
+        );
+    }
+
+    #[test]
+    fn rejects_defaults_of_the_wrong_kind_or_outside_inherited_bounds() {
+        let mut descriptors = string_types();
+        descriptors.push(with_property(
+            with_ref(
+                descriptor("Nickname.PropertyType", DescriptorKind::PropertyType, 6),
+                ReferenceRole::ValueType,
+                "ShortText.StringValueType",
+            ),
+            "DefaultValue",
+            LiteralValue::String("far too long for ten".to_string()),
+        ));
+        descriptors.push(with_property(
+            with_ref(
+                descriptor("Motto.PropertyType", DescriptorKind::PropertyType, 7),
+                ReferenceRole::ValueType,
+                "ShortText.StringValueType",
+            ),
+            "DefaultValue",
+            LiteralValue::Boolean(false),
+        ));
+
+        let diagnostics = check(descriptors);
+
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].to_string().contains("violates MaximumLength 10"));
+        assert_eq!(diagnostics[0].origin, Some(origin(6)));
+        assert!(diagnostics[1].to_string().contains("is not a string literal"));
+    }
+
+    #[test]
+    fn rejects_enum_defaults_that_name_no_member() {
+        let diagnostics = check(vec![
+            with_ref(
+                descriptor("Color.EnumType", DescriptorKind::Enum, 2),
+                ReferenceRole::Variants,
+                "Color.EnumType.Red",
+            ),
+            with_ref(
+                TypeDescriptor::new(
+                    "Color.EnumType.Red",
+                    "Red",
+                    DescriptorKind::EnumVariant,
+                    "Test Schema",
+                    origin(3),
+                ),
+                ReferenceRole::VariantOf,
+                "Color.EnumType",
+            ),
+            with_property(
+                with_ref(
+                    descriptor("Shade.PropertyType", DescriptorKind::PropertyType, 4),
+                    ReferenceRole::ValueType,
+                    "Color.EnumType",
+                ),
+                "DefaultValue",
+                LiteralValue::String("Blue".to_string()),
+            ),
+        ]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].to_string().contains("expected one of Red"));
+    }
+
+    #[test]
+    fn matches_unresolved_inverse_by_name_and_reports_endpoint_mismatch() {
+        let diagnostics = check(vec![
+            descriptor("Person.HolonType", DescriptorKind::HolonType, 2),
+            descriptor("Book.HolonType", DescriptorKind::HolonType, 3),
+            with_ref(
+                with_ref(
+                    with_ref(
+                        descriptor(
+                            "(Person.HolonType)-[Wrote]->(Book.HolonType)",
+                            DescriptorKind::RelationshipType,
+                            4,
+                        ),
+                        ReferenceRole::SourceType,
+                        "Person.HolonType",
+                    ),
+                    ReferenceRole::TargetType,
+                    "Book.HolonType",
+                ),
+                ReferenceRole::HasInverse,
+                "(Book.HolonType)-[WrittenBy]->(Person.HolonType)",
+            ),
+            {
+                let mut inverse = with_ref(
+                    with_ref(
+                        descriptor(
+                            "(Book.HolonType)-[WrittenBy]->(Book.HolonType)",
+                            DescriptorKind::RelationshipType,
+                            5,
+                        ),
+                        ReferenceRole::SourceType,
+                        "Book.HolonType",
+                    ),
+                    ReferenceRole::TargetType,
+                    "Book.HolonType",
+                );
+                inverse.relationship_flavor = Some(RelationshipFlavor::Inverse);
+                inverse
+            },
+        ]);
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                DiagnosticKind::InverseEndpointMismatch {
+                    declared: "(Person.HolonType)-[Wrote]->(Book.HolonType)".to_string(),
+                    inverse: "(Book.HolonType)-[WrittenBy]->(Book.HolonType)".to_string(),
+                },
+                Some(origin(5)),
+            )]
+        );
+    }
+
+    #[test]
+    fn rejects_members_already_provided_by_an_ancestor() {
+        let diagnostics = check(vec![
+            descriptor("Name.PropertyType", DescriptorKind::PropertyType, 2),
+            with_ref(
+                descriptor("Agent.HolonType", DescriptorKind::HolonType, 3),
+                ReferenceRole::InstanceProperty,
+                "Name.PropertyType",
+            ),
+            with_ref(
+                with_ref(
+                    descriptor("Person.HolonType", DescriptorKind::HolonType, 4),
+                    ReferenceRole::Extends,
+                    "Agent.HolonType",
+                ),
+                ReferenceRole::InstanceProperty,
+                "Name.PropertyType",
+            ),
+        ]);
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                DiagnosticKind::InheritedMemberRedeclared {
+                    descriptor: "Person.HolonType".to_string(),
+                    member: "Name.PropertyType".to_string(),
+                },
+                Some(origin(4)),
+            )]
+        );
+    }
+
+    #[test]
+    fn rejects_enum_member_names_repeated_across_the_effective_enum() {
+        let variant = |key: &str, name: &str, enum_key: &str, line| {
+            with_ref(
+                TypeDescriptor::new(
+                    key,
+                    name,
+                    DescriptorKind::EnumVariant,
+                    "Test Schema",
+                    origin(line),
+                ),
+                ReferenceRole::VariantOf,
+                enum_key,
+            )
+        };
+        let diagnostics = check(vec![
+            descriptor("Color.EnumType", DescriptorKind::Enum, 2),
+            variant("Color.EnumType.Red", "Red", "Color.EnumType", 3),
+            with_ref(
+                descriptor("Warm.Color", DescriptorKind::Enum, 4),
+                ReferenceRole::Extends,
+                "Color.EnumType",
+            ),
+            variant("Warm.Color.Red", "Red", "Warm.Color", 5),
+        ]);
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                DiagnosticKind::DuplicateEnumMemberName {
+                    descriptor: "Warm.Color".to_string(),
+                    member: "Red".to_string(),
+                },
+                Some(origin(5)),
+            )]
+        );
+    }
+}
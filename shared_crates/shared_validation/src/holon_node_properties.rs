@@ -1,10 +1,12 @@
 //! Descriptor-independent rules for native property maps.
 
+use base_types::MapRecord;
 use integrity_core_types::{PropertyMap, PropertyName, PropertyValue, PvlViolation};
 
 use crate::pvl_limits_v1::{
-    raw_byte_len, saturating_u32, utf8_byte_len, MAX_BYTES_VALUE_BYTES, MAX_ENUM_VALUE_BYTES,
-    MAX_PROPERTY_NAME_BYTES, MAX_STRING_VALUE_BYTES,
+    raw_byte_len, saturating_u32, utf8_byte_len, MAX_BYTES_VALUE_BYTES, MAX_COLLECTION_ITEMS,
+    MAX_DECIMAL_PRECISION, MAX_DECIMAL_SCALE, MAX_ENUM_VALUE_BYTES, MAX_PROPERTY_NAME_BYTES,
+    MAX_STRING_VALUE_BYTES, MAX_VALUE_NESTING_DEPTH,
 };
 
 /// Validates the representation of a property name.
@@ -79,11 +81,136 @@ pub fn validate_property_value(
                 });
             }
         }
+        PropertyValue::DecimalValue(value) => {
+            if value.precision() > MAX_DECIMAL_PRECISION || value.scale() > MAX_DECIMAL_SCALE {
+                return Err(PvlViolation::DecimalValueOutOfRange {
+                    property_name: property_name.clone(),
+                    actual_precision: value.precision(),
+                    actual_scale: value.scale(),
+                    max_precision: MAX_DECIMAL_PRECISION,
+                    max_scale: MAX_DECIMAL_SCALE,
+                });
+            }
+        }
+        // Their ranges are enforced by construction and by the SmartLink decoder.
+        PropertyValue::TimestampValue(_) | PropertyValue::DateValue(_) => {}
+        PropertyValue::ValueArray(values) => validate_value_array(property_name, value, &values.0)?,
+        PropertyValue::Record(fields) => validate_record(property_name, value, fields)?,
+    }
+
+    Ok(())
+}
+
+/// Applies the collection rules to a value array, then the scalar rules to
+/// each of its elements.
+fn validate_value_array(
+    property_name: &PropertyName,
+    value: &PropertyValue,
+    values: &[PropertyValue],
+) -> Result<(), PvlViolation> {
+    if values.len() > MAX_COLLECTION_ITEMS {
+        return Err(PvlViolation::CollectionTooLarge {
+            property_name: property_name.clone(),
+            actual_items: saturating_u32(values.len()),
+            max_items: saturating_u32(MAX_COLLECTION_ITEMS),
+        });
+    }
+
+    validate_nesting_depth(property_name, value)?;
+
+    if let Some(first) = values.first() {
+        let expected_kind = value_kind(first);
+        for (item_index, item) in values.iter().enumerate().skip(1) {
+            if value_kind(item) != expected_kind {
+                return Err(PvlViolation::HeterogeneousCollection {
+                    property_name: property_name.clone(),
+                    expected_kind: expected_kind.into(),
+                    actual_kind: value_kind(item).into(),
+                    item_index: saturating_u32(item_index),
+                });
+            }
+        }
+    }
+
+    for item in values.iter() {
+        validate_property_value(property_name, item)?;
+    }
+
+    Ok(())
+}
+
+/// Applies the collection and nesting rules to a record, then the name rules to
+/// each field name and the scalar rules to each field value.
+fn validate_record(
+    property_name: &PropertyName,
+    value: &PropertyValue,
+    fields: &MapRecord,
+) -> Result<(), PvlViolation> {
+    if fields.len() > MAX_COLLECTION_ITEMS {
+        return Err(PvlViolation::CollectionTooLarge {
+            property_name: property_name.clone(),
+            actual_items: saturating_u32(fields.len()),
+            max_items: saturating_u32(MAX_COLLECTION_ITEMS),
+        });
+    }
+
+    validate_nesting_depth(property_name, value)?;
+
+    for (field_name, field_value) in fields.iter() {
+        validate_property_name(&PropertyName(field_name.clone()))?;
+        validate_property_value(property_name, field_value)?;
+    }
+
+    Ok(())
+}
+
+fn validate_nesting_depth(
+    property_name: &PropertyName,
+    value: &PropertyValue,
+) -> Result<(), PvlViolation> {
+    let actual_depth = nesting_depth(value, 1);
+    if actual_depth > MAX_VALUE_NESTING_DEPTH {
+        return Err(PvlViolation::ValueNestingTooDeep {
+            property_name: property_name.clone(),
+            actual_depth: u8::try_from(actual_depth).unwrap_or(u8::MAX),
+            max_depth: MAX_VALUE_NESTING_DEPTH as u8,
+        });
     }
 
     Ok(())
 }
 
+/// Measures nesting depth, counting a scalar as depth 1.
+///
+/// Descent stops one level past the PVL limit, so adversarially deep input is
+/// reported at that depth without being walked in full.
+fn nesting_depth(value: &PropertyValue, depth: usize) -> usize {
+    match value {
+        PropertyValue::ValueArray(values) if depth <= MAX_VALUE_NESTING_DEPTH => {
+            values.iter().map(|item| nesting_depth(item, depth + 1)).max().unwrap_or(depth)
+        }
+        PropertyValue::Record(fields) if depth <= MAX_VALUE_NESTING_DEPTH => {
+            fields.iter().map(|(_, field)| nesting_depth(field, depth + 1)).max().unwrap_or(depth)
+        }
+        _ => depth,
+    }
+}
+
+fn value_kind(value: &PropertyValue) -> &'static str {
+    match value {
+        PropertyValue::StringValue(_) => "String",
+        PropertyValue::BooleanValue(_) => "Boolean",
+        PropertyValue::IntegerValue(_) => "Integer",
+        PropertyValue::EnumValue(_) => "Enum",
+        PropertyValue::BytesValue(_) => "Bytes",
+        PropertyValue::DecimalValue(_) => "Decimal",
+        PropertyValue::TimestampValue(_) => "Timestamp",
+        PropertyValue::DateValue(_) => "Date",
+        PropertyValue::ValueArray(_) => "ValueArray",
+        PropertyValue::Record(_) => "Record",
+    }
+}
+
 /// Validates any native property map in deterministic key order.
 ///
 /// This validator applies only property-name and scalar-value rules. It is
@@ -100,7 +227,11 @@ pub fn validate_property_map(property_map: &PropertyMap) -> Result<(), PvlViolat
 
 #[cfg(test)]
 mod tests {
-    use base_types::{BaseValue, MapBoolean, MapBytes, MapEnumValue, MapInteger, MapString};
+    use base_types::{
+        BaseValue, MapBoolean, MapBytes, MapDate, MapDecimal, MapEnumValue, MapInteger, MapString,
+        MapTimestamp, MapValueArray,
+    };
+    use std::collections::BTreeMap;
 
     use super::*;
 
@@ -255,14 +386,48 @@ mod tests {
     }
 
     #[test]
-    fn all_five_scalar_variants_are_supported_at_single_value_depth() {
+    fn decimal_value_precision_and_scale_boundaries_are_inclusive() {
+        let name = property_name("price");
+        let widest = "9".repeat(38).parse::<MapDecimal>().unwrap();
+        let finest = MapDecimal::new(1, 28);
+        assert_eq!(validate_property_value(&name, &BaseValue::DecimalValue(widest)), Ok(()));
+        assert_eq!(validate_property_value(&name, &BaseValue::DecimalValue(finest)), Ok(()));
+
+        let too_wide = "1".repeat(39).parse::<MapDecimal>().unwrap();
+        assert_eq!(
+            validate_property_value(&name, &BaseValue::DecimalValue(too_wide)),
+            Err(PvlViolation::DecimalValueOutOfRange {
+                property_name: name.clone(),
+                actual_precision: 39,
+                actual_scale: 0,
+                max_precision: 38,
+                max_scale: 28,
+            })
+        );
+        assert_eq!(
+            validate_property_value(&name, &BaseValue::DecimalValue(MapDecimal::new(1, 29))),
+            Err(PvlViolation::DecimalValueOutOfRange {
+                property_name: name,
+                actual_precision: 1,
+                actual_scale: 29,
+                max_precision: 38,
+                max_scale: 28,
+            })
+        );
+    }
+
+    #[test]
+    fn all_eight_scalar_variants_are_supported_at_single_value_depth() {
         let name = property_name("value");
-        let values: [PropertyValue; 5] = [
+        let values: [PropertyValue; 8] = [
             BaseValue::StringValue(MapString("text".into())),
             BaseValue::BooleanValue(MapBoolean(true)),
             BaseValue::IntegerValue(MapInteger(i64::MIN)),
             BaseValue::EnumValue(MapEnumValue(MapString("member".into()))),
             BaseValue::BytesValue(MapBytes(vec![1, 2, 3])),
+            BaseValue::DecimalValue(MapDecimal::new(-1999, 2)),
+            BaseValue::TimestampValue(MapTimestamp::MAX),
+            BaseValue::DateValue(MapDate::MIN),
         ];
 
         for value in &values {
@@ -270,6 +435,144 @@ mod tests {
         }
     }
 
+    #[test]
+    fn value_array_item_count_boundary_is_inclusive() {
+        let name = property_name("tags");
+        let tags = |count: usize| {
+            BaseValue::ValueArray(MapValueArray(vec![
+                BaseValue::StringValue(MapString(
+                    "tag".into()
+                ));
+                count
+            ]))
+        };
+        assert_eq!(validate_property_value(&name, &tags(1_024)), Ok(()));
+        assert_eq!(
+            validate_property_value(&name, &tags(1_025)),
+            Err(PvlViolation::CollectionTooLarge {
+                property_name: name,
+                actual_items: 1_025,
+                max_items: 1_024,
+            })
+        );
+    }
+
+    #[test]
+    fn value_arrays_must_be_flat_and_homogeneous() {
+        let name = property_name("aliases");
+        let alias = |text: &str| BaseValue::StringValue(MapString(text.into()));
+        assert_eq!(
+            validate_property_value(&name, &BaseValue::ValueArray(MapValueArray::default())),
+            Ok(())
+        );
+
+        let nested =
+            BaseValue::ValueArray(MapValueArray(vec![BaseValue::ValueArray(MapValueArray(vec![
+                alias("inner"),
+            ]))]));
+        assert_eq!(
+            validate_property_value(&name, &nested),
+            Err(PvlViolation::ValueNestingTooDeep {
+                property_name: name.clone(),
+                actual_depth: 3,
+                max_depth: 2,
+            })
+        );
+
+        let mixed = BaseValue::ValueArray(MapValueArray(vec![
+            alias("first"),
+            alias("second"),
+            BaseValue::IntegerValue(MapInteger(3)),
+        ]));
+        assert_eq!(
+            validate_property_value(&name, &mixed),
+            Err(PvlViolation::HeterogeneousCollection {
+                property_name: name.clone(),
+                expected_kind: "String".into(),
+                actual_kind: "Integer".into(),
+                item_index: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn value_array_elements_follow_scalar_rules() {
+        let name = property_name("aliases");
+        let aliases = BaseValue::ValueArray(MapValueArray(vec![
+            BaseValue::StringValue(MapString("short".into())),
+            BaseValue::StringValue(MapString("a".repeat(16_385))),
+        ]));
+
+        assert_eq!(
+            validate_property_value(&name, &aliases),
+            Err(PvlViolation::StringValueTooLarge {
+                property_name: name,
+                actual_bytes: 16_385,
+                max_bytes: 16_384,
+            })
+        );
+    }
+
+    #[test]
+    fn records_hold_named_scalar_fields_at_the_nesting_limit() {
+        let name = property_name("price");
+        let field = |field: &str, value: BaseValue| (MapString(field.into()), value);
+        let price = BaseValue::Record(MapRecord(BTreeMap::from([
+            field("Amount", BaseValue::DecimalValue(MapDecimal::new(950, 2))),
+            field("Currency", BaseValue::StringValue(MapString("EUR".into()))),
+        ])));
+        assert_eq!(validate_property_value(&name, &price), Ok(()));
+        assert_eq!(
+            validate_property_value(&name, &BaseValue::Record(MapRecord::default())),
+            Ok(())
+        );
+
+        let tagged = BaseValue::Record(MapRecord(BTreeMap::from([field(
+            "Tags",
+            BaseValue::ValueArray(MapValueArray(vec![BaseValue::BooleanValue(MapBoolean(true))])),
+        )])));
+        assert_eq!(
+            validate_property_value(&name, &tagged),
+            Err(PvlViolation::ValueNestingTooDeep {
+                property_name: name.clone(),
+                actual_depth: 3,
+                max_depth: 2,
+            })
+        );
+        let in_array = BaseValue::ValueArray(MapValueArray(vec![price]));
+        assert_eq!(
+            validate_property_value(&name, &in_array),
+            Err(PvlViolation::ValueNestingTooDeep {
+                property_name: name.clone(),
+                actual_depth: 3,
+                max_depth: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn record_fields_follow_name_and_scalar_rules() {
+        let name = property_name("address");
+        let unnamed = BaseValue::Record(MapRecord(BTreeMap::from([(
+            MapString(String::new()),
+            BaseValue::StringValue(MapString("Oslo".into())),
+        )])));
+        assert_eq!(validate_property_value(&name, &unnamed), Err(PvlViolation::EmptyPropertyName));
+
+        let oversized = BaseValue::Record(MapRecord(BTreeMap::from([(
+            MapString("Street".into()),
+            BaseValue::StringValue(MapString("a".repeat(16_385))),
+        )])));
+        assert_eq!(
+            validate_property_value(&name, &oversized),
+            Err(PvlViolation::StringValueTooLarge {
+                property_name: name,
+                actual_bytes: 16_385,
+                max_bytes: 16_384,
+            })
+        );
+    }
+
     #[test]
     fn property_values_are_concrete_scalar_base_values() {
         let name = property_name("value");
@@ -19,6 +19,10 @@ pub const MAX_ENUM_VALUE_BYTES: usize = 256;
 pub const MAX_CANONICAL_KEY_BYTES: usize = 256;
 /// Maximum raw byte length of a bytes property value.
 pub const MAX_BYTES_VALUE_BYTES: usize = 131_072;
+/// Maximum number of significant digits in a decimal property value.
+pub const MAX_DECIMAL_PRECISION: u8 = 38;
+/// Maximum number of fractional digits in a decimal property value.
+pub const MAX_DECIMAL_SCALE: u8 = 28;
 /// Maximum number of items in a collection property value.
 pub const MAX_COLLECTION_ITEMS: usize = 1_024;
 /// Maximum nesting depth of a property value.
@@ -77,6 +81,8 @@ mod tests {
         assert_eq!(MAX_ENUM_VALUE_BYTES, 256);
         assert_eq!(MAX_CANONICAL_KEY_BYTES, 256);
         assert_eq!(MAX_BYTES_VALUE_BYTES, 131_072);
+        assert_eq!(MAX_DECIMAL_PRECISION, 38);
+        assert_eq!(MAX_DECIMAL_SCALE, 28);
         assert_eq!(MAX_COLLECTION_ITEMS, 1_024);
         assert_eq!(MAX_VALUE_NESTING_DEPTH, 2);
         assert_eq!(MAX_RELATIONSHIP_NAME_BYTES, 128);
@@ -155,18 +155,27 @@ pub fn map_smartlink_decode_error(error: SmartLinkTagDecodeError) -> PvlViolatio
         SmartLinkTagDecodeError::UnknownSectionType(_) => {
             malformed(InvalidDiscriminant(PropertySectionType))
         }
-        SmartLinkTagDecodeError::UnknownValueType(_) => {
+        SmartLinkTagDecodeError::UnknownValueType(_)
+        | SmartLinkTagDecodeError::NestedValueArray
+        | SmartLinkTagDecodeError::NestedRecord => {
             malformed(InvalidDiscriminant(PropertyValueDiscriminant))
         }
         SmartLinkTagDecodeError::SectionBoundaryCrossing => {
             malformed(InvalidLength(PropertySection))
         }
-        SmartLinkTagDecodeError::InvalidIntegerLength(_) => malformed(InvalidLength(PropertyValue)),
+        SmartLinkTagDecodeError::InvalidIntegerLength(_)
+        | SmartLinkTagDecodeError::InvalidDecimalLength(_)
+        | SmartLinkTagDecodeError::InvalidTemporalLength(_)
+        | SmartLinkTagDecodeError::ValueArrayElementOverrun
+        | SmartLinkTagDecodeError::RecordFieldOverrun => malformed(InvalidLength(PropertyValue)),
         SmartLinkTagDecodeError::DuplicateSection(_)
         | SmartLinkTagDecodeError::EmptySection(_)
         | SmartLinkTagDecodeError::NonCanonicalSectionOrder
         | SmartLinkTagDecodeError::NonCanonicalPropertyOrder
-        | SmartLinkTagDecodeError::InvalidBooleanValue => malformed(NonCanonicalEncoding),
+        | SmartLinkTagDecodeError::NonCanonicalRecordFieldOrder
+        | SmartLinkTagDecodeError::InvalidBooleanValue
+        | SmartLinkTagDecodeError::NonCanonicalDecimal => malformed(NonCanonicalEncoding),
+        SmartLinkTagDecodeError::TemporalOutOfRange => malformed(DecodeFailed),
     }
 }
 
@@ -186,9 +195,9 @@ fn utf8_field(field: SmartLinkUtf8Field) -> PvlField {
         SmartLinkUtf8Field::RelationshipName => PvlField::RelationshipName,
         SmartLinkUtf8Field::CanonicalKey => PvlField::CanonicalKey,
         SmartLinkUtf8Field::PropertyName => PvlField::PropertyName,
-        SmartLinkUtf8Field::StringPropertyValue | SmartLinkUtf8Field::EnumPropertyValue => {
-            PvlField::PropertyValue
-        }
+        SmartLinkUtf8Field::StringPropertyValue
+        | SmartLinkUtf8Field::EnumPropertyValue
+        | SmartLinkUtf8Field::RecordFieldName => PvlField::PropertyValue,
     }
 }
 
@@ -442,10 +451,43 @@ mod tests {
                 SmartLinkTagDecodeError::InvalidIntegerLength(1),
                 PvlMalformedReason::InvalidLength(PvlField::PropertyValue),
             ),
+            (
+                SmartLinkTagDecodeError::InvalidDecimalLength(16),
+                PvlMalformedReason::InvalidLength(PvlField::PropertyValue),
+            ),
+            (
+                SmartLinkTagDecodeError::InvalidTemporalLength(4),
+                PvlMalformedReason::InvalidLength(PvlField::PropertyValue),
+            ),
+            (SmartLinkTagDecodeError::TemporalOutOfRange, PvlMalformedReason::DecodeFailed),
+            (
+                SmartLinkTagDecodeError::ValueArrayElementOverrun,
+                PvlMalformedReason::InvalidLength(PvlField::PropertyValue),
+            ),
+            (
+                SmartLinkTagDecodeError::NestedValueArray,
+                PvlMalformedReason::InvalidDiscriminant(PvlField::PropertyValueDiscriminant),
+            ),
+            (
+                SmartLinkTagDecodeError::RecordFieldOverrun,
+                PvlMalformedReason::InvalidLength(PvlField::PropertyValue),
+            ),
+            (
+                SmartLinkTagDecodeError::NonCanonicalRecordFieldOrder,
+                PvlMalformedReason::NonCanonicalEncoding,
+            ),
+            (
+                SmartLinkTagDecodeError::NestedRecord,
+                PvlMalformedReason::InvalidDiscriminant(PvlField::PropertyValueDiscriminant),
+            ),
             (
                 SmartLinkTagDecodeError::InvalidBooleanValue,
                 PvlMalformedReason::NonCanonicalEncoding,
             ),
+            (
+                SmartLinkTagDecodeError::NonCanonicalDecimal,
+                PvlMalformedReason::NonCanonicalEncoding,
+            ),
             (SmartLinkTagDecodeError::EmptySection(1), PvlMalformedReason::NonCanonicalEncoding),
             (
                 SmartLinkTagDecodeError::DuplicateSection(1),
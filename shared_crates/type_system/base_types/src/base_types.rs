@@ -1,9 +1,17 @@
 use derive_new::new;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fmt;
+use std::str::FromStr;
+
+use crate::temporal::{MapDate, MapTimestamp};
 
 // Scalar Wrapper Types – newtype wrappers around primitive Rust types
-//  (e.g., `MapString`, `MapBoolean`, `MapInteger`, `MapEnumValue`, `MapBytes`)
+//  (e.g., `MapString`, `MapBoolean`, `MapInteger`, `MapDecimal`, `MapEnumValue`, `MapBytes`)
+//  Calendar values (`MapTimestamp`, `MapDate`) live in the `temporal` module.
+//  `MapValueArray` holds a homogeneous sequence of scalar `BaseValue`s.
+//  `MapRecord` holds named scalar fields, e.g. an address or a money amount.
 //  that support serialization, hashing, and consistent formatting.
 
 // ===============================
@@ -51,6 +59,191 @@ impl fmt::Display for MapInteger {
     }
 }
 
+// ===============================
+// 📦 MapDecimal
+// ===============================
+/// Exact base-10 number, `mantissa × 10^-scale`.
+///
+/// Values are kept normalized — no trailing fractional zeros, and zero has scale 0 —
+/// so equality and hashing are numeric: `1.50` and `1.5` are the same value.
+/// Ordering is numeric as well. A decimal serializes as its canonical string
+/// (e.g. `"-12.5"`) so no digits are lost in JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MapDecimal {
+    mantissa: i128,
+    scale: u8,
+}
+
+impl MapDecimal {
+    /// Returns `mantissa × 10^-scale`, normalized.
+    pub fn new(mantissa: i128, scale: u8) -> Self {
+        let (mut mantissa, mut scale) = (mantissa, scale);
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Self { mantissa, scale }
+    }
+
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Number of fractional digits.
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Number of significant digits in the mantissa (1 for zero).
+    pub fn precision(&self) -> u8 {
+        let mut digits = 1;
+        let mut rest = self.mantissa.unsigned_abs() / 10;
+        while rest > 0 {
+            digits += 1;
+            rest /= 10;
+        }
+        digits
+    }
+
+    /// Returns the sum, or `None` if it cannot be represented.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let lhs = rescale(self.mantissa, scale - self.scale)?;
+        let rhs = rescale(other.mantissa, scale - other.scale)?;
+        Some(Self::new(lhs.checked_add(rhs)?, scale))
+    }
+}
+
+/// Multiplies `mantissa` by `10^by`, or `None` on overflow.
+fn rescale(mantissa: i128, by: u8) -> Option<i128> {
+    10_i128.checked_pow(u32::from(by)).and_then(|factor| mantissa.checked_mul(factor))
+}
+
+impl Ord for MapDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = self.mantissa.signum().cmp(&other.mantissa.signum());
+        if sign != Ordering::Equal || self.mantissa == 0 {
+            return sign;
+        }
+        // Same nonzero sign: compare at the larger scale. A mantissa that
+        // overflows when rescaled is larger in magnitude than any i128.
+        let scale = self.scale.max(other.scale);
+        match (
+            rescale(self.mantissa, scale - self.scale),
+            rescale(other.mantissa, scale - other.scale),
+        ) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            (None, _) => self.mantissa.signum().cmp(&0),
+            (_, None) => 0.cmp(&other.mantissa.signum()),
+        }
+    }
+}
+
+impl PartialOrd for MapDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for MapDecimal {
+    /// Displays the canonical plain-notation form, e.g. `-0.05` or `1200`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let scale = usize::from(self.scale);
+        if scale == 0 {
+            return write!(f, "{sign}{digits}");
+        }
+        if digits.len() > scale {
+            let (whole, fraction) = digits.split_at(digits.len() - scale);
+            write!(f, "{sign}{whole}.{fraction}")
+        } else {
+            write!(f, "{sign}0.{}{digits}", "0".repeat(scale - digits.len()))
+        }
+    }
+}
+
+/// Error returned when a string is not a representable `MapDecimal`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDecimalError(String);
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid decimal: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+impl FromStr for MapDecimal {
+    type Err = ParseDecimalError;
+
+    /// Parses plain (`-12.50`) or exponent (`1.25e-3`) notation.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseDecimalError(format!("'{input}'"));
+        let out_of_range = || ParseDecimalError(format!("'{input}' is out of range"));
+
+        let (number, exponent) = match input.find(['e', 'E']) {
+            Some(index) => {
+                (&input[..index], input[index + 1..].parse::<i32>().map_err(|_| invalid())?)
+            }
+            None => (input, 0),
+        };
+        let (negative, unsigned) = match number.as_bytes().first() {
+            Some(b'-') => (true, &number[1..]),
+            Some(b'+') => (false, &number[1..]),
+            _ => (false, number),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if whole.is_empty() && fraction.is_empty()
+            || !whole.bytes().chain(fraction.bytes()).all(|byte| byte.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let mut mantissa: i128 = 0;
+        for byte in whole.bytes().chain(fraction.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(i128::from(byte - b'0')))
+                .ok_or_else(out_of_range)?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+
+        let scale =
+            i64::try_from(fraction.len()).map_err(|_| out_of_range())? - i64::from(exponent);
+        if scale < 0 {
+            let mantissa = u8::try_from(-scale)
+                .ok()
+                .and_then(|by| rescale(mantissa, by))
+                .ok_or_else(out_of_range)?;
+            return Ok(Self::new(mantissa, 0));
+        }
+        // Trailing zeros may push the raw scale past `u8`; normalize them away first.
+        let mut scale = scale;
+        while scale > i64::from(u8::MAX) && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Ok(Self::new(mantissa, u8::try_from(scale).map_err(|_| out_of_range())?))
+    }
+}
+
+impl Serialize for MapDecimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MapDecimal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 // ===============================
 // 📦 MapEnumValue
 // ===============================
@@ -79,6 +272,114 @@ impl fmt::Display for MapBytes {
     }
 }
 
+// ===============================
+// 📦 MapValueArray
+// ===============================
+/// Ordered sequence of property values, e.g. a holon's tags or aliases.
+///
+/// Elements are expected to share one scalar kind; that, the item limit and the
+/// nesting limit are enforced by property validation rather than by this type.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct MapValueArray(pub Vec<BaseValue>);
+
+impl MapValueArray {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, BaseValue> {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for MapValueArray {
+    /// Displays the elements in brackets, e.g. `[StringValue("a"), StringValue("b")]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (index, value) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl From<Vec<BaseValue>> for MapValueArray {
+    #[inline]
+    fn from(values: Vec<BaseValue>) -> Self {
+        MapValueArray(values)
+    }
+}
+
+impl FromIterator<BaseValue> for MapValueArray {
+    fn from_iter<I: IntoIterator<Item = BaseValue>>(iter: I) -> Self {
+        MapValueArray(iter.into_iter().collect())
+    }
+}
+
+// ===============================
+// 📦 MapRecord
+// ===============================
+/// Small structured value with named fields, e.g. an address or an amount with
+/// its currency, stored inline rather than as a separate holon.
+///
+/// Fields are kept in name order so equal records display, convert and encode
+/// identically. Field names, scalar field values and the nesting limit are
+/// enforced by property validation rather than by this type.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct MapRecord(pub BTreeMap<MapString, BaseValue>);
+
+impl MapRecord {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, field: &str) -> Option<&BaseValue> {
+        self.0.get(&MapString(field.to_string()))
+    }
+
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, MapString, BaseValue> {
+        self.0.iter()
+    }
+}
+
+impl fmt::Display for MapRecord {
+    /// Displays the fields in braces, e.g. `{Amount: DecimalValue(9.50), Currency: StringValue("EUR")}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (index, (field, value)) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", field, value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl From<BTreeMap<MapString, BaseValue>> for MapRecord {
+    #[inline]
+    fn from(fields: BTreeMap<MapString, BaseValue>) -> Self {
+        MapRecord(fields)
+    }
+}
+
+impl FromIterator<(MapString, BaseValue)> for MapRecord {
+    fn from_iter<I: IntoIterator<Item = (MapString, BaseValue)>>(iter: I) -> Self {
+        MapRecord(iter.into_iter().collect())
+    }
+}
+
 // ===============================
 // 📦 BaseValue Enum
 // ===============================
@@ -89,6 +390,11 @@ pub enum BaseValue {
     IntegerValue(MapInteger),
     EnumValue(MapEnumValue), // for simple enum variants
     BytesValue(MapBytes),
+    DecimalValue(MapDecimal),
+    TimestampValue(MapTimestamp),
+    DateValue(MapDate),
+    ValueArray(MapValueArray),
+    Record(MapRecord),
 }
 
 impl fmt::Display for BaseValue {
@@ -99,6 +405,11 @@ impl fmt::Display for BaseValue {
     /// - `BooleanValue(true)`
     /// - `IntegerValue(42)`
     /// - `EnumValue(Status)`
+    /// - `DecimalValue(19.99)`
+    /// - `TimestampValue(2024-03-01T09:30:00Z)`
+    /// - `DateValue(2024-03-01)`
+    /// - `ValueArray([StringValue("a"), StringValue("b")])`
+    /// - `Record({Currency: StringValue("EUR")})`
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             BaseValue::StringValue(val) => write!(f, "StringValue(\"{}\")", val),
@@ -106,12 +417,21 @@ impl fmt::Display for BaseValue {
             BaseValue::IntegerValue(val) => write!(f, "IntegerValue({})", val),
             BaseValue::EnumValue(val) => write!(f, "EnumValue({})", val),
             BaseValue::BytesValue(val) => write!(f, "BytesValue({})", val),
+            BaseValue::DecimalValue(val) => write!(f, "DecimalValue({})", val),
+            BaseValue::TimestampValue(val) => write!(f, "TimestampValue({})", val),
+            BaseValue::DateValue(val) => write!(f, "DateValue({})", val),
+            BaseValue::ValueArray(val) => write!(f, "ValueArray({})", val),
+            BaseValue::Record(val) => write!(f, "Record({})", val),
         }
     }
 }
 
 impl BaseValue {
-    /// Convert any `BaseValue` to raw bytes (big-endian for integers).
+    /// Convert any `BaseValue` to raw bytes (big-endian for integers and for the
+    /// epoch offsets of timestamps and dates, canonical string for decimals).
+    /// Arrays concatenate their elements, each prefixed by its big-endian u32 length.
+    /// Records concatenate their fields in name order as length-prefixed name and
+    /// value pairs, so equal records always yield equal bytes.
     pub fn into_bytes(&self) -> MapBytes {
         match self {
             Self::StringValue(map_string) => MapBytes(map_string.0.clone().into_bytes()),
@@ -119,6 +439,31 @@ impl BaseValue {
             Self::IntegerValue(map_int) => MapBytes(map_int.0.to_be_bytes().to_vec()),
             Self::EnumValue(map_enum) => MapBytes(map_enum.0 .0.clone().into_bytes()),
             Self::BytesValue(map_bytes) => map_bytes.clone(),
+            Self::DecimalValue(map_decimal) => MapBytes(map_decimal.to_string().into_bytes()),
+            Self::TimestampValue(timestamp) => {
+                MapBytes(timestamp.unix_micros().to_be_bytes().to_vec())
+            }
+            Self::DateValue(date) => MapBytes(date.days_since_epoch().to_be_bytes().to_vec()),
+            Self::ValueArray(values) => {
+                let mut bytes = Vec::new();
+                for value in values.iter() {
+                    let element = value.into_bytes().0;
+                    bytes.extend_from_slice(&(element.len() as u32).to_be_bytes());
+                    bytes.extend_from_slice(&element);
+                }
+                MapBytes(bytes)
+            }
+            Self::Record(fields) => {
+                let mut bytes = Vec::new();
+                for (field, value) in fields.iter() {
+                    let value = value.into_bytes().0;
+                    bytes.extend_from_slice(&(field.0.len() as u32).to_be_bytes());
+                    bytes.extend_from_slice(field.0.as_bytes());
+                    bytes.extend_from_slice(&(value.len() as u32).to_be_bytes());
+                    bytes.extend_from_slice(&value);
+                }
+                MapBytes(bytes)
+            }
         }
     }
 }
@@ -133,6 +478,17 @@ impl Into<String> for &BaseValue {
             BaseValue::BooleanValue(val) => val.0.to_string(),
             BaseValue::EnumValue(val) => val.0 .0.clone(),
             BaseValue::BytesValue(val) => val.to_string(),
+            BaseValue::DecimalValue(val) => val.to_string(),
+            BaseValue::TimestampValue(val) => val.to_string(),
+            BaseValue::DateValue(val) => val.to_string(),
+            BaseValue::ValueArray(val) => {
+                val.iter().map(|value| value.into()).collect::<Vec<String>>().join(", ")
+            }
+            BaseValue::Record(val) => val
+                .iter()
+                .map(|(field, value)| format!("{}={}", field, Into::<String>::into(value)))
+                .collect::<Vec<String>>()
+                .join(", "),
         }
     }
 }
@@ -169,6 +525,31 @@ impl ToBaseValue for MapInteger {
         BaseValue::IntegerValue(self)
     }
 }
+impl ToBaseValue for MapDecimal {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::DecimalValue(self)
+    }
+}
+impl ToBaseValue for MapTimestamp {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::TimestampValue(self)
+    }
+}
+impl ToBaseValue for MapDate {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::DateValue(self)
+    }
+}
+impl ToBaseValue for MapValueArray {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::ValueArray(self)
+    }
+}
+impl ToBaseValue for MapRecord {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::Record(self)
+    }
+}
 impl ToBaseValue for MapEnumValue {
     fn to_base_value(self) -> BaseValue {
         BaseValue::EnumValue(self)
@@ -196,6 +577,31 @@ impl ToBaseValue for &MapInteger {
         BaseValue::IntegerValue(self.clone())
     }
 }
+impl ToBaseValue for &MapDecimal {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::DecimalValue(self.clone())
+    }
+}
+impl ToBaseValue for &MapTimestamp {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::TimestampValue(*self)
+    }
+}
+impl ToBaseValue for &MapDate {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::DateValue(*self)
+    }
+}
+impl ToBaseValue for &MapValueArray {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::ValueArray(self.clone())
+    }
+}
+impl ToBaseValue for &MapRecord {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::Record(self.clone())
+    }
+}
 impl ToBaseValue for &MapEnumValue {
     fn to_base_value(self) -> BaseValue {
         BaseValue::EnumValue(self.clone())
@@ -284,3 +690,119 @@ impl From<MapInteger> for i64 {
         value.0
     }
 }
+
+// MapDecimal <- i64
+impl From<i64> for MapDecimal {
+    #[inline]
+    fn from(value: i64) -> Self {
+        MapDecimal::new(i128::from(value), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(text: &str) -> MapDecimal {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn decimal_parses_plain_and_exponent_notation_to_canonical_form() {
+        let cases = [
+            ("19.99", "19.99"),
+            ("-0.050", "-0.05"),
+            ("+7", "7"),
+            (".5", "0.5"),
+            ("12.", "12"),
+            ("1.25e-3", "0.00125"),
+            ("1.2E3", "1200"),
+            ("-0.000", "0"),
+        ];
+        for (input, canonical) in cases {
+            assert_eq!(decimal(input).to_string(), canonical, "input {input}");
+        }
+        assert_eq!(decimal("1.50"), MapDecimal::new(15, 1));
+        assert_eq!(decimal("100").scale(), 0);
+        assert_eq!(decimal("-123.45").precision(), 5);
+    }
+
+    #[test]
+    fn decimal_rejects_malformed_and_unrepresentable_input() {
+        for input in ["", "-", ".", "1.2.3", "1e", "abc", "1,5", "--1"] {
+            assert!(input.parse::<MapDecimal>().is_err(), "input {input:?}");
+        }
+        assert!("1e40".parse::<MapDecimal>().is_err());
+        assert!("1".repeat(40).parse::<MapDecimal>().is_err());
+    }
+
+    #[test]
+    fn decimal_ordering_is_numeric_across_scales() {
+        let mut values =
+            [decimal("2"), decimal("-1.5"), decimal("0.25"), decimal("-10"), decimal("0")];
+        values.sort();
+        let ordered: Vec<String> = values.iter().map(ToString::to_string).collect();
+        assert_eq!(ordered, ["-10", "-1.5", "0", "0.25", "2"]);
+
+        // Rescaling the larger-magnitude side would overflow i128.
+        let huge = MapDecimal::new(i128::MAX, 0);
+        let tiny = MapDecimal::new(1, 200);
+        assert!(huge > tiny);
+        assert!(MapDecimal::new(-i128::MAX, 0) < MapDecimal::new(-1, 200));
+    }
+
+    #[test]
+    fn value_array_displays_and_converts_its_elements() {
+        let tags = MapValueArray::from(vec![
+            BaseValue::StringValue(MapString("red".into())),
+            BaseValue::StringValue(MapString("blue".into())),
+        ]);
+        assert_eq!(tags.to_string(), r#"[StringValue("red"), StringValue("blue")]"#);
+
+        let value = tags.to_base_value();
+        let text: String = (&value).into();
+        assert_eq!(text, "red, blue");
+        assert_eq!(
+            value.into_bytes(),
+            MapBytes(vec![0, 0, 0, 3, b'r', b'e', b'd', 0, 0, 0, 4, b'b', b'l', b'u', b'e'])
+        );
+        assert_eq!(MapValueArray::default().to_base_value().into_bytes(), MapBytes(Vec::new()));
+    }
+
+    #[test]
+    fn record_displays_and_converts_its_fields_in_name_order() {
+        let price: MapRecord = [
+            (MapString("Currency".into()), BaseValue::StringValue(MapString("EUR".into()))),
+            (MapString("Amount".into()), BaseValue::DecimalValue(decimal("9.5"))),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            price.to_string(),
+            r#"{Amount: DecimalValue(9.5), Currency: StringValue("EUR")}"#
+        );
+        assert_eq!(price.get("Currency"), Some(&BaseValue::StringValue(MapString("EUR".into()))));
+
+        let value = price.to_base_value();
+        let text: String = (&value).into();
+        assert_eq!(text, "Amount=9.5, Currency=EUR");
+
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&[0, 0, 0, 6]);
+        expected.extend_from_slice(b"Amount");
+        expected.extend_from_slice(&[0, 0, 0, 3]);
+        expected.extend_from_slice(b"9.5");
+        expected.extend_from_slice(&[0, 0, 0, 8]);
+        expected.extend_from_slice(b"Currency");
+        expected.extend_from_slice(&[0, 0, 0, 3]);
+        expected.extend_from_slice(b"EUR");
+        assert_eq!(value.into_bytes(), MapBytes(expected));
+    }
+
+    #[test]
+    fn decimal_addition_aligns_scales() {
+        assert_eq!(decimal("0.1").checked_add(&decimal("0.2")), Some(decimal("0.3")));
+        assert_eq!(decimal("1.75").checked_add(&decimal("-1.25")), Some(decimal("0.5")));
+        assert_eq!(MapDecimal::new(i128::MAX, 0).checked_add(&decimal("1")), None);
+    }
+}
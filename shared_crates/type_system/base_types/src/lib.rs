@@ -7,13 +7,19 @@
 //!
 //! Types in this crate include:
 //! - Scalar wrappers like `MapString`, `MapBoolean`, and `MapInteger`
+//! - Exact decimal numbers via `MapDecimal`
+//! - UTC instants and calendar dates via `MapTimestamp` and `MapDate`
 //! - Enumeration values via `MapEnumValue`
 //! - Byte arrays via `MapBytes`
+//! - Homogeneous value arrays via `MapValueArray`
+//! - Small records of named scalar fields via `MapRecord`
 //! - The `BaseValue` enum for representing dynamically typed property values
 //!
 //! These types are portable between guest-side and client-side environments
 //! and are designed for serialization, hashing, and consistent formatting.
 
 pub mod base_types;
+pub mod temporal;
 
 pub use base_types::*;
+pub use temporal::*;
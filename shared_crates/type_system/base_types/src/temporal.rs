@@ -0,0 +1,394 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+// Calendar value types. Both are proleptic Gregorian, UTC, and limited to the
+// years 0001 through 9999 so that every value has a four-digit ISO 8601 form.
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+const MICROS_PER_DAY: i64 = SECONDS_PER_DAY * MICROS_PER_SECOND;
+/// 0001-01-01, in days since 1970-01-01.
+const MIN_DAY: i64 = -719_162;
+/// 9999-12-31, in days since 1970-01-01.
+const MAX_DAY: i64 = 2_932_896;
+
+// ===============================
+// 📦 MapTimestamp
+// ===============================
+/// A UTC instant with microsecond precision, the resolution of `PersistenceTimestamp`.
+///
+/// Stored as microseconds since the Unix epoch, so ordering is chronological.
+/// A timestamp serializes as RFC 3339 text in UTC, with the fractional second
+/// present only when nonzero and trimmed of trailing zeros
+/// (e.g. `"2024-03-01T09:30:00.25Z"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MapTimestamp {
+    micros: i64,
+}
+
+impl MapTimestamp {
+    /// `0001-01-01T00:00:00Z`
+    pub const MIN: Self = Self { micros: MIN_DAY * MICROS_PER_DAY };
+    /// `9999-12-31T23:59:59.999999Z`
+    pub const MAX: Self = Self { micros: (MAX_DAY + 1) * MICROS_PER_DAY - 1 };
+
+    /// Returns the instant `micros` microseconds after the Unix epoch, or `None`
+    /// outside `MIN..=MAX`.
+    pub fn from_unix_micros(micros: i64) -> Option<Self> {
+        (Self::MIN.micros..=Self::MAX.micros).contains(&micros).then_some(Self { micros })
+    }
+
+    pub fn unix_micros(&self) -> i64 {
+        self.micros
+    }
+
+    /// The UTC calendar date of this instant.
+    pub fn date(&self) -> MapDate {
+        MapDate { days: self.micros.div_euclid(MICROS_PER_DAY) as i32 }
+    }
+}
+
+impl fmt::Display for MapTimestamp {
+    /// Displays the canonical RFC 3339 UTC form, e.g. `2024-03-01T09:30:00.25Z`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let of_day = self.micros.rem_euclid(MICROS_PER_DAY);
+        let seconds = of_day / MICROS_PER_SECOND;
+        let fraction = of_day % MICROS_PER_SECOND;
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date(),
+            seconds / 3_600,
+            seconds / 60 % 60,
+            seconds % 60
+        )?;
+        if fraction != 0 {
+            write!(f, ".{}", format!("{fraction:06}").trim_end_matches('0'))?;
+        }
+        write!(f, "Z")
+    }
+}
+
+impl FromStr for MapTimestamp {
+    type Err = ParseTemporalError;
+
+    /// Parses RFC 3339 text: `YYYY-MM-DDTHH:MM:SS`, an optional fraction of up to
+    /// nine digits, and `Z` or a `±HH:MM` offset. Digits finer than a
+    /// microsecond must be zero.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseTemporalError(format!("invalid timestamp '{input}'"));
+        let (date, rest) = input.split_at_checked(10).ok_or_else(invalid)?;
+        let days = i64::from(MapDate::from_str(date).map_err(|_| invalid())?.days);
+        let rest = rest.strip_prefix(['T', 't']).ok_or_else(invalid)?;
+
+        let (time, rest) = rest.split_at_checked(8).ok_or_else(invalid)?;
+        let [hour, b":", minute, b":", second] = split_fields(time, [2, 1, 2, 1, 2]) else {
+            return Err(invalid());
+        };
+        let (hour, minute, second) = (
+            digits(hour).filter(|hour| *hour < 24),
+            digits(minute).filter(|minute| *minute < 60),
+            digits(second).filter(|second| *second < 60),
+        );
+        let (Some(hour), Some(minute), Some(second)) = (hour, minute, second) else {
+            return Err(invalid());
+        };
+
+        let (fraction, zone) = match rest.strip_prefix('.') {
+            Some(rest) => {
+                let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+                let (fraction, zone) = rest.split_at(end);
+                if fraction.is_empty() || fraction.len() > 9 {
+                    return Err(invalid());
+                }
+                let (micros, finer) = fraction.split_at(fraction.len().min(6));
+                if finer.bytes().any(|digit| digit != b'0') {
+                    return Err(ParseTemporalError(format!(
+                        "timestamp '{input}' is more precise than a microsecond"
+                    )));
+                }
+                let scale = 10_i64.pow(6 - micros.len() as u32);
+                (digits(micros.as_bytes()).ok_or_else(invalid)? * scale, zone)
+            }
+            None => (0, rest),
+        };
+
+        let offset_seconds = match zone {
+            "Z" | "z" => 0,
+            _ => {
+                let [sign, hours, b":", minutes] = split_fields(zone, [1, 2, 1, 2]) else {
+                    return Err(invalid());
+                };
+                let sign = match sign {
+                    b"+" => 1,
+                    b"-" => -1,
+                    _ => return Err(invalid()),
+                };
+                let hours = digits(hours).filter(|hours| *hours < 24).ok_or_else(invalid)?;
+                let minutes =
+                    digits(minutes).filter(|minutes| *minutes < 60).ok_or_else(invalid)?;
+                sign * (hours * 3_600 + minutes * 60)
+            }
+        };
+
+        let seconds = days * SECONDS_PER_DAY + hour * 3_600 + minute * 60 + second;
+        Self::from_unix_micros((seconds - offset_seconds) * MICROS_PER_SECOND + fraction)
+            .ok_or_else(|| {
+                ParseTemporalError(format!("timestamp '{input}' is outside years 0001-9999"))
+            })
+    }
+}
+
+impl Serialize for MapTimestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MapTimestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// ===============================
+// 📦 MapDate
+// ===============================
+/// A calendar date without a time of day or time zone.
+///
+/// Stored as days since 1970-01-01, so ordering is chronological. A date
+/// serializes as ISO 8601 `YYYY-MM-DD` text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MapDate {
+    days: i32,
+}
+
+impl MapDate {
+    /// `0001-01-01`
+    pub const MIN: Self = Self { days: MIN_DAY as i32 };
+    /// `9999-12-31`
+    pub const MAX: Self = Self { days: MAX_DAY as i32 };
+
+    /// Returns the date `days` days after 1970-01-01, or `None` outside `MIN..=MAX`.
+    pub fn from_days_since_epoch(days: i32) -> Option<Self> {
+        (Self::MIN.days..=Self::MAX.days).contains(&days).then_some(Self { days })
+    }
+
+    /// Returns the given calendar date, or `None` if it does not exist or is
+    /// outside the years 0001-9999.
+    pub fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=9999).contains(&year)
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return None;
+        }
+        Some(Self { days: days_from_civil(year, month, day) as i32 })
+    }
+
+    pub fn days_since_epoch(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns `(year, month, day)`.
+    pub fn ymd(&self) -> (i32, u8, u8) {
+        civil_from_days(i64::from(self.days))
+    }
+
+    /// The instant at which this date begins, in UTC.
+    pub fn start_of_day(&self) -> MapTimestamp {
+        MapTimestamp { micros: i64::from(self.days) * MICROS_PER_DAY }
+    }
+}
+
+impl fmt::Display for MapDate {
+    /// Displays the ISO 8601 form, e.g. `2024-03-01`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.ymd();
+        write!(f, "{year:04}-{month:02}-{day:02}")
+    }
+}
+
+impl FromStr for MapDate {
+    type Err = ParseTemporalError;
+
+    /// Parses ISO 8601 `YYYY-MM-DD` text.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseTemporalError(format!("invalid date '{input}'"));
+        let [year, b"-", month, b"-", day] = split_fields(input, [4, 1, 2, 1, 2]) else {
+            return Err(invalid());
+        };
+        match (digits(year), digits(month), digits(day)) {
+            (Some(year), Some(month), Some(day)) => {
+                Self::from_ymd(year as i32, month as u8, day as u8).ok_or_else(invalid)
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Serialize for MapDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MapDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Error returned when a string is not a representable `MapTimestamp` or `MapDate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTemporalError(String);
+
+impl fmt::Display for ParseTemporalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseTemporalError {}
+
+/// Splits `text` into consecutive fields of the given byte widths, which must
+/// cover it exactly.
+fn split_fields<const N: usize>(text: &str, widths: [usize; N]) -> [&[u8]; N] {
+    let mut fields = [&[][..]; N];
+    if widths.iter().sum::<usize>() != text.len() {
+        return fields;
+    }
+    let mut rest = text.as_bytes();
+    for (field, width) in fields.iter_mut().zip(widths) {
+        let (head, tail) = rest.split_at(width);
+        *field = head;
+        rest = tail;
+    }
+    fields
+}
+
+/// Parses a non-empty run of ASCII digits.
+fn digits(field: &[u8]) -> Option<i64> {
+    if field.is_empty() || !field.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(field.iter().fold(0, |value, digit| value * 10 + i64::from(digit - b'0')))
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days-from-civil and its inverse, after Howard Hinnant's `chrono`-compatible
+// algorithms: eras are 400-year cycles starting on March 1st.
+
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(text: &str) -> MapTimestamp {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn dates_round_trip_through_iso_text() {
+        for (text, days) in [
+            ("1970-01-01", 0),
+            ("2000-02-29", 11_016),
+            ("1969-12-31", -1),
+            ("0001-01-01", MIN_DAY as i32),
+            ("9999-12-31", MAX_DAY as i32),
+        ] {
+            let date: MapDate = text.parse().unwrap();
+            assert_eq!(date.days_since_epoch(), days, "{text}");
+            assert_eq!(date.to_string(), text);
+        }
+        for text in ["2023-02-29", "2024-13-01", "2024-1-01", "0000-01-01", "2024-01-01Z", ""] {
+            assert!(text.parse::<MapDate>().is_err(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn timestamps_normalize_offsets_and_fractions_to_canonical_utc() {
+        assert_eq!(timestamp("1970-01-01T00:00:00Z").unix_micros(), 0);
+        assert_eq!(
+            timestamp("2024-03-01T10:30:00.250+01:00").to_string(),
+            "2024-03-01T09:30:00.25Z"
+        );
+        assert_eq!(timestamp("2024-03-01t09:30:00.000000000z").to_string(), "2024-03-01T09:30:00Z");
+        assert_eq!(timestamp("1969-12-31T23:59:59.999999Z").unix_micros(), -1);
+        assert_eq!(timestamp("2024-03-01T23:30:00-01:00").date().to_string(), "2024-03-02");
+    }
+
+    #[test]
+    fn timestamps_reject_malformed_or_unrepresentable_text() {
+        for text in [
+            "2024-03-01",
+            "2024-03-01T24:00:00Z",
+            "2024-03-01T09:30:60Z",
+            "2024-03-01T09:30:00",
+            "2024-03-01T09:30:00.Z",
+            "2024-03-01T09:30:00.0000001Z",
+            "2024-03-01T09:30:00+0100",
+            "0001-01-01T00:00:00+00:01",
+        ] {
+            assert!(text.parse::<MapTimestamp>().is_err(), "{text:?}");
+        }
+        assert_eq!(MapTimestamp::MAX.to_string(), "9999-12-31T23:59:59.999999Z");
+        assert!(MapTimestamp::from_unix_micros(MapTimestamp::MAX.unix_micros() + 1).is_none());
+    }
+
+    #[test]
+    fn temporal_values_deserialize_from_their_canonical_text() {
+        use serde::de::{value::Error, IntoDeserializer};
+
+        let value = MapTimestamp::deserialize(IntoDeserializer::<Error>::into_deserializer(
+            "2024-03-01T10:30:00+01:00",
+        ))
+        .unwrap();
+        let date = MapDate::deserialize(IntoDeserializer::<Error>::into_deserializer("2024-03-01"))
+            .unwrap();
+        assert_eq!(date, value.date());
+        assert!(date.start_of_day() < value);
+        assert!(MapDate::deserialize(IntoDeserializer::<Error>::into_deserializer("2024-02-30"))
+            .is_err());
+    }
+}
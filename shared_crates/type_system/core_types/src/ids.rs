@@ -1,3 +1,4 @@
+use base_types::{BaseValue, MapBytes, ToBaseValue};
 use integrity_core_types::{short_hex, HolonError, LocalId};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -197,6 +198,36 @@ impl<'a> ByteCursor<'a> {
     }
 }
 
+/// Holon-reference property values carry the canonical HolonId bytes.
+///
+/// The encoding aliases `BaseValue::BytesValue`: nothing in the value itself marks
+/// it as a reference, so descriptor-independent checks accept any bytes. Property
+/// validation against a `HolonReferenceValueType` rejects bytes that do not decode.
+impl ToBaseValue for HolonId {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::BytesValue(MapBytes(self.to_canonical_bytes()))
+    }
+}
+
+impl ToBaseValue for &HolonId {
+    fn to_base_value(self) -> BaseValue {
+        BaseValue::BytesValue(MapBytes(self.to_canonical_bytes()))
+    }
+}
+
+impl TryFrom<&BaseValue> for HolonId {
+    type Error = HolonError;
+
+    fn try_from(value: &BaseValue) -> Result<Self, Self::Error> {
+        match value {
+            BaseValue::BytesValue(bytes) => HolonId::from_canonical_bytes(&bytes.0),
+            other => Err(HolonError::InvalidParameter(format!(
+                "Expected canonical HolonId bytes, found {other}"
+            ))),
+        }
+    }
+}
+
 impl fmt::Display for HolonId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -244,4 +275,20 @@ mod tests {
                 if message.contains("Invalid HolonId bytes tag")
         ));
     }
+
+    #[test]
+    fn base_value_round_trip_holon_id() {
+        let expected = HolonId::External(ExternalId {
+            space_id: OutboundProxyId(LocalId(vec![7])),
+            local_id: LocalId(vec![8, 9]),
+        });
+
+        let value = (&expected).to_base_value();
+        assert_eq!(value, BaseValue::BytesValue(MapBytes(expected.to_canonical_bytes())));
+        assert_eq!(HolonId::try_from(&value).expect("bytes should decode"), expected);
+
+        let error = HolonId::try_from(&BaseValue::BooleanValue(base_types::MapBoolean(true)))
+            .expect_err("non-bytes value should be rejected");
+        assert!(matches!(error, HolonError::InvalidParameter(_)));
+    }
 }
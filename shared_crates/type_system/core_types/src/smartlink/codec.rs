@@ -1,6 +1,9 @@
 use std::{collections::BTreeSet, error::Error, fmt};
 
-use base_types::{BaseValue, MapBoolean, MapBytes, MapEnumValue, MapInteger, MapString};
+use base_types::{
+    BaseValue, MapBoolean, MapBytes, MapDate, MapDecimal, MapEnumValue, MapInteger, MapRecord,
+    MapString, MapTimestamp, MapValueArray,
+};
 
 use crate::{
     CanonicalKey, CanonicalKeyPrefix, DecodedSmartLinkTag, ExternalId, HolonId, LocalId,
@@ -26,6 +29,19 @@ const BOOLEAN_VALUE_TYPE: u8 = 2;
 const INTEGER_VALUE_TYPE: u8 = 3;
 const ENUM_VALUE_TYPE: u8 = 4;
 const BYTES_VALUE_TYPE: u8 = 5;
+const DECIMAL_VALUE_TYPE: u8 = 6;
+/// Decimal payload: one scale byte followed by the big-endian i128 mantissa.
+const DECIMAL_VALUE_BYTES: usize = 17;
+/// Timestamp payload: big-endian i64 microseconds since the Unix epoch.
+const TIMESTAMP_VALUE_TYPE: u8 = 7;
+/// Date payload: big-endian i32 days since 1970-01-01.
+const DATE_VALUE_TYPE: u8 = 8;
+/// Value array payload: each element as its scalar value type, u16 length and
+/// payload, in array order. Arrays do not nest.
+const VALUE_ARRAY_VALUE_TYPE: u8 = 9;
+/// Record payload: each field as u16 name length, name, scalar value type, u16
+/// length and payload, in strictly ascending name order. Records hold scalars only.
+const RECORD_VALUE_TYPE: u8 = 10;
 
 /// NUL-delimited prefix fields decoded from the SmartLink Tag v1 grammar.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -51,6 +67,7 @@ pub enum SmartLinkUtf8Field {
     PropertyName,
     StringPropertyValue,
     EnumPropertyValue,
+    RecordFieldName,
 }
 
 impl SmartLinkUtf8Field {
@@ -61,6 +78,7 @@ impl SmartLinkUtf8Field {
             Self::PropertyName => "property name",
             Self::StringPropertyValue => "string property value",
             Self::EnumPropertyValue => "enum property value",
+            Self::RecordFieldName => "record field name",
         }
     }
 }
@@ -143,6 +161,8 @@ pub enum SmartLinkTagEncodeError {
     InvalidEndpointLength { endpoint: SmartLinkEndpointRole, actual: usize },
     LengthOverflow(SmartLinkLengthField),
     DuplicateCacheCandidate(String),
+    NestedValueArray,
+    NestedRecord,
 }
 
 impl fmt::Display for SmartLinkTagEncodeError {
@@ -169,6 +189,10 @@ impl fmt::Display for SmartLinkTagEncodeError {
             Self::DuplicateCacheCandidate(name) => {
                 write!(f, "duplicate SmartLink cache candidate {name}")
             }
+            Self::NestedValueArray => write!(f, "SmartLink value array contains a value array"),
+            Self::NestedRecord => {
+                write!(f, "SmartLink record is nested in or contains a composite value")
+            }
         }
     }
 }
@@ -195,6 +219,15 @@ pub enum SmartLinkTagDecodeError {
     UnknownValueType(u8),
     InvalidBooleanValue,
     InvalidIntegerLength(usize),
+    InvalidDecimalLength(usize),
+    NonCanonicalDecimal,
+    InvalidTemporalLength(usize),
+    TemporalOutOfRange,
+    ValueArrayElementOverrun,
+    NestedValueArray,
+    RecordFieldOverrun,
+    NonCanonicalRecordFieldOrder,
+    NestedRecord,
 }
 
 impl fmt::Display for SmartLinkTagDecodeError {
@@ -248,6 +281,29 @@ impl fmt::Display for SmartLinkTagDecodeError {
             Self::InvalidIntegerLength(length) => {
                 write!(f, "SmartLink integer has noncanonical length {length}")
             }
+            Self::InvalidDecimalLength(length) => {
+                write!(f, "SmartLink decimal has noncanonical length {length}")
+            }
+            Self::NonCanonicalDecimal => write!(f, "SmartLink decimal is not normalized"),
+            Self::InvalidTemporalLength(length) => {
+                write!(f, "SmartLink timestamp or date has noncanonical length {length}")
+            }
+            Self::TemporalOutOfRange => {
+                write!(f, "SmartLink timestamp or date is outside years 0001-9999")
+            }
+            Self::ValueArrayElementOverrun => {
+                write!(f, "SmartLink value array element overruns its property value")
+            }
+            Self::NestedValueArray => write!(f, "SmartLink value array contains a value array"),
+            Self::RecordFieldOverrun => {
+                write!(f, "SmartLink record field overruns its property value")
+            }
+            Self::NonCanonicalRecordFieldOrder => {
+                write!(f, "SmartLink record fields are not in canonical order")
+            }
+            Self::NestedRecord => {
+                write!(f, "SmartLink record is nested in or contains a composite value")
+            }
         }
     }
 }
@@ -511,7 +567,7 @@ fn encode_property_entry(
     let mut bytes = Vec::new();
     append_u16(&mut bytes, name.0 .0.len(), SmartLinkLengthField::PropertyName)?;
     bytes.extend_from_slice(name.0 .0.as_bytes());
-    let (value_type, value_bytes) = encode_value(value);
+    let (value_type, value_bytes) = encode_value(value)?;
     bytes.push(value_type);
     append_u16(&mut bytes, value_bytes.len(), SmartLinkLengthField::PropertyValue)?;
     bytes.extend_from_slice(&value_bytes);
@@ -545,14 +601,58 @@ fn decode_property_section(bytes: &[u8]) -> Result<PropertyMap, SmartLinkTagDeco
     Ok(properties)
 }
 
-fn encode_value(value: &BaseValue) -> (u8, Vec<u8>) {
-    match value {
+fn encode_value(value: &BaseValue) -> Result<(u8, Vec<u8>), SmartLinkTagEncodeError> {
+    Ok(match value {
         BaseValue::StringValue(value) => (STRING_VALUE_TYPE, value.0.as_bytes().to_vec()),
         BaseValue::BooleanValue(value) => (BOOLEAN_VALUE_TYPE, vec![u8::from(value.0)]),
         BaseValue::IntegerValue(value) => (INTEGER_VALUE_TYPE, value.0.to_be_bytes().to_vec()),
         BaseValue::EnumValue(value) => (ENUM_VALUE_TYPE, value.0 .0.as_bytes().to_vec()),
         BaseValue::BytesValue(value) => (BYTES_VALUE_TYPE, value.0.clone()),
-    }
+        BaseValue::DecimalValue(value) => {
+            let mut bytes = Vec::with_capacity(DECIMAL_VALUE_BYTES);
+            bytes.push(value.scale());
+            bytes.extend_from_slice(&value.mantissa().to_be_bytes());
+            (DECIMAL_VALUE_TYPE, bytes)
+        }
+        BaseValue::TimestampValue(value) => {
+            (TIMESTAMP_VALUE_TYPE, value.unix_micros().to_be_bytes().to_vec())
+        }
+        BaseValue::DateValue(value) => {
+            (DATE_VALUE_TYPE, value.days_since_epoch().to_be_bytes().to_vec())
+        }
+        BaseValue::ValueArray(values) => {
+            let mut bytes = Vec::new();
+            for element in values.iter() {
+                match element {
+                    BaseValue::ValueArray(_) => {
+                        return Err(SmartLinkTagEncodeError::NestedValueArray)
+                    }
+                    BaseValue::Record(_) => return Err(SmartLinkTagEncodeError::NestedRecord),
+                    _ => {}
+                }
+                let (element_type, element_bytes) = encode_value(element)?;
+                bytes.push(element_type);
+                append_u16(&mut bytes, element_bytes.len(), SmartLinkLengthField::PropertyValue)?;
+                bytes.extend_from_slice(&element_bytes);
+            }
+            (VALUE_ARRAY_VALUE_TYPE, bytes)
+        }
+        BaseValue::Record(fields) => {
+            let mut bytes = Vec::new();
+            for (field, value) in fields.iter() {
+                if matches!(value, BaseValue::ValueArray(_) | BaseValue::Record(_)) {
+                    return Err(SmartLinkTagEncodeError::NestedRecord);
+                }
+                append_u16(&mut bytes, field.0.len(), SmartLinkLengthField::PropertyName)?;
+                bytes.extend_from_slice(field.0.as_bytes());
+                let (value_type, value_bytes) = encode_value(value)?;
+                bytes.push(value_type);
+                append_u16(&mut bytes, value_bytes.len(), SmartLinkLengthField::PropertyValue)?;
+                bytes.extend_from_slice(&value_bytes);
+            }
+            (RECORD_VALUE_TYPE, bytes)
+        }
+    })
 }
 
 fn decode_value(value_type: u8, bytes: &[u8]) -> Result<BaseValue, SmartLinkTagDecodeError> {
@@ -577,6 +677,90 @@ fn decode_value(value_type: u8, bytes: &[u8]) -> Result<BaseValue, SmartLinkTagD
             SmartLinkUtf8Field::EnumPropertyValue,
         )?)))),
         BYTES_VALUE_TYPE => Ok(BaseValue::BytesValue(MapBytes(bytes.to_vec()))),
+        DECIMAL_VALUE_TYPE => {
+            if bytes.len() != DECIMAL_VALUE_BYTES {
+                return Err(SmartLinkTagDecodeError::InvalidDecimalLength(bytes.len()));
+            }
+            let scale = bytes[0];
+            let mantissa = i128::from_be_bytes(
+                bytes[1..].try_into().expect("a 16-byte slice converts to a 16-byte array"),
+            );
+            // Only the normalized encoding is valid, so equal decimals always
+            // produce identical tags.
+            let decimal = MapDecimal::new(mantissa, scale);
+            if decimal.scale() != scale {
+                return Err(SmartLinkTagDecodeError::NonCanonicalDecimal);
+            }
+            Ok(BaseValue::DecimalValue(decimal))
+        }
+        TIMESTAMP_VALUE_TYPE => {
+            let micros = i64::from_be_bytes(
+                bytes
+                    .try_into()
+                    .map_err(|_| SmartLinkTagDecodeError::InvalidTemporalLength(bytes.len()))?,
+            );
+            MapTimestamp::from_unix_micros(micros)
+                .map(BaseValue::TimestampValue)
+                .ok_or(SmartLinkTagDecodeError::TemporalOutOfRange)
+        }
+        DATE_VALUE_TYPE => {
+            let days = i32::from_be_bytes(
+                bytes
+                    .try_into()
+                    .map_err(|_| SmartLinkTagDecodeError::InvalidTemporalLength(bytes.len()))?,
+            );
+            MapDate::from_days_since_epoch(days)
+                .map(BaseValue::DateValue)
+                .ok_or(SmartLinkTagDecodeError::TemporalOutOfRange)
+        }
+        VALUE_ARRAY_VALUE_TYPE => {
+            let mut cursor = ByteCursor::new(bytes);
+            let mut elements = Vec::new();
+            while !cursor.is_empty() {
+                let element_type =
+                    cursor.take_u8().ok_or(SmartLinkTagDecodeError::ValueArrayElementOverrun)?;
+                match element_type {
+                    VALUE_ARRAY_VALUE_TYPE => {
+                        return Err(SmartLinkTagDecodeError::NestedValueArray)
+                    }
+                    RECORD_VALUE_TYPE => return Err(SmartLinkTagDecodeError::NestedRecord),
+                    _ => {}
+                }
+                let element_length =
+                    cursor.take_u16().ok_or(SmartLinkTagDecodeError::ValueArrayElementOverrun)?
+                        as usize;
+                let element_bytes = cursor
+                    .take(element_length)
+                    .ok_or(SmartLinkTagDecodeError::ValueArrayElementOverrun)?;
+                elements.push(decode_value(element_type, element_bytes)?);
+            }
+            Ok(BaseValue::ValueArray(MapValueArray(elements)))
+        }
+        RECORD_VALUE_TYPE => {
+            let mut cursor = ByteCursor::new(bytes);
+            let mut fields = MapRecord::default();
+            while !cursor.is_empty() {
+                let name_length =
+                    cursor.take_u16().ok_or(SmartLinkTagDecodeError::RecordFieldOverrun)? as usize;
+                let name_bytes =
+                    cursor.take(name_length).ok_or(SmartLinkTagDecodeError::RecordFieldOverrun)?;
+                let name = MapString(read_utf8(name_bytes, SmartLinkUtf8Field::RecordFieldName)?);
+                if fields.0.last_key_value().is_some_and(|(previous, _)| previous >= &name) {
+                    return Err(SmartLinkTagDecodeError::NonCanonicalRecordFieldOrder);
+                }
+                let value_type =
+                    cursor.take_u8().ok_or(SmartLinkTagDecodeError::RecordFieldOverrun)?;
+                if matches!(value_type, VALUE_ARRAY_VALUE_TYPE | RECORD_VALUE_TYPE) {
+                    return Err(SmartLinkTagDecodeError::NestedRecord);
+                }
+                let value_length =
+                    cursor.take_u16().ok_or(SmartLinkTagDecodeError::RecordFieldOverrun)? as usize;
+                let value_bytes =
+                    cursor.take(value_length).ok_or(SmartLinkTagDecodeError::RecordFieldOverrun)?;
+                fields.0.insert(name, decode_value(value_type, value_bytes)?);
+            }
+            Ok(BaseValue::Record(fields))
+        }
         other => Err(SmartLinkTagDecodeError::UnknownValueType(other)),
     }
 }
@@ -748,12 +932,36 @@ mod tests {
         BTreeMap::from([
             (property_name("Boolean"), BaseValue::BooleanValue(MapBoolean(true))),
             (property_name("Bytes"), bytes(vec![0, 1, 0, 255])),
+            (property_name("Date"), BaseValue::DateValue(MapDate::from_ymd(1969, 7, 20).unwrap())),
+            (property_name("Decimal"), BaseValue::DecimalValue(MapDecimal::new(-1999, 2))),
             (
                 property_name("Enum"),
                 BaseValue::EnumValue(MapEnumValue(MapString("Active".to_string()))),
             ),
             (property_name("Integer"), BaseValue::IntegerValue(MapInteger(-42))),
+            (
+                property_name("Price"),
+                BaseValue::Record(MapRecord(BTreeMap::from([
+                    (
+                        MapString("Amount".to_string()),
+                        BaseValue::DecimalValue(MapDecimal::new(950, 2)),
+                    ),
+                    (MapString("Currency".to_string()), string("EUR")),
+                ]))),
+            ),
             (property_name("String"), string("text\0value")),
+            (
+                property_name("Tags"),
+                BaseValue::ValueArray(MapValueArray(vec![
+                    string("red"),
+                    string(""),
+                    string("blue"),
+                ])),
+            ),
+            (
+                property_name("Timestamp"),
+                BaseValue::TimestampValue(MapTimestamp::from_unix_micros(-1).unwrap()),
+            ),
         ])
     }
 
@@ -913,6 +1121,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejects_nested_value_arrays_before_packing() {
+        let mut input = local_input();
+        let inner = BaseValue::ValueArray(MapValueArray(vec![string("inner")]));
+        input
+            .relationship_property_values
+            .insert(property_name("Nested"), BaseValue::ValueArray(MapValueArray(vec![inner])));
+
+        assert_eq!(encode_smartlink_tag(&input), Err(SmartLinkTagEncodeError::NestedValueArray));
+    }
+
+    #[test]
+    fn rejects_records_nested_with_composites_before_packing() {
+        let record = MapRecord(BTreeMap::from([(MapString("City".to_string()), string("Oslo"))]));
+        let mut in_array = local_input();
+        in_array.relationship_property_values.insert(
+            property_name("Addresses"),
+            BaseValue::ValueArray(MapValueArray(vec![BaseValue::Record(record.clone())])),
+        );
+        let mut in_record = local_input();
+        in_record.relationship_property_values.insert(
+            property_name("Address"),
+            BaseValue::Record(MapRecord(BTreeMap::from([(
+                MapString("Inner".to_string()),
+                BaseValue::Record(record),
+            )]))),
+        );
+
+        assert_eq!(encode_smartlink_tag(&in_array), Err(SmartLinkTagEncodeError::NestedRecord));
+        assert_eq!(encode_smartlink_tag(&in_record), Err(SmartLinkTagEncodeError::NestedRecord));
+    }
+
     #[test]
     fn equivalent_property_maps_produce_identical_bytes() {
         let mut first = local_input();
@@ -1090,6 +1330,130 @@ mod tests {
             decode_smartlink_tag(&invalid_integer, hash(1)),
             Err(SmartLinkTagDecodeError::InvalidIntegerLength(1))
         );
+
+        let typed_entry = |value_type: u8, payload: &[u8]| {
+            let mut entry = Vec::new();
+            append_u16(&mut entry, 1, SmartLinkLengthField::PropertyName).unwrap();
+            entry.extend_from_slice(b"d");
+            entry.push(value_type);
+            append_u16(&mut entry, payload.len(), SmartLinkLengthField::PropertyValue).unwrap();
+            entry.extend_from_slice(payload);
+            entry
+        };
+        let mut short_decimal = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(&mut short_decimal, 1, &typed_entry(DECIMAL_VALUE_TYPE, &[0; 8]));
+        assert_eq!(
+            decode_smartlink_tag(&short_decimal, hash(1)),
+            Err(SmartLinkTagDecodeError::InvalidDecimalLength(8))
+        );
+        // 1.50 encoded with scale 2 instead of the normalized 1.5.
+        let mut trailing_zero = vec![2];
+        trailing_zero.extend_from_slice(&150_i128.to_be_bytes());
+        let mut non_canonical_decimal = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut non_canonical_decimal,
+            1,
+            &typed_entry(DECIMAL_VALUE_TYPE, &trailing_zero),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&non_canonical_decimal, hash(1)),
+            Err(SmartLinkTagDecodeError::NonCanonicalDecimal)
+        );
+
+        let mut short_timestamp = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(&mut short_timestamp, 1, &typed_entry(TIMESTAMP_VALUE_TYPE, &[0; 4]));
+        assert_eq!(
+            decode_smartlink_tag(&short_timestamp, hash(1)),
+            Err(SmartLinkTagDecodeError::InvalidTemporalLength(4))
+        );
+        let mut year_zero = encode_smartlink_tag(&local_input()).unwrap();
+        let before_min = MapDate::MIN.days_since_epoch() - 1;
+        append_raw_section(
+            &mut year_zero,
+            1,
+            &typed_entry(DATE_VALUE_TYPE, &before_min.to_be_bytes()),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&year_zero, hash(1)),
+            Err(SmartLinkTagDecodeError::TemporalOutOfRange)
+        );
+
+        // One string element declaring three payload bytes but carrying two.
+        let mut overrun_array = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut overrun_array,
+            1,
+            &typed_entry(VALUE_ARRAY_VALUE_TYPE, &[STRING_VALUE_TYPE, 0, 3, b'a', b'b']),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&overrun_array, hash(1)),
+            Err(SmartLinkTagDecodeError::ValueArrayElementOverrun)
+        );
+        let mut nested_array = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut nested_array,
+            1,
+            &typed_entry(VALUE_ARRAY_VALUE_TYPE, &[VALUE_ARRAY_VALUE_TYPE, 0, 0]),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&nested_array, hash(1)),
+            Err(SmartLinkTagDecodeError::NestedValueArray)
+        );
+        let mut bad_element = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut bad_element,
+            1,
+            &typed_entry(VALUE_ARRAY_VALUE_TYPE, &[BOOLEAN_VALUE_TYPE, 0, 1, 2]),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&bad_element, hash(1)),
+            Err(SmartLinkTagDecodeError::InvalidBooleanValue)
+        );
+        let mut record_in_array = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut record_in_array,
+            1,
+            &typed_entry(VALUE_ARRAY_VALUE_TYPE, &[RECORD_VALUE_TYPE, 0, 0]),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&record_in_array, hash(1)),
+            Err(SmartLinkTagDecodeError::NestedRecord)
+        );
+
+        // One field named "a" whose string value declares two bytes but carries one.
+        let mut overrun_record = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut overrun_record,
+            1,
+            &typed_entry(RECORD_VALUE_TYPE, &[0, 1, b'a', STRING_VALUE_TYPE, 0, 2, b'x']),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&overrun_record, hash(1)),
+            Err(SmartLinkTagDecodeError::RecordFieldOverrun)
+        );
+        let mut unordered_record = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut unordered_record,
+            1,
+            &typed_entry(
+                RECORD_VALUE_TYPE,
+                &[0, 1, b'b', BOOLEAN_VALUE_TYPE, 0, 1, 1, 0, 1, b'a', BOOLEAN_VALUE_TYPE, 0, 1, 0],
+            ),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&unordered_record, hash(1)),
+            Err(SmartLinkTagDecodeError::NonCanonicalRecordFieldOrder)
+        );
+        let mut nested_record = encode_smartlink_tag(&local_input()).unwrap();
+        append_raw_section(
+            &mut nested_record,
+            1,
+            &typed_entry(RECORD_VALUE_TYPE, &[0, 1, b'a', RECORD_VALUE_TYPE, 0, 0]),
+        );
+        assert_eq!(
+            decode_smartlink_tag(&nested_record, hash(1)),
+            Err(SmartLinkTagDecodeError::NestedRecord)
+        );
     }
 
     #[test]
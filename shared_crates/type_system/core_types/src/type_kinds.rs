@@ -33,9 +33,12 @@ impl TypeKind {
             TypeKind::Value(value_type) => match value_type {
                 BaseTypeKind::Boolean => "TypeKind.Value.Boolean".to_string(),
                 BaseTypeKind::Bytes => "TypeKind.Value.Bytes".to_string(),
+                BaseTypeKind::Date => "TypeKind.Value.Date".to_string(),
+                BaseTypeKind::Decimal => "TypeKind.Value.Decimal".to_string(),
                 BaseTypeKind::Enum => "TypeKind.Value.Enum".to_string(),
                 BaseTypeKind::Integer => "TypeKind.Value.Integer".to_string(),
                 BaseTypeKind::String => "TypeKind.Value.String".to_string(),
+                BaseTypeKind::Timestamp => "TypeKind.Value.Timestamp".to_string(),
             },
             TypeKind::ValueArray(_) => "TypeKind.Value.Array".to_string(),
         }
@@ -59,16 +62,22 @@ impl fmt::Display for TypeKind {
             TypeKind::Value(value_type) => match value_type {
                 BaseTypeKind::Boolean => write!(f, "BooleanValue"),
                 BaseTypeKind::Bytes => write!(f, "BytesValue"),
+                BaseTypeKind::Date => write!(f, "DateValue"),
+                BaseTypeKind::Decimal => write!(f, "DecimalValue"),
                 BaseTypeKind::Enum => write!(f, "EnumValue"),
                 BaseTypeKind::Integer => write!(f, "IntegerValue"),
                 BaseTypeKind::String => write!(f, "StringValue"),
+                BaseTypeKind::Timestamp => write!(f, "TimestampValue"),
             },
             TypeKind::ValueArray(value_type) => match value_type {
                 BaseTypeKind::Boolean => write!(f, "Array of BooleanValue"),
                 BaseTypeKind::Bytes => write!(f, "Array of BytesValue"),
+                BaseTypeKind::Date => write!(f, "Array of DateValue"),
+                BaseTypeKind::Decimal => write!(f, "Array of DecimalValue"),
                 BaseTypeKind::Enum => write!(f, "Array of EnumValue"),
                 BaseTypeKind::Integer => write!(f, "Array of IntegerValue"),
                 BaseTypeKind::String => write!(f, "Array of StringValue"),
+                BaseTypeKind::Timestamp => write!(f, "Array of TimestampValue"),
             },
         }
     }
@@ -82,9 +91,12 @@ impl fmt::Display for TypeKind {
 pub enum BaseTypeKind {
     Boolean,
     Bytes,
+    Date,
+    Decimal,
     Enum,
     Integer,
     String,
+    Timestamp,
 }
 
 impl fmt::Display for BaseTypeKind {
@@ -93,9 +105,12 @@ impl fmt::Display for BaseTypeKind {
         match self {
             BaseTypeKind::Boolean => write!(f, "Boolean"),
             BaseTypeKind::Bytes => write!(f, "Bytes"),
+            BaseTypeKind::Date => write!(f, "Date"),
+            BaseTypeKind::Decimal => write!(f, "Decimal"),
             BaseTypeKind::Enum => write!(f, "Enum"),
             BaseTypeKind::Integer => write!(f, "Integer"),
             BaseTypeKind::String => write!(f, "String"),
+            BaseTypeKind::Timestamp => write!(f, "Timestamp"),
         }
     }
 }
@@ -115,14 +130,20 @@ mod tests {
             (TypeKind::Dance, "Dance"),
             (TypeKind::Value(BaseTypeKind::Boolean), "BooleanValue"),
             (TypeKind::Value(BaseTypeKind::Bytes), "BytesValue"),
+            (TypeKind::Value(BaseTypeKind::Date), "DateValue"),
+            (TypeKind::Value(BaseTypeKind::Decimal), "DecimalValue"),
             (TypeKind::Value(BaseTypeKind::Enum), "EnumValue"),
             (TypeKind::Value(BaseTypeKind::Integer), "IntegerValue"),
             (TypeKind::Value(BaseTypeKind::String), "StringValue"),
+            (TypeKind::Value(BaseTypeKind::Timestamp), "TimestampValue"),
             (TypeKind::ValueArray(BaseTypeKind::Boolean), "Array of BooleanValue"),
             (TypeKind::ValueArray(BaseTypeKind::Bytes), "Array of BytesValue"),
+            (TypeKind::ValueArray(BaseTypeKind::Date), "Array of DateValue"),
+            (TypeKind::ValueArray(BaseTypeKind::Decimal), "Array of DecimalValue"),
             (TypeKind::ValueArray(BaseTypeKind::Enum), "Array of EnumValue"),
             (TypeKind::ValueArray(BaseTypeKind::Integer), "Array of IntegerValue"),
             (TypeKind::ValueArray(BaseTypeKind::String), "Array of StringValue"),
+            (TypeKind::ValueArray(BaseTypeKind::Timestamp), "Array of TimestampValue"),
         ];
 
         for (type_kind, expected) in cases {
@@ -141,9 +162,12 @@ mod tests {
             (TypeKind::Dance, "TypeKind.Dance"),
             (TypeKind::Value(BaseTypeKind::Boolean), "TypeKind.Value.Boolean"),
             (TypeKind::Value(BaseTypeKind::Bytes), "TypeKind.Value.Bytes"),
+            (TypeKind::Value(BaseTypeKind::Date), "TypeKind.Value.Date"),
+            (TypeKind::Value(BaseTypeKind::Decimal), "TypeKind.Value.Decimal"),
             (TypeKind::Value(BaseTypeKind::Enum), "TypeKind.Value.Enum"),
             (TypeKind::Value(BaseTypeKind::Integer), "TypeKind.Value.Integer"),
             (TypeKind::Value(BaseTypeKind::String), "TypeKind.Value.String"),
+            (TypeKind::Value(BaseTypeKind::Timestamp), "TypeKind.Value.Timestamp"),
             (TypeKind::ValueArray(BaseTypeKind::Boolean), "TypeKind.Value.Array"),
             (TypeKind::ValueArray(BaseTypeKind::Bytes), "TypeKind.Value.Array"),
             (TypeKind::ValueArray(BaseTypeKind::Enum), "TypeKind.Value.Array"),
@@ -161,9 +185,12 @@ mod tests {
         let cases = [
             (BaseTypeKind::Boolean, "Boolean"),
             (BaseTypeKind::Bytes, "Bytes"),
+            (BaseTypeKind::Date, "Date"),
+            (BaseTypeKind::Decimal, "Decimal"),
             (BaseTypeKind::Enum, "Enum"),
             (BaseTypeKind::Integer, "Integer"),
             (BaseTypeKind::String, "String"),
+            (BaseTypeKind::Timestamp, "Timestamp"),
         ];
 
         for (base_type_kind, expected) in cases {
@@ -54,6 +54,11 @@ pub enum HolonError {
         max_inclusive: bool,
         descriptor: String,
     },
+    #[error(
+        "Value array has {count} items, out of range for descriptor {descriptor}: \
+        min={min:?}, max={max:?}"
+    )]
+    ItemCountOutOfRange { count: usize, min: Option<i64>, max: Option<i64>, descriptor: String },
     #[error("Invalid HolonReference, {0}")]
     InvalidHolonReference(String),
     #[error("Invalid wire format for {wire_type}: {reason}")]
@@ -82,6 +87,10 @@ pub enum HolonError {
     MissingStagedCollection(String),
     #[error("Missing DescribedBy relationship for holon: {holon}")]
     MissingDescribedBy { holon: String },
+    #[error("Missing binding for required parameter {parameter} of query {query}")]
+    MissingQueryParameter { parameter: String, query: String },
+    #[error("Missing required record field {field} for descriptor {descriptor}")]
+    MissingRecordField { field: String, descriptor: String },
     #[error("Multiple DescribedBy relationships found for holon {holon}: {count}")]
     MultipleDescribedBy { holon: String, count: usize },
     #[error("Multiple Extends relationships found for descriptor {descriptor}: {count}")]
@@ -118,20 +127,63 @@ pub enum HolonError {
     NotImplemented(String),
     #[error("{0}")]
     PvlViolation(PvlViolation),
+    #[error(
+        "Binding for parameter {parameter} of query {query} has type {found}, expected {expected}"
+    )]
+    QueryParameterTypeMismatch { parameter: String, query: String, expected: String, found: String },
     #[error("Couldn't convert Record to {0}")]
     RecordConversion(String),
+    #[error(
+        "Relationship {relationship} would hold {count} members, exceeding max cardinality {max} \
+        declared by descriptor {descriptor}"
+    )]
+    RelationshipCardinalityExceeded {
+        relationship: String,
+        count: usize,
+        max: i64,
+        descriptor: String,
+    },
+    #[error(
+        "Relationship {relationship} target {target} has type {found}, expected {expected} for \
+        descriptor {descriptor}"
+    )]
+    RelationshipEndpointMismatch {
+        relationship: String,
+        target: String,
+        expected: String,
+        found: String,
+        descriptor: String,
+    },
     #[error(
         "Reference context_binding failed for {reference_kind}: {reason} (id: {reference_id:?})"
     )]
     ReferenceBindingFailed { reference_kind: String, reference_id: Option<String>, reason: String },
     #[error("Reference resolution failed for {reference_kind}({reference_id}): {reason}")]
     ReferenceResolutionFailed { reference_kind: String, reference_id: String, reason: String },
+    #[error("Referenced holon has type {found}, expected {expected} for descriptor {descriptor}")]
+    ReferenceTargetTypeMismatch { expected: String, found: String, descriptor: String },
     #[error("Service '{0}' is not available")]
     ServiceNotAvailable(String),
     #[error(
         "String length {length} is out of range for descriptor {descriptor}: min={min:?}, max={max:?}"
     )]
     StringLengthOutOfRange { length: usize, min: Option<i64>, max: Option<i64>, descriptor: String },
+    #[error("String value is not a valid {format} for descriptor {descriptor}")]
+    StringFormatMismatch { format: String, descriptor: String },
+    #[error("String value does not match pattern {pattern} for descriptor {descriptor}")]
+    StringPatternMismatch { pattern: String, descriptor: String },
+    #[error(
+        "Temporal value {value} is out of range for descriptor {descriptor}: min={min:?} \
+        inclusive={min_inclusive}, max={max:?} inclusive={max_inclusive}"
+    )]
+    TemporalOutOfRange {
+        value: String,
+        min: Option<String>,
+        max: Option<String>,
+        min_inclusive: bool,
+        max_inclusive: bool,
+        descriptor: String,
+    },
     #[error("Transaction {tx_id} is already committed")]
     TransactionAlreadyCommitted { tx_id: u64 },
     #[error("Transaction {tx_id} is currently committing and cannot accept external mutations")]
@@ -142,6 +194,8 @@ pub enum HolonError {
     UnableToAddHolons(String),
     #[error("Unable to cast {0} into expected ValueType: {1}")]
     UnexpectedValueType(String, String),
+    #[error("Parameter {parameter} is not declared by query {query}")]
+    UnexpectedQueryParameter { parameter: String, query: String },
     #[error("Unknown operator category: {value}")]
     UnknownOperatorCategory { value: String },
     #[error(
@@ -169,6 +223,7 @@ pub enum SchemaInvalidityKind {
     IncompatibleConstraintFamily,
     UnsupportedExecutableConstraint,
     UnclassifiedConstraint,
+    InvalidConstraintParameter,
 }
 
 // Remove this implementation - no longer needed with RwLock
@@ -198,6 +253,7 @@ pub enum HolonErrorKind {
     HolonNotFound,
     IndexOutOfRange,
     IntegerOutOfRange,
+    ItemCountOutOfRange,
     InvalidHolonReference,
     InvalidWireFormat,
     InvalidState,
@@ -211,6 +267,8 @@ pub enum HolonErrorKind {
     Misc,
     MissingStagedCollection,
     MissingDescribedBy,
+    MissingQueryParameter,
+    MissingRecordField,
     MultipleDescribedBy,
     MultipleExtends,
     CyclicExtends,
@@ -226,16 +284,24 @@ pub enum HolonErrorKind {
     NotAccessible,
     NotImplemented,
     PvlViolation,
+    QueryParameterTypeMismatch,
     RecordConversion,
+    RelationshipCardinalityExceeded,
+    RelationshipEndpointMismatch,
     ReferenceBindingFailed,
     ReferenceResolutionFailed,
+    ReferenceTargetTypeMismatch,
     ServiceNotAvailable,
     StringLengthOutOfRange,
+    StringFormatMismatch,
+    StringPatternMismatch,
+    TemporalOutOfRange,
     TransactionAlreadyCommitted,
     TransactionCommitInProgress,
     TransactionNotOpen,
     UnableToAddHolons,
     UnexpectedValueType,
+    UnexpectedQueryParameter,
     UnknownOperatorCategory,
     UnsupportedOperator,
     UnsupportedStagedTraversal,
@@ -262,6 +328,7 @@ impl From<&HolonError> for HolonErrorKind {
             HolonError::HolonNotFound(_) => Self::HolonNotFound,
             HolonError::IndexOutOfRange(_) => Self::IndexOutOfRange,
             HolonError::IntegerOutOfRange { .. } => Self::IntegerOutOfRange,
+            HolonError::ItemCountOutOfRange { .. } => Self::ItemCountOutOfRange,
             HolonError::InvalidHolonReference(_) => Self::InvalidHolonReference,
             HolonError::InvalidWireFormat { .. } => Self::InvalidWireFormat,
             HolonError::InvalidState(_) => Self::InvalidState,
@@ -275,6 +342,8 @@ impl From<&HolonError> for HolonErrorKind {
             HolonError::Misc(_) => Self::Misc,
             HolonError::MissingStagedCollection(_) => Self::MissingStagedCollection,
             HolonError::MissingDescribedBy { .. } => Self::MissingDescribedBy,
+            HolonError::MissingQueryParameter { .. } => Self::MissingQueryParameter,
+            HolonError::MissingRecordField { .. } => Self::MissingRecordField,
             HolonError::MultipleDescribedBy { .. } => Self::MultipleDescribedBy,
             HolonError::MultipleExtends { .. } => Self::MultipleExtends,
             HolonError::CyclicExtends { .. } => Self::CyclicExtends,
@@ -292,16 +361,26 @@ impl From<&HolonError> for HolonErrorKind {
             HolonError::NotAccessible(_, _) => Self::NotAccessible,
             HolonError::NotImplemented(_) => Self::NotImplemented,
             HolonError::PvlViolation(_) => Self::PvlViolation,
+            HolonError::QueryParameterTypeMismatch { .. } => Self::QueryParameterTypeMismatch,
             HolonError::RecordConversion(_) => Self::RecordConversion,
+            HolonError::RelationshipCardinalityExceeded { .. } => {
+                Self::RelationshipCardinalityExceeded
+            }
+            HolonError::RelationshipEndpointMismatch { .. } => Self::RelationshipEndpointMismatch,
             HolonError::ReferenceBindingFailed { .. } => Self::ReferenceBindingFailed,
             HolonError::ReferenceResolutionFailed { .. } => Self::ReferenceResolutionFailed,
+            HolonError::ReferenceTargetTypeMismatch { .. } => Self::ReferenceTargetTypeMismatch,
             HolonError::ServiceNotAvailable(_) => Self::ServiceNotAvailable,
             HolonError::StringLengthOutOfRange { .. } => Self::StringLengthOutOfRange,
+            HolonError::StringFormatMismatch { .. } => Self::StringFormatMismatch,
+            HolonError::StringPatternMismatch { .. } => Self::StringPatternMismatch,
+            HolonError::TemporalOutOfRange { .. } => Self::TemporalOutOfRange,
             HolonError::TransactionAlreadyCommitted { .. } => Self::TransactionAlreadyCommitted,
             HolonError::TransactionCommitInProgress { .. } => Self::TransactionCommitInProgress,
             HolonError::TransactionNotOpen { .. } => Self::TransactionNotOpen,
             HolonError::UnableToAddHolons(_) => Self::UnableToAddHolons,
             HolonError::UnexpectedValueType(_, _) => Self::UnexpectedValueType,
+            HolonError::UnexpectedQueryParameter { .. } => Self::UnexpectedQueryParameter,
             HolonError::UnknownOperatorCategory { .. } => Self::UnknownOperatorCategory,
             HolonError::UnsupportedOperator { .. } => Self::UnsupportedOperator,
             HolonError::UnsupportedStagedTraversal { .. } => Self::UnsupportedStagedTraversal,
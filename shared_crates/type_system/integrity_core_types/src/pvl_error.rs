@@ -128,6 +128,13 @@ pub enum PvlViolation {
         actual_bytes: u16,
         max_bytes: u16,
     },
+    DecimalValueOutOfRange {
+        property_name: PropertyName,
+        actual_precision: u8,
+        actual_scale: u8,
+        max_precision: u8,
+        max_scale: u8,
+    },
     CollectionTooLarge {
         property_name: PropertyName,
         actual_items: u32,
@@ -227,6 +234,7 @@ impl PvlViolation {
             Self::ValueNestingTooDeep { .. } => "MAP-PVL-1115",
             Self::EmptyEnumValue { .. } => "MAP-PVL-1116",
             Self::MalformedPropertyValue { .. } => "MAP-PVL-1117",
+            Self::DecimalValueOutOfRange { .. } => "MAP-PVL-1119",
             Self::InvalidIdentifier { .. } => "MAP-PVL-1201",
             Self::EmptyIdentifier { .. } => "MAP-PVL-1202",
             Self::IdentifierTooLong { .. } => "MAP-PVL-1203",
@@ -284,6 +292,10 @@ impl fmt::Display for PvlViolation {
             Self::CanonicalKeyTooLarge { max_bytes, .. } => {
                 write!(formatter, "canonical key exceeds {max_bytes}-byte limit")
             }
+            Self::DecimalValueOutOfRange { max_precision, max_scale, .. } => write!(
+                formatter,
+                "decimal value exceeds {max_precision}-digit precision or {max_scale}-digit scale"
+            ),
             Self::CollectionTooLarge { max_items, .. } => {
                 write!(formatter, "collection exceeds {max_items}-item limit")
             }
@@ -371,6 +383,13 @@ mod tests {
                 max_bytes: 1,
             },
             PvlViolation::CanonicalKeyTooLarge { actual_bytes: 1, max_bytes: 1 },
+            PvlViolation::DecimalValueOutOfRange {
+                property_name: name(),
+                actual_precision: 1,
+                actual_scale: 1,
+                max_precision: 1,
+                max_scale: 1,
+            },
             PvlViolation::CollectionTooLarge {
                 property_name: name(),
                 actual_items: 1,
@@ -438,7 +457,7 @@ mod tests {
         let codes: Vec<_> = variants.iter().map(exhaustive_code).collect();
         let unique: HashSet<_> = codes.iter().copied().collect();
 
-        assert_eq!(codes.len(), 30);
+        assert_eq!(codes.len(), 31);
         assert_eq!(unique.len(), codes.len());
         assert!(codes.iter().all(|code| {
             code.strip_prefix("MAP-PVL-").is_some_and(|digits| {
@@ -465,6 +484,7 @@ mod tests {
             | PvlViolation::EnumValueTooLarge { .. }
             | PvlViolation::BytesValueTooLarge { .. }
             | PvlViolation::CanonicalKeyTooLarge { .. }
+            | PvlViolation::DecimalValueOutOfRange { .. }
             | PvlViolation::CollectionTooLarge { .. }
             | PvlViolation::ValueNestingTooDeep { .. }
             | PvlViolation::SmartLinkTagTooLarge { .. }
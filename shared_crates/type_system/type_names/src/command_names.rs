@@ -87,12 +87,15 @@ pub enum CoreCommandTypeName {
     GetVersionedKey,
     GetPropertyValue,
     GetRelatedHolons,
+    GetRelatedHolonsPage,
     WithPropertyValue,
     RemovePropertyValue,
     AddRelatedHolons,
     RemoveRelatedHolons,
     WithDescriptor,
     Commit,
+    ValidateTransaction,
+    SetRelationshipEnforcement,
     UndoLast,
     RedoLast,
     UndoToMarker,
@@ -100,6 +103,11 @@ pub enum CoreCommandTypeName {
     LoadHolons,
     Dance,
     GetAllHolons,
+    GetAllHolonsPage,
+    GetHolonsByDescriptor,
+    GetSavedHolonByKey,
+    GetSavedHolonByVersionedKey,
+    GetSavedHolonsByKeyPrefix,
     GetStagedHolonByBaseKey,
     GetStagedHolonsByBaseKey,
     GetStagedHolonByVersionedKey,
@@ -122,6 +130,7 @@ pub enum CoreDanceImplementationName {
     DeleteHolon,
     Commit,
     LoadHolons,
+    QueryDance,
 }
 
 impl CoreCommandTypeName {
@@ -161,12 +170,15 @@ mod tests {
             CoreCommandTypeName::GetVersionedKey,
             CoreCommandTypeName::GetPropertyValue,
             CoreCommandTypeName::GetRelatedHolons,
+            CoreCommandTypeName::GetRelatedHolonsPage,
             CoreCommandTypeName::WithPropertyValue,
             CoreCommandTypeName::RemovePropertyValue,
             CoreCommandTypeName::AddRelatedHolons,
             CoreCommandTypeName::RemoveRelatedHolons,
             CoreCommandTypeName::WithDescriptor,
             CoreCommandTypeName::Commit,
+            CoreCommandTypeName::ValidateTransaction,
+            CoreCommandTypeName::SetRelationshipEnforcement,
             CoreCommandTypeName::UndoLast,
             CoreCommandTypeName::RedoLast,
             CoreCommandTypeName::UndoToMarker,
@@ -174,6 +186,11 @@ mod tests {
             CoreCommandTypeName::LoadHolons,
             CoreCommandTypeName::Dance,
             CoreCommandTypeName::GetAllHolons,
+            CoreCommandTypeName::GetAllHolonsPage,
+            CoreCommandTypeName::GetHolonsByDescriptor,
+            CoreCommandTypeName::GetSavedHolonByKey,
+            CoreCommandTypeName::GetSavedHolonByVersionedKey,
+            CoreCommandTypeName::GetSavedHolonsByKeyPrefix,
             CoreCommandTypeName::GetStagedHolonByBaseKey,
             CoreCommandTypeName::GetStagedHolonsByBaseKey,
             CoreCommandTypeName::GetStagedHolonByVersionedKey,
@@ -61,6 +61,8 @@ impl ToDanceName for &DanceName {
 
 #[derive(Debug, Clone, VariantNames)]
 pub enum CoreHolonTypeName {
+    AggregateResult,
+    Aggregation,
     BytesValueConstraint,
     Collection,
     CommandType,
@@ -68,8 +70,12 @@ pub enum CoreHolonTypeName {
     Dance,
     DanceDiagnostic,
     DanceType,
+    DateValueConstraint,
     DanceInvocation,
     DeclaredRelationshipType,
+    EmailFormat,
+    ExecutionInstance,
+    ExistsFilter,
     Holon,
     HolonError,
     HolonLoadError,
@@ -78,14 +84,34 @@ pub enum CoreHolonTypeName {
     IntegerValueConstraint,
     InverseRelationshipType,
     MaximumLength,
+    MaximumDate,
+    MaximumItems,
+    MaximumTimestamp,
     MaximumValue,
     MinimumLength,
+    MinimumDate,
+    MinimumItems,
+    MinimumTimestamp,
     MinimumValue,
+    PathTraversal,
+    Pattern,
     Projection,
+    PropertyFilter,
+    Query,
+    QueryExpression,
+    QueryExpressionExecution,
+    QuerySubTree,
+    RelationshipTraversal,
     Schema,
+    SemverFormat,
+    SlugFormat,
     StringValueConstraint,
+    TimestampValueConstraint,
     Transaction,
     TypeDescriptor,
+    TypeFilter,
+    UriFormat,
+    UuidFormat,
     ValueArrayConstraint,
     ValueConstraintType,
 }
@@ -3,6 +3,7 @@ pub mod holon_names;
 pub mod operator_names;
 pub mod property_names;
 pub mod relationship_names;
+pub mod validation_rule_names;
 pub mod value_names;
 
 pub use command_names::*;
@@ -10,4 +11,5 @@ pub use holon_names::*;
 pub use operator_names::*;
 pub use property_names::*;
 pub use relationship_names::*;
+pub use validation_rule_names::*;
 pub use value_names::*;
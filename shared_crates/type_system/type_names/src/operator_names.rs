@@ -79,7 +79,19 @@ impl ToOperatorName for &OperatorName {
 #[derive(Debug, Clone, PartialEq, Eq, VariantNames)]
 pub enum CoreOperatorTypeName {
     EqualsOperator,
+    NotEqualsOperator,
     LessThanOperator,
+    GreaterThanOperator,
+    LessOrEqualOperator,
+    GreaterOrEqualOperator,
+    BetweenOperator,
+    InOperator,
+    StartsWithOperator,
+    ContainsOperator,
+    MatchesOperator,
+    IsNullOperator,
+    HasPrefixOperator,
+    IsPrefixOfOperator,
 }
 
 impl CoreOperatorTypeName {
@@ -102,7 +114,22 @@ mod tests {
     }
 
     fn all_core_operator_type_names() -> Vec<CoreOperatorTypeName> {
-        vec![CoreOperatorTypeName::EqualsOperator, CoreOperatorTypeName::LessThanOperator]
+        vec![
+            CoreOperatorTypeName::EqualsOperator,
+            CoreOperatorTypeName::NotEqualsOperator,
+            CoreOperatorTypeName::LessThanOperator,
+            CoreOperatorTypeName::GreaterThanOperator,
+            CoreOperatorTypeName::LessOrEqualOperator,
+            CoreOperatorTypeName::GreaterOrEqualOperator,
+            CoreOperatorTypeName::BetweenOperator,
+            CoreOperatorTypeName::InOperator,
+            CoreOperatorTypeName::StartsWithOperator,
+            CoreOperatorTypeName::ContainsOperator,
+            CoreOperatorTypeName::MatchesOperator,
+            CoreOperatorTypeName::IsNullOperator,
+            CoreOperatorTypeName::HasPrefixOperator,
+            CoreOperatorTypeName::IsPrefixOfOperator,
+        ]
     }
 
     #[test]
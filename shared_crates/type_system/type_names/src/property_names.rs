@@ -70,20 +70,30 @@ impl ToPropertyName for &PropertyName {
 
 #[derive(Debug, Clone, VariantNames)]
 pub enum CorePropertyTypeName {
+    AggregateFunction,
+    AggregateProperty,
+    AggregateValue,
     AllowsAdditionalProperties,
     AllowsAdditionalRelationships,
     AllowsDuplicates,
     Arity,
+    BlockingBehavior,
+    BlockingResultCount,
     CommitRequestStatus,
     CommitsAttempted,
     Context,
+    ConstraintDateValue,
     ConstraintIntegerValue,
     ConstraintIsInclusive,
+    ConstraintItemCount,
     ConstraintLength,
+    ConstraintPattern,
+    ConstraintTimestampValue,
     DanceDescription,
     DanceDiagnosticSeverity,
     DanceName,
     DanceSummary,
+    DefaultSeverity,
     DefaultValue,
     DeletionSemantic,
     Description,
@@ -94,10 +104,18 @@ pub enum CorePropertyTypeName {
     ErrorCount,
     ErrorMessage,
     ErrorType,
+    ExecutionStatus,
     Filename,
+    FilterOperand,
+    FilterOperator,
+    FilterProperty,
+    FilterTypeName,
     HolonKey,
     HolonId,
     HolonsStaged,
+    ImplementationEngine,
+    ImplementationEntrypoint,
+    ImplementationRef,
     InstanceTypeKind,
     IsAbstractType,
     IsDefinitional,
@@ -113,20 +131,32 @@ pub enum CorePropertyTypeName {
     MapInteger,
     MapString,
     MaxCardinality,
+    MemberCount,
     MinCardinality,
+    MinimumBlockingBehavior,
     OperatorCategory,
+    ParameterName,
+    PathExpression,
     ProxyKey,
     ProxyId,
     PropertyName,
+    QueryName,
     RelationshipName,
     ResponseStatusCode,
+    ResultSeverity,
+    SemanticAuthority,
     SpaceName,
     StartUtf8ByteOffset,
+    SubjectPath,
     TotalBundles,
     TotalLoaderHolons,
     Type,
     TypeName,
     TypeNamePlural,
+    ValidatedHolonCount,
+    ValidationLevel,
+    ValidationMessage,
+    ValidationRuleDescription,
     HolonsCommitted,
 }
 
@@ -73,51 +73,85 @@ pub enum CoreRelationshipTypeName {
     AffordsDanceInvocation,
     AffordsOperator,
     AffordsTransactionModel,
+    AppliesTo,
+    BindsParameter,
     BundleMembers,
+    CollectionMembers,
     CommandAffordedBy,
     ComponentOf,
+    Condition,
     Constraints,
     Contains,
     DanceAffordedBy,
+    DefaultBinding,
     RequestType,
     RequestTypeFor,
     Dependents,
     DependsOn,
     DescribedBy,
     Diagnostics,
+    ElementType,
     ElementValueType,
     ElementValueTypeFor,
+    ExecutesExpression,
+    ExecutesQuery,
+    ExecutionResult,
+    ExpressionExecutions,
+    ExpressionParameters,
+    ExtendedBy,
     Extends,
     ForDance,
+    GroupMembers,
     HasInverse,
     HasImplementation,
     HasLoadError,
     HasRelationshipReference,
+    HasValidationBinding,
+    ImplementedBy,
+    ImplementsRule,
+    InitialInput,
+    Input,
     InstanceKeyRule,
     InstanceProperties,
     InstanceRelationshipFor,
     InstanceRelationships,
     Instances,
     InverseOf,
+    Next,
     OwnedBy,
     Owns,
+    ParameterBindingType,
     Predecessor,
+    ProducedByRule,
     Properties,
     PropertyName,
     Request,
+    RequestedQuery,
+    RequestParameters,
+    RecordFields,
+    RecordFieldFor,
     ReferenceSource,
     ReferenceTarget,
+    ReferenceTargetType,
+    ReferenceTargetTypeFor,
+    ReportsResult,
     Response,
     ResponseBody,
     ResponseBodyFor,
     ResponseFor,
+    Result,
+    RootExpression,
+    RuntimeParameters,
     SavedHolons,
     SourceOf,
     SourceType,
+    Subtree,
     Successor,
     TargetOf,
     TargetType,
     TransactionModelAffordedBy,
+    UsesRule,
+    ValidatedHolon,
     ValidationRuleAffordedBy,
     Validations,
     ValueType,
@@ -0,0 +1,77 @@
+use base_types::MapString;
+use convert_case::{Case, Casing};
+use strum_macros::VariantNames;
+
+/// Seeded Core `ValidationRule` type names that have a built-in runtime implementation.
+///
+/// Each variant matches the `type_name` of a `<Name>.ValidationRule` holon in
+/// `schema-src/validation/schema.tdl`. Rules declared by the schema but absent
+/// here are bound without an implementation and skipped by the runtime engine.
+#[derive(Debug, Clone, PartialEq, Eq, VariantNames)]
+pub enum CoreValidationRuleName {
+    // Holon level
+    AtMostOneDirectParent,
+    AcyclicExtendsLineage,
+    ExactlyOneDescribedBy,
+    ConcreteDescribingType,
+    NoUndescribedProperties,
+    ExplicitKeylessness,
+    KeyPresenceAndValue,
+    // Property level
+    RequiredPropertyPresence,
+    PropertyValueConformance,
+    // Value level
+    BaseValueKindMatchesString,
+    StringLength,
+    StringPattern,
+    StringFormat,
+    BaseValueKindMatchesInteger,
+    IntegerRange,
+    BaseValueKindMatchesDecimal,
+    BaseValueKindMatchesTimestamp,
+    TimestampRange,
+    BaseValueKindMatchesDate,
+    DateRange,
+    BaseValueKindMatchesBoolean,
+    BaseValueKindMatchesEnum,
+    EnumTokenMembership,
+    BaseValueKindMatchesBytes,
+    BaseValueKindMatchesValueArray,
+    ValueArrayItemCount,
+    ValueArrayElementConformance,
+    BaseValueKindMatchesHolonReference,
+    HolonReferenceTargetType,
+    BaseValueKindMatchesRecord,
+    RecordFieldsDeclared,
+    RecordFieldConformance,
+    // Relationship level
+    RelationshipEndpointCompatibility,
+    RelationshipCollectionPolicy,
+    RelationshipCardinality,
+}
+
+impl CoreValidationRuleName {
+    /// Canonical validation rule type name in ClassCase (UpperCamel).
+    pub fn as_rule_name(&self) -> MapString {
+        MapString(format!("{self:?}").to_case(Case::UpperCamel))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::VariantNames as _;
+
+    #[test]
+    fn test_variant_string_conversion() {
+        assert_eq!(
+            MapString("RequiredPropertyPresence".to_string()),
+            CoreValidationRuleName::RequiredPropertyPresence.as_rule_name()
+        );
+        assert_eq!(
+            MapString("BaseValueKindMatchesHolonReference".to_string()),
+            CoreValidationRuleName::BaseValueKindMatchesHolonReference.as_rule_name()
+        );
+        assert!(CoreValidationRuleName::VARIANTS.contains(&"RelationshipCardinality"));
+    }
+}
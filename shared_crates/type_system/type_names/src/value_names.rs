@@ -5,22 +5,34 @@ use strum_macros::VariantNames;
 #[derive(Debug, Clone, VariantNames)]
 pub enum CoreValueTypeName {
     DanceDiagnosticSeverity,
+    DateValueType,
+    DecimalValueType,
+    HolonReferenceValueType,
     IntegerValueType,
     InvocationSource,
     MapBytesValueType,
     MapEnumValueType,
+    MapHolonReferenceValueType,
     MapValueArrayType,
+    RecordValueType,
     StringValueType,
+    TimestampValueType,
     ValueBoolean,
     ValueBytes,
+    ValueDate,
+    ValueDecimal,
     ValueEnum,
     ValueInteger,
     ValueString,
+    ValueTimestamp,
     ValueArrayBoolean,
     ValueArrayBytes,
+    ValueArrayDate,
+    ValueArrayDecimal,
     ValueArrayEnum,
     ValueArrayInteger,
     ValueArrayString,
+    ValueArrayTimestamp,
 }
 
 impl CoreValueTypeName {
@@ -36,6 +48,18 @@ mod tests {
 
     #[test]
     fn test_variant_string_conversion() {
+        assert_eq!(
+            MapString("DateValueType".to_string()),
+            CoreValueTypeName::DateValueType.as_value_name()
+        );
+        assert_eq!(
+            MapString("DecimalValueType".to_string()),
+            CoreValueTypeName::DecimalValueType.as_value_name()
+        );
+        assert_eq!(
+            MapString("HolonReferenceValueType".to_string()),
+            CoreValueTypeName::HolonReferenceValueType.as_value_name()
+        );
         assert_eq!(
             MapString("IntegerValueType".to_string()),
             CoreValueTypeName::IntegerValueType.as_value_name()
@@ -48,14 +72,26 @@ mod tests {
             MapString("MapEnumValueType".to_string()),
             CoreValueTypeName::MapEnumValueType.as_value_name()
         );
+        assert_eq!(
+            MapString("MapHolonReferenceValueType".to_string()),
+            CoreValueTypeName::MapHolonReferenceValueType.as_value_name()
+        );
         assert_eq!(
             MapString("MapValueArrayType".to_string()),
             CoreValueTypeName::MapValueArrayType.as_value_name()
         );
+        assert_eq!(
+            MapString("RecordValueType".to_string()),
+            CoreValueTypeName::RecordValueType.as_value_name()
+        );
         assert_eq!(
             MapString("StringValueType".to_string()),
             CoreValueTypeName::StringValueType.as_value_name()
         );
+        assert_eq!(
+            MapString("TimestampValueType".to_string()),
+            CoreValueTypeName::TimestampValueType.as_value_name()
+        );
         assert_eq!(
             MapString("ValueBoolean".to_string()),
             CoreValueTypeName::ValueBoolean.as_value_name()
@@ -64,6 +100,14 @@ mod tests {
             MapString("ValueBytes".to_string()),
             CoreValueTypeName::ValueBytes.as_value_name()
         );
+        assert_eq!(
+            MapString("ValueDate".to_string()),
+            CoreValueTypeName::ValueDate.as_value_name()
+        );
+        assert_eq!(
+            MapString("ValueDecimal".to_string()),
+            CoreValueTypeName::ValueDecimal.as_value_name()
+        );
         assert_eq!(
             MapString("ValueEnum".to_string()),
             CoreValueTypeName::ValueEnum.as_value_name()
@@ -76,6 +120,10 @@ mod tests {
             MapString("ValueString".to_string()),
             CoreValueTypeName::ValueString.as_value_name()
         );
+        assert_eq!(
+            MapString("ValueTimestamp".to_string()),
+            CoreValueTypeName::ValueTimestamp.as_value_name()
+        );
         assert_eq!(
             MapString("ValueArrayBoolean".to_string()),
             CoreValueTypeName::ValueArrayBoolean.as_value_name()
@@ -84,6 +132,14 @@ mod tests {
             MapString("ValueArrayBytes".to_string()),
             CoreValueTypeName::ValueArrayBytes.as_value_name()
         );
+        assert_eq!(
+            MapString("ValueArrayDate".to_string()),
+            CoreValueTypeName::ValueArrayDate.as_value_name()
+        );
+        assert_eq!(
+            MapString("ValueArrayDecimal".to_string()),
+            CoreValueTypeName::ValueArrayDecimal.as_value_name()
+        );
         assert_eq!(
             MapString("ValueArrayEnum".to_string()),
             CoreValueTypeName::ValueArrayEnum.as_value_name()
@@ -96,5 +152,9 @@ mod tests {
             MapString("ValueArrayString".to_string()),
             CoreValueTypeName::ValueArrayString.as_value_name()
         );
+        assert_eq!(
+            MapString("ValueArrayTimestamp".to_string()),
+            CoreValueTypeName::ValueArrayTimestamp.as_value_name()
+        );
     }
 }
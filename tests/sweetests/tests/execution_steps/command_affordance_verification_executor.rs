@@ -40,6 +40,8 @@ const STABLE_COMMAND_TYPES: &[(&str, CoreCommandTypeName)] = &[
     ("RemoveRelatedHolons.CommandType", CoreCommandTypeName::RemoveRelatedHolons),
     ("WithDescriptor.CommandType", CoreCommandTypeName::WithDescriptor),
     ("Commit.CommandType", CoreCommandTypeName::Commit),
+    ("ValidateTransaction.CommandType", CoreCommandTypeName::ValidateTransaction),
+    ("SetRelationshipEnforcement.CommandType", CoreCommandTypeName::SetRelationshipEnforcement),
     ("UndoLast.CommandType", CoreCommandTypeName::UndoLast),
     ("RedoLast.CommandType", CoreCommandTypeName::RedoLast),
     ("UndoToMarker.CommandType", CoreCommandTypeName::UndoToMarker),
@@ -87,6 +89,8 @@ const HOLON_TYPE_AFFORDED_COMMANDS: &[CoreCommandTypeName] = &[
 // but is not itself a command available from an active transaction model.
 const TRANSACTION_AFFORDED_COMMANDS: &[CoreCommandTypeName] = &[
     CoreCommandTypeName::Commit,
+    CoreCommandTypeName::ValidateTransaction,
+    CoreCommandTypeName::SetRelationshipEnforcement,
     CoreCommandTypeName::UndoLast,
     CoreCommandTypeName::RedoLast,
     CoreCommandTypeName::UndoToMarker,
@@ -650,8 +650,13 @@ pub async fn execute_verify_book_person_descriptors(state: &mut TestExecutionSta
 /// corresponding inverse edge on the target, so this asserts both directions:
 /// - forward: Book --AuthoredBy--> Person and Book --DescribedBy--> Book.HolonType
 /// - inverse: Person --AuthorOf--> Book and Book.HolonType --Instances--> Book
+///
+/// The same commit indexed each instance under its descriptor, so the descriptor
+/// index is read back as well: per type, and through the shared `HolonType`
+/// supertype only when subtypes are included.
 pub async fn execute_verify_book_person_instance_links(state: &mut TestExecutionState) {
-    let holons = loaded_holons(state, "verify_book_person_instance_links").await;
+    let step_name = "verify_book_person_instance_links";
+    let holons = loaded_holons(state, step_name).await;
 
     let book = find_holon_by_key(&holons, BOOK_KEY);
     let person = find_holon_by_key(&holons, PERSON_1_KEY);
@@ -668,6 +673,24 @@ pub async fn execute_verify_book_person_instance_links(state: &mut TestExecution
     assert_contains(&related_holon_keys(&book_type, "Instances"), BOOK_KEY);
     assert_contains(&related_holon_keys(&person_type, "Instances"), PERSON_1_KEY);
 
+    // Descriptor instance index.
+    let book_instances = holons_by_descriptor(state, step_name, &book_type, false).await;
+    assert_eq!(holon_keys(&book_instances), vec![BOOK_KEY.to_string()]);
+    let person_instances = holons_by_descriptor(state, step_name, &person_type, false).await;
+    assert_eq!(holon_keys(&person_instances), vec![PERSON_1_KEY.to_string()]);
+
+    let holon_type = find_holon_by_key(&holons, HOLON_TYPE_KEY);
+    let direct_instances =
+        holon_keys(&holons_by_descriptor(state, step_name, &holon_type, false).await);
+    assert!(
+        !direct_instances.iter().any(|key| key == BOOK_KEY || key == PERSON_1_KEY),
+        "expected HolonType's own index to exclude subtype instances, got {direct_instances:?}"
+    );
+    let subtype_instances =
+        holon_keys(&holons_by_descriptor(state, step_name, &holon_type, true).await);
+    assert_contains(&subtype_instances, BOOK_KEY);
+    assert_contains(&subtype_instances, PERSON_1_KEY);
+
     info!("verified bidirectional Book/Person instance SmartLink traversal");
 }
 
@@ -784,6 +807,47 @@ async fn loaded_holons(state: &mut TestExecutionState, step_name: &str) -> Holon
     }
 }
 
+async fn holons_by_descriptor(
+    state: &mut TestExecutionState,
+    step_name: &str,
+    descriptor: &HolonReference,
+    include_subtypes: bool,
+) -> HolonCollection {
+    let context = state.open_assertion_context(step_name).await.unwrap_or_else(|error| {
+        panic!("{step_name}: failed to open assertion transaction: {error:?}")
+    });
+
+    let command = MapCommand::Transaction(TransactionCommand {
+        context: context.clone(),
+        action: TransactionAction::GetHolonsByDescriptor {
+            descriptor: descriptor.clone(),
+            include_subtypes,
+        },
+    });
+    let result = state
+        .dispatch_command(command, step_name)
+        .await
+        .unwrap_or_else(|error| panic!("{step_name}: get_holons_by_descriptor failed: {error:?}"));
+
+    match result {
+        MapResult::Collection(collection) => collection,
+        other => panic!("{step_name}: expected Collection, got {other:?}"),
+    }
+}
+
+fn holon_keys(holons: &HolonCollection) -> Vec<String> {
+    holons
+        .get_members()
+        .iter()
+        .filter_map(|holon| {
+            holon
+                .key()
+                .unwrap_or_else(|error| panic!("key read failed: {error:?}"))
+                .map(|key| key.0)
+        })
+        .collect()
+}
+
 fn find_holon_by_key(holons: &HolonCollection, key: &str) -> HolonReference {
     holons
         .get_by_key(&MapString::from(key))
@@ -10,18 +10,20 @@
 //! root-addressed `Update` actions, and that Integrity accepts the topology this layer produces
 //! while rejecting the topology it refuses to produce.
 
-use base_types::{BaseValue, MapString};
+use base_types::{BaseValue, MapInteger, MapString};
 use core_types::{
-    CanonicalKey, HolonId, HolonWriteRequest, LineageId, PreparedSmartLink, PutSmartLinkOutcome,
-    StoredHolonNode,
+    CanonicalKey, CanonicalKeyPrefix, HolonId, HolonWriteRequest, KeyMatch, LineageId,
+    PreparedSmartLink, PutSmartLinkOutcome, StoredHolonNode,
 };
 use holochain::prelude::{Action, ActionHash, Record};
+use holons_core::core_shared_objects::{ReadableHolonState, SavedHolon};
 use holons_test::harness::helpers::{
     assert_commit_rejected_with_pvl, setup_probe_enabled_conductor, setup_test_conductor,
 };
 use holons_test::MockConductorConfig;
 use integrity_core_types::{HolonNodeModel, LocalId, PropertyMap, PropertyName, RelationshipName};
 use std::collections::BTreeMap;
+use type_names::{CorePropertyTypeName, ToPropertyName};
 
 const ZOME: &str = "holons";
 const PROBE_ZOME: &str = "holons_test_probes";
@@ -40,6 +42,14 @@ fn node(title: &str) -> HolonNodeModel {
     )]))
 }
 
+/// A node carrying a `Key` property, so publishing it indexes it under `holon_keys`.
+fn keyed_node(key: &str) -> HolonNodeModel {
+    HolonNodeModel::new(BTreeMap::from([(
+        CorePropertyTypeName::Key.as_property_name(),
+        BaseValue::StringValue(MapString(key.to_string())),
+    )]))
+}
+
 fn title_of(stored: &StoredHolonNode) -> String {
     match stored.holon_node.property_map.get(&PropertyName(MapString("title".to_string()))) {
         Some(BaseValue::StringValue(value)) => value.0.clone(),
@@ -77,6 +87,13 @@ async fn get_holons(
     backend.conductor.call(&backend.cell.zome(ZOME), "holon_storage_get_many", ids).await
 }
 
+async fn ids_by_key(backend: &MockConductorConfig, key_match: KeyMatch) -> Vec<HolonId> {
+    backend
+        .conductor
+        .call(&backend.cell.zome(ZOME), "holon_storage_get_ids_by_key", key_match)
+        .await
+}
+
 /// A well-formed action hash that was never persisted.
 ///
 /// Built through `ActionHash` rather than from hand-written bytes so the multihash prefix is
@@ -584,3 +601,48 @@ async fn integrity_accepts_an_update_targeting_a_lineage_root() {
         get_holon(&backend, &authored).await.expect("the authored update must be readable");
     assert_eq!(stored.version_metadata.lineage_id, Some(LineageId(root_id)));
 }
+
+// ---------------------------------------------------------------------------
+// Key index
+// ---------------------------------------------------------------------------
+
+/// Publishing a version that renames the key moves the lineage root's index entry: the root is
+/// found under the new key, by exact match and by prefix, and no longer under the old one.
+#[tokio::test(flavor = "multi_thread")]
+async fn publishing_a_renamed_version_moves_the_key_index_entry() {
+    let backend = setup_test_conductor().await;
+    let root =
+        persist(&backend, HolonWriteRequest::PublishRoot { holon_node: keyed_node("Emerson") })
+            .await;
+    let root_id = root.version_metadata.version_id.clone();
+
+    let version = persist(
+        &backend,
+        HolonWriteRequest::PublishVersion {
+            holon_node: keyed_node("Emerson, Ralph Waldo"),
+            predecessor_ids: vec![root_id.clone()],
+        },
+    )
+    .await;
+    let version_id = version.version_metadata.version_id.clone();
+
+    let old_key = KeyMatch::Exact(CanonicalKey::new("Emerson").unwrap());
+    let new_key = KeyMatch::Exact(CanonicalKey::new("Emerson, Ralph Waldo").unwrap());
+    let new_prefix = KeyMatch::StartsWith(CanonicalKeyPrefix::new("Emerson, R").unwrap());
+
+    assert!(ids_by_key(&backend, old_key).await.is_empty());
+    assert_eq!(ids_by_key(&backend, new_key).await, vec![HolonId::Local(version_id.clone())]);
+    assert_eq!(ids_by_key(&backend, new_prefix).await, vec![HolonId::Local(version_id.clone())]);
+
+    // The hit is the current version, so it carries the key it was found under rather than the
+    // root's, and its versioned key is built from that key.
+    let found = get_holon(&backend, &version_id).await.expect("the current version is persisted");
+    let saved = SavedHolon::new(
+        found.version_metadata.version_id,
+        found.holon_node.property_map,
+        found.version_metadata.lineage_id.map(LineageId::into_local_id),
+        MapInteger(1),
+    );
+    assert_eq!(saved.key(), Some(MapString("Emerson, Ralph Waldo".to_string())));
+    assert_eq!(saved.versioned_key().unwrap(), MapString("Emerson, Ralph Waldo1".to_string()));
+}
@@ -8,3 +8,9 @@ pub struct Diagnostic {
 pub fn format_diagnostics(diagnostics: &[Diagnostic]) -> String {
     diagnostics.iter().map(|diagnostic| diagnostic.message.as_str()).collect::<Vec<_>>().join("\n")
 }
+
+impl From<map_schema_semantic::Diagnostic> for Diagnostic {
+    fn from(diagnostic: map_schema_semantic::Diagnostic) -> Self {
+        Self { message: diagnostic.to_string() }
+    }
+}
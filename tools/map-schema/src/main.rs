@@ -43,7 +43,7 @@ enum Commands {
         out_dir: Option<PathBuf>,
     },
 
-    /// Validate TDL syntax and lowering diagnostics.
+    /// Validate TDL syntax, lowering, and meta-level schema rules.
     Check {
         /// Input TDL files or directories containing TDL files.
         inputs: Vec<PathBuf>,
@@ -107,6 +107,9 @@ fn main() -> Result<()> {
                 check_inputs(&inputs)?
             };
             print!("{}", render_check_output(&diagnostics));
+            if !diagnostics.is_empty() {
+                std::process::exit(1);
+            }
         }
         Commands::RoundtripJson { inputs, tdl_out, json_out } => {
             if inputs.is_empty() {
@@ -141,7 +144,9 @@ Commands:
       Convert TDL files into generated loader JSON. Compile works over a corpus.
 
   check [TDL_FILE_OR_DIR ...]
-      Validate TDL syntax and lowering constraints without writing JSON.
+      Validate TDL syntax, lowering constraints, and meta-level schema rules
+      without writing JSON. Rule violations are reported with their file and
+      line, and make the command exit with status 1.
 
   roundtrip-json [JSON_FILE_OR_DIR ...] --tdl-out <DIR> --json-out <DIR>
       Decompile JSON to scratch TDL, recompile that TDL to canonical JSON,
@@ -166,7 +171,9 @@ Notes:
   supplied when preserving cross-file source relationships.
 
   Compile validates TDL syntax and lowering constraints across the files passed
-  in the same invocation. Descriptor semantics remain outside source tooling.
+  in the same invocation. Check additionally enforces the descriptor rules
+  that can be decided from source alone, such as acyclic Extends lineage and
+  inverse endpoint correspondence; instance-level validation stays in the guest.
 "#
 }
 
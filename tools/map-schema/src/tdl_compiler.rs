@@ -1,9 +1,10 @@
 use crate::diagnostics::{format_diagnostics, Diagnostic};
 use anyhow::{anyhow, Context, Result};
+use map_schema_semantic as semantic;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     fs,
     path::{Path, PathBuf},
 };
@@ -91,6 +92,7 @@ struct ParsedTdlFile {
 #[derive(Debug, Clone)]
 struct TdlSchema {
     name: String,
+    line: usize,
     dependencies: Vec<String>,
     literal_properties: TdlLiteralObject,
     literal_relationships: Vec<LiteralRelationship>,
@@ -103,6 +105,7 @@ struct TdlSchema {
 struct TdlDescriptor {
     kind: DescriptorKind,
     name: String,
+    line: usize,
     header: Option<DescriptorHeader>,
     is_generic_instance: bool,
     is_abstract: bool,
@@ -165,8 +168,10 @@ pub fn compile_input_string(raw: &str, source_name: impl Into<PathBuf>) -> Resul
 }
 
 pub fn check_inputs(inputs: &[PathBuf]) -> Result<Vec<Diagnostic>> {
-    build_r6_compilation(parse_inputs(inputs)?)?;
-    Ok(Vec::new())
+    let parsed_files = parse_inputs(inputs)?;
+    let diagnostics = check_schema_rules(&parsed_files)?;
+    build_r6_compilation(parsed_files)?;
+    Ok(diagnostics)
 }
 
 /// Renders the CLI output for `map-schema:check`.
@@ -187,8 +192,195 @@ pub fn render_check_output(diagnostics: &[Diagnostic]) -> String {
 pub fn check_input_string(raw: &str, source_name: impl Into<PathBuf>) -> Result<Vec<Diagnostic>> {
     let source_name = source_name.into();
     let parsed = parse_tdl_file(raw, &source_name)?;
+    let diagnostics = check_schema_rules(std::slice::from_ref(&parsed))?;
     build_r6_compilation(vec![parsed])?;
-    Ok(Vec::new())
+    Ok(diagnostics)
+}
+
+/// Runs the meta-level schema rules over the semantic lowering of `parsed_files`.
+///
+/// Rule diagnostics carry the TDL file and declaration line of the offending descriptor. Index
+/// diagnostics are not reported: a document checked on its own routinely references descriptors
+/// declared in other files, and the rules skip references that do not resolve.
+fn check_schema_rules(parsed_files: &[ParsedTdlFile]) -> Result<Vec<Diagnostic>> {
+    let mut model = lower_semantic_model(parsed_files)?;
+    let (index, _) = semantic::SymbolIndex::build(&mut model);
+    Ok(semantic::check_schema_rules(&model, &index).into_iter().map(Diagnostic::from).collect())
+}
+
+/// Lowers parsed TDL files into one Canonical Holon IR model.
+///
+/// Each schema is declared once even when its descriptors span several files. A descriptor's
+/// `type` clause is kept as a literal `DescribedBy` relationship, matching the holon shape of
+/// JSON imports.
+fn lower_semantic_model(parsed_files: &[ParsedTdlFile]) -> Result<semantic::SemanticModel> {
+    let mut model = semantic::SemanticModel::new();
+    let mut declared_schemas = HashSet::new();
+
+    for parsed in parsed_files {
+        let schema = &parsed.schema;
+        if declared_schemas.insert(schema.name.clone()) {
+            model.push_schema(semantic::Schema {
+                name: schema.name.clone(),
+                key: schema.name.clone(),
+                origin: tdl_origin(parsed, schema.line),
+                dependencies: schema
+                    .dependencies
+                    .iter()
+                    .map(|dependency| {
+                        semantic::SemanticReference::unresolved(
+                            semantic::ReferenceRole::DependsOn,
+                            dependency,
+                        )
+                    })
+                    .collect(),
+                literal_properties: semantic_literal_object(&schema.literal_properties),
+                literal_relationships: semantic_literal_relationships(
+                    &schema.literal_relationships,
+                ),
+                header: schema.header.as_ref().map(semantic_header),
+                allows_additional_properties: schema.allows_additional_properties,
+                allows_additional_relationships: schema.allows_additional_relationships,
+            });
+        }
+
+        for descriptor in &parsed.descriptors {
+            model.push_descriptor(lower_semantic_descriptor(parsed, descriptor)?);
+        }
+    }
+
+    Ok(model)
+}
+
+fn lower_semantic_descriptor(
+    parsed: &ParsedTdlFile,
+    descriptor: &TdlDescriptor,
+) -> Result<semantic::TypeDescriptor> {
+    use semantic::ReferenceRole as Role;
+
+    let key = descriptor_key_r6(descriptor)?;
+    let kind = match descriptor.kind {
+        DescriptorKind::HolonType => semantic::DescriptorKind::HolonType,
+        DescriptorKind::ValueType => semantic::DescriptorKind::ValueType,
+        DescriptorKind::Enum => semantic::DescriptorKind::Enum,
+        DescriptorKind::PropertyType => semantic::DescriptorKind::PropertyType,
+        DescriptorKind::RelationshipType => semantic::DescriptorKind::RelationshipType,
+        DescriptorKind::EnumVariant => semantic::DescriptorKind::EnumVariant,
+    };
+    let mut lowered = semantic::TypeDescriptor::new(
+        key.clone(),
+        local_type_name(descriptor, &key),
+        kind,
+        parsed.schema.name.clone(),
+        tdl_origin(parsed, descriptor.line),
+    );
+
+    let mut references = Vec::new();
+    if !descriptor.is_generic_instance {
+        references.push((Role::ComponentOf, parsed.schema.name.clone()));
+    }
+    for (role, target) in [
+        (Role::Extends, &descriptor.extends),
+        (Role::KeyRule, &descriptor.key_rule),
+        (Role::ValueType, &descriptor.value_type),
+        (Role::SourceType, &descriptor.source_type),
+        (Role::TargetType, &descriptor.target_type),
+        (Role::InverseOf, &descriptor.inverse_of),
+        (Role::HasInverse, &descriptor.has_inverse),
+        (Role::VariantOf, &descriptor.variant_of),
+    ] {
+        references.extend(target.iter().map(|target| (role, target.clone())));
+    }
+    for (role, targets) in [
+        (Role::Variants, &descriptor.variants),
+        (Role::InstanceProperty, &descriptor.instance_properties),
+        (Role::InstanceRelationship, &descriptor.instance_relationships),
+    ] {
+        references.extend(targets.iter().map(|target| (role, target.clone())));
+    }
+    for (role, target) in references {
+        semantic::push_reference(
+            &mut lowered,
+            semantic::SemanticReference::unresolved(role, target),
+        );
+    }
+
+    lowered.header = descriptor.header.as_ref().map(semantic_header);
+    lowered.is_abstract = descriptor.is_abstract;
+    lowered.relationship_flavor = descriptor.relationship_flavor.map(|flavor| match flavor {
+        RelationshipFlavor::Declared => semantic::RelationshipFlavor::Declared,
+        RelationshipFlavor::Inverse => semantic::RelationshipFlavor::Inverse,
+    });
+    lowered.is_definitional = descriptor.is_definitional;
+    lowered.min_cardinality = descriptor.min_cardinality;
+    lowered.max_cardinality = descriptor.max_cardinality;
+    lowered.deletion_semantic = descriptor.deletion_semantic.clone();
+    lowered.is_ordered = descriptor.is_ordered;
+    lowered.allows_duplicates = descriptor.allows_duplicates;
+    lowered.allows_additional_properties = descriptor.allows_additional_properties;
+    lowered.allows_additional_relationships = descriptor.allows_additional_relationships;
+    lowered.literal_properties = semantic_literal_object(&descriptor.literal_properties);
+    lowered.literal_relationships =
+        semantic_literal_relationships(&descriptor.literal_relationships);
+    if let Some(descriptor_type) = &descriptor.descriptor_type {
+        lowered.literal_relationships.push(semantic::LiteralRelationship {
+            name: "DescribedBy".to_string(),
+            targets: vec![descriptor_type.clone()],
+        });
+    }
+
+    Ok(lowered)
+}
+
+fn tdl_origin(parsed: &ParsedTdlFile, line: usize) -> semantic::Origin {
+    semantic::Origin::tdl_file(&parsed.relative_path, u32::try_from(line).ok(), None)
+}
+
+fn semantic_header(header: &DescriptorHeader) -> semantic::DescriptorHeader {
+    semantic::DescriptorHeader {
+        description: header.description.clone(),
+        display_name: header.display_name.clone(),
+        display_name_plural: header.display_name_plural.clone(),
+        type_name_plural: header.type_name_plural.clone(),
+    }
+}
+
+fn semantic_literal_relationships(
+    relationships: &[LiteralRelationship],
+) -> Vec<semantic::LiteralRelationship> {
+    relationships
+        .iter()
+        .map(|relationship| semantic::LiteralRelationship {
+            name: relationship.name.clone(),
+            targets: relationship.targets.clone(),
+        })
+        .collect()
+}
+
+fn semantic_literal_object(object: &TdlLiteralObject) -> semantic::LiteralObject {
+    let mut lowered = semantic::LiteralObject::new();
+    lowered.extend(
+        object
+            .iter()
+            .map(|(name, value)| (canonical_property_name(name), semantic_literal_value(value))),
+    );
+    lowered
+}
+
+fn semantic_literal_value(value: &TdlLiteralValue) -> semantic::LiteralValue {
+    match value {
+        TdlLiteralValue::Null => semantic::LiteralValue::Null,
+        TdlLiteralValue::Boolean(value) => semantic::LiteralValue::Boolean(*value),
+        TdlLiteralValue::Integer(value) => semantic::LiteralValue::Integer(*value),
+        TdlLiteralValue::Number(value) => semantic::LiteralValue::Number(value.clone()),
+        TdlLiteralValue::String(value) => semantic::LiteralValue::String(value.clone()),
+        TdlLiteralValue::Array(values) => {
+            semantic::LiteralValue::Array(values.iter().map(semantic_literal_value).collect())
+        }
+        TdlLiteralValue::Object(object) => {
+            semantic::LiteralValue::Object(semantic_literal_object(object))
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -916,6 +1108,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_schema_decl(&mut self) -> Result<TdlSchema> {
+        let line_number = self.line_number();
         let line = self.consume_trimmed().unwrap();
         let header = parse_inline_header(&line, "schema")?;
         let name = header.name;
@@ -967,6 +1160,7 @@ impl<'a> Parser<'a> {
 
         Ok(TdlSchema {
             name,
+            line: line_number,
             dependencies,
             literal_properties,
             literal_relationships,
@@ -977,6 +1171,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_descriptor_decl(&mut self, variant_of: Option<String>) -> Result<TdlDescriptor> {
+        let line_number = self.line_number();
         let line = self.consume_trimmed().unwrap();
         let parsed = parse_descriptor_header(&line)?;
         let declaration_name = parsed.name.clone();
@@ -985,6 +1180,7 @@ impl<'a> Parser<'a> {
         let mut descriptor = TdlDescriptor {
             kind: parsed.kind,
             name: parsed.name,
+            line: line_number,
             header: None,
             is_generic_instance: parsed.is_generic_instance,
             is_abstract: parsed.is_abstract,
@@ -1171,6 +1367,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_variant_decl(&mut self, variant_of: Option<String>) -> Result<TdlDescriptor> {
+        let line_number = self.line_number();
         let line = self.consume_trimmed().unwrap();
         let parsed = parse_descriptor_header(&line)?;
         if parsed.kind != DescriptorKind::EnumVariant {
@@ -1182,6 +1379,7 @@ impl<'a> Parser<'a> {
         let mut descriptor = TdlDescriptor {
             kind: DescriptorKind::EnumVariant,
             name: parsed.name,
+            line: line_number,
             header: None,
             is_generic_instance: parsed.is_generic_instance,
             is_abstract: parsed.is_abstract,
@@ -1397,6 +1595,11 @@ impl<'a> Parser<'a> {
         false
     }
 
+    /// One-based source line of the next unconsumed line.
+    fn line_number(&self) -> usize {
+        self.index + 1
+    }
+
     fn peek_raw(&self) -> Option<&'a str> {
         self.lines.get(self.index).copied()
     }
@@ -2078,6 +2281,67 @@ holon Example.HolonType {
             .contains("relationship maps must use a newline-oriented braced block"));
     }
 
+    #[test]
+    fn check_reports_schema_rule_violations_at_declaration_lines() -> Result<()> {
+        let diagnostics = check_input_string(
+            r#"schema Example Schema-v0.0.1
+
+holon Agent.HolonType {
+  type MetaHolonType.MetaTypeDescriptor
+  extends Person.HolonType
+  relationships {
+    InstanceProperties -> Name.PropertyType
+  }
+}
+
+holon Person.HolonType {
+  type MetaHolonType.MetaTypeDescriptor
+  extends Agent.HolonType
+}
+
+holon Employee.HolonType {
+  type MetaHolonType.MetaTypeDescriptor
+  extends Agent.HolonType
+  relationships {
+    InstanceProperties -> Name.PropertyType
+  }
+}
+
+enum Color.MapEnumValueType {
+  type MetaEnumValueType.MetaValueType
+  variants {
+    variant Red {
+      type MetaEnumVariantValueType.MetaValueType
+    }
+  }
+}
+
+property Shade.PropertyType {
+  type MetaPropertyType.MetaTypeDescriptor
+  value Color.MapEnumValueType
+  DefaultValue "Blue"
+}
+"#,
+            "rules.tdl",
+        )?;
+
+        let messages = render_check_output(&diagnostics);
+        assert_eq!(diagnostics.len(), 3, "unexpected diagnostics: {messages}");
+        assert!(messages.contains(
+            "AcyclicExtendsLineage: descriptor `Agent.HolonType` has a cyclic Extends lineage \
+             Agent.HolonType -> Person.HolonType -> Agent.HolonType (rules.tdl:3)"
+        ));
+        assert!(messages.contains(
+            "DefaultValueConformance: property `Shade.PropertyType` default value `Blue` is not a \
+             member of `Color.MapEnumValueType` (expected one of Red) (rules.tdl:33)"
+        ));
+        assert!(messages.contains(
+            "NoInheritedMemberRedeclaration: descriptor `Employee.HolonType` redeclares inherited \
+             member `Name.PropertyType` (rules.tdl:16)"
+        ));
+        Ok(())
+    }
+
     #[test]
     fn core_schema_check_accepts_tdl_v09_corpus() -> Result<()> {
         let fixture_root = fixture_dir();
@@ -2160,8 +2424,7 @@ holon Example.HolonType {
         let out_dir = temp_out_dir();
         compile_inputs(&[fixture_dir()], &out_dir)?;
 
-        let relationship_json =
-            fs::read_to_string(out_dir.join("core/relationship-types.json"))?;
+        let relationship_json = fs::read_to_string(out_dir.join("core/relationship-types.json"))?;
         let relationship_value: Value = serde_json::from_str(&relationship_json)?;
         let component_of = relationship_value["holons"]
             .as_array()
@@ -2192,8 +2455,7 @@ holon Example.HolonType {
         let out_dir = temp_out_dir();
         compile_inputs(&[fixture_dir()], &out_dir)?;
 
-        let relationship_json =
-            fs::read_to_string(out_dir.join("core/relationship-types.json"))?;
+        let relationship_json = fs::read_to_string(out_dir.join("core/relationship-types.json"))?;
         let relationship_value: Value = serde_json::from_str(&relationship_json)?;
         let instance_properties = relationship_value["holons"]
             .as_array()
@@ -2239,9 +2501,9 @@ holon Example.HolonType {
         let affordance = clone_holon["relationships"]
             .as_array()
             .and_then(|relationships| {
-                relationships.iter().find(|relationship| {
-                    relationship["name"].as_str() == Some("CommandAffordedBy")
-                })
+                relationships
+                    .iter()
+                    .find(|relationship| relationship["name"].as_str() == Some("CommandAffordedBy"))
             })
             .expect("CloneHolon CommandAffordedBy relationship");
         assert_eq!(affordance["target"], json!([{ "$ref": "HolonType.TypeDescriptor" }]));
@@ -2255,8 +2517,7 @@ holon Example.HolonType {
         let out_dir = temp_out_dir();
         compile_inputs(&[fixture_dir()], &out_dir)?;
 
-        let property_json =
-            fs::read_to_string(out_dir.join("core/property-types.json"))?;
+        let property_json = fs::read_to_string(out_dir.join("core/property-types.json"))?;
         let property_value: Value = serde_json::from_str(&property_json)?;
         let max_cardinality = property_value["holons"]
             .as_array()